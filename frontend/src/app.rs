@@ -4,15 +4,18 @@ use yew_router::prelude::*;
 use crate::components::{protected_route::ProtectedRoute, organizer_route::OrganizerRoute};
 use crate::pages::{
     home::Home, login::Login, signup::Signup, auth_callback::AuthCallback,
-    my_talks::MyTalks, not_found::NotFound, submit_talk::SubmitTalk,
+    my_talks::MyTalks, my_availability::MyAvailability, not_found::NotFound,
+    submit_talk::SubmitTalk,
     speaker_dashboard::SpeakerDashboard, organizer_talks::OrganizerTalks,
     organizer_dashboard::OrganizerDashboard, organizer_labels::OrganizerLabels,
-    ratings_dashboard::RatingsDashboard, manage_tracks::ManageTracks,
+    ratings_dashboard::RatingsDashboard, review_queue::ReviewQueue, bulk_decisions::BulkDecisions,
+    compare_talks::CompareTalks,
+    manage_tracks::ManageTracks,
     manage_schedule_slots::ManageScheduleSlots, assign_talks::AssignTalks,
     schedule_builder::ScheduleBuilder, public_schedule::PublicSchedule,
     manage_email_templates::ManageEmailTemplates, bulk_email::BulkEmail,
     export_talks::ExportTalks, ai_auto_tag::AIAutoTag,
-    configuration::Configuration,
+    configuration::Configuration, speaker_invitation::SpeakerInvitation,
 };
 use crate::services::auth::AuthService;
 
@@ -32,8 +35,12 @@ pub enum Route {
     SubmitTalk,
     #[at("/talks/mine")]
     MyTalks,
+    #[at("/speaker-invitations/:token")]
+    SpeakerInvitation { token: String },
     #[at("/speaker/dashboard")]
     SpeakerDashboard,
+    #[at("/speaker/availability")]
+    MyAvailability,
     #[at("/organizer/dashboard")]
     OrganizerDashboard,
     #[at("/organizer/talks")]
@@ -42,6 +49,12 @@ pub enum Route {
     OrganizerLabels,
     #[at("/organizer/ratings")]
     RatingsDashboard,
+    #[at("/organizer/review-queue")]
+    ReviewQueue,
+    #[at("/organizer/compare")]
+    CompareTalks,
+    #[at("/organizer/decisions")]
+    BulkDecisions,
     #[at("/organizer/tracks")]
     ManageTracks,
     #[at("/organizer/schedule-slots")]
@@ -82,11 +95,21 @@ fn switch(routes: Route) -> Html {
                 <MyTalks />
             </ProtectedRoute>
         },
+        Route::SpeakerInvitation { token } => html! {
+            <ProtectedRoute>
+                <SpeakerInvitation token={token} />
+            </ProtectedRoute>
+        },
         Route::SpeakerDashboard => html! {
             <ProtectedRoute>
                 <SpeakerDashboard />
             </ProtectedRoute>
         },
+        Route::MyAvailability => html! {
+            <ProtectedRoute>
+                <MyAvailability />
+            </ProtectedRoute>
+        },
         Route::OrganizerDashboard => html! {
             <OrganizerRoute>
                 <OrganizerDashboard />
@@ -107,6 +130,21 @@ fn switch(routes: Route) -> Html {
                 <RatingsDashboard />
             </OrganizerRoute>
         },
+        Route::ReviewQueue => html! {
+            <OrganizerRoute>
+                <ReviewQueue />
+            </OrganizerRoute>
+        },
+        Route::CompareTalks => html! {
+            <OrganizerRoute>
+                <CompareTalks />
+            </OrganizerRoute>
+        },
+        Route::BulkDecisions => html! {
+            <OrganizerRoute>
+                <BulkDecisions />
+            </OrganizerRoute>
+        },
         Route::ManageTracks => html! {
             <OrganizerRoute>
                 <ManageTracks />
@@ -196,6 +234,7 @@ fn app_content() -> Html {
                         <>
                             <Link<Route> to={Route::SpeakerDashboard}>{ "Dashboard" }</Link<Route>>
                             <Link<Route> to={Route::MyTalks}>{ "My Talks" }</Link<Route>>
+                            <Link<Route> to={Route::MyAvailability}>{ "My Availability" }</Link<Route>>
                             <Link<Route> to={Route::SubmitTalk}>{ "Submit Talk" }</Link<Route>>
                             if *is_organizer {
                                 <Link<Route> to={Route::OrganizerDashboard}>{ "Dashboard" }</Link<Route>>
@@ -206,6 +245,9 @@ fn app_content() -> Html {
                                 <Link<Route> to={Route::AssignTalks}>{ "Assign Talks" }</Link<Route>>
                                 <Link<Route> to={Route::ScheduleBuilder}>{ "Schedule Builder" }</Link<Route>>
                                 <Link<Route> to={Route::RatingsDashboard}>{ "Ratings Dashboard" }</Link<Route>>
+                                <Link<Route> to={Route::ReviewQueue}>{ "Review Queue" }</Link<Route>>
+                                <Link<Route> to={Route::CompareTalks}>{ "Compare Talks" }</Link<Route>>
+                                <Link<Route> to={Route::BulkDecisions}>{ "Bulk Decisions" }</Link<Route>>
                                 <Link<Route> to={Route::ManageEmailTemplates}>{ "Email Templates" }</Link<Route>>
                                 <Link<Route> to={Route::BulkEmail}>{ "Bulk Email" }</Link<Route>>
                                 <Link<Route> to={Route::ExportTalks}>{ "Export Talks" }</Link<Route>>
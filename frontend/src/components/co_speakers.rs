@@ -0,0 +1,140 @@
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::{
+    services::talks::TalkService,
+    types::{TalkSpeaker, TalkSpeakerStatus},
+};
+
+#[derive(Properties, PartialEq)]
+pub struct CoSpeakersProps {
+    pub talk_id: String,
+    pub speakers: Vec<TalkSpeaker>,
+}
+
+#[function_component(CoSpeakers)]
+pub fn co_speakers(props: &CoSpeakersProps) -> Html {
+    let speakers = use_state(|| props.speakers.clone());
+    let email = use_state(|| String::new());
+    let error = use_state(|| None::<String>);
+    let inviting = use_state(|| false);
+
+    let on_email_input = {
+        let email = email.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            email.set(input.value());
+        })
+    };
+
+    let on_invite = {
+        let talk_id = props.talk_id.clone();
+        let speakers = speakers.clone();
+        let email = email.clone();
+        let error = error.clone();
+        let inviting = inviting.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            let talk_id = talk_id.clone();
+            let speakers = speakers.clone();
+            let email = email.clone();
+            let error = error.clone();
+            let inviting = inviting.clone();
+            let address = (*email).trim().to_string();
+
+            if address.is_empty() {
+                return;
+            }
+
+            error.set(None);
+            inviting.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::invite_co_speaker(&talk_id, address).await {
+                    Ok(speaker) => {
+                        let mut current = (*speakers).clone();
+                        current.push(speaker);
+                        speakers.set(current);
+                        email.set(String::new());
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+                inviting.set(false);
+            });
+        })
+    };
+
+    let create_remove_handler = |invitation_id: String| {
+        let talk_id = props.talk_id.clone();
+        let speakers = speakers.clone();
+        let error = error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let invitation_id = invitation_id.clone();
+            let speakers = speakers.clone();
+            let error = error.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::remove_co_speaker(&talk_id, &invitation_id).await {
+                    Ok(_) => {
+                        let current: Vec<TalkSpeaker> = (*speakers)
+                            .iter()
+                            .filter(|s| s.invitation_id.as_deref() != Some(invitation_id.as_str()))
+                            .cloned()
+                            .collect();
+                        speakers.set(current);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="talk-speakers">
+            <span class="meta-label">{ "Speakers:" }</span>
+            <ul class="speaker-list">
+                {
+                    for speakers.iter().map(|speaker| {
+                        let status = match speaker.status {
+                            TalkSpeakerStatus::Invited => " (invited)",
+                            _ => "",
+                        };
+                        html! {
+                            <li>
+                                { &speaker.full_name }
+                                { status }
+                                if let Some(invitation_id) = speaker.invitation_id.clone() {
+                                    <button
+                                        class="label-remove-btn"
+                                        onclick={create_remove_handler(invitation_id)}
+                                        aria-label={format!("Remove {}", speaker.full_name)}
+                                    >
+                                        { "×" }
+                                    </button>
+                                }
+                            </li>
+                        }
+                    })
+                }
+            </ul>
+            <form class="invite-form" onsubmit={on_invite}>
+                <input
+                    type="email"
+                    placeholder="Co-speaker email"
+                    value={(*email).clone()}
+                    oninput={on_email_input}
+                    disabled={*inviting}
+                />
+                <button type="submit" class="btn-secondary" disabled={*inviting}>
+                    { if *inviting { "Inviting..." } else { "Invite Co-speaker" } }
+                </button>
+            </form>
+            if let Some(err) = (*error).as_ref() {
+                <div class="error-message">{ err }</div>
+            }
+        </div>
+    }
+}
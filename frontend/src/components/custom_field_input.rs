@@ -0,0 +1,171 @@
+use serde_json::Value;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::types::{CustomField, CustomFieldType};
+
+#[derive(Properties, PartialEq)]
+pub struct CustomFieldInputProps {
+    pub field: CustomField,
+    pub value: Option<Value>,
+    pub on_change: Callback<Value>,
+    #[prop_or_default]
+    pub disabled: bool,
+}
+
+/// Renders one organizer-defined question on the submission form
+#[function_component(CustomFieldInput)]
+pub fn custom_field_input(props: &CustomFieldInputProps) -> Html {
+    let field = &props.field;
+    let id = format!("custom-{}", field.field_key);
+
+    let input = match field.field_type {
+        CustomFieldType::Text => {
+            let on_change = props.on_change.clone();
+            let value = props.value.as_ref().and_then(|v| v.as_str()).unwrap_or("").to_string();
+            html! {
+                <input
+                    type="text"
+                    id={id.clone()}
+                    value={value}
+                    disabled={props.disabled}
+                    required={field.is_required}
+                    onchange={Callback::from(move |e: Event| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        on_change.emit(Value::String(input.value()));
+                    })}
+                />
+            }
+        }
+        CustomFieldType::Number => {
+            let on_change = props.on_change.clone();
+            let value = props.value.as_ref().map(|v| v.to_string()).unwrap_or_default();
+            html! {
+                <input
+                    type="number"
+                    id={id.clone()}
+                    value={value}
+                    disabled={props.disabled}
+                    required={field.is_required}
+                    onchange={Callback::from(move |e: Event| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        let raw = input.value();
+                        let number = raw
+                            .parse::<i64>()
+                            .map(Value::from)
+                            .ok()
+                            .or_else(|| {
+                                raw.parse::<f64>()
+                                    .ok()
+                                    .and_then(serde_json::Number::from_f64)
+                                    .map(Value::Number)
+                            })
+                            .unwrap_or(Value::Null);
+                        on_change.emit(number);
+                    })}
+                />
+            }
+        }
+        CustomFieldType::Checkbox => {
+            let on_change = props.on_change.clone();
+            let checked = props.value.as_ref().and_then(|v| v.as_bool()).unwrap_or(false);
+            html! {
+                <input
+                    type="checkbox"
+                    id={id.clone()}
+                    checked={checked}
+                    disabled={props.disabled}
+                    onchange={Callback::from(move |e: Event| {
+                        let input: HtmlInputElement = e.target_unchecked_into();
+                        on_change.emit(Value::Bool(input.checked()));
+                    })}
+                />
+            }
+        }
+        CustomFieldType::Select => {
+            let on_change = props.on_change.clone();
+            let selected = props.value.as_ref().and_then(|v| v.as_str()).unwrap_or("").to_string();
+            html! {
+                <select
+                    id={id.clone()}
+                    disabled={props.disabled}
+                    required={field.is_required}
+                    onchange={Callback::from(move |e: Event| {
+                        let select: HtmlSelectElement = e.target_unchecked_into();
+                        on_change.emit(Value::String(select.value()));
+                    })}
+                >
+                    <option value="" selected={selected.is_empty()}>{ "-- Select --" }</option>
+                    {
+                        for field.options.iter().map(|option| html! {
+                            <option value={option.clone()} selected={*option == selected}>{ option }</option>
+                        })
+                    }
+                </select>
+            }
+        }
+        CustomFieldType::MultiSelect => {
+            let current: Vec<String> = props
+                .value
+                .as_ref()
+                .and_then(|v| v.as_array())
+                .map(|items| items.iter().filter_map(|i| i.as_str().map(str::to_string)).collect())
+                .unwrap_or_default();
+            html! {
+                <div class="custom-field-options">
+                    {
+                        for field.options.iter().map(|option| {
+                            let on_change = props.on_change.clone();
+                            let current = current.clone();
+                            let option_value = option.clone();
+                            html! {
+                                <label class="custom-field-option">
+                                    <input
+                                        type="checkbox"
+                                        checked={current.contains(option)}
+                                        disabled={props.disabled}
+                                        onchange={Callback::from(move |_| {
+                                            let mut selected = current.clone();
+                                            if let Some(pos) = selected.iter().position(|s| s == &option_value) {
+                                                selected.remove(pos);
+                                            } else {
+                                                selected.push(option_value.clone());
+                                            }
+                                            on_change.emit(Value::Array(
+                                                selected.into_iter().map(Value::String).collect(),
+                                            ));
+                                        })}
+                                    />
+                                    { option }
+                                </label>
+                            }
+                        })
+                    }
+                </div>
+            }
+        }
+    };
+
+    html! {
+        <div class="form-group">
+            <label for={id}>
+                { format!("{} ", field.label) }
+                {
+                    if field.is_required {
+                        html! { <span class="required">{ "*" }</span> }
+                    } else {
+                        html! { <span class="optional">{ "(Optional)" }</span> }
+                    }
+                }
+            </label>
+            { input }
+            {
+                if let Some(help) = &field.help_text {
+                    html! { <small class="field-help">{ help }</small> }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
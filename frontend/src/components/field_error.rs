@@ -0,0 +1,17 @@
+use yew::prelude::*;
+use crate::types::FormError;
+
+#[derive(Properties, PartialEq)]
+pub struct FieldErrorMessageProps {
+    pub error: Option<FormError>,
+    pub field: AttrValue,
+}
+
+/// Shows the API's validation message for one input, if it has one
+#[function_component(FieldErrorMessage)]
+pub fn field_error_message(props: &FieldErrorMessageProps) -> Html {
+    match props.error.as_ref().and_then(|e| e.for_field(&props.field)) {
+        Some(message) => html! { <small class="field-error">{ message }</small> },
+        None => html! {},
+    }
+}
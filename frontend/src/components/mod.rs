@@ -3,8 +3,20 @@ pub mod label_badge;
 pub mod organizer_route;
 pub mod rating_stars;
 pub mod rating_form;
+pub mod co_speakers;
+pub mod custom_field_input;
+pub mod revision_history;
+pub mod talk_discussion;
+pub mod speaker_feedback_editor;
+pub mod field_error;
 
 pub use label_badge::LabelBadge;
 pub use organizer_route::OrganizerRoute;
 pub use rating_stars::RatingStars;
 pub use rating_form::RatingForm;
+pub use co_speakers::CoSpeakers;
+pub use custom_field_input::CustomFieldInput;
+pub use revision_history::RevisionHistory;
+pub use talk_discussion::TalkDiscussion;
+pub use speaker_feedback_editor::SpeakerFeedbackEditor;
+pub use field_error::FieldErrorMessage;
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use yew::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::HtmlTextAreaElement;
@@ -5,13 +6,24 @@ use web_sys::HtmlTextAreaElement;
 use crate::{
     components::rating_stars::RatingStars,
     services::ratings::RatingService,
-    types::Rating,
+    types::{CriterionScore, Rating, RubricCriterion},
 };
 
+/// Criterion scores of a rating, keyed by criterion ID
+fn scores_by_criterion(rating: &Rating) -> HashMap<String, i32> {
+    rating
+        .scores
+        .iter()
+        .map(|s| (s.criterion_id.clone(), s.score))
+        .collect()
+}
+
 #[derive(Properties, PartialEq)]
 pub struct RatingFormProps {
     /// ID of the talk being rated
     pub talk_id: String,
+    /// Conference whose review rubric is rendered
+    pub conference_id: String,
     /// Existing rating if user has already rated this talk
     #[prop_or_default]
     pub existing_rating: Option<Rating>,
@@ -32,6 +44,14 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
             .map(|r| r.rating)
             .unwrap_or(0)
     });
+    let scores = use_state(|| {
+        props
+            .existing_rating
+            .as_ref()
+            .map(scores_by_criterion)
+            .unwrap_or_default()
+    });
+    let rubric = use_state(Vec::<RubricCriterion>::new);
     let notes = use_state(|| {
         props
             .existing_rating
@@ -45,14 +65,32 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
 
     let notes_ref = use_node_ref();
 
+    // Load the conference's rubric; without one a single rating is given
+    {
+        let rubric = rubric.clone();
+        let error = error.clone();
+        use_effect_with(props.conference_id.clone(), move |conference_id| {
+            let conference_id = conference_id.clone();
+            spawn_local(async move {
+                match RatingService::get_rubric(&conference_id).await {
+                    Ok(criteria) => rubric.set(criteria),
+                    Err(e) => error.set(Some(format!("Failed to load review rubric: {}", e))),
+                }
+            });
+            || ()
+        });
+    }
+
     // Update form when existing_rating prop changes
     {
         let rating = rating.clone();
+        let scores = scores.clone();
         let notes = notes.clone();
         let existing_rating = props.existing_rating.clone();
         use_effect_with(existing_rating, move |existing_rating| {
             if let Some(r) = existing_rating {
                 rating.set(r.rating);
+                scores.set(scores_by_criterion(r));
                 notes.set(r.notes.clone().unwrap_or_default());
             }
             || ()
@@ -66,6 +104,15 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
         })
     };
 
+    let on_score = {
+        let scores = scores.clone();
+        Callback::from(move |(criterion_id, score): (String, i32)| {
+            let mut updated = (*scores).clone();
+            updated.insert(criterion_id, score);
+            scores.set(updated);
+        })
+    };
+
     let on_notes_change = {
         let notes = notes.clone();
         Callback::from(move |e: Event| {
@@ -77,6 +124,8 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
     let on_submit = {
         let talk_id = props.talk_id.clone();
         let rating = rating.clone();
+        let scores = scores.clone();
+        let rubric = rubric.clone();
         let notes = notes.clone();
         let is_loading = is_loading.clone();
         let error = error.clone();
@@ -86,13 +135,28 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
 
-            if *rating == 0 {
-                error.set(Some("Please select a rating".to_string()));
-                return;
-            }
+            let (rating_value, score_values) = if rubric.is_empty() {
+                if *rating == 0 {
+                    error.set(Some("Please select a rating".to_string()));
+                    return;
+                }
+                (Some(*rating), Vec::new())
+            } else {
+                if let Some(missing) = rubric.iter().find(|c| !scores.contains_key(&c.id)) {
+                    error.set(Some(format!("Please score '{}'", missing.name)));
+                    return;
+                }
+                let values = rubric
+                    .iter()
+                    .map(|c| CriterionScore {
+                        criterion_id: c.id.clone(),
+                        score: scores[&c.id],
+                    })
+                    .collect();
+                (None, values)
+            };
 
             let talk_id = talk_id.clone();
-            let rating_value = *rating;
             let notes_value = if notes.trim().is_empty() {
                 None
             } else {
@@ -108,8 +172,13 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
             success_message.set(None);
 
             spawn_local(async move {
-                match RatingService::create_or_update_rating(&talk_id, rating_value, notes_value)
-                    .await
+                match RatingService::create_or_update_rating(
+                    &talk_id,
+                    rating_value,
+                    score_values,
+                    notes_value,
+                )
+                .await
                 {
                     Ok(new_rating) => {
                         success_message.set(Some("Rating saved successfully!".to_string()));
@@ -132,6 +201,7 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
         let error = error.clone();
         let success_message = success_message.clone();
         let rating = rating.clone();
+        let scores = scores.clone();
         let notes = notes.clone();
         let on_delete = props.on_delete.clone();
 
@@ -141,6 +211,7 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
             let error = error.clone();
             let success_message = success_message.clone();
             let rating = rating.clone();
+            let scores = scores.clone();
             let notes = notes.clone();
             let on_delete = on_delete.clone();
 
@@ -153,6 +224,7 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
                     Ok(()) => {
                         success_message.set(Some("Rating deleted successfully!".to_string()));
                         rating.set(0);
+                        scores.set(HashMap::new());
                         notes.set(String::new());
                         if let Some(callback) = on_delete {
                             callback.emit(());
@@ -172,15 +244,69 @@ pub fn rating_form(props: &RatingFormProps) -> Html {
     html! {
         <div class="rating-form">
             <form onsubmit={on_submit}>
-                <div class="form-group">
-                    <label class="form-label">{"Your Rating"}</label>
-                    <RatingStars
-                        rating={*rating}
-                        interactive={true}
-                        on_rate={on_rate}
-                        size="large"
-                    />
-                </div>
+                {
+                    if rubric.is_empty() {
+                        html! {
+                            <div class="form-group">
+                                <label class="form-label">{"Your Rating"}</label>
+                                <RatingStars
+                                    rating={*rating}
+                                    interactive={true}
+                                    on_rate={on_rate}
+                                    size="large"
+                                />
+                            </div>
+                        }
+                    } else {
+                        html! {
+                            <div class="rubric-scores">
+                                {
+                                    for rubric.iter().map(|criterion| {
+                                        let criterion_id = criterion.id.clone();
+                                        let on_score = on_score.clone();
+                                        let on_rate = Callback::from(move |score: i32| {
+                                            on_score.emit((criterion_id.clone(), score));
+                                        });
+                                        html! {
+                                            <div class="form-group rubric-criterion" key={criterion.id.clone()}>
+                                                <label class="form-label">
+                                                    { &criterion.name }
+                                                    <span class="criterion-weight">
+                                                        { format!(" (weight {})", criterion.weight) }
+                                                    </span>
+                                                </label>
+                                                {
+                                                    if let Some(description) = &criterion.description {
+                                                        html! { <p class="criterion-description">{ description }</p> }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
+                                                <RatingStars
+                                                    rating={scores.get(&criterion.id).copied().unwrap_or(0)}
+                                                    interactive={true}
+                                                    on_rate={on_rate}
+                                                    size="medium"
+                                                />
+                                            </div>
+                                        }
+                                    })
+                                }
+                                {
+                                    if let Some(weighted) = props.existing_rating.as_ref().and_then(|r| r.weighted_score) {
+                                        html! {
+                                            <div class="weighted-score">
+                                                { format!("Weighted score: {:.2}", weighted) }
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        }
+                    }
+                }
 
                 <div class="form-group">
                     <label class="form-label" for="rating-notes">
@@ -0,0 +1,137 @@
+use yew::prelude::*;
+
+use crate::{
+    services::talks::TalkService,
+    types::{TalkRevision, TalkRevisionDiff},
+};
+
+#[derive(Properties, PartialEq)]
+pub struct RevisionHistoryProps {
+    pub talk_id: String,
+}
+
+#[function_component(RevisionHistory)]
+pub fn revision_history(props: &RevisionHistoryProps) -> Html {
+    let revisions = use_state(|| Vec::<TalkRevision>::new());
+    let diff = use_state(|| None::<TalkRevisionDiff>);
+    let error = use_state(|| None::<String>);
+
+    // Fetch the revision list on mount
+    {
+        let talk_id = props.talk_id.clone();
+        let revisions = revisions.clone();
+        let error = error.clone();
+        use_effect_with(talk_id.clone(), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::list_revisions(&talk_id).await {
+                    Ok(fetched) => revisions.set(fetched),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let create_compare_handler = |revision_number: i32| {
+        let talk_id = props.talk_id.clone();
+        let diff = diff.clone();
+        let error = error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let diff = diff.clone();
+            let error = error.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::diff_revisions(&talk_id, revision_number - 1, revision_number).await {
+                    Ok(fetched) => diff.set(Some(fetched)),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    if revisions.len() < 2 && error.is_none() {
+        return html! {};
+    }
+
+    html! {
+        <details class="revision-history">
+            <summary>{ format!("Revision history ({} revisions)", revisions.len()) }</summary>
+
+            {
+                if let Some(err) = (*error).as_ref() {
+                    html! { <div class="error-message">{ err }</div> }
+                } else {
+                    html! {}
+                }
+            }
+
+            <ul class="revision-list">
+                {
+                    for revisions.iter().rev().map(|revision| {
+                        html! {
+                            <li key={revision.revision_number}>
+                                <span class="revision-number">{ format!("#{}", revision.revision_number) }</span>
+                                { " " }
+                                <span class="revision-date">{ &revision.created_at }</span>
+                                {
+                                    if let Some(name) = &revision.edited_by_name {
+                                        html! { <span class="revision-editor">{ format!(" by {}", name) }</span> }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if revision.revision_number > 1 {
+                                        html! {
+                                            <button
+                                                class="btn-link"
+                                                onclick={create_compare_handler(revision.revision_number)}
+                                            >
+                                                { "Compare with previous" }
+                                            </button>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </li>
+                        }
+                    })
+                }
+            </ul>
+
+            {
+                if let Some(diff) = (*diff).as_ref() {
+                    html! {
+                        <div class="revision-diff">
+                            <h4>{ format!("Changes from #{} to #{}", diff.from_revision, diff.to_revision) }</h4>
+                            {
+                                if diff.changes.is_empty() {
+                                    html! { <p>{ "No content changes." }</p> }
+                                } else {
+                                    html! {
+                                        <dl>
+                                            {
+                                                for diff.changes.iter().map(|change| html! {
+                                                    <>
+                                                        <dt>{ &change.field }</dt>
+                                                        <dd class="diff-old">{ change.old_value.clone().unwrap_or_default() }</dd>
+                                                        <dd class="diff-new">{ change.new_value.clone().unwrap_or_default() }</dd>
+                                                    </>
+                                                })
+                                            }
+                                        </dl>
+                                    }
+                                }
+                            }
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </details>
+    }
+}
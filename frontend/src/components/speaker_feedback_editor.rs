@@ -0,0 +1,221 @@
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use crate::{
+    services::feedback::FeedbackService,
+    types::{FeedbackRequest, TalkFeedback},
+};
+
+#[derive(Properties, PartialEq)]
+pub struct SpeakerFeedbackEditorProps {
+    pub talk_id: String,
+}
+
+/// Organizer view of the feedback written for a talk's speakers. Feedback
+/// is only released to the speakers once approved.
+#[function_component(SpeakerFeedbackEditor)]
+pub fn speaker_feedback_editor(props: &SpeakerFeedbackEditorProps) -> Html {
+    let feedback = use_state(|| Vec::<TalkFeedback>::new());
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+    let new_body = use_state(String::new);
+    let editing = use_state(|| None::<String>);
+    let edit_body = use_state(String::new);
+
+    // Fetch the feedback on mount and after every change
+    {
+        let talk_id = props.talk_id.clone();
+        let feedback = feedback.clone();
+        let error = error.clone();
+        use_effect_with((talk_id.clone(), *reload), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match FeedbackService::list_feedback(&talk_id).await {
+                    Ok(fetched) => feedback.set(fetched),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    // Run a change, then refresh the list or show the error
+    let run = {
+        let error = error.clone();
+        let reload = reload.clone();
+        move |action: std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>>>>| {
+            let error = error.clone();
+            let reload = reload.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match action.await {
+                    Ok(()) => {
+                        error.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        }
+    };
+
+    let on_new_input = {
+        let new_body = new_body.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            new_body.set(input.value());
+        })
+    };
+
+    let on_create = {
+        let talk_id = props.talk_id.clone();
+        let new_body = new_body.clone();
+        let run = run.clone();
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let body = (*new_body).clone();
+            new_body.set(String::new());
+            run(Box::pin(async move {
+                FeedbackService::create_feedback(&talk_id, FeedbackRequest { body })
+                    .await
+                    .map(|_| ())
+            }));
+        })
+    };
+
+    let on_edit_input = {
+        let edit_body = edit_body.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            edit_body.set(input.value());
+        })
+    };
+
+    html! {
+        <details class="speaker-feedback-editor">
+            <summary>{ format!("Feedback for the speaker ({})", feedback.len()) }</summary>
+
+            {
+                if let Some(err) = (*error).as_ref() {
+                    html! { <div class="error-message">{ err }</div> }
+                } else {
+                    html! {}
+                }
+            }
+
+            <ul class="feedback-list">
+                {
+                    for feedback.iter().map(|entry| {
+                        let id = entry.id.clone();
+                        let is_editing = editing.as_ref() == Some(&id);
+
+                        let on_start_edit = {
+                            let editing = editing.clone();
+                            let edit_body = edit_body.clone();
+                            let id = id.clone();
+                            let body = entry.body.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                edit_body.set(body.clone());
+                                editing.set(Some(id.clone()));
+                            })
+                        };
+                        let on_save = {
+                            let editing = editing.clone();
+                            let edit_body = edit_body.clone();
+                            let run = run.clone();
+                            let id = id.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let id = id.clone();
+                                let body = (*edit_body).clone();
+                                editing.set(None);
+                                run(Box::pin(async move {
+                                    FeedbackService::update_feedback(&id, FeedbackRequest { body })
+                                        .await
+                                        .map(|_| ())
+                                }));
+                            })
+                        };
+                        let on_cancel = {
+                            let editing = editing.clone();
+                            Callback::from(move |_: MouseEvent| editing.set(None))
+                        };
+                        let on_toggle_approval = {
+                            let run = run.clone();
+                            let id = id.clone();
+                            let approve = entry.approved_at.is_none();
+                            Callback::from(move |_: MouseEvent| {
+                                let id = id.clone();
+                                run(Box::pin(async move {
+                                    FeedbackService::set_approved(&id, approve).await.map(|_| ())
+                                }));
+                            })
+                        };
+                        let on_delete = {
+                            let run = run.clone();
+                            let id = id.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let id = id.clone();
+                                run(Box::pin(async move { FeedbackService::delete_feedback(&id).await }));
+                            })
+                        };
+
+                        html! {
+                            <li key={id.clone()} class="feedback-entry">
+                                <div class="feedback-meta">
+                                    { format!("By {}", entry.author_name) }
+                                    {
+                                        match &entry.approved_by_name {
+                                            Some(name) => html! {
+                                                <span class="feedback-approved">{ format!(" · Approved by {}", name) }</span>
+                                            },
+                                            None if entry.approved_at.is_some() => html! {
+                                                <span class="feedback-approved">{ " · Approved" }</span>
+                                            },
+                                            None => html! {
+                                                <span class="feedback-draft">{ " · Awaiting approval" }</span>
+                                            },
+                                        }
+                                    }
+                                </div>
+                                {
+                                    if is_editing {
+                                        html! {
+                                            <div class="comment-form">
+                                                <textarea value={(*edit_body).clone()} oninput={on_edit_input.clone()} rows="4" />
+                                                <button class="btn btn-primary btn-small" onclick={on_save}>{ "Save" }</button>
+                                                <button class="btn btn-secondary btn-small" onclick={on_cancel}>{ "Cancel" }</button>
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {
+                                            <>
+                                                <p class="feedback-body">{ &entry.body }</p>
+                                                <div class="comment-actions">
+                                                    <button class="btn-link" onclick={on_toggle_approval}>
+                                                        { if entry.approved_at.is_some() { "Withdraw approval" } else { "Approve" } }
+                                                    </button>
+                                                    <button class="btn-link" onclick={on_start_edit}>{ "Edit" }</button>
+                                                    <button class="btn-link" onclick={on_delete}>{ "Delete" }</button>
+                                                </div>
+                                            </>
+                                        }
+                                    }
+                                }
+                            </li>
+                        }
+                    })
+                }
+            </ul>
+
+            <div class="comment-form">
+                <textarea
+                    value={(*new_body).clone()}
+                    oninput={on_new_input}
+                    rows="4"
+                    placeholder="Shown to the speakers once approved and the talk is decided."
+                />
+                <button class="btn btn-primary btn-small" onclick={on_create}>
+                    { "Add feedback" }
+                </button>
+            </div>
+        </details>
+    }
+}
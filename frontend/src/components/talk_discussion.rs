@@ -0,0 +1,310 @@
+use web_sys::HtmlTextAreaElement;
+use yew::prelude::*;
+
+use crate::{
+    services::{auth::AuthService, comments::CommentService},
+    types::{CreateCommentRequest, TalkComment, UpdateCommentRequest},
+};
+
+#[derive(Properties, PartialEq)]
+pub struct TalkDiscussionProps {
+    pub talk_id: String,
+}
+
+/// What the comment form below a comment is being used for
+#[derive(Clone, PartialEq)]
+enum Draft {
+    Reply(String),
+    Edit(String),
+}
+
+struct Thread<'a> {
+    comments: &'a [TalkComment],
+    current_user_id: Option<String>,
+    draft: &'a UseStateHandle<Option<Draft>>,
+    draft_body: &'a UseStateHandle<String>,
+    on_draft_input: Callback<InputEvent>,
+    on_draft_submit: Callback<MouseEvent>,
+    on_delete: Callback<String>,
+}
+
+impl Thread<'_> {
+    fn render(&self, parent_id: Option<&str>) -> Html {
+        let children: Vec<&TalkComment> = self
+            .comments
+            .iter()
+            .filter(|comment| comment.parent_id.as_deref() == parent_id)
+            .collect();
+
+        if children.is_empty() {
+            return html! {};
+        }
+
+        html! {
+            <ul class="comment-thread">
+                { for children.into_iter().map(|comment| self.render_comment(comment)) }
+            </ul>
+        }
+    }
+
+    fn render_comment(&self, comment: &TalkComment) -> Html {
+        let is_own = self.current_user_id.as_deref() == Some(comment.author_id.as_str());
+        let is_deleted = comment.deleted_at.is_some();
+
+        let start_draft = |draft: Draft, body: String| {
+            let draft_state = self.draft.clone();
+            let draft_body = self.draft_body.clone();
+            Callback::from(move |_: MouseEvent| {
+                draft_body.set(body.clone());
+                draft_state.set(Some(draft.clone()));
+            })
+        };
+        let on_reply = start_draft(Draft::Reply(comment.id.clone()), String::new());
+        let on_edit = start_draft(Draft::Edit(comment.id.clone()), comment.body.clone());
+        let on_delete = {
+            let on_delete = self.on_delete.clone();
+            let comment_id = comment.id.clone();
+            Callback::from(move |_: MouseEvent| on_delete.emit(comment_id.clone()))
+        };
+        let on_cancel = {
+            let draft = self.draft.clone();
+            Callback::from(move |_: MouseEvent| draft.set(None))
+        };
+
+        let editing = **self.draft == Some(Draft::Edit(comment.id.clone()));
+        let replying = **self.draft == Some(Draft::Reply(comment.id.clone()));
+
+        html! {
+            <li class="comment" key={comment.id.clone()}>
+                <div class="comment-meta">
+                    <strong>{ &comment.author_name }</strong>
+                    { " " }
+                    <span class="comment-date">{ &comment.created_at }</span>
+                    {
+                        if comment.edited_at.is_some() && !is_deleted {
+                            html! { <span class="comment-edited">{ " (edited)" }</span> }
+                        } else {
+                            html! {}
+                        }
+                    }
+                </div>
+                {
+                    if is_deleted {
+                        html! { <p class="comment-body comment-deleted">{ "Comment deleted" }</p> }
+                    } else if editing {
+                        html! {}
+                    } else {
+                        html! { <p class="comment-body">{ &comment.body }</p> }
+                    }
+                }
+                {
+                    if editing || replying {
+                        html! {
+                            <div class="comment-form">
+                                <textarea
+                                    value={(**self.draft_body).clone()}
+                                    oninput={self.on_draft_input.clone()}
+                                    rows="3"
+                                />
+                                <button class="btn btn-primary btn-small" onclick={self.on_draft_submit.clone()}>
+                                    { if editing { "Save" } else { "Reply" } }
+                                </button>
+                                <button class="btn btn-secondary btn-small" onclick={on_cancel}>
+                                    { "Cancel" }
+                                </button>
+                            </div>
+                        }
+                    } else if is_deleted {
+                        html! {}
+                    } else {
+                        html! {
+                            <div class="comment-actions">
+                                <button class="btn-link" onclick={on_reply}>{ "Reply" }</button>
+                                {
+                                    if is_own {
+                                        html! {
+                                            <>
+                                                <button class="btn-link" onclick={on_edit}>{ "Edit" }</button>
+                                                <button class="btn-link" onclick={on_delete}>{ "Delete" }</button>
+                                            </>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        }
+                    }
+                }
+                { self.render(Some(comment.id.as_str())) }
+            </li>
+        }
+    }
+}
+
+/// Organizer-only discussion thread on a talk. Speakers never see it.
+#[function_component(TalkDiscussion)]
+pub fn talk_discussion(props: &TalkDiscussionProps) -> Html {
+    let comments = use_state(|| Vec::<TalkComment>::new());
+    let error = use_state(|| None::<String>);
+    let reload = use_state(|| 0u32);
+    let new_body = use_state(String::new);
+    let draft = use_state(|| None::<Draft>);
+    let draft_body = use_state(String::new);
+
+    // Fetch the discussion on mount and after every change
+    {
+        let talk_id = props.talk_id.clone();
+        let comments = comments.clone();
+        let error = error.clone();
+        use_effect_with((talk_id.clone(), *reload), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match CommentService::list_comments(&talk_id).await {
+                    Ok(fetched) => comments.set(fetched),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_new_input = {
+        let new_body = new_body.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            new_body.set(input.value());
+        })
+    };
+
+    let on_new_submit = {
+        let talk_id = props.talk_id.clone();
+        let new_body = new_body.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let new_body = new_body.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            let request = CreateCommentRequest {
+                body: (*new_body).clone(),
+                parent_id: None,
+            };
+            wasm_bindgen_futures::spawn_local(async move {
+                match CommentService::create_comment(&talk_id, request).await {
+                    Ok(_) => {
+                        new_body.set(String::new());
+                        error.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let on_draft_input = {
+        let draft_body = draft_body.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            draft_body.set(input.value());
+        })
+    };
+
+    let on_draft_submit = {
+        let talk_id = props.talk_id.clone();
+        let draft = draft.clone();
+        let draft_body = draft_body.clone();
+        let error = error.clone();
+        let reload = reload.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(current) = (*draft).clone() else {
+                return;
+            };
+            let talk_id = talk_id.clone();
+            let body = (*draft_body).clone();
+            let draft = draft.clone();
+            let error = error.clone();
+            let reload = reload.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = match current {
+                    Draft::Reply(parent_id) => CommentService::create_comment(
+                        &talk_id,
+                        CreateCommentRequest {
+                            body,
+                            parent_id: Some(parent_id),
+                        },
+                    )
+                    .await
+                    .map(|_| ()),
+                    Draft::Edit(comment_id) => {
+                        CommentService::update_comment(&comment_id, UpdateCommentRequest { body })
+                            .await
+                            .map(|_| ())
+                    }
+                };
+                match result {
+                    Ok(()) => {
+                        draft.set(None);
+                        error.set(None);
+                        reload.set(*reload + 1);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let error = error.clone();
+        let reload = reload.clone();
+        Callback::from(move |comment_id: String| {
+            let error = error.clone();
+            let reload = reload.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match CommentService::delete_comment(&comment_id).await {
+                    Ok(()) => reload.set(*reload + 1),
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let thread = Thread {
+        comments: comments.as_slice(),
+        current_user_id: AuthService::current_user_id(),
+        draft: &draft,
+        draft_body: &draft_body,
+        on_draft_input,
+        on_draft_submit,
+        on_delete,
+    };
+
+    html! {
+        <details class="talk-discussion">
+            <summary>{ format!("Internal discussion ({} comments)", comments.iter().filter(|c| c.deleted_at.is_none()).count()) }</summary>
+
+            {
+                if let Some(err) = (*error).as_ref() {
+                    html! { <div class="error-message">{ err }</div> }
+                } else {
+                    html! {}
+                }
+            }
+
+            { thread.render(None) }
+
+            <div class="comment-form">
+                <textarea
+                    value={(*new_body).clone()}
+                    oninput={on_new_input}
+                    rows="3"
+                    placeholder="Only organizers can see this. Mention someone with @username or @email."
+                />
+                <button class="btn btn-primary btn-small" onclick={on_new_submit}>
+                    { "Comment" }
+                </button>
+            </div>
+        </details>
+    }
+}
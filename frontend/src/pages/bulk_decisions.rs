@@ -0,0 +1,341 @@
+use std::collections::HashMap;
+
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    services::{conferences::ConferenceService, decisions::DecisionService, labels::LabelService},
+    types::{
+        BulkDecisionPreview, BulkDecisionRequest, CommitDecisionsRequest, DecisionChange,
+        DecisionScore, Label, LabelQuota, TalkState,
+    },
+};
+
+fn state_label(state: &TalkState) -> &'static str {
+    match state {
+        TalkState::Draft => "Draft",
+        TalkState::Submitted => "Submitted",
+        TalkState::Pending => "Pending",
+        TalkState::Accepted => "Accepted",
+        TalkState::Rejected => "Rejected",
+        TalkState::Withdrawn => "Withdrawn",
+        TalkState::Waitlisted => "Waitlisted",
+    }
+}
+
+/// Decide many submitted talks at once: preview the rules, then commit
+#[function_component(BulkDecisions)]
+pub fn bulk_decisions() -> Html {
+    let conference_id = use_state(|| None::<String>);
+    let labels = use_state(Vec::<Label>::new);
+    let score = use_state(DecisionScore::default);
+    let top_n = use_state(String::new);
+    let min_score = use_state(String::new);
+    let others = use_state(|| None::<TalkState>);
+    let reason = use_state(String::new);
+    let quotas = use_state(HashMap::<String, String>::new);
+    let preview = use_state(|| None::<BulkDecisionPreview>);
+    let error = use_state(|| None::<String>);
+    let message = use_state(|| None::<String>);
+
+    // Fetch the active conference and the labels on mount
+    {
+        let conference_id = conference_id.clone();
+        let labels = labels.clone();
+        let error = error.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match ConferenceService::get_active_conference().await {
+                    Ok(conference) => conference_id.set(Some(conference.id)),
+                    Err(e) => error.set(Some(e)),
+                }
+                if let Ok(labels_data) = LabelService::list_labels().await {
+                    labels.set(labels_data);
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_score_change = {
+        let score = score.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            score.set(match select.value().as_str() {
+                "normalized" => DecisionScore::Normalized,
+                "trimmed" => DecisionScore::Trimmed,
+                _ => DecisionScore::Average,
+            });
+        })
+    };
+
+    let on_others_change = {
+        let others = others.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            others.set(match select.value().as_str() {
+                "rejected" => Some(TalkState::Rejected),
+                "waitlisted" => Some(TalkState::Waitlisted),
+                _ => None,
+            });
+        })
+    };
+
+    let text_input = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            handle.set(input.value());
+        })
+    };
+    let on_top_n_input = text_input(&top_n);
+    let on_min_score_input = text_input(&min_score);
+
+    let on_reason_input = {
+        let reason = reason.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            reason.set(input.value());
+        })
+    };
+
+    let on_preview = {
+        let conference_id = conference_id.clone();
+        let score = score.clone();
+        let top_n = top_n.clone();
+        let min_score = min_score.clone();
+        let others = others.clone();
+        let reason = reason.clone();
+        let quotas = quotas.clone();
+        let preview = preview.clone();
+        let error = error.clone();
+        let message = message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference_id) = (*conference_id).clone() else {
+                return;
+            };
+            let request = BulkDecisionRequest {
+                score: *score,
+                top_n: top_n.trim().parse().ok(),
+                min_score: min_score.trim().parse().ok(),
+                talk_ids: Vec::new(),
+                label_quotas: quotas
+                    .iter()
+                    .filter_map(|(label_id, max)| {
+                        max.trim().parse().ok().map(|max_selected| LabelQuota {
+                            label_id: label_id.clone(),
+                            max_selected,
+                        })
+                    })
+                    .collect(),
+                others: (*others).clone(),
+                reason: Some((*reason).clone()).filter(|r| !r.trim().is_empty()),
+            };
+            let preview = preview.clone();
+            let error = error.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                match DecisionService::preview(&conference_id, request).await {
+                    Ok(data) => {
+                        preview.set(Some(data));
+                        error.set(None);
+                        message.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to preview: {}", e))),
+                }
+            });
+        })
+    };
+
+    let on_commit = {
+        let conference_id = conference_id.clone();
+        let reason = reason.clone();
+        let preview = preview.clone();
+        let error = error.clone();
+        let message = message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let (Some(conference_id), Some(data)) = ((*conference_id).clone(), (*preview).clone())
+            else {
+                return;
+            };
+            let request = CommitDecisionsRequest {
+                decisions: data
+                    .decisions
+                    .iter()
+                    .filter_map(|d| {
+                        d.to_state.clone().map(|to_state| DecisionChange {
+                            talk_id: d.talk_id.clone(),
+                            from_state: d.from_state.clone(),
+                            to_state,
+                        })
+                    })
+                    .collect(),
+                reason: Some((*reason).clone()).filter(|r| !r.trim().is_empty()),
+            };
+            let preview = preview.clone();
+            let error = error.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                match DecisionService::commit(&conference_id, request).await {
+                    Ok(result) => {
+                        message.set(Some(format!("Updated {} talk(s)", result.talks.len())));
+                        preview.set(None);
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to commit: {}", e))),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="bulk-decisions-page">
+            <h1>{ "Bulk Decisions" }</h1>
+
+            {
+                if let Some(err) = (*error).as_ref() {
+                    html! { <div class="error-message">{ err }</div> }
+                } else {
+                    html! {}
+                }
+            }
+            {
+                if let Some(msg) = (*message).as_ref() {
+                    html! { <div class="success-message">{ msg }</div> }
+                } else {
+                    html! {}
+                }
+            }
+
+            <section class="dashboard-section">
+                <h2>{ "Rules" }</h2>
+                <div class="form-group">
+                    <label>{ "Rank by" }</label>
+                    <select onchange={on_score_change}>
+                        <option value="average" selected={*score == DecisionScore::Average}>{ "Average rating" }</option>
+                        <option value="normalized" selected={*score == DecisionScore::Normalized}>{ "Normalized score" }</option>
+                        <option value="trimmed" selected={*score == DecisionScore::Trimmed}>{ "Trimmed average" }</option>
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label>{ "Accept the top" }</label>
+                    <input type="number" min="1" value={(*top_n).clone()} oninput={on_top_n_input} placeholder="No limit" />
+                </div>
+                <div class="form-group">
+                    <label>{ "Minimum score" }</label>
+                    <input type="number" step="0.1" value={(*min_score).clone()} oninput={on_min_score_input} placeholder="No threshold" />
+                </div>
+                <div class="form-group">
+                    <label>{ "Talks that aren't selected" }</label>
+                    <select onchange={on_others_change}>
+                        <option value="" selected={others.is_none()}>{ "Leave submitted" }</option>
+                        <option value="rejected" selected={*others == Some(TalkState::Rejected)}>{ "Reject" }</option>
+                        <option value="waitlisted" selected={*others == Some(TalkState::Waitlisted)}>{ "Waitlist" }</option>
+                    </select>
+                </div>
+                <div class="form-group">
+                    <label>{ "Reason (sent as {{reason}})" }</label>
+                    <textarea value={(*reason).clone()} oninput={on_reason_input} rows="3" />
+                </div>
+
+                <h3>{ "Label quotas" }</h3>
+                <div class="label-quotas">
+                    {
+                        for labels.iter().map(|label| {
+                            let on_input = {
+                                let quotas = quotas.clone();
+                                let label_id = label.id.clone();
+                                Callback::from(move |e: InputEvent| {
+                                    let input: HtmlInputElement = e.target_unchecked_into();
+                                    let mut current = (*quotas).clone();
+                                    current.insert(label_id.clone(), input.value());
+                                    quotas.set(current);
+                                })
+                            };
+                            html! {
+                                <div class="form-group label-quota" key={label.id.clone()}>
+                                    <label>{ &label.name }</label>
+                                    <input
+                                        type="number"
+                                        min="0"
+                                        placeholder="No quota"
+                                        value={quotas.get(&label.id).cloned().unwrap_or_default()}
+                                        oninput={on_input}
+                                    />
+                                </div>
+                            }
+                        })
+                    }
+                </div>
+
+                <button class="btn btn-primary" onclick={on_preview}>{ "Preview" }</button>
+            </section>
+
+            {
+                if let Some(data) = (*preview).as_ref() {
+                    let changes = data.decisions.iter().filter(|d| d.to_state.is_some()).count();
+                    html! {
+                        <section class="dashboard-section">
+                            <h2>{ format!("Preview: {} change(s), {} email(s)", changes, data.emails.len()) }</h2>
+                            {
+                                if data.emails_enabled {
+                                    html! {}
+                                } else {
+                                    html! { <p class="no-data">{ "Email is not configured; no emails will be sent." }</p> }
+                                }
+                            }
+                            <table class="decision-table">
+                                <thead>
+                                    <tr>
+                                        <th>{ "Talk" }</th>
+                                        <th>{ "Score" }</th>
+                                        <th>{ "Decision" }</th>
+                                        <th>{ "Why" }</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {
+                                        for data.decisions.iter().map(|d| html! {
+                                            <tr key={d.talk_id.clone()}>
+                                                <td>{ &d.talk_title }</td>
+                                                <td>{ d.score.map(|s| format!("{:.2}", s)).unwrap_or_else(|| "-".to_string()) }</td>
+                                                <td>
+                                                    {
+                                                        match &d.to_state {
+                                                            Some(to_state) => format!("{} → {}", state_label(&d.from_state), state_label(to_state)),
+                                                            None => "Unchanged".to_string(),
+                                                        }
+                                                    }
+                                                </td>
+                                                <td>{ &d.note }</td>
+                                            </tr>
+                                        })
+                                    }
+                                </tbody>
+                            </table>
+
+                            <h3>{ "Emails" }</h3>
+                            {
+                                for data.emails.iter().map(|email| html! {
+                                    <details class="decision-email">
+                                        <summary>
+                                            { format!("{} · {}", email.to, email.subject.clone().unwrap_or_else(|| email.template_type.clone())) }
+                                        </summary>
+                                        <pre>{ email.body.clone().unwrap_or_else(|| "No template found".to_string()) }</pre>
+                                    </details>
+                                })
+                            }
+
+                            <button class="btn btn-primary" onclick={on_commit} disabled={changes == 0}>
+                                { "Commit decisions" }
+                            </button>
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
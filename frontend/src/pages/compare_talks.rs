@@ -0,0 +1,150 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    services::{comparisons::ComparisonService, conferences::ConferenceService},
+    types::{ComparisonTalk, NextComparison, RecordComparisonRequest},
+};
+
+/// Load the next pair for the current reviewer into `next`
+fn load_next(
+    conference_id: String,
+    next: UseStateHandle<Option<NextComparison>>,
+    error: UseStateHandle<Option<String>>,
+) {
+    spawn_local(async move {
+        match ComparisonService::next(&conference_id).await {
+            Ok(data) => {
+                next.set(Some(data));
+                error.set(None);
+            }
+            Err(e) => error.set(Some(format!("Failed to load talks: {}", e))),
+        }
+    });
+}
+
+/// Head-to-head review: pick the better of two talks, one pair at a time
+#[function_component(CompareTalks)]
+pub fn compare_talks() -> Html {
+    let conference_id = use_state(|| None::<String>);
+    let next = use_state(|| None::<NextComparison>);
+    let error = use_state(|| None::<String>);
+
+    // Fetch the active conference and its first pair on mount
+    {
+        let conference_id = conference_id.clone();
+        let next = next.clone();
+        let error = error.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match ConferenceService::get_active_conference().await {
+                    Ok(conference) => {
+                        conference_id.set(Some(conference.id.clone()));
+                        load_next(conference.id, next, error);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+            || ()
+        });
+    }
+
+    let choose = {
+        let conference_id = conference_id.clone();
+        let next = next.clone();
+        let error = error.clone();
+        move |winner: &ComparisonTalk, loser: &ComparisonTalk| {
+            let conference_id = conference_id.clone();
+            let next = next.clone();
+            let error = error.clone();
+            let request = RecordComparisonRequest {
+                winner_id: winner.id.clone(),
+                loser_id: loser.id.clone(),
+            };
+            Callback::from(move |_: MouseEvent| {
+                let Some(conference_id) = (*conference_id).clone() else {
+                    return;
+                };
+                let request = request.clone();
+                let next = next.clone();
+                let error = error.clone();
+                spawn_local(async move {
+                    match ComparisonService::record(&conference_id, request).await {
+                        Ok(_) => load_next(conference_id, next, error),
+                        Err(e) => error.set(Some(format!("Failed to save: {}", e))),
+                    }
+                });
+            })
+        }
+    };
+
+    let render_talk = |talk: &ComparisonTalk, onclick: Callback<MouseEvent>| {
+        html! {
+            <div class="comparison-card">
+                <h2>{ &talk.title }</h2>
+                <div class="comparison-meta">
+                    { format!("{} · {} min", talk.format.label(), talk.duration_minutes) }
+                </div>
+                <p class="comparison-summary">{ &talk.short_summary }</p>
+                {
+                    if let Some(description) = &talk.long_description {
+                        html! {
+                            <details>
+                                <summary>{ "Full description" }</summary>
+                                <p>{ description }</p>
+                            </details>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <button class="btn btn-primary" {onclick}>{ "This one is better" }</button>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="compare-talks-page">
+            <h1>{ "Compare Talks" }</h1>
+            <p class="page-description">
+                { "Pick the stronger proposal of each pair. Your choices feed the pairwise ranking on the ratings dashboard." }
+            </p>
+
+            {
+                if let Some(err) = (*error).as_ref() {
+                    html! { <div class="error-message">{ err }</div> }
+                } else {
+                    html! {}
+                }
+            }
+
+            {
+                match (*next).as_ref() {
+                    Some(data) => html! {
+                        <>
+                            <div class="comparison-progress">
+                                { format!("{} compared, {} left", data.compared, data.remaining) }
+                            </div>
+                            {
+                                match data.pair.as_deref() {
+                                    Some([first, second]) => html! {
+                                        <div class="comparison-pair">
+                                            { render_talk(first, choose(first, second)) }
+                                            { render_talk(second, choose(second, first)) }
+                                        </div>
+                                    },
+                                    _ => html! {
+                                        <div class="empty-state">
+                                            <p>{ "You have compared every pair of talks." }</p>
+                                        </div>
+                                    },
+                                }
+                            }
+                        </>
+                    },
+                    None => html! { <div class="loading">{ "Loading..." }</div> },
+                }
+            }
+        </div>
+    }
+}
@@ -274,6 +274,7 @@ pub fn manage_email_templates() -> Html {
                             <li><code>{ "{{schedule_date}}" }</code>{ " - Scheduled date" }</li>
                             <li><code>{ "{{schedule_time}}" }</code>{ " - Scheduled time" }</li>
                             <li><code>{ "{{track_name}}" }</code>{ " - Track/room name" }</li>
+                            <li><code>{ "{{feedback}}" }</code>{ " - Approved feedback for the speaker (for acceptances and rejections)" }</li>
                         </ul>
                         <p><small>{ "Use " }<code>{ "{{#if variable}}...{{/if}}" }</code>{ " for conditional sections" }</small></p>
                     </div>
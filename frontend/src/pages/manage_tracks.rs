@@ -1,7 +1,8 @@
 use yew::prelude::*;
+use std::collections::HashMap;
 use crate::{
-    services::{tracks::TrackService, conferences::ConferenceService},
-    types::{Track, CreateTrackRequest, UpdateTrackRequest},
+    services::{tracks::TrackService, conferences::ConferenceService, labels::LabelService},
+    types::{Label, Track, CreateTrackRequest, UpdateTrackRequest},
 };
 
 #[function_component(ManageTracks)]
@@ -12,6 +13,9 @@ pub fn manage_tracks() -> Html {
     let show_form = use_state(|| false);
     let editing_track = use_state(|| None::<Track>);
     let conference_id = use_state(|| None::<String>);
+    let labels = use_state(|| Vec::<Label>::new());
+    // Track id -> ids of the labels the track is meant for
+    let track_labels = use_state(HashMap::<String, Vec<String>>::new);
 
     // Form state
     let name = use_state(|| String::new());
@@ -24,6 +28,8 @@ pub fn manage_tracks() -> Html {
         let tracks = tracks.clone();
         let loading = loading.clone();
         let error = error.clone();
+        let labels = labels.clone();
+        let track_labels = track_labels.clone();
 
         use_effect_with((), move |_| {
             wasm_bindgen_futures::spawn_local(async move {
@@ -44,6 +50,13 @@ pub fn manage_tracks() -> Html {
                 // Fetch tracks
                 match TrackService::list_tracks().await {
                     Ok(data) => {
+                        let mut topics = HashMap::new();
+                        for track in &data {
+                            if let Ok(assigned) = TrackService::get_track_labels(&track.id).await {
+                                topics.insert(track.id.clone(), assigned.into_iter().map(|l| l.id).collect());
+                            }
+                        }
+                        track_labels.set(topics);
                         tracks.set(data);
                         error.set(None);
                     }
@@ -51,12 +64,47 @@ pub fn manage_tracks() -> Html {
                         error.set(Some(e));
                     }
                 }
+                if let Ok(data) = LabelService::list_labels().await {
+                    labels.set(data);
+                }
                 loading.set(false);
             });
             || ()
         });
     }
 
+    // Toggle one of a track's topic labels
+    let create_label_toggle = |track_id: String, label_id: String| {
+        let track_labels = track_labels.clone();
+        let error = error.clone();
+
+        Callback::from(move |_: Event| {
+            let mut label_ids = track_labels.get(&track_id).cloned().unwrap_or_default();
+            if let Some(index) = label_ids.iter().position(|id| *id == label_id) {
+                label_ids.remove(index);
+            } else {
+                label_ids.push(label_id.clone());
+            }
+            let track_labels = track_labels.clone();
+            let track_id = track_id.clone();
+            let error = error.clone();
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TrackService::set_track_labels(&track_id, label_ids).await {
+                    Ok(saved) => {
+                        let mut current = (*track_labels).clone();
+                        current.insert(track_id, saved.into_iter().map(|l| l.id).collect());
+                        track_labels.set(current);
+                        error.set(None);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to update track topics: {}", e)));
+                    }
+                }
+            });
+        })
+    };
+
     // Submit handler (create or update)
     let on_submit = {
         let tracks = tracks.clone();
@@ -329,6 +377,30 @@ pub fn manage_tracks() -> Html {
                                     if let Some(cap) = track.capacity {
                                         <p class="track-capacity">{ format!("Capacity: {}", cap) }</p>
                                     }
+                                    if !labels.is_empty() {
+                                        <div class="form-group">
+                                            <label>{ "Topics (used by the auto-scheduler)" }</label>
+                                            <div class="track-labels">
+                                                {
+                                                    labels.iter().map(|label| {
+                                                        let checked = track_labels
+                                                            .get(&track.id)
+                                                            .is_some_and(|ids| ids.contains(&label.id));
+                                                        html! {
+                                                            <label key={label.id.clone()}>
+                                                                <input
+                                                                    type="checkbox"
+                                                                    {checked}
+                                                                    onchange={create_label_toggle(track.id.clone(), label.id.clone())}
+                                                                />
+                                                                { format!(" {}", label.name) }
+                                                            </label>
+                                                        }
+                                                    }).collect::<Html>()
+                                                }
+                                            </div>
+                                        </div>
+                                    }
                                 </div>
                             }
                         }).collect::<Html>()
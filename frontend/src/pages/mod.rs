@@ -3,6 +3,8 @@ pub mod login;
 pub mod signup;
 pub mod auth_callback;
 pub mod my_talks;
+pub mod my_availability;
+pub mod speaker_invitation;
 pub mod not_found;
 pub mod submit_talk;
 pub mod speaker_dashboard;
@@ -10,6 +12,7 @@ pub mod organizer_talks;
 pub mod organizer_dashboard;
 pub mod organizer_labels;
 pub mod ratings_dashboard;
+pub mod review_queue;
 pub mod manage_tracks;
 pub mod manage_schedule_slots;
 pub mod assign_talks;
@@ -20,3 +23,5 @@ pub mod bulk_email;
 pub mod export_talks;
 pub mod ai_auto_tag;
 pub mod configuration;
+pub mod bulk_decisions;
+pub mod compare_talks;
@@ -0,0 +1,228 @@
+use yew::prelude::*;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    services::{availability::AvailabilityService, conferences::ConferenceService, talks::TalkService},
+    types::{AvailabilityWindow, Conference, SetAvailabilityRequest, SpeakerAvailability, Talk},
+};
+
+/// The windows saved for one scope: general when `talk_id` is `None`
+fn windows_for(saved: &[SpeakerAvailability], talk_id: Option<&String>) -> Vec<AvailabilityWindow> {
+    saved
+        .iter()
+        .filter(|a| a.talk_id.as_ref() == talk_id)
+        .map(|a| AvailabilityWindow {
+            available_date: a.available_date.clone(),
+            start_time: a.start_time.clone(),
+            end_time: a.end_time.clone(),
+        })
+        .collect()
+}
+
+/// Let speakers say which conference days and hours they can present
+#[function_component(MyAvailability)]
+pub fn my_availability() -> Html {
+    let conference = use_state(|| None::<Conference>);
+    let talks = use_state(Vec::<Talk>::new);
+    let saved = use_state(Vec::<SpeakerAvailability>::new);
+    // `None` edits the general windows, `Some(id)` those for one talk
+    let scope = use_state(|| None::<String>);
+    let windows = use_state(Vec::<AvailabilityWindow>::new);
+    let error = use_state(|| None::<String>);
+    let message = use_state(|| None::<String>);
+
+    // Fetch the active conference, the speaker's talks and their windows on mount
+    {
+        let conference = conference.clone();
+        let talks = talks.clone();
+        let saved = saved.clone();
+        let windows = windows.clone();
+        let error = error.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let active = match ConferenceService::get_active_conference().await {
+                    Ok(active) => active,
+                    Err(e) => {
+                        error.set(Some(format!("Failed to load active conference: {}", e)));
+                        return;
+                    }
+                };
+                if let Ok(mine) = TalkService::get_my_talks().await {
+                    talks.set(mine.into_iter().filter(|t| t.conference_id == active.id).collect());
+                }
+                match AvailabilityService::get_mine(&active.id).await {
+                    Ok(data) => {
+                        windows.set(windows_for(&data, None));
+                        saved.set(data);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to load availability: {}", e))),
+                }
+                conference.set(Some(active));
+            });
+            || ()
+        });
+    }
+
+    let on_scope_change = {
+        let scope = scope.clone();
+        let saved = saved.clone();
+        let windows = windows.clone();
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let value = select.value();
+            let talk_id = (!value.is_empty()).then_some(value);
+            windows.set(windows_for(&saved, talk_id.as_ref()));
+            scope.set(talk_id);
+            message.set(None);
+        })
+    };
+
+    let on_add = {
+        let conference = conference.clone();
+        let windows = windows.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference) = (*conference).as_ref() else {
+                return;
+            };
+            let mut next = (*windows).clone();
+            next.push(AvailabilityWindow {
+                available_date: conference.start_date.clone(),
+                start_time: "09:00".to_string(),
+                end_time: "17:00".to_string(),
+            });
+            windows.set(next);
+        })
+    };
+
+    let on_save = {
+        let conference = conference.clone();
+        let scope = scope.clone();
+        let saved = saved.clone();
+        let windows = windows.clone();
+        let error = error.clone();
+        let message = message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference_id) = (*conference).as_ref().map(|c| c.id.clone()) else {
+                return;
+            };
+            let request = SetAvailabilityRequest {
+                talk_id: (*scope).clone(),
+                windows: (*windows).clone(),
+            };
+            let saved = saved.clone();
+            let error = error.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                match AvailabilityService::set_mine(&conference_id, request).await {
+                    Ok(data) => {
+                        saved.set(data);
+                        error.set(None);
+                        message.set(Some("Availability saved.".to_string()));
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save availability: {}", e))),
+                }
+            });
+        })
+    };
+
+    // Change one field of one window
+    let edit = |index: usize, field: &'static str| {
+        let windows = windows.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*windows).clone();
+            if let Some(window) = next.get_mut(index) {
+                match field {
+                    "date" => window.available_date = input.value(),
+                    "start" => window.start_time = input.value(),
+                    _ => window.end_time = input.value(),
+                }
+            }
+            windows.set(next);
+        })
+    };
+
+    let remove = |index: usize| {
+        let windows = windows.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut next = (*windows).clone();
+            next.remove(index);
+            windows.set(next);
+        })
+    };
+
+    let (min_date, max_date) = (*conference)
+        .as_ref()
+        .map(|c| (c.start_date.clone(), c.end_date.clone()))
+        .unwrap_or_default();
+
+    html! {
+        <div class="my-availability-page">
+            <h1>{ "My Availability" }</h1>
+            <p class="page-description">
+                { "Tell the organizers when you can present. Add a window for each day and time range you're available. Windows for a single talk replace your general ones for that talk. Leave everything empty if you can present at any time." }
+            </p>
+
+            if let Some(err) = (*error).as_ref() {
+                <div class="error-message">{ err }</div>
+            }
+            if let Some(msg) = (*message).as_ref() {
+                <div class="success-message">{ msg }</div>
+            }
+
+            if let Some(conference) = (*conference).as_ref() {
+                <p>{ format!("{} runs from {} to {}.", conference.name, conference.start_date, conference.end_date) }</p>
+
+                <div class="form-group">
+                    <label for="availability-scope">{ "Applies to" }</label>
+                    <select id="availability-scope" onchange={on_scope_change}>
+                        <option value="" selected={scope.is_none()}>{ "All my talks" }</option>
+                        {
+                            talks.iter().map(|talk| {
+                                html! {
+                                    <option value={talk.id.clone()} selected={scope.as_ref() == Some(&talk.id)}>
+                                        { &talk.title }
+                                    </option>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </select>
+                </div>
+
+                if windows.is_empty() {
+                    <p class="no-data">{ "No windows: available at any time." }</p>
+                } else {
+                    <div class="availability-windows">
+                        {
+                            windows.iter().enumerate().map(|(index, window)| {
+                                html! {
+                                    <div class="availability-window" key={index}>
+                                        <input
+                                            type="date"
+                                            min={min_date.clone()}
+                                            max={max_date.clone()}
+                                            value={window.available_date.clone()}
+                                            onchange={edit(index, "date")}
+                                        />
+                                        <input type="time" value={window.start_time.clone()} onchange={edit(index, "start")} />
+                                        <span>{ "to" }</span>
+                                        <input type="time" value={window.end_time.clone()} onchange={edit(index, "end")} />
+                                        <button class="btn-danger btn-small" onclick={remove(index)}>{ "Remove" }</button>
+                                    </div>
+                                }
+                            }).collect::<Html>()
+                        }
+                    </div>
+                }
+
+                <div class="availability-actions">
+                    <button class="btn-secondary" onclick={on_add}>{ "Add Window" }</button>
+                    <button class="btn-primary" onclick={on_save}>{ "Save" }</button>
+                </div>
+            } else if error.is_none() {
+                <div class="loading">{ "Loading..." }</div>
+            }
+        </div>
+    }
+}
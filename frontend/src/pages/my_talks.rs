@@ -1,9 +1,10 @@
+use web_sys::HtmlTextAreaElement;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
 use crate::{
     app::Route,
-    components::LabelBadge,
+    components::{CoSpeakers, LabelBadge},
     services::talks::TalkService,
     types::{Talk, TalkState},
 };
@@ -16,6 +17,9 @@ pub fn my_talks() -> Html {
     let error = use_state(|| None::<String>);
     let responding_to = use_state(|| None::<String>);
     let success_message = use_state(|| None::<String>);
+    // Talk whose withdrawal form is open, and the reason typed so far
+    let withdrawing = use_state(|| None::<String>);
+    let withdraw_reason = use_state(|| String::new());
 
     {
         let talks = talks.clone();
@@ -92,6 +96,107 @@ pub fn my_talks() -> Html {
         })
     };
 
+    let create_submit_handler = |talk_id: String| {
+        let talks = talks.clone();
+        let responding_to = responding_to.clone();
+        let error = error.clone();
+        let success_message = success_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let talks = talks.clone();
+            let responding_to = responding_to.clone();
+            let error = error.clone();
+            let success_message = success_message.clone();
+
+            error.set(None);
+            success_message.set(None);
+            responding_to.set(Some(talk_id.clone()));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::submit_talk(&talk_id).await {
+                    Ok(submitted_talk) => {
+                        let mut current_talks = (*talks).clone();
+                        if let Some(talk) = current_talks.iter_mut().find(|t| t.id == talk_id) {
+                            *talk = submitted_talk;
+                        }
+                        talks.set(current_talks);
+                        success_message.set(Some("Talk submitted successfully!".to_string()));
+                        responding_to.set(None);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to submit: {}", e)));
+                        responding_to.set(None);
+                    }
+                }
+            });
+        })
+    };
+
+    let create_open_withdraw_handler = |talk_id: Option<String>| {
+        let withdrawing = withdrawing.clone();
+        let withdraw_reason = withdraw_reason.clone();
+        Callback::from(move |_: MouseEvent| {
+            withdraw_reason.set(String::new());
+            withdrawing.set(talk_id.clone());
+        })
+    };
+
+    let on_withdraw_reason_input = {
+        let withdraw_reason = withdraw_reason.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            withdraw_reason.set(input.value());
+        })
+    };
+
+    let create_withdraw_handler = |talk_id: String| {
+        let talks = talks.clone();
+        let responding_to = responding_to.clone();
+        let withdrawing = withdrawing.clone();
+        let withdraw_reason = withdraw_reason.clone();
+        let error = error.clone();
+        let success_message = success_message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let talk_id = talk_id.clone();
+            let reason = (*withdraw_reason).trim().to_string();
+            let talks = talks.clone();
+            let responding_to = responding_to.clone();
+            let withdrawing = withdrawing.clone();
+            let error = error.clone();
+            let success_message = success_message.clone();
+
+            if reason.is_empty() {
+                error.set(Some("Please tell the organizers why you are withdrawing".to_string()));
+                return;
+            }
+
+            error.set(None);
+            success_message.set(None);
+            responding_to.set(Some(talk_id.clone()));
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::withdraw_talk(&talk_id, reason).await {
+                    Ok(withdrawn_talk) => {
+                        let mut current_talks = (*talks).clone();
+                        if let Some(talk) = current_talks.iter_mut().find(|t| t.id == talk_id) {
+                            *talk = withdrawn_talk;
+                        }
+                        talks.set(current_talks);
+                        success_message.set(Some("Your talk has been withdrawn. The organizers have been notified.".to_string()));
+                        withdrawing.set(None);
+                        responding_to.set(None);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("Failed to withdraw: {}", e)));
+                        responding_to.set(None);
+                    }
+                }
+            });
+        })
+    };
+
     html! {
         <div class="talks-container">
             <div class="talks-header">
@@ -140,10 +245,18 @@ pub fn my_talks() -> Html {
                                 for talks.iter().map(|talk| {
                                     let state_class = format!("state-{:?}", talk.state).to_lowercase();
                                     let is_pending = matches!(talk.state, TalkState::Pending);
+                                    let is_draft = matches!(talk.state, TalkState::Draft);
+                                    let is_accepted = matches!(talk.state, TalkState::Accepted);
+                                    let is_withdraw_open = (*withdrawing).as_ref() == Some(&talk.id);
                                     let is_responding = (*responding_to).as_ref() == Some(&talk.id);
 
                                     // State-specific display information
                                     let (state_icon, state_label, state_help) = match talk.state {
+                                        TalkState::Draft => (
+                                            "✏️",
+                                            "Draft",
+                                            "This talk is saved but not yet submitted. Organizers can't see it until you submit it."
+                                        ),
                                         TalkState::Submitted => (
                                             "📝",
                                             "Submitted",
@@ -164,6 +277,16 @@ pub fn my_talks() -> Html {
                                             "Not Selected",
                                             "Thank you for your submission. While this talk wasn't selected, we encourage you to submit again in the future."
                                         ),
+                                        TalkState::Waitlisted => (
+                                            "🕒",
+                                            "Waitlisted",
+                                            "Your talk is on the waitlist. If a slot opens up, we'll contact you to offer it."
+                                        ),
+                                        TalkState::Withdrawn => (
+                                            "↩️",
+                                            "Withdrawn",
+                                            "You withdrew this talk. It has been removed from the schedule."
+                                        ),
                                     };
 
                                     html! {
@@ -180,6 +303,10 @@ pub fn my_talks() -> Html {
                                                 { state_help }
                                             </div>
                                             <p class="talk-summary">{ &talk.short_summary }</p>
+                                            <CoSpeakers
+                                                talk_id={talk.id.clone()}
+                                                speakers={talk.speakers.clone()}
+                                            />
                                             {
                                                 if !talk.labels.is_empty() {
                                                     html! {
@@ -226,6 +353,81 @@ pub fn my_talks() -> Html {
                                                 }
                                             }
 
+                                            // Submit button for drafts
+                                            {
+                                                if is_draft {
+                                                    html! {
+                                                        <div class="talk-actions">
+                                                            <div class="action-buttons">
+                                                                <button
+                                                                    onclick={create_submit_handler(talk.id.clone())}
+                                                                    disabled={is_responding}
+                                                                    class="btn-primary"
+                                                                >
+                                                                    { if is_responding { "Submitting..." } else { "Submit Talk" } }
+                                                                </button>
+                                                            </div>
+                                                        </div>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+
+                                            // Withdrawal of accepted talks
+                                            {
+                                                if is_accepted && is_withdraw_open {
+                                                    html! {
+                                                        <div class="talk-actions withdraw-form">
+                                                            <label for={format!("withdraw-{}", talk.id)}>
+                                                                { "Why are you withdrawing? The organizers will see this." }
+                                                            </label>
+                                                            <textarea
+                                                                id={format!("withdraw-{}", talk.id)}
+                                                                value={(*withdraw_reason).clone()}
+                                                                oninput={on_withdraw_reason_input.clone()}
+                                                                rows="3"
+                                                            />
+                                                            <div class="action-buttons">
+                                                                <button
+                                                                    onclick={create_withdraw_handler(talk.id.clone())}
+                                                                    disabled={is_responding}
+                                                                    class="btn-decline"
+                                                                >
+                                                                    { if is_responding { "Withdrawing..." } else { "Confirm Withdrawal" } }
+                                                                </button>
+                                                                <button
+                                                                    onclick={create_open_withdraw_handler(None)}
+                                                                    disabled={is_responding}
+                                                                    class="btn-secondary"
+                                                                >
+                                                                    { "Cancel" }
+                                                                </button>
+                                                            </div>
+                                                        </div>
+                                                    }
+                                                } else if is_accepted {
+                                                    html! {
+                                                        <div class="talk-actions">
+                                                            <button
+                                                                onclick={create_open_withdraw_handler(Some(talk.id.clone()))}
+                                                                class="btn-link"
+                                                            >
+                                                                { "Can't make it? Withdraw this talk" }
+                                                            </button>
+                                                        </div>
+                                                    }
+                                                } else if let Some(reason) = &talk.withdrawal_reason {
+                                                    html! {
+                                                        <div class="talk-status-help">
+                                                            { format!("Reason given: {}", reason) }
+                                                        </div>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+
                                             // Action buttons for pending talks
                                             {
                                                 if is_pending {
@@ -257,7 +459,30 @@ pub fn my_talks() -> Html {
                                                 }
                                             }
 
+                                            {
+                                                if talk.feedback.is_empty() {
+                                                    html! {}
+                                                } else {
+                                                    html! {
+                                                        <div class="speaker-feedback">
+                                                            <h4>{ "Feedback from the organizers" }</h4>
+                                                            {
+                                                                for talk.feedback.iter().map(|feedback| html! {
+                                                                    <p class="speaker-feedback-body">{ &feedback.body }</p>
+                                                                })
+                                                            }
+                                                        </div>
+                                                    }
+                                                }
+                                            }
+
                                             <div class="talk-meta">
+                                                <div class="meta-item">
+                                                    <span class="meta-label">{ "Format:" }</span>
+                                                    <span class="meta-value">
+                                                        { format!("{} ({} min)", talk.format.label(), talk.duration_minutes) }
+                                                    </span>
+                                                </div>
                                                 <div class="meta-item">
                                                     <span class="meta-label">{ "Submitted:" }</span>
                                                     <span class="meta-value">{ &talk.submitted_at[..10] }</span>
@@ -3,15 +3,68 @@ use yew_router::prelude::*;
 use crate::{
     app::Route,
     components::RatingStars,
-    services::dashboard::DashboardService,
-    types::{DashboardStats, TalkState},
+    services::{conferences::ConferenceService, dashboard::DashboardService},
+    types::{DashboardStats, ReviewPhase, ReviewPhases, TalkState},
 };
 
+/// The review cycle as a row of steps, with the current phase highlighted
+fn render_review_phases(phases: &ReviewPhases) -> Html {
+    let steps = [
+        (ReviewPhase::Submission, "Submission", &phases.submission_close),
+        (ReviewPhase::Review, "Review", &phases.review_deadline),
+        (ReviewPhase::Decision, "Decision", &phases.decision_deadline),
+        (ReviewPhase::Confirmation, "Confirmation", &phases.confirmation_deadline),
+    ];
+
+    html! {
+        <section class="dashboard-section">
+            <h2>{ "Review Phase" }</h2>
+            <ol class="review-phases">
+                {
+                    for steps.iter().map(|(phase, label, deadline)| html! {
+                        <li class={classes!("review-phase", (*phase == phases.phase).then_some("current"))}>
+                            <span class="review-phase-name">{ *label }</span>
+                            <span class="review-phase-deadline">
+                                {
+                                    match deadline {
+                                        Some(date) => format!("Ends {}", &date[..10]),
+                                        None => "No deadline".to_string(),
+                                    }
+                                }
+                            </span>
+                        </li>
+                    })
+                }
+            </ol>
+            <p class="review-phase-message">{ &phases.message }</p>
+            <p class="review-phase-locks">
+                { if phases.ratings_open { "Ratings open" } else { "Ratings locked" } }
+                { " · " }
+                { if phases.decisions_open { "Decisions open" } else { "Decisions locked" } }
+            </p>
+        </section>
+    }
+}
+
 #[function_component(OrganizerDashboard)]
 pub fn organizer_dashboard() -> Html {
     let stats = use_state(|| None::<DashboardStats>);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
+    let review_phases = use_state(|| None::<ReviewPhases>);
+
+    // Fetch the active conference's review phase on mount
+    {
+        let review_phases = review_phases.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(conference) = ConferenceService::get_active_conference().await {
+                    review_phases.set(conference.review_phases);
+                }
+            });
+            || ()
+        });
+    }
 
     // Fetch dashboard stats on mount
     {
@@ -58,6 +111,13 @@ pub fn organizer_dashboard() -> Html {
             <div class="organizer-dashboard">
                 <h1>{ "Organizer Dashboard" }</h1>
 
+                {
+                    match (*review_phases).as_ref() {
+                        Some(phases) => render_review_phases(phases),
+                        None => html! {},
+                    }
+                }
+
                 // Overview Stats
                 <section class="dashboard-section">
                     <h2>{ "Overview" }</h2>
@@ -118,6 +178,16 @@ pub fn organizer_dashboard() -> Html {
                             <div class="stat-value">{ data.talks_by_state.rejected }</div>
                             <div class="stat-label">{ "Rejected" }</div>
                         </div>
+
+                        <div class="stat-card state-withdrawn">
+                            <div class="stat-value">{ data.talks_by_state.withdrawn }</div>
+                            <div class="stat-label">{ "Withdrawn" }</div>
+                        </div>
+
+                        <div class="stat-card state-waitlisted">
+                            <div class="stat-value">{ data.talks_by_state.waitlisted }</div>
+                            <div class="stat-label">{ "Waitlisted" }</div>
+                        </div>
                     </div>
                 </section>
 
@@ -160,6 +230,9 @@ pub fn organizer_dashboard() -> Html {
                                         TalkState::Pending => "pending",
                                         TalkState::Accepted => "accepted",
                                         TalkState::Rejected => "rejected",
+                                        TalkState::Draft => "draft",
+                                        TalkState::Withdrawn => "withdrawn",
+                                        TalkState::Waitlisted => "waitlisted",
                                     };
 
                                     html! {
@@ -173,6 +246,9 @@ pub fn organizer_dashboard() -> Html {
                                                             TalkState::Pending => "Pending",
                                                             TalkState::Accepted => "Accepted",
                                                             TalkState::Rejected => "Rejected",
+                                                            TalkState::Draft => "Draft",
+                                                            TalkState::Withdrawn => "Withdrawn",
+                                                            TalkState::Waitlisted => "Waitlisted",
                                                         }
                                                     }
                                                 </span>
@@ -1,8 +1,9 @@
 use yew::prelude::*;
 use web_sys::HtmlInputElement;
 use crate::{
+    components::FieldErrorMessage,
     services::labels::LabelService,
-    types::{Label, CreateLabelRequest, UpdateLabelRequest},
+    types::{Label, CreateLabelRequest, FormError, UpdateLabelRequest},
 };
 
 #[function_component(OrganizerLabels)]
@@ -10,6 +11,8 @@ pub fn organizer_labels() -> Html {
     let labels = use_state(|| Vec::<Label>::new());
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
+    // Field errors for whichever label form was last submitted
+    let form_error = use_state(|| None::<FormError>);
     let search_term = use_state(|| String::new());
     let editing_label = use_state(|| None::<Label>);
     let show_create_form = use_state(|| false);
@@ -56,6 +59,7 @@ pub fn organizer_labels() -> Html {
         let create_color = create_color.clone();
         let show_create_form = show_create_form.clone();
         let error = error.clone();
+        let form_error = form_error.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
@@ -77,6 +81,7 @@ pub fn organizer_labels() -> Html {
             let create_color = create_color.clone();
             let show_create_form = show_create_form.clone();
             let error = error.clone();
+            let form_error = form_error.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
                 let request = CreateLabelRequest { name, description, color };
@@ -90,9 +95,11 @@ pub fn organizer_labels() -> Html {
                         create_color.set(String::from("#3498db"));
                         show_create_form.set(false);
                         error.set(None);
+                        form_error.set(None);
                     }
                     Err(e) => {
-                        error.set(Some(format!("Failed to create label: {}", e)));
+                        error.set(Some(format!("Failed to create label: {}", e.message)));
+                        form_error.set(Some(e));
                     }
                 }
             });
@@ -107,6 +114,7 @@ pub fn organizer_labels() -> Html {
         let edit_description = edit_description.clone();
         let edit_color = edit_color.clone();
         let error = error.clone();
+        let form_error = form_error.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
@@ -115,6 +123,7 @@ pub fn organizer_labels() -> Html {
                 let labels = labels.clone();
                 let editing_label = editing_label.clone();
                 let error = error.clone();
+                let form_error = form_error.clone();
 
                 let name = if !edit_name.is_empty() {
                     Some((*edit_name).clone())
@@ -143,9 +152,11 @@ pub fn organizer_labels() -> Html {
                             labels.set(current_labels);
                             editing_label.set(None);
                             error.set(None);
+                            form_error.set(None);
                         }
                         Err(e) => {
-                            error.set(Some(format!("Failed to update label: {}", e)));
+                            error.set(Some(format!("Failed to update label: {}", e.message)));
+                            form_error.set(Some(e));
                         }
                     }
                 });
@@ -195,8 +206,10 @@ pub fn organizer_labels() -> Html {
         let edit_name = edit_name.clone();
         let edit_description = edit_description.clone();
         let edit_color = edit_color.clone();
+        let form_error = form_error.clone();
 
         Callback::from(move |label: Label| {
+            form_error.set(None);
             edit_name.set(label.name.clone());
             edit_description.set(label.description.clone().unwrap_or_default());
             edit_color.set(label.color.clone().unwrap_or_default());
@@ -259,6 +272,7 @@ pub fn organizer_labels() -> Html {
                                     create_name.set(input.value());
                                 })}
                             />
+                            <FieldErrorMessage error={(*form_error).clone()} field="name" />
                         </div>
 
                         <div class="form-group">
@@ -272,6 +286,7 @@ pub fn organizer_labels() -> Html {
                                     create_description.set(input.value());
                                 })}
                             />
+                            <FieldErrorMessage error={(*form_error).clone()} field="description" />
                         </div>
 
                         <div class="form-group">
@@ -290,6 +305,7 @@ pub fn organizer_labels() -> Html {
                                     { &*create_color }
                                 </span>
                             </div>
+                            <FieldErrorMessage error={(*form_error).clone()} field="color" />
                         </div>
 
                         <div class="form-actions">
@@ -355,6 +371,7 @@ pub fn organizer_labels() -> Html {
                                                         edit_name_clone.set(input.value());
                                                     })}
                                                 />
+                                                <FieldErrorMessage error={(*form_error).clone()} field="name" />
                                             </div>
                                             <div class="form-group">
                                                 <textarea
@@ -366,6 +383,7 @@ pub fn organizer_labels() -> Html {
                                                         edit_description_clone.set(input.value());
                                                     })}
                                                 />
+                                                <FieldErrorMessage error={(*form_error).clone()} field="description" />
                                             </div>
                                             <div class="form-group">
                                                 <div class="color-picker-group">
@@ -4,17 +4,36 @@ use wasm_bindgen_futures::spawn_local;
 use std::collections::HashMap;
 
 use crate::{
-    components::{LabelBadge, RatingForm, RatingStars},
+    components::{
+        LabelBadge, RatingForm, RatingStars, RevisionHistory, SpeakerFeedbackEditor,
+        TalkDiscussion,
+    },
     services::{talks::TalkService, ratings::RatingService},
-    types::{Talk, Rating, TalkState, ChangeStateRequest},
+    types::{Talk, Rating, TalkState, TalkFormat, ChangeStateRequest, PromoteTalkRequest},
 };
 
+/// Display a custom answer as plain text
+fn format_answer(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Bool(true) => "Yes".to_string(),
+        serde_json::Value::Bool(false) => "No".to_string(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(format_answer)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
 #[function_component(OrganizerTalks)]
 pub fn organizer_talks() -> Html {
     let talks = use_state(|| Vec::<Talk>::new());
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let selected_filter = use_state(|| String::from(""));
+    let selected_format = use_state(|| String::from(""));
     let my_ratings = use_state(|| HashMap::<String, Rating>::new());
     let all_ratings = use_state(|| HashMap::<String, Vec<Rating>>::new());
     let expanded_talk_id = use_state(|| None::<String>);
@@ -26,8 +45,9 @@ pub fn organizer_talks() -> Html {
         let error = error.clone();
         let my_ratings = my_ratings.clone();
         let filter = (*selected_filter).clone();
+        let format = TalkFormat::from_value(&selected_format);
 
-        use_effect_with(selected_filter.clone(), move |_| {
+        use_effect_with((selected_filter.clone(), selected_format.clone()), move |_| {
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
@@ -38,7 +58,7 @@ pub fn organizer_talks() -> Html {
                     Some(filter)
                 };
 
-                match TalkService::list_all_talks(state_filter).await {
+                match TalkService::list_all_talks_filtered(state_filter, format).await {
                     Ok(fetched_talks) => {
                         talks.set(fetched_talks.clone());
 
@@ -71,6 +91,14 @@ pub fn organizer_talks() -> Html {
         })
     };
 
+    let on_format_filter_change = {
+        let selected_format = selected_format.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            selected_format.set(select.value());
+        })
+    };
+
     html! {
         <div class="talks-container">
             <div class="talks-header">
@@ -88,6 +116,22 @@ pub fn organizer_talks() -> Html {
                         <option value="pending">{ "Pending" }</option>
                         <option value="accepted">{ "Accepted" }</option>
                         <option value="rejected">{ "Rejected" }</option>
+                        <option value="withdrawn">{ "Withdrawn" }</option>
+                        <option value="waitlisted">{ "Waitlisted" }</option>
+                    </select>
+                    <label for="format-filter">{ "Format: " }</label>
+                    <select
+                        id="format-filter"
+                        class="filter-dropdown"
+                        onchange={on_format_filter_change}
+                        value={(*selected_format).clone()}
+                    >
+                        <option value="">{ "All" }</option>
+                        {
+                            for TalkFormat::ALL.iter().map(|format| html! {
+                                <option value={format.as_str()}>{ format.label() }</option>
+                            })
+                        }
                     </select>
                 </div>
             </div>
@@ -108,7 +152,7 @@ pub fn organizer_talks() -> Html {
                         <div class="empty-state">
                             <p>{ "No talks found" }</p>
                             {
-                                if !(*selected_filter).is_empty() {
+                                if !(*selected_filter).is_empty() || !(*selected_format).is_empty() {
                                     html! {
                                         <p>{ "Try changing the filter or removing it to see all talks." }</p>
                                     }
@@ -169,6 +213,43 @@ pub fn organizer_talks() -> Html {
                                         }
                                     });
 
+                                    let talks_clone = talks.clone();
+                                    let error_clone = error.clone();
+                                    let talk_id_for_reveal = talk_id.clone();
+                                    let on_reveal_speakers = Callback::from(move |_: MouseEvent| {
+                                        let Some(window) = web_sys::window() else {
+                                            return;
+                                        };
+                                        // Cancelling the prompt cancels the reveal
+                                        let Ok(Some(reason)) = window.prompt_with_message(
+                                            "Reveal who submitted this talk? The reveal is logged. Reason (optional):",
+                                        ) else {
+                                            return;
+                                        };
+                                        let reason = Some(reason.trim().to_string()).filter(|r| !r.is_empty());
+                                        let talks = talks_clone.clone();
+                                        let error = error_clone.clone();
+                                        let talk_id = talk_id_for_reveal.clone();
+                                        spawn_local(async move {
+                                            match TalkService::reveal_speakers(&talk_id, reason).await {
+                                                Ok(speakers) => {
+                                                    let mut updated = (*talks).clone();
+                                                    if let Some(talk) = updated.iter_mut().find(|t| t.id == talk_id) {
+                                                        if let Some(primary) = speakers.iter().find(|s| s.is_primary) {
+                                                            talk.speaker_name = primary.full_name.clone();
+                                                            talk.speaker_email = primary.email.clone();
+                                                            talk.speaker_id = primary.user_id.clone().unwrap_or_default();
+                                                        }
+                                                        talk.speakers = speakers;
+                                                        talk.speakers_hidden = false;
+                                                    }
+                                                    talks.set(updated);
+                                                }
+                                                Err(e) => error.set(Some(format!("Failed to reveal speakers: {}", e))),
+                                            }
+                                        });
+                                    });
+
                                     let talk_ratings = all_ratings.get(&talk_id);
                                     let average_rating = talk_ratings.as_ref().and_then(|ratings| {
                                         if ratings.is_empty() {
@@ -184,20 +265,76 @@ pub fn organizer_talks() -> Html {
                                             <div class="talk-header">
                                                 <h3>{ &talk.title }</h3>
                                                 <span class={classes!("talk-status", state_class)}>
-                                                    { format!("{:?}", talk.state) }
+                                                    {
+                                                        match talk.waitlist_rank {
+                                                            Some(rank) => format!("Waitlisted #{}", rank),
+                                                            None => format!("{:?}", talk.state),
+                                                        }
+                                                    }
                                                 </span>
                                             </div>
 
                                             <div class="speaker-info">
                                                 <strong>{ "Speaker: " }</strong>
                                                 { &talk.speaker_name }
-                                                <span class="speaker-email">
-                                                    { " <" }{ &talk.speaker_email }{ ">" }
-                                                </span>
+                                                {
+                                                    if talk.speakers_hidden {
+                                                        html! {
+                                                            <>
+                                                                <span class="blind-review-badge">{ "Blind review" }</span>
+                                                                <button class="btn-secondary btn-sm reveal-speakers" onclick={on_reveal_speakers}>
+                                                                    { "Reveal speakers" }
+                                                                </button>
+                                                            </>
+                                                        }
+                                                    } else {
+                                                        html! {
+                                                            <span class="speaker-email">
+                                                                { " <" }{ &talk.speaker_email }{ ">" }
+                                                            </span>
+                                                        }
+                                                    }
+                                                }
+                                            </div>
+
+                                            <div class="talk-format">
+                                                { format!("{} · {} min", talk.format.label(), talk.duration_minutes) }
                                             </div>
 
                                             <p class="talk-summary">{ &talk.short_summary }</p>
 
+                                            {
+                                                if let Some(reason) = &talk.withdrawal_reason {
+                                                    html! {
+                                                        <div class="talk-status-help">
+                                                            <strong>{ "Withdrawn by the speaker: " }</strong>
+                                                            { reason }
+                                                        </div>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+
+                                            {
+                                                if !talk.custom_answers.is_empty() {
+                                                    html! {
+                                                        <dl class="custom-answers">
+                                                            {
+                                                                for talk.custom_answers.iter().map(|(key, value)| html! {
+                                                                    <>
+                                                                        <dt>{ key.replace('_', " ") }</dt>
+                                                                        <dd>{ format_answer(value) }</dd>
+                                                                    </>
+                                                                })
+                                                            }
+                                                        </dl>
+                                                    }
+                                                } else {
+                                                    html! {}
+                                                }
+                                            }
+
                                             {
                                                 if !talk.labels.is_empty() {
                                                     html! {
@@ -250,9 +387,13 @@ pub fn organizer_talks() -> Html {
                                                 <small>{ format!("Submitted: {}", talk.submitted_at) }</small>
                                             </div>
 
+                                            <RevisionHistory talk_id={talk_id.clone()} />
+                                            <TalkDiscussion talk_id={talk_id.clone()} />
+                                            <SpeakerFeedbackEditor talk_id={talk_id.clone()} />
+
                                             {
                                                 // State change actions for non-terminal states
-                                                if talk.state != TalkState::Accepted && talk.state != TalkState::Rejected {
+                                                if !matches!(talk.state, TalkState::Accepted | TalkState::Rejected | TalkState::Withdrawn) {
                                                     let talks_clone = talks.clone();
                                                     let talk_id_for_state = talk_id.clone();
                                                     let current_state = talk.state.clone();
@@ -261,22 +402,36 @@ pub fn organizer_talks() -> Html {
                                                         let talk_id = talk_id_for_state.clone();
                                                         let talks = talks_clone.clone();
 
+                                                        let current_state = current_state.clone();
                                                         Callback::from(move |new_state: TalkState| {
                                                             let talk_id = talk_id.clone();
                                                             let talks = talks.clone();
+                                                            // Leaving the waitlist upwards goes through promote
+                                                            let promote = current_state == TalkState::Waitlisted
+                                                                && matches!(new_state, TalkState::Pending | TalkState::Accepted);
 
                                                             spawn_local(async move {
-                                                                let request = ChangeStateRequest {
-                                                                    new_state: new_state.clone(),
-                                                                    reason: None,
+                                                                let result = if promote {
+                                                                    let request = PromoteTalkRequest {
+                                                                        target: new_state.clone(),
+                                                                        slot_id: None,
+                                                                    };
+                                                                    TalkService::promote_talk(&talk_id, request).await
+                                                                } else {
+                                                                    let request = ChangeStateRequest {
+                                                                        new_state: new_state.clone(),
+                                                                        reason: None,
+                                                                    };
+                                                                    TalkService::change_state(&talk_id, request).await
                                                                 };
 
-                                                                match TalkService::change_state(&talk_id, request).await {
+                                                                match result {
                                                                     Ok(updated_talk) => {
                                                                         // Update the talk in the list
                                                                         let mut talks_list = (*talks).clone();
                                                                         if let Some(talk) = talks_list.iter_mut().find(|t| t.id == talk_id) {
                                                                             talk.state = updated_talk.state;
+                                                                            talk.waitlist_rank = updated_talk.waitlist_rank;
                                                                             talk.updated_at = updated_talk.updated_at;
                                                                         }
                                                                         talks.set(talks_list);
@@ -306,6 +461,15 @@ pub fn organizer_talks() -> Html {
                                                                                 >
                                                                                     { "Move to Pending" }
                                                                                 </button>
+                                                                                <button
+                                                                                    class="btn-secondary"
+                                                                                    onclick={
+                                                                                        let on_change = on_state_change.clone();
+                                                                                        Callback::from(move |_| on_change.emit(TalkState::Waitlisted))
+                                                                                    }
+                                                                                >
+                                                                                    { "Waitlist" }
+                                                                                </button>
                                                                                 <button
                                                                                     class="btn-decline"
                                                                                     onclick={
@@ -329,6 +493,37 @@ pub fn organizer_talks() -> Html {
                                                                                 { "Return to Submitted" }
                                                                             </button>
                                                                         },
+                                                                        TalkState::Waitlisted => html! {
+                                                                            <>
+                                                                                <button
+                                                                                    class="btn-accept"
+                                                                                    onclick={
+                                                                                        let on_change = on_state_change.clone();
+                                                                                        Callback::from(move |_| on_change.emit(TalkState::Pending))
+                                                                                    }
+                                                                                >
+                                                                                    { "Promote to Pending" }
+                                                                                </button>
+                                                                                <button
+                                                                                    class="btn-primary"
+                                                                                    onclick={
+                                                                                        let on_change = on_state_change.clone();
+                                                                                        Callback::from(move |_| on_change.emit(TalkState::Accepted))
+                                                                                    }
+                                                                                >
+                                                                                    { "Promote & Accept" }
+                                                                                </button>
+                                                                                <button
+                                                                                    class="btn-decline"
+                                                                                    onclick={
+                                                                                        let on_change = on_state_change.clone();
+                                                                                        Callback::from(move |_| on_change.emit(TalkState::Rejected))
+                                                                                    }
+                                                                                >
+                                                                                    { "Reject" }
+                                                                                </button>
+                                                                            </>
+                                                                        },
                                                                         _ => html! {}
                                                                     }
                                                                 }
@@ -342,8 +537,20 @@ pub fn organizer_talks() -> Html {
 
                                             <div class="rating-section">
                                                 <h3>{ "Rate This Talk" }</h3>
+                                                {
+                                                    if existing_rating.as_ref().is_some_and(|r| r.talk_changed_since_rating) {
+                                                        html! {
+                                                            <div class="talk-changed-notice">
+                                                                { "This talk was edited after you rated it." }
+                                                            </div>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
                                                 <RatingForm
                                                     talk_id={talk_id.clone()}
+                                                    conference_id={talk.conference_id.clone()}
                                                     existing_rating={existing_rating}
                                                     on_success={on_rating_success}
                                                     on_delete={on_rating_delete}
@@ -418,10 +625,30 @@ pub fn organizer_talks() -> Html {
                                                                                                                     interactive={false}
                                                                                                                     size="small"
                                                                                                                 />
+                                                                                                                {
+                                                                                                                    if let Some(weighted) = rating.weighted_score {
+                                                                                                                        html! {
+                                                                                                                            <span class="weighted-score">
+                                                                                                                                { format!("Weighted: {:.2}", weighted) }
+                                                                                                                            </span>
+                                                                                                                        }
+                                                                                                                    } else {
+                                                                                                                        html! {}
+                                                                                                                    }
+                                                                                                                }
                                                                                                             </div>
                                                                                                             <span class="rating-date">
                                                                                                                 { &rating.created_at }
                                                                                                             </span>
+                                                                                                            {
+                                                                                                                if rating.talk_changed_since_rating {
+                                                                                                                    html! {
+                                                                                                                        <span class="talk-changed-badge">{ "Talk edited since" }</span>
+                                                                                                                    }
+                                                                                                                } else {
+                                                                                                                    html! {}
+                                                                                                                }
+                                                                                                            }
                                                                                                         </div>
                                                                                                         {
                                                                                                             if let Some(notes) = &rating.notes {
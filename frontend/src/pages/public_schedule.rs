@@ -90,6 +90,9 @@ pub fn public_schedule() -> Html {
         <div class="public-schedule-container">
             <div class="page-header">
                 <h1>{ "Conference Schedule" }</h1>
+                <a class="calendar-subscribe" href="/api/schedule.ics">{ "Add to Calendar" }</a>
+                <a class="calendar-subscribe" href="/api/schedule.xml">{ "schedule.xml" }</a>
+                <a class="calendar-subscribe" href="/api/schedule.json">{ "schedule.json" }</a>
             </div>
 
             if let Some(err) = (*error).as_ref() {
@@ -123,9 +126,11 @@ pub fn public_schedule() -> Html {
                         {
                             track_names.iter().map(|track_name| {
                                 let slots = schedule_by_track.get(track_name).unwrap();
+                                let track_calendar = format!("/api/tracks/{}/schedule.ics", slots[0].track_id);
                                 html! {
                                     <div class="track-column" key={track_name.clone()}>
                                         <h2 class="track-header">{ track_name }</h2>
+                                        <a class="calendar-subscribe" href={track_calendar}>{ "Add this track to your calendar" }</a>
                                         <div class="track-slots">
                                             {
                                                 slots.iter().map(|slot| {
@@ -12,19 +12,21 @@ pub fn ratings_dashboard() -> Html {
     let statistics = use_state(|| None::<RatingsStatisticsResponse>);
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
+    let sort = use_state(|| "rating_count".to_string());
 
-    // Fetch statistics on mount
+    // Fetch statistics on mount and whenever the ranking changes
     {
         let statistics = statistics.clone();
         let loading = loading.clone();
         let error = error.clone();
 
-        use_effect_with((), move |_| {
+        use_effect_with((*sort).clone(), move |sort| {
+            let sort = sort.clone();
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
 
-                match RatingService::get_statistics().await {
+                match RatingService::get_statistics(&sort).await {
                     Ok(stats) => {
                         statistics.set(Some(stats));
                         loading.set(false);
@@ -39,6 +41,14 @@ pub fn ratings_dashboard() -> Html {
         });
     }
 
+    let on_sort_change = {
+        let sort = sort.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            sort.set(select.value());
+        })
+    };
+
     html! {
         <div class="ratings-dashboard">
             <h2>{ "Ratings Dashboard" }</h2>
@@ -87,6 +97,30 @@ pub fn ratings_dashboard() -> Html {
                                         html! {}
                                     }
                                 }
+                                {
+                                    if let Some(avg) = stats.overall_weighted_average {
+                                        html! {
+                                            <div class="stat-card">
+                                                <div class="stat-value">{ format!("{:.2}", avg) }</div>
+                                                <div class="stat-label">{ "Weighted Rubric Score" }</div>
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                                {
+                                    if stats.total_comparisons > 0 {
+                                        html! {
+                                            <div class="stat-card">
+                                                <div class="stat-value">{ stats.total_comparisons }</div>
+                                                <div class="stat-label">{ "Comparisons" }</div>
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
                             </div>
 
                             <div class="rating-distribution-section">
@@ -129,8 +163,59 @@ pub fn ratings_dashboard() -> Html {
                                 </div>
                             </div>
 
+                            {
+                                if stats.reviewer_bias.is_empty() {
+                                    html! {}
+                                } else {
+                                    html! {
+                                        <div class="reviewer-bias-section">
+                                            <h3>{ "Reviewer Bias" }</h3>
+                                            <table class="reviewer-bias-table">
+                                                <thead>
+                                                    <tr>
+                                                        <th>{ "Reviewer" }</th>
+                                                        <th>{ "Ratings" }</th>
+                                                        <th>{ "Mean" }</th>
+                                                        <th>{ "Std. Dev." }</th>
+                                                        <th>{ "Bias" }</th>
+                                                    </tr>
+                                                </thead>
+                                                <tbody>
+                                                    {
+                                                        for stats.reviewer_bias.iter().map(|reviewer| {
+                                                            html! {
+                                                                <tr key={reviewer.reviewer_id.clone()}>
+                                                                    <td>{ &reviewer.reviewer_name }</td>
+                                                                    <td>{ reviewer.rating_count }</td>
+                                                                    <td>{ format!("{:.2}", reviewer.mean_rating) }</td>
+                                                                    <td>{ format!("{:.2}", reviewer.std_dev) }</td>
+                                                                    <td>{ format!("{:+.2}", reviewer.bias) }</td>
+                                                                </tr>
+                                                            }
+                                                        })
+                                                    }
+                                                </tbody>
+                                            </table>
+                                        </div>
+                                    }
+                                }
+                            }
+
                             <div class="talk-stats-section">
-                                <h3>{ "Talk Ratings" }</h3>
+                                <div class="talk-stats-header">
+                                    <h3>{ "Talk Ratings" }</h3>
+                                    <label>
+                                        { "Rank by " }
+                                        <select onchange={on_sort_change}>
+                                            <option value="rating_count" selected={*sort == "rating_count"}>{ "Most rated" }</option>
+                                            <option value="average" selected={*sort == "average"}>{ "Average rating" }</option>
+                                            <option value="normalized" selected={*sort == "normalized"}>{ "Normalized score" }</option>
+                                            <option value="trimmed" selected={*sort == "trimmed"}>{ "Trimmed mean" }</option>
+                                            <option value="weighted" selected={*sort == "weighted"}>{ "Weighted rubric score" }</option>
+                                            <option value="pairwise" selected={*sort == "pairwise"}>{ "Pairwise ranking" }</option>
+                                        </select>
+                                    </label>
+                                </div>
                                 <div class="talk-stats-table">
                                     {
                                         for stats.talk_stats.iter().map(|talk_stat| {
@@ -161,6 +246,57 @@ pub fn ratings_dashboard() -> Html {
                                                                         <div class="rating-count-display">
                                                                             { format!("({} rating{})", talk_stat.rating_count, if talk_stat.rating_count == 1 { "" } else { "s" }) }
                                                                         </div>
+                                                                        {
+                                                                            if let Some(normalized) = talk_stat.normalized_score {
+                                                                                html! {
+                                                                                    <div class="normalized-score">
+                                                                                        { format!("Normalized: {:+.2}", normalized) }
+                                                                                    </div>
+                                                                                }
+                                                                            } else {
+                                                                                html! {}
+                                                                            }
+                                                                        }
+                                                                        {
+                                                                            if let Some(trimmed) = talk_stat.trimmed_average {
+                                                                                html! {
+                                                                                    <div class="trimmed-average">
+                                                                                        { format!("Trimmed mean: {:.2}", trimmed) }
+                                                                                    </div>
+                                                                                }
+                                                                            } else {
+                                                                                html! {}
+                                                                            }
+                                                                        }
+                                                                        {
+                                                                            if let Some(weighted) = talk_stat.weighted_average {
+                                                                                html! {
+                                                                                    <div class="weighted-score">
+                                                                                        { format!("Weighted: {:.2}", weighted) }
+                                                                                    </div>
+                                                                                }
+                                                                            } else {
+                                                                                html! {}
+                                                                            }
+                                                                        }
+                                                                        <ul class="criteria-averages">
+                                                                            {
+                                                                                for talk_stat.criteria_averages.iter().map(|criterion| {
+                                                                                    html! {
+                                                                                        <li key={criterion.criterion_id.clone()}>
+                                                                                            { &criterion.name }
+                                                                                            { ": " }
+                                                                                            {
+                                                                                                criterion
+                                                                                                    .average
+                                                                                                    .map(|avg| format!("{:.1}", avg))
+                                                                                                    .unwrap_or_else(|| "-".to_string())
+                                                                                            }
+                                                                                        </li>
+                                                                                    }
+                                                                                })
+                                                                            }
+                                                                        </ul>
                                                                     </>
                                                                 }
                                                             } else {
@@ -169,6 +305,17 @@ pub fn ratings_dashboard() -> Html {
                                                                 }
                                                             }
                                                         }
+                                                        {
+                                                            if let Some(pairwise) = talk_stat.pairwise_score {
+                                                                html! {
+                                                                    <div class="pairwise-score">
+                                                                        { format!("Pairwise: {:.0} ({} comparison{})", pairwise, talk_stat.comparison_count, if talk_stat.comparison_count == 1 { "" } else { "s" }) }
+                                                                    </div>
+                                                                }
+                                                            } else {
+                                                                html! {}
+                                                            }
+                                                        }
                                                     </div>
                                                 </div>
                                             }
@@ -0,0 +1,459 @@
+use yew::prelude::*;
+use web_sys::HtmlInputElement;
+use wasm_bindgen_futures::spawn_local;
+
+use crate::{
+    components::RatingStars,
+    services::{conferences::ConferenceService, labels::LabelService, reviews::ReviewService},
+    types::{
+        AssignReviewersRequest, DeclareConflictRequest, Label, ReviewCompletionReport,
+        ReviewConflict, ReviewQueueItem,
+    },
+};
+
+#[function_component(ReviewQueue)]
+pub fn review_queue() -> Html {
+    let conference_id = use_state(|| None::<String>);
+    let queue = use_state(Vec::<ReviewQueueItem>::new);
+    let report = use_state(|| None::<ReviewCompletionReport>);
+    let labels = use_state(Vec::<Label>::new);
+    let expertise = use_state(Vec::<String>::new);
+    let conflicts = use_state(Vec::<ReviewConflict>::new);
+    let conflict_domain = use_state(String::new);
+    let reviews_per_talk = use_state(|| 3);
+    let match_expertise = use_state(|| true);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+    let message = use_state(|| None::<String>);
+
+    // Fetch the queue, report and expertise for the active conference on mount
+    {
+        let conference_id = conference_id.clone();
+        let queue = queue.clone();
+        let report = report.clone();
+        let labels = labels.clone();
+        let expertise = expertise.clone();
+        let conflicts = conflicts.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                loading.set(true);
+
+                let conference = match ConferenceService::get_active_conference().await {
+                    Ok(conference) => conference,
+                    Err(e) => {
+                        error.set(Some(e));
+                        loading.set(false);
+                        return;
+                    }
+                };
+
+                let queue_result = ReviewService::get_my_queue(Some(&conference.id)).await;
+                let report_result = ReviewService::get_completion_report(&conference.id).await;
+                let labels_result = LabelService::list_labels().await;
+                let expertise_result = ReviewService::get_my_expertise().await;
+                if let Ok(conflicts_data) = ReviewService::list_my_conflicts().await {
+                    conflicts.set(conflicts_data);
+                }
+
+                match (queue_result, report_result, labels_result, expertise_result) {
+                    (Ok(queue_data), Ok(report_data), Ok(labels_data), Ok(expertise_data)) => {
+                        queue.set(queue_data);
+                        report.set(Some(report_data));
+                        labels.set(labels_data);
+                        expertise.set(expertise_data.into_iter().map(|l| l.id).collect());
+                        error.set(None);
+                    }
+                    (Err(e), _, _, _) | (_, Err(e), _, _) | (_, _, Err(e), _) | (_, _, _, Err(e)) => {
+                        error.set(Some(e));
+                    }
+                }
+                conference_id.set(Some(conference.id));
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let on_reviews_per_talk_change = {
+        let reviews_per_talk = reviews_per_talk.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Ok(value) = input.value().parse::<i32>() {
+                reviews_per_talk.set(value);
+            }
+        })
+    };
+
+    let on_match_expertise_change = {
+        let match_expertise = match_expertise.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            match_expertise.set(input.checked());
+        })
+    };
+
+    let on_assign = {
+        let conference_id = conference_id.clone();
+        let queue = queue.clone();
+        let report = report.clone();
+        let reviews_per_talk = reviews_per_talk.clone();
+        let match_expertise = match_expertise.clone();
+        let error = error.clone();
+        let message = message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference_id) = (*conference_id).clone() else {
+                return;
+            };
+            let queue = queue.clone();
+            let report = report.clone();
+            let error = error.clone();
+            let message = message.clone();
+            let request = AssignReviewersRequest {
+                reviews_per_talk: *reviews_per_talk,
+                reviewer_ids: None,
+                match_expertise: *match_expertise,
+            };
+
+            spawn_local(async move {
+                match ReviewService::assign_reviewers(&conference_id, request).await {
+                    Ok(result) => {
+                        let mut text = format!("Made {} new assignment(s)", result.new_assignments);
+                        if !result.understaffed_talk_ids.is_empty() {
+                            text.push_str(&format!(
+                                "; {} talk(s) still need more reviewers",
+                                result.understaffed_talk_ids.len()
+                            ));
+                        }
+                        message.set(Some(text));
+                        error.set(None);
+
+                        if let Ok(queue_data) = ReviewService::get_my_queue(Some(&conference_id)).await {
+                            queue.set(queue_data);
+                        }
+                        if let Ok(report_data) = ReviewService::get_completion_report(&conference_id).await {
+                            report.set(Some(report_data));
+                        }
+                    }
+                    Err(e) => error.set(Some(format!("Failed to assign reviewers: {}", e))),
+                }
+            });
+        })
+    };
+
+    let create_expertise_toggle = |label_id: String| {
+        let expertise = expertise.clone();
+        Callback::from(move |_: Event| {
+            let mut current = (*expertise).clone();
+            if let Some(pos) = current.iter().position(|id| *id == label_id) {
+                current.remove(pos);
+            } else {
+                current.push(label_id.clone());
+            }
+            expertise.set(current);
+        })
+    };
+
+    let on_save_expertise = {
+        let expertise = expertise.clone();
+        let error = error.clone();
+        let message = message.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let expertise = expertise.clone();
+            let error = error.clone();
+            let message = message.clone();
+            let label_ids = (*expertise).clone();
+
+            spawn_local(async move {
+                match ReviewService::set_my_expertise(label_ids).await {
+                    Ok(saved) => {
+                        expertise.set(saved.into_iter().map(|l| l.id).collect());
+                        message.set(Some("Expertise saved".to_string()));
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to save expertise: {}", e))),
+                }
+            });
+        })
+    };
+
+    // Declaring a conflict also takes the reviewer off the covered talks
+    let declare = {
+        let conference_id = conference_id.clone();
+        let queue = queue.clone();
+        let conflicts = conflicts.clone();
+        let error = error.clone();
+        let message = message.clone();
+
+        move |request: DeclareConflictRequest| {
+            let conference_id = (*conference_id).clone();
+            let queue = queue.clone();
+            let conflicts = conflicts.clone();
+            let error = error.clone();
+            let message = message.clone();
+
+            spawn_local(async move {
+                match ReviewService::declare_conflict(request).await {
+                    Ok(conflict) => {
+                        let mut current = (*conflicts).clone();
+                        current.insert(0, conflict);
+                        conflicts.set(current);
+                        message.set(Some("Conflict of interest declared".to_string()));
+                        error.set(None);
+
+                        if let Ok(queue_data) = ReviewService::get_my_queue(conference_id.as_deref()).await {
+                            queue.set(queue_data);
+                        }
+                    }
+                    Err(e) => error.set(Some(format!("Failed to declare conflict: {}", e))),
+                }
+            });
+        }
+    };
+
+    let create_talk_conflict_handler = |talk_id: String| {
+        let declare = declare.clone();
+        Callback::from(move |_: MouseEvent| {
+            declare(DeclareConflictRequest {
+                talk_id: Some(talk_id.clone()),
+                ..Default::default()
+            });
+        })
+    };
+
+    let on_conflict_domain_input = {
+        let conflict_domain = conflict_domain.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            conflict_domain.set(input.value());
+        })
+    };
+
+    let on_declare_domain = {
+        let declare = declare.clone();
+        let conflict_domain = conflict_domain.clone();
+        Callback::from(move |_: MouseEvent| {
+            if conflict_domain.trim().is_empty() {
+                return;
+            }
+            declare(DeclareConflictRequest {
+                email_domain: Some((*conflict_domain).clone()),
+                ..Default::default()
+            });
+            conflict_domain.set(String::new());
+        })
+    };
+
+    let create_withdraw_handler = |conflict_id: String| {
+        let conflicts = conflicts.clone();
+        let error = error.clone();
+        Callback::from(move |_: MouseEvent| {
+            let conflicts = conflicts.clone();
+            let error = error.clone();
+            let conflict_id = conflict_id.clone();
+
+            spawn_local(async move {
+                match ReviewService::withdraw_conflict(&conflict_id).await {
+                    Ok(()) => {
+                        let current: Vec<ReviewConflict> = (*conflicts)
+                            .iter()
+                            .filter(|c| c.id != conflict_id)
+                            .cloned()
+                            .collect();
+                        conflicts.set(current);
+                    }
+                    Err(e) => error.set(Some(format!("Failed to withdraw conflict: {}", e))),
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="review-queue-page">
+            <h2>{ "Review Queue" }</h2>
+
+            if let Some(err) = (*error).as_ref() {
+                <div class="error-message">{ err }</div>
+            }
+            if let Some(msg) = (*message).as_ref() {
+                <div class="success-message">{ msg }</div>
+            }
+
+            {
+                if *loading {
+                    html! { <div class="loading">{ "Loading review queue..." }</div> }
+                } else {
+                    html! {
+                        <>
+                            <section class="my-review-queue">
+                                <h3>{ "My Assigned Talks" }</h3>
+                                {
+                                    if queue.is_empty() {
+                                        html! { <p class="empty-state">{ "No talks assigned to you yet." }</p> }
+                                    } else {
+                                        html! {
+                                            <ul class="review-queue-list">
+                                                { for queue.iter().map(|item| html! {
+                                                    <li
+                                                        key={item.talk_id.clone()}
+                                                        class={classes!("review-queue-item", item.rating.is_some().then_some("reviewed"))}
+                                                    >
+                                                        <strong>{ &item.title }</strong>
+                                                        <p>{ &item.short_summary }</p>
+                                                        {
+                                                            if let Some(rating) = item.rating {
+                                                                html! { <RatingStars rating={rating} interactive={false} size="small" /> }
+                                                            } else {
+                                                                html! { <span class="review-pending">{ "Awaiting your review" }</span> }
+                                                            }
+                                                        }
+                                                        <button
+                                                            class="btn-secondary btn-sm declare-conflict"
+                                                            onclick={create_talk_conflict_handler(item.talk_id.clone())}
+                                                        >
+                                                            { "Declare conflict" }
+                                                        </button>
+                                                    </li>
+                                                }) }
+                                            </ul>
+                                        }
+                                    }
+                                }
+                            </section>
+
+                            <section class="reviewer-expertise">
+                                <h3>{ "My Expertise" }</h3>
+                                <div class="label-checkboxes">
+                                    { for labels.iter().map(|label| html! {
+                                        <label class="label-checkbox" key={label.id.clone()}>
+                                            <input
+                                                type="checkbox"
+                                                checked={expertise.contains(&label.id)}
+                                                onchange={create_expertise_toggle(label.id.clone())}
+                                            />
+                                            { format!(" {}", label.name) }
+                                        </label>
+                                    }) }
+                                </div>
+                                <button class="btn-secondary" onclick={on_save_expertise}>{ "Save Expertise" }</button>
+                            </section>
+
+                            <section class="review-conflicts">
+                                <h3>{ "My Conflicts of Interest" }</h3>
+                                <p class="help-text">
+                                    { "You can't rate talks covered by a conflict, and any ratings you gave them are left out of the results." }
+                                </p>
+                                <ul class="review-conflict-list">
+                                    { for conflicts.iter().map(|conflict| {
+                                        let target = if let Some(domain) = &conflict.email_domain {
+                                            format!("Anyone at @{}", domain)
+                                        } else if let Some(title) = &conflict.talk_title {
+                                            format!("Talk: {}", title)
+                                        } else {
+                                            format!("Speaker: {}", conflict.speaker_name.clone().unwrap_or_default())
+                                        };
+                                        html! {
+                                            <li key={conflict.id.clone()}>
+                                                { target }
+                                                <button
+                                                    class="btn-secondary btn-sm"
+                                                    onclick={create_withdraw_handler(conflict.id.clone())}
+                                                >
+                                                    { "Withdraw" }
+                                                </button>
+                                            </li>
+                                        }
+                                    }) }
+                                </ul>
+                                <div class="form-group">
+                                    <label for="conflict-domain">{ "Email domain" }</label>
+                                    <input
+                                        id="conflict-domain"
+                                        type="text"
+                                        placeholder="example.com"
+                                        value={(*conflict_domain).clone()}
+                                        oninput={on_conflict_domain_input}
+                                    />
+                                </div>
+                                <button class="btn-secondary" onclick={on_declare_domain}>{ "Declare Conflict" }</button>
+                            </section>
+
+                            <section class="assign-reviewers">
+                                <h3>{ "Assign Reviewers" }</h3>
+                                <div class="form-group">
+                                    <label for="reviews-per-talk">{ "Reviews per talk" }</label>
+                                    <input
+                                        id="reviews-per-talk"
+                                        type="number"
+                                        min="1"
+                                        value={reviews_per_talk.to_string()}
+                                        onchange={on_reviews_per_talk_change}
+                                    />
+                                </div>
+                                <label>
+                                    <input
+                                        type="checkbox"
+                                        checked={*match_expertise}
+                                        onchange={on_match_expertise_change}
+                                    />
+                                    { " Match reviewer expertise to talk labels" }
+                                </label>
+                                <button class="btn-primary" onclick={on_assign}>{ "Assign Reviewers" }</button>
+                            </section>
+
+                            {
+                                if let Some(report) = (*report).as_ref() {
+                                    html! {
+                                        <section class="review-completion">
+                                            <h3>
+                                                { format!(
+                                                    "Completion: {} of {} reviews done",
+                                                    report.completed_assignments, report.total_assignments
+                                                ) }
+                                            </h3>
+                                            <table class="review-completion-table">
+                                                <thead>
+                                                    <tr><th>{ "Reviewer" }</th><th>{ "Done" }</th><th>{ "Assigned" }</th></tr>
+                                                </thead>
+                                                <tbody>
+                                                    { for report.reviewers.iter().map(|r| html! {
+                                                        <tr key={r.reviewer_id.clone()}>
+                                                            <td>{ &r.reviewer_name }</td>
+                                                            <td>{ r.completed }</td>
+                                                            <td>{ r.assigned }</td>
+                                                        </tr>
+                                                    }) }
+                                                </tbody>
+                                            </table>
+                                            <table class="review-completion-table">
+                                                <thead>
+                                                    <tr><th>{ "Talk" }</th><th>{ "Done" }</th><th>{ "Assigned" }</th><th>{ "All ratings" }</th></tr>
+                                                </thead>
+                                                <tbody>
+                                                    { for report.talks.iter().map(|t| html! {
+                                                        <tr key={t.talk_id.clone()}>
+                                                            <td>{ &t.title }</td>
+                                                            <td>{ t.completed }</td>
+                                                            <td>{ t.assigned }</td>
+                                                            <td>{ t.total_ratings }</td>
+                                                        </tr>
+                                                    }) }
+                                                </tbody>
+                                            </table>
+                                        </section>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        </>
+                    }
+                }
+            }
+        </div>
+    }
+}
@@ -1,8 +1,16 @@
 use yew::prelude::*;
 use std::collections::HashMap;
+use wasm_bindgen_futures::spawn_local;
 use crate::{
-    services::{schedule_slots::ScheduleSlotService, tracks::TrackService, talks::TalkService},
-    types::{ScheduleSlot, Track, Talk},
+    services::{
+        availability::AvailabilityService, conferences::ConferenceService,
+        schedule_slots::ScheduleSlotService,
+        tracks::TrackService, talks::TalkService,
+    },
+    types::{
+        CommitScheduleRequest, ConflictSeverity, ScheduleConflictReport, ScheduleProposal,
+        ScheduleProposalRequest, ScheduleSlot, SlotAssignment, SpeakerAvailability, Track, Talk,
+    },
 };
 
 #[function_component(ScheduleBuilder)]
@@ -13,6 +21,14 @@ pub fn schedule_builder() -> Html {
     let loading = use_state(|| true);
     let error = use_state(|| None::<String>);
     let selected_date = use_state(|| None::<String>);
+    let conflicts = use_state(|| None::<ScheduleConflictReport>);
+    let conference_id = use_state(|| None::<String>);
+    let include_pending = use_state(|| false);
+    let proposal = use_state(|| None::<ScheduleProposal>);
+    // Talk id -> the slot it will be committed to, or None to leave it out
+    let choices = use_state(HashMap::<String, Option<String>>::new);
+    let auto_message = use_state(|| None::<String>);
+    let availability = use_state(Vec::<SpeakerAvailability>::new);
 
     // Fetch all data on mount
     {
@@ -21,6 +37,9 @@ pub fn schedule_builder() -> Html {
         let talks = talks.clone();
         let loading = loading.clone();
         let error = error.clone();
+        let conflicts = conflicts.clone();
+        let conference_id = conference_id.clone();
+        let availability = availability.clone();
 
         use_effect_with((), move |_| {
             wasm_bindgen_futures::spawn_local(async move {
@@ -42,6 +61,16 @@ pub fn schedule_builder() -> Html {
                         error.set(Some(e));
                     }
                 }
+
+                if let Ok(conference) = ConferenceService::get_active_conference().await {
+                    if let Ok(report) = ScheduleSlotService::get_conflicts(&conference.id).await {
+                        conflicts.set(Some(report));
+                    }
+                    if let Ok(data) = AvailabilityService::list_for_conference(&conference.id).await {
+                        availability.set(data);
+                    }
+                    conference_id.set(Some(conference.id));
+                }
                 loading.set(false);
             });
             || ()
@@ -90,6 +119,111 @@ pub fn schedule_builder() -> Html {
         .map(|t| (t.id.clone(), t.clone()))
         .collect();
 
+    // Worst conflict severity per slot, to flag cells in the grid
+    let mut slot_severity: HashMap<String, ConflictSeverity> = HashMap::new();
+    if let Some(report) = (*conflicts).as_ref() {
+        for conflict in &report.conflicts {
+            for slot_id in &conflict.slot_ids {
+                let severity = slot_severity.entry(slot_id.clone()).or_insert(conflict.severity);
+                if conflict.severity == ConflictSeverity::Error {
+                    *severity = ConflictSeverity::Error;
+                }
+            }
+        }
+    }
+
+    let on_include_pending = {
+        let include_pending = include_pending.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            include_pending.set(input.checked());
+        })
+    };
+
+    let on_propose = {
+        let conference_id = conference_id.clone();
+        let include_pending = include_pending.clone();
+        let proposal = proposal.clone();
+        let choices = choices.clone();
+        let auto_message = auto_message.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference_id) = (*conference_id).clone() else {
+                return;
+            };
+            let request = ScheduleProposalRequest {
+                include_pending: *include_pending,
+            };
+            let proposal = proposal.clone();
+            let choices = choices.clone();
+            let auto_message = auto_message.clone();
+            spawn_local(async move {
+                match ScheduleSlotService::propose_schedule(&conference_id, request).await {
+                    Ok(data) => {
+                        choices.set(
+                            data.assignments
+                                .iter()
+                                .map(|a| (a.talk_id.clone(), Some(a.slot_id.clone())))
+                                .collect(),
+                        );
+                        proposal.set(Some(data));
+                        auto_message.set(None);
+                    }
+                    Err(e) => auto_message.set(Some(format!("Failed to propose a schedule: {}", e))),
+                }
+            });
+        })
+    };
+
+    let on_commit = {
+        let conference_id = conference_id.clone();
+        let choices = choices.clone();
+        let proposal = proposal.clone();
+        let auto_message = auto_message.clone();
+        let slots = slots.clone();
+        let conflicts = conflicts.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(conference_id) = (*conference_id).clone() else {
+                return;
+            };
+            let assignments: Vec<SlotAssignment> = choices
+                .iter()
+                .filter_map(|(talk_id, slot_id)| {
+                    Some(SlotAssignment {
+                        slot_id: slot_id.clone()?,
+                        talk_id: talk_id.clone(),
+                    })
+                })
+                .collect();
+            let proposal = proposal.clone();
+            let auto_message = auto_message.clone();
+            let slots = slots.clone();
+            let conflicts = conflicts.clone();
+            spawn_local(async move {
+                match ScheduleSlotService::commit_schedule(&conference_id, CommitScheduleRequest { assignments }).await {
+                    Ok(saved) => {
+                        auto_message.set(Some(format!("Scheduled {} talk(s).", saved.len())));
+                        proposal.set(None);
+                        if let Ok(data) = ScheduleSlotService::list_schedule_slots().await {
+                            slots.set(data);
+                        }
+                        if let Ok(report) = ScheduleSlotService::get_conflicts(&conference_id).await {
+                            conflicts.set(Some(report));
+                        }
+                    }
+                    Err(e) => auto_message.set(Some(format!("Failed to commit the schedule: {}", e))),
+                }
+            });
+        })
+    };
+
+    // Empty slots an assignment can be moved to, labelled for the picker
+    let track_names: HashMap<String, String> = (*tracks)
+        .iter()
+        .map(|t| (t.id.clone(), t.name.clone()))
+        .collect();
+    let mut free_slots: Vec<&ScheduleSlot> = (*slots).iter().filter(|s| s.talk_id.is_none()).collect();
+    free_slots.sort_by(|a, b| (&a.slot_date, &a.start_time).cmp(&(&b.slot_date, &b.start_time)));
+
     // Date selector handler
     let on_date_change = {
         let selected_date = selected_date.clone();
@@ -183,8 +317,14 @@ pub fn schedule_builder() -> Html {
                                                         Some(slot) => {
                                                             let talk_opt = slot.talk_id.as_ref().and_then(|tid| talk_map.get(tid));
 
+                                                            let conflict_class = match slot_severity.get(&slot.id) {
+                                                                Some(ConflictSeverity::Error) => Some("slot-conflict-error"),
+                                                                Some(ConflictSeverity::Warning) => Some("slot-conflict-warning"),
+                                                                None => None,
+                                                            };
+
                                                             html! {
-                                                                <td key={format!("{}-{}-{}", track.id, start_time, end_time)} class="slot-cell">
+                                                                <td key={format!("{}-{}-{}", track.id, start_time, end_time)} class={classes!("slot-cell", conflict_class)}>
                                                                     {
                                                                         if let Some(talk) = talk_opt {
                                                                             html! {
@@ -227,6 +367,165 @@ pub fn schedule_builder() -> Html {
                     </table>
                 </div>
 
+                if let Some(report) = (*conflicts).as_ref() {
+                    <div class="schedule-conflicts">
+                        <h3>{ format!("Conflicts: {} error(s), {} warning(s)", report.error_count, report.warning_count) }</h3>
+                        if report.conflicts.is_empty() {
+                            <p class="no-data">{ "No conflicts in the schedule." }</p>
+                        } else {
+                            <ul>
+                                {
+                                    report.conflicts.iter().map(|conflict| {
+                                        let class = match conflict.severity {
+                                            ConflictSeverity::Error => "conflict-error",
+                                            ConflictSeverity::Warning => "conflict-warning",
+                                        };
+                                        html! { <li class={class}>{ &conflict.message }</li> }
+                                    }).collect::<Html>()
+                                }
+                            </ul>
+                        }
+                    </div>
+                }
+
+                <div class="speaker-availability">
+                    <h3>{ "Speaker Availability" }</h3>
+                    if availability.is_empty() {
+                        <p class="no-data">{ "No speaker has limited their availability." }</p>
+                    } else {
+                        <table class="availability-table">
+                            <thead>
+                                <tr>
+                                    <th>{ "Speaker" }</th>
+                                    <th>{ "Applies to" }</th>
+                                    <th>{ "Date" }</th>
+                                    <th>{ "Time" }</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {
+                                    availability.iter().map(|window| {
+                                        html! {
+                                            <tr key={window.id.clone()}>
+                                                <td>{ &window.speaker_name }</td>
+                                                <td>{ window.talk_title.clone().unwrap_or_else(|| "All their talks".to_string()) }</td>
+                                                <td>{ &window.available_date }</td>
+                                                <td>{ format!("{} - {}", window.start_time, window.end_time) }</td>
+                                            </tr>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                            </tbody>
+                        </table>
+                    }
+                </div>
+
+                <div class="auto-schedule">
+                    <h3>{ "Auto-Schedule" }</h3>
+                    <p class="page-description">
+                        { "Propose slots for accepted talks that aren't scheduled yet. Talks already in a slot stay where they are. Review the proposal, change or drop assignments, then commit." }
+                    </p>
+                    <div class="auto-schedule-controls">
+                        <label>
+                            <input type="checkbox" checked={*include_pending} onchange={on_include_pending} />
+                            { " Include talks pending confirmation" }
+                        </label>
+                        <button class="btn btn-secondary" onclick={on_propose}>{ "Propose Schedule" }</button>
+                    </div>
+
+                    if let Some(message) = (*auto_message).as_ref() {
+                        <div class="auto-schedule-message">{ message }</div>
+                    }
+
+                    if let Some(data) = (*proposal).as_ref() {
+                        <p>{ format!("{} slot(s) already filled were left alone.", data.pinned_count) }</p>
+                        if data.assignments.is_empty() {
+                            <p class="no-data">{ "Nothing to place." }</p>
+                        } else {
+                            <table class="auto-schedule-table">
+                                <thead>
+                                    <tr>
+                                        <th>{ "Talk" }</th>
+                                        <th>{ "Slot" }</th>
+                                        <th>{ "Why" }</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {
+                                        data.assignments.iter().map(|assignment| {
+                                            let talk_id = assignment.talk_id.clone();
+                                            let chosen = choices.get(&talk_id).cloned().flatten();
+                                            let onchange = {
+                                                let choices = choices.clone();
+                                                let talk_id = talk_id.clone();
+                                                Callback::from(move |e: Event| {
+                                                    let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                                                    let value = select.value();
+                                                    let mut next = (*choices).clone();
+                                                    next.insert(talk_id.clone(), (!value.is_empty()).then_some(value));
+                                                    choices.set(next);
+                                                })
+                                            };
+                                            html! {
+                                                <tr key={talk_id.clone()}>
+                                                    <td>{ &assignment.talk_title }</td>
+                                                    <td>
+                                                        <select {onchange}>
+                                                            <option value="" selected={chosen.is_none()}>{ "Don't schedule" }</option>
+                                                            {
+                                                                free_slots.iter().map(|slot| {
+                                                                    html! {
+                                                                        <option value={slot.id.clone()} selected={chosen.as_ref() == Some(&slot.id)}>
+                                                                            { format!(
+                                                                                "{} {}-{} · {}",
+                                                                                slot.slot_date,
+                                                                                slot.start_time,
+                                                                                slot.end_time,
+                                                                                track_names.get(&slot.track_id).map(String::as_str).unwrap_or("Unknown track")
+                                                                            ) }
+                                                                        </option>
+                                                                    }
+                                                                }).collect::<Html>()
+                                                            }
+                                                        </select>
+                                                    </td>
+                                                    <td>{ assignment.reasons.join("; ") }</td>
+                                                </tr>
+                                            }
+                                        }).collect::<Html>()
+                                    }
+                                </tbody>
+                            </table>
+                        }
+
+                        if !data.unplaced.is_empty() {
+                            <h4>{ "Not placed" }</h4>
+                            <ul>
+                                {
+                                    data.unplaced.iter().map(|talk| {
+                                        html! { <li key={talk.talk_id.clone()}>{ format!("{}: {}", talk.talk_title, talk.reason) }</li> }
+                                    }).collect::<Html>()
+                                }
+                            </ul>
+                        }
+
+                        if !data.warnings.is_empty() {
+                            <h4>{ "Warnings" }</h4>
+                            <ul>
+                                {
+                                    data.warnings.iter().map(|conflict| {
+                                        html! { <li class="conflict-warning">{ &conflict.message }</li> }
+                                    }).collect::<Html>()
+                                }
+                            </ul>
+                        }
+
+                        if !data.assignments.is_empty() {
+                            <button class="btn btn-primary" onclick={on_commit}>{ "Commit Schedule" }</button>
+                        }
+                    }
+                </div>
+
                 <div class="schedule-legend">
                     <h3>{ "Legend" }</h3>
                     <div class="legend-items">
@@ -242,6 +541,14 @@ pub fn schedule_builder() -> Html {
                             <span class="legend-color no-slot"></span>
                             <span>{ "No Slot Configured" }</span>
                         </div>
+                        <div class="legend-item">
+                            <span class="legend-color conflict-error"></span>
+                            <span>{ "Schedule Error" }</span>
+                        </div>
+                        <div class="legend-item">
+                            <span class="legend-color conflict-warning"></span>
+                            <span>{ "Schedule Warning" }</span>
+                        </div>
                     </div>
                 </div>
             }
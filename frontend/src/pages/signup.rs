@@ -4,8 +4,9 @@ use web_sys::HtmlInputElement;
 
 use crate::{
     app::Route,
+    components::FieldErrorMessage,
     services::auth::AuthService,
-    types::RegisterRequest,
+    types::{FormError, RegisterRequest},
 };
 
 #[function_component(Signup)]
@@ -18,6 +19,7 @@ pub fn signup() -> Html {
     let confirm_password = use_state(|| String::new());
     let bio = use_state(|| String::new());
     let error = use_state(|| None::<String>);
+    let form_error = use_state(|| None::<FormError>);
     let loading = use_state(|| false);
 
     // Redirect to home if already authenticated
@@ -75,6 +77,7 @@ pub fn signup() -> Html {
         let confirm_password = confirm_password.clone();
         let bio = bio.clone();
         let error = error.clone();
+        let form_error = form_error.clone();
         let loading = loading.clone();
         let navigator = navigator.clone();
 
@@ -88,6 +91,7 @@ pub fn signup() -> Html {
             let confirm_password = (*confirm_password).clone();
             let bio = (*bio).clone();
             let error = error.clone();
+            let form_error = form_error.clone();
             let loading = loading.clone();
             let navigator = navigator.clone();
 
@@ -109,6 +113,7 @@ pub fn signup() -> Html {
 
             loading.set(true);
             error.set(None);
+            form_error.set(None);
 
             wasm_bindgen_futures::spawn_local(async move {
                 let request = RegisterRequest {
@@ -124,7 +129,8 @@ pub fn signup() -> Html {
                         navigator.push(&Route::Home);
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.message.clone()));
+                        form_error.set(Some(e));
                         loading.set(false);
                     }
                 }
@@ -147,6 +153,7 @@ pub fn signup() -> Html {
                             disabled={*loading}
                             required=true
                         />
+                        <FieldErrorMessage error={(*form_error).clone()} field="email" />
                     </div>
 
                     <div class="form-group">
@@ -158,6 +165,7 @@ pub fn signup() -> Html {
                             onchange={on_username_change}
                             disabled={*loading}
                         />
+                        <FieldErrorMessage error={(*form_error).clone()} field="username" />
                     </div>
 
                     <div class="form-group">
@@ -170,6 +178,7 @@ pub fn signup() -> Html {
                             disabled={*loading}
                             required=true
                         />
+                        <FieldErrorMessage error={(*form_error).clone()} field="full_name" />
                     </div>
 
                     <div class="form-group">
@@ -183,6 +192,7 @@ pub fn signup() -> Html {
                             required=true
                         />
                         <small>{ "Minimum 8 characters" }</small>
+                        <FieldErrorMessage error={(*form_error).clone()} field="password" />
                     </div>
 
                     <div class="form-group">
@@ -206,6 +216,7 @@ pub fn signup() -> Html {
                             disabled={*loading}
                             rows="3"
                         />
+                        <FieldErrorMessage error={(*form_error).clone()} field="bio" />
                     </div>
 
                     {
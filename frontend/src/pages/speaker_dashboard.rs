@@ -206,6 +206,9 @@ pub fn speaker_dashboard() -> Html {
                                                     TalkState::Pending => ("⏳", "Awaiting Response"),
                                                     TalkState::Accepted => ("✅", "Accepted"),
                                                     TalkState::Rejected => ("❌", "Not Selected"),
+                                                    TalkState::Draft => ("✏️", "Draft"),
+                                                    TalkState::Withdrawn => ("↩️", "Withdrawn"),
+                                                    TalkState::Waitlisted => ("🕒", "Waitlisted"),
                                                 };
 
                                                 let state_class = format!("state-{:?}", talk.state).to_lowercase();
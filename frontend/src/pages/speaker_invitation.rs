@@ -0,0 +1,120 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{
+    app::Route,
+    services::talks::TalkService,
+    types::{SpeakerInvitation as Invitation, TalkSpeakerStatus},
+};
+
+#[derive(Properties, PartialEq)]
+pub struct SpeakerInvitationProps {
+    pub token: String,
+}
+
+#[function_component(SpeakerInvitation)]
+pub fn speaker_invitation(props: &SpeakerInvitationProps) -> Html {
+    let navigator = use_navigator().unwrap();
+    let invitation = use_state(|| None::<Invitation>);
+    let loading = use_state(|| true);
+    let responding = use_state(|| false);
+    let error = use_state(|| None::<String>);
+
+    {
+        let invitation = invitation.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let token = props.token.clone();
+
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::get_invitation(&token).await {
+                    Ok(fetched) => invitation.set(Some(fetched)),
+                    Err(e) => error.set(Some(e)),
+                }
+                loading.set(false);
+            });
+            || ()
+        });
+    }
+
+    let create_respond_handler = |action: &'static str| {
+        let token = props.token.clone();
+        let navigator = navigator.clone();
+        let responding = responding.clone();
+        let error = error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let token = token.clone();
+            let navigator = navigator.clone();
+            let responding = responding.clone();
+            let error = error.clone();
+
+            error.set(None);
+            responding.set(true);
+
+            wasm_bindgen_futures::spawn_local(async move {
+                match TalkService::respond_to_invitation(&token, action).await {
+                    Ok(_) => navigator.push(&Route::MyTalks),
+                    Err(e) => {
+                        error.set(Some(e));
+                        responding.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="talks-container">
+            <h2>{ "Co-speaker Invitation" }</h2>
+            {
+                if *loading {
+                    html! { <div class="loading">{ "Loading invitation..." }</div> }
+                } else if let Some(inv) = (*invitation).as_ref() {
+                    html! {
+                        <div class="talk-card">
+                            <h3>{ &inv.talk_title }</h3>
+                            <p>
+                                { format!(
+                                    "{} invited you to co-present this talk at {}.",
+                                    inv.invited_by_name.clone().unwrap_or_else(|| "A speaker".to_string()),
+                                    inv.conference_name
+                                ) }
+                            </p>
+                            {
+                                if inv.status == TalkSpeakerStatus::Invited {
+                                    html! {
+                                        <div class="action-buttons">
+                                            <button
+                                                onclick={create_respond_handler("accept")}
+                                                disabled={*responding}
+                                                class="btn-accept"
+                                            >
+                                                { "Accept" }
+                                            </button>
+                                            <button
+                                                onclick={create_respond_handler("decline")}
+                                                disabled={*responding}
+                                                class="btn-decline"
+                                            >
+                                                { "Decline" }
+                                            </button>
+                                        </div>
+                                    }
+                                } else {
+                                    html! { <p>{ "This invitation has already been responded to." }</p> }
+                                }
+                            }
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            if let Some(err) = (*error).as_ref() {
+                <div class="error-message">{ err }</div>
+            }
+        </div>
+    }
+}
@@ -1,13 +1,28 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
-use web_sys::{File, HtmlInputElement, HtmlTextAreaElement};
+use web_sys::{File, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
 
 use crate::{
     app::Route,
-    services::{talks::TalkService, labels::LabelService},
-    types::{CreateTalkRequest, Talk, Label},
+    components::{CustomFieldInput, FieldErrorMessage},
+    services::{talks::TalkService, labels::LabelService, conferences::ConferenceService},
+    types::{CreateTalkRequest, CustomField, FormError, Talk, TalkFormat, TalkState, Label, SubmissionOptions, SubmissionWindow, SubmissionWindowState},
 };
 
+/// Format a countdown such as "3 days, 4 hours"
+fn format_countdown(seconds: i64) -> String {
+    let days = seconds / 86_400;
+    let hours = (seconds % 86_400) / 3_600;
+    let minutes = (seconds % 3_600) / 60;
+    if days > 0 {
+        format!("{} days, {} hours", days, hours)
+    } else if hours > 0 {
+        format!("{} hours, {} minutes", hours, minutes)
+    } else {
+        format!("{} minutes", minutes.max(1))
+    }
+}
+
 #[function_component(SubmitTalk)]
 pub fn submit_talk() -> Html {
     let navigator = use_navigator().unwrap();
@@ -17,12 +32,54 @@ pub fn submit_talk() -> Html {
     let slides_file = use_state(|| None::<File>);
     let created_talk = use_state(|| None::<Talk>);
     let error = use_state(|| None::<String>);
+    // Per-field problems reported by the API, shown next to each input
+    let form_error = use_state(|| None::<FormError>);
     let success = use_state(|| false);
     let loading = use_state(|| false);
     let uploading_slides = use_state(|| false);
+    // Set by whichever button submitted the form
+    let save_as_draft = use_mut_ref(|| false);
     let available_labels = use_state(|| Vec::<Label>::new());
     let selected_label_ids = use_state(|| Vec::<String>::new());
     let labels_loading = use_state(|| true);
+    let submission_window = use_state(|| None::<SubmissionWindow>);
+    let submission_options = use_state(|| None::<SubmissionOptions>);
+    let duration_minutes = use_state(|| None::<i32>);
+    let talk_format = use_state(|| TalkFormat::default());
+    let custom_fields = use_state(|| Vec::<CustomField>::new());
+    let custom_answers = use_state(|| serde_json::Map::<String, serde_json::Value>::new());
+
+    // Fetch the active conference's CfP window and custom questions on mount
+    {
+        let submission_window = submission_window.clone();
+        let custom_fields = custom_fields.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(conference) = ConferenceService::get_active_conference().await {
+                    submission_window.set(conference.submission_window);
+                    if let Ok(fields) = ConferenceService::list_custom_fields(&conference.id).await {
+                        custom_fields.set(fields);
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    // Fetch the allowed durations on mount
+    {
+        let submission_options = submission_options.clone();
+        let duration_minutes = duration_minutes.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                if let Ok(options) = TalkService::get_submission_options().await {
+                    duration_minutes.set(Some(options.default_duration as i32));
+                    submission_options.set(Some(options));
+                }
+            });
+            || ()
+        });
+    }
 
     // Fetch available labels on mount
     {
@@ -62,6 +119,27 @@ pub fn submit_talk() -> Html {
         long_description_clone.set(textarea.value());
     });
 
+    let duration_clone = duration_minutes.clone();
+    let on_duration_change = Callback::from(move |e: Event| {
+        let select: HtmlSelectElement = e.target_unchecked_into();
+        duration_clone.set(select.value().parse().ok());
+    });
+
+    let format_clone = talk_format.clone();
+    let on_format_change = Callback::from(move |e: Event| {
+        let select: HtmlSelectElement = e.target_unchecked_into();
+        format_clone.set(TalkFormat::from_value(&select.value()).unwrap_or_default());
+    });
+
+    let create_answer_handler = |field_key: String| {
+        let custom_answers = custom_answers.clone();
+        Callback::from(move |value: serde_json::Value| {
+            let mut answers = (*custom_answers).clone();
+            answers.insert(field_key.clone(), value);
+            custom_answers.set(answers);
+        })
+    };
+
     let slides_file_clone = slides_file.clone();
     let on_file_change = Callback::from(move |e: Event| {
         let input: HtmlInputElement = e.target_unchecked_into();
@@ -91,11 +169,16 @@ pub fn submit_talk() -> Html {
         let long_description = long_description.clone();
         let slides_file = slides_file.clone();
         let selected_label_ids = selected_label_ids.clone();
+        let duration_minutes = duration_minutes.clone();
+        let talk_format = talk_format.clone();
+        let custom_answers = custom_answers.clone();
         let created_talk = created_talk.clone();
         let error = error.clone();
+        let form_error = form_error.clone();
         let success = success.clone();
         let loading = loading.clone();
         let uploading_slides = uploading_slides.clone();
+        let save_as_draft = save_as_draft.clone();
 
         Callback::from(move |e: SubmitEvent| {
             e.prevent_default();
@@ -105,8 +188,13 @@ pub fn submit_talk() -> Html {
             let long_description_val = (*long_description).clone();
             let slides_file_opt = (*slides_file).clone();
             let label_ids_val = (*selected_label_ids).clone();
+            let duration_val = *duration_minutes;
+            let format_val = *talk_format;
+            let custom_answers_val = (*custom_answers).clone();
+            let save_as_draft_val = *save_as_draft.borrow();
             let created_talk = created_talk.clone();
             let error = error.clone();
+            let form_error = form_error.clone();
             let success = success.clone();
             let loading = loading.clone();
             let uploading_slides = uploading_slides.clone();
@@ -116,13 +204,9 @@ pub fn submit_talk() -> Html {
                 return;
             }
 
-            if title_val.len() > 500 {
-                error.set(Some("Title must be 500 characters or less".to_string()));
-                return;
-            }
-
             loading.set(true);
             error.set(None);
+            form_error.set(None);
             success.set(false);
 
             wasm_bindgen_futures::spawn_local(async move {
@@ -139,10 +223,15 @@ pub fn submit_talk() -> Html {
                 };
 
                 let request = CreateTalkRequest {
+                    conference_id: None,
                     title: title_val.trim().to_string(),
                     short_summary: short_summary_val.trim().to_string(),
                     long_description: long_desc,
                     label_ids: label_ids_opt,
+                    duration_minutes: duration_val,
+                    format: Some(format_val),
+                    custom_answers: Some(custom_answers_val),
+                    save_as_draft: save_as_draft_val,
                 };
 
                 match TalkService::create_talk(request).await {
@@ -172,7 +261,8 @@ pub fn submit_talk() -> Html {
                         }
                     }
                     Err(e) => {
-                        error.set(Some(e));
+                        error.set(Some(e.message.clone()));
+                        form_error.set(Some(e));
                         loading.set(false);
                     }
                 }
@@ -180,6 +270,27 @@ pub fn submit_talk() -> Html {
         })
     };
 
+    // Length limits from the server config; 0 means not loaded yet
+    let limits = (*submission_options).clone();
+    let max_title = limits.as_ref().map(|o| o.max_title_length).filter(|&m| m > 0).unwrap_or(500);
+    let summary_hint = match limits.as_ref() {
+        Some(o) if o.max_summary_length > 0 => format!(
+            "{}/{} characters (at least {})",
+            short_summary.chars().count(),
+            o.max_summary_length,
+            o.min_summary_length
+        ),
+        _ => format!("{} characters", short_summary.chars().count()),
+    };
+    let description_hint = match limits.as_ref() {
+        Some(o) if o.max_description_length > 0 => format!(
+            "{}/{} characters",
+            long_description.chars().count(),
+            o.max_description_length
+        ),
+        _ => format!("{} characters", long_description.chars().count()),
+    };
+
     let on_view_talks = {
         let navigator = navigator.clone();
         Callback::from(move |_| {
@@ -192,11 +303,43 @@ pub fn submit_talk() -> Html {
             <div class="form-card">
                 <h2>{ "Submit a Talk" }</h2>
 
+                {
+                    if let Some(window) = (*submission_window).as_ref() {
+                        match window.state {
+                            SubmissionWindowState::Open => html! {
+                                if let Some(seconds) = window.seconds_until_close {
+                                    <div class="cfp-countdown">
+                                        { format!("CfP closes in {}", format_countdown(seconds)) }
+                                    </div>
+                                }
+                            },
+                            SubmissionWindowState::NotYetOpen => html! {
+                                <div class="cfp-closed">
+                                    { window.seconds_until_open
+                                        .map(|seconds| format!("CfP not yet open; opens in {}", format_countdown(seconds)))
+                                        .unwrap_or_else(|| window.message.clone()) }
+                                </div>
+                            },
+                            SubmissionWindowState::Closed => html! {
+                                <div class="cfp-closed">{ &window.message }</div>
+                            },
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+
                 {
                     if *success {
                         html! {
                             <div class="success-message">
-                                <p>{ "Your talk has been submitted successfully!" }</p>
+                                {
+                                    if (*created_talk).as_ref().is_some_and(|t| t.state == TalkState::Draft) {
+                                        html! { <p>{ "Your draft has been saved. Submit it from My Talks when it's ready." }</p> }
+                                    } else {
+                                        html! { <p>{ "Your talk has been submitted successfully!" }</p> }
+                                    }
+                                }
                                 {
                                     if *uploading_slides {
                                         html! { <p>{ "Uploading slides..." }</p> }
@@ -227,13 +370,14 @@ pub fn submit_talk() -> Html {
                                         value={(*title).clone()}
                                         onchange={on_title_change}
                                         disabled={*loading}
-                                        placeholder="Enter your talk title (max 500 characters)"
-                                        maxlength="500"
+                                        placeholder={format!("Enter your talk title (max {} characters)", max_title)}
+                                        maxlength={max_title.to_string()}
                                         required=true
                                     />
                                     <small class="char-count">
-                                        { format!("{}/500 characters", title.len()) }
+                                        { format!("{}/{} characters", title.chars().count(), max_title) }
                                     </small>
+                                    <FieldErrorMessage error={(*form_error).clone()} field="title" />
                                 </div>
 
                                 <div class="form-group">
@@ -250,6 +394,8 @@ pub fn submit_talk() -> Html {
                                         rows="4"
                                         required=true
                                     />
+                                    <small class="char-count">{ summary_hint }</small>
+                                    <FieldErrorMessage error={(*form_error).clone()} field="short_summary" />
                                 </div>
 
                                 <div class="form-group">
@@ -265,8 +411,59 @@ pub fn submit_talk() -> Html {
                                         placeholder="Detailed description, outline, or additional information"
                                         rows="8"
                                     />
+                                    <small class="char-count">{ description_hint }</small>
+                                    <FieldErrorMessage error={(*form_error).clone()} field="long_description" />
                                 </div>
 
+                                <div class="form-group">
+                                    <label for="format">{ "Format" }</label>
+                                    <select id="format" onchange={on_format_change} disabled={*loading}>
+                                        {
+                                            for TalkFormat::ALL.iter().map(|format| html! {
+                                                <option value={format.as_str()} selected={*format == *talk_format}>
+                                                    { format.label() }
+                                                </option>
+                                            })
+                                        }
+                                    </select>
+                                </div>
+
+                                {
+                                    if let Some(options) = (*submission_options).as_ref() {
+                                        html! {
+                                            <div class="form-group">
+                                                <label for="duration">{ "Duration" }</label>
+                                                <select id="duration" onchange={on_duration_change} disabled={*loading}>
+                                                    {
+                                                        for options.talk_durations.iter().map(|minutes| {
+                                                            let minutes = *minutes as i32;
+                                                            html! {
+                                                                <option value={minutes.to_string()} selected={Some(minutes) == *duration_minutes}>
+                                                                    { format!("{} minutes", minutes) }
+                                                                </option>
+                                                            }
+                                                        })
+                                                    }
+                                                </select>
+                                            </div>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+
+                                {
+                                    for custom_fields.iter().map(|field| html! {
+                                        <CustomFieldInput
+                                            key={field.id.clone()}
+                                            field={field.clone()}
+                                            value={custom_answers.get(&field.field_key).cloned()}
+                                            on_change={create_answer_handler(field.field_key.clone())}
+                                            disabled={*loading}
+                                        />
+                                    })
+                                }
+
                                 <div class="form-group">
                                     <label for="slides">
                                         { "Slides " }
@@ -355,17 +552,39 @@ pub fn submit_talk() -> Html {
                                     }
                                 }
 
-                                <button type="submit" disabled={*loading || *uploading_slides}>
-                                    {
-                                        if *loading {
-                                            "Submitting..."
-                                        } else if *uploading_slides {
-                                            "Uploading slides..."
-                                        } else {
-                                            "Submit Talk"
+                                <div class="form-actions">
+                                    <button
+                                        type="submit"
+                                        disabled={*loading || *uploading_slides}
+                                        onclick={
+                                            let save_as_draft = save_as_draft.clone();
+                                            Callback::from(move |_: MouseEvent| *save_as_draft.borrow_mut() = false)
                                         }
-                                    }
-                                </button>
+                                    >
+                                        {
+                                            if *loading {
+                                                "Submitting..."
+                                            } else if *uploading_slides {
+                                                "Uploading slides..."
+                                            } else {
+                                                "Submit Talk"
+                                            }
+                                        }
+                                    </button>
+                                    // Drafts may leave required questions blank
+                                    <button
+                                        type="submit"
+                                        class="btn-secondary"
+                                        formnovalidate=true
+                                        disabled={*loading || *uploading_slides}
+                                        onclick={
+                                            let save_as_draft = save_as_draft.clone();
+                                            Callback::from(move |_: MouseEvent| *save_as_draft.borrow_mut() = true)
+                                        }
+                                    >
+                                        { "Save as Draft" }
+                                    </button>
+                                </div>
                             </form>
                         }
                     }
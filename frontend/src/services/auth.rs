@@ -3,10 +3,11 @@ use gloo_storage::{LocalStorage, Storage};
 use serde::Deserialize;
 use base64::{Engine as _, engine::general_purpose};
 
-use crate::types::{AuthResponse, ErrorResponse, LoginRequest, RegisterRequest};
+use crate::types::{AuthResponse, ErrorResponse, FormError, LoginRequest, RegisterRequest};
 
 #[derive(Debug, Deserialize)]
 struct JwtClaims {
+    sub: String,
     is_organizer: bool,
 }
 
@@ -15,7 +16,7 @@ const TOKEN_KEY: &str = "auth_token";
 pub struct AuthService;
 
 impl AuthService {
-    pub async fn register(request: RegisterRequest) -> Result<AuthResponse, String> {
+    pub async fn register(request: RegisterRequest) -> Result<AuthResponse, FormError> {
         let response = Request::post("/api/auth/register")
             .json(&request)
             .map_err(|e| format!("Failed to serialize request: {}", e))?
@@ -39,7 +40,7 @@ impl AuthService {
                 .json::<ErrorResponse>()
                 .await
                 .map_err(|e| format!("Failed to parse error: {}", e))?;
-            Err(error.error)
+            Err(error.into())
         }
     }
 
@@ -120,4 +121,11 @@ impl AuthService {
             .map(|claims| claims.is_organizer)
             .unwrap_or(false)
     }
+
+    /// The current user's ID, read from the JWT token
+    pub fn current_user_id() -> Option<String> {
+        Self::get_token()
+            .and_then(|token| Self::decode_jwt_claims(&token))
+            .map(|claims| claims.sub)
+    }
 }
@@ -0,0 +1,80 @@
+use gloo_net::http::Request;
+use crate::{services::auth::AuthService, types::{ErrorResponse, SetAvailabilityRequest, SpeakerAvailability}};
+
+pub struct AvailabilityService;
+
+impl AvailabilityService {
+    /// Get the current speaker's availability for a conference
+    pub async fn get_mine(conference_id: &str) -> Result<Vec<SpeakerAvailability>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/conferences/{}/availability/mine", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<SpeakerAvailability>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Replace the current speaker's general or per-talk availability
+    pub async fn set_mine(conference_id: &str, request: SetAvailabilityRequest) -> Result<Vec<SpeakerAvailability>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::put(&format!("/api/conferences/{}/availability/mine", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<SpeakerAvailability>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// List every speaker's availability for a conference (organizer only)
+    pub async fn list_for_conference(conference_id: &str) -> Result<Vec<SpeakerAvailability>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/conferences/{}/availability", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<SpeakerAvailability>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
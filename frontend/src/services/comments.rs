@@ -0,0 +1,113 @@
+use gloo_net::http::Request;
+
+use crate::{
+    services::auth::AuthService,
+    types::{CreateCommentRequest, ErrorResponse, TalkComment, UpdateCommentRequest},
+};
+
+pub struct CommentService;
+
+impl CommentService {
+    /// Get the internal discussion on a talk (organizer only)
+    pub async fn list_comments(talk_id: &str) -> Result<Vec<TalkComment>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/talks/{}/comments", talk_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<TalkComment>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Comment on a talk or reply to a comment (organizer only)
+    pub async fn create_comment(
+        talk_id: &str,
+        request: CreateCommentRequest,
+    ) -> Result<TalkComment, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/talks/{}/comments", talk_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkComment>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Edit one of your comments (organizer only)
+    pub async fn update_comment(
+        comment_id: &str,
+        request: UpdateCommentRequest,
+    ) -> Result<TalkComment, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::put(&format!("/api/talk-comments/{}", comment_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkComment>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Delete one of your comments (organizer only)
+    pub async fn delete_comment(comment_id: &str) -> Result<(), String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::delete(&format!("/api/talk-comments/{}", comment_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() || response.status() == 204 {
+            Ok(())
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
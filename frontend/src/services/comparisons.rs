@@ -0,0 +1,63 @@
+use gloo_net::http::Request;
+
+use crate::{
+    services::auth::AuthService,
+    types::{ErrorResponse, NextComparison, PairwiseComparison, RecordComparisonRequest},
+};
+
+pub struct ComparisonService;
+
+impl ComparisonService {
+    /// Get the next pair of talks for the current reviewer (organizer only)
+    pub async fn next(conference_id: &str) -> Result<NextComparison, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/conferences/{}/comparisons/next", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<NextComparison>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Record which of two talks is better (organizer only)
+    pub async fn record(
+        conference_id: &str,
+        request: RecordComparisonRequest,
+    ) -> Result<PairwiseComparison, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/comparisons", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<PairwiseComparison>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
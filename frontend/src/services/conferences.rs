@@ -1,5 +1,5 @@
 use gloo_net::http::Request;
-use crate::types::{Conference, ErrorResponse};
+use crate::types::{Conference, CustomField, ErrorResponse};
 
 pub struct ConferenceService;
 
@@ -24,4 +24,25 @@ impl ConferenceService {
             Err(error.error)
         }
     }
+
+    /// List the custom submission fields for a conference (public endpoint)
+    pub async fn list_custom_fields(conference_id: &str) -> Result<Vec<CustomField>, String> {
+        let response = Request::get(&format!("/api/conferences/{}/custom-fields", conference_id))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<CustomField>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
 }
@@ -0,0 +1,71 @@
+use gloo_net::http::Request;
+
+use crate::{
+    services::auth::AuthService,
+    types::{
+        BulkDecisionPreview, BulkDecisionRequest, CommitDecisionsRequest, ErrorResponse,
+        TalksListResponse,
+    },
+};
+
+pub struct DecisionService;
+
+impl DecisionService {
+    /// Preview a bulk decision without changing anything (organizer only)
+    pub async fn preview(
+        conference_id: &str,
+        request: BulkDecisionRequest,
+    ) -> Result<BulkDecisionPreview, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/decisions/preview", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<BulkDecisionPreview>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Apply previewed decisions in one go (organizer only)
+    pub async fn commit(
+        conference_id: &str,
+        request: CommitDecisionsRequest,
+    ) -> Result<TalksListResponse, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/decisions", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalksListResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
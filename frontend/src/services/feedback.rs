@@ -0,0 +1,144 @@
+use gloo_net::http::Request;
+
+use crate::{
+    services::auth::AuthService,
+    types::{ErrorResponse, FeedbackRequest, TalkFeedback},
+};
+
+pub struct FeedbackService;
+
+impl FeedbackService {
+    /// Get all feedback written for a talk's speakers (organizer only)
+    pub async fn list_feedback(talk_id: &str) -> Result<Vec<TalkFeedback>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/talks/{}/feedback", talk_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<TalkFeedback>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Write feedback for a talk's speakers (organizer only)
+    pub async fn create_feedback(
+        talk_id: &str,
+        request: FeedbackRequest,
+    ) -> Result<TalkFeedback, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/talks/{}/feedback", talk_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkFeedback>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Edit feedback, which withdraws its approval (organizer only)
+    pub async fn update_feedback(
+        feedback_id: &str,
+        request: FeedbackRequest,
+    ) -> Result<TalkFeedback, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::put(&format!("/api/speaker-feedback/{}", feedback_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkFeedback>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Delete feedback (organizer only)
+    pub async fn delete_feedback(feedback_id: &str) -> Result<(), String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::delete(&format!("/api/speaker-feedback/{}", feedback_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() || response.status() == 204 {
+            Ok(())
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Approve feedback for release, or withdraw the approval (organizer only)
+    pub async fn set_approved(feedback_id: &str, approved: bool) -> Result<TalkFeedback, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let url = format!("/api/speaker-feedback/{}/approve", feedback_id);
+        let request = if approved {
+            Request::post(&url)
+        } else {
+            Request::delete(&url)
+        };
+
+        let response = request
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkFeedback>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
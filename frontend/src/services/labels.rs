@@ -1,5 +1,5 @@
 use gloo_net::http::Request;
-use crate::{services::auth::AuthService, types::{Label, ErrorResponse, AddLabelToTalkRequest, CreateLabelRequest, UpdateLabelRequest}};
+use crate::{services::auth::AuthService, types::{Label, ErrorResponse, FormError, AddLabelToTalkRequest, CreateLabelRequest, UpdateLabelRequest}};
 
 pub struct LabelService;
 
@@ -99,7 +99,7 @@ impl LabelService {
     }
 
     /// Create a new label (organizer only)
-    pub async fn create_label(request: CreateLabelRequest) -> Result<Label, String> {
+    pub async fn create_label(request: CreateLabelRequest) -> Result<Label, FormError> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
         let response = Request::post("/api/labels")
@@ -114,18 +114,18 @@ impl LabelService {
             response
                 .json::<Label>()
                 .await
-                .map_err(|e| format!("Failed to parse response: {}", e))
+                .map_err(|e| format!("Failed to parse response: {}", e).into())
         } else {
             let error = response
                 .json::<ErrorResponse>()
                 .await
                 .map_err(|e| format!("Failed to parse error: {}", e))?;
-            Err(error.error)
+            Err(error.into())
         }
     }
 
     /// Update an existing label (organizer only)
-    pub async fn update_label(id: &str, request: UpdateLabelRequest) -> Result<Label, String> {
+    pub async fn update_label(id: &str, request: UpdateLabelRequest) -> Result<Label, FormError> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
         let response = Request::put(&format!("/api/labels/{}", id))
@@ -140,13 +140,13 @@ impl LabelService {
             response
                 .json::<Label>()
                 .await
-                .map_err(|e| format!("Failed to parse response: {}", e))
+                .map_err(|e| format!("Failed to parse response: {}", e).into())
         } else {
             let error = response
                 .json::<ErrorResponse>()
                 .await
                 .map_err(|e| format!("Failed to parse error: {}", e))?;
-            Err(error.error)
+            Err(error.into())
         }
     }
 
@@ -8,3 +8,9 @@ pub mod schedule_slots;
 pub mod conferences;
 pub mod email_templates;
 pub mod bulk_email;
+pub mod reviews;
+pub mod comments;
+pub mod feedback;
+pub mod decisions;
+pub mod comparisons;
+pub mod availability;
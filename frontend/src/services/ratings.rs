@@ -2,21 +2,55 @@ use gloo_net::http::Request;
 
 use crate::{
     services::auth::AuthService,
-    types::{CreateRatingRequest, ErrorResponse, Rating, RatingsStatisticsResponse},
+    types::{
+        CreateRatingRequest, CriterionScore, ErrorResponse, Rating, RatingsStatisticsResponse,
+        RubricCriterion,
+    },
 };
 
 pub struct RatingService;
 
 impl RatingService {
-    /// Create or update a rating for a talk (organizer only)
+    /// Get a conference's review rubric (organizer only)
+    pub async fn get_rubric(conference_id: &str) -> Result<Vec<RubricCriterion>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/conferences/{}/rubric", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let criteria = response
+                .json::<Vec<RubricCriterion>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(criteria)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Create or update a rating for a talk (organizer only). Conferences
+    /// with a rubric take per-criterion scores instead of a single rating.
     pub async fn create_or_update_rating(
         talk_id: &str,
-        rating: i32,
+        rating: Option<i32>,
+        scores: Vec<CriterionScore>,
         notes: Option<String>,
     ) -> Result<Rating, String> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
-        let request_body = CreateRatingRequest { rating, notes };
+        let request_body = CreateRatingRequest {
+            rating,
+            scores,
+            notes,
+        };
 
         let response = Request::post(&format!("/api/talks/{}/rate", talk_id))
             .header("Authorization", &format!("Bearer {}", token))
@@ -115,11 +149,12 @@ impl RatingService {
         }
     }
 
-    /// Get aggregated ratings statistics for all talks (organizer only)
-    pub async fn get_statistics() -> Result<RatingsStatisticsResponse, String> {
+    /// Get aggregated ratings statistics for all talks (organizer only),
+    /// ranked by the given sort key
+    pub async fn get_statistics(sort: &str) -> Result<RatingsStatisticsResponse, String> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
-        let response = Request::get("/api/ratings/statistics")
+        let response = Request::get(&format!("/api/ratings/statistics?sort={}", sort))
             .header("Authorization", &format!("Bearer {}", token))
             .send()
             .await
@@ -0,0 +1,219 @@
+use gloo_net::http::Request;
+
+use crate::{
+    services::auth::AuthService,
+    types::{
+        AssignReviewersRequest, AssignReviewersResponse, DeclareConflictRequest, ErrorResponse,
+        Label, ReviewCompletionReport, ReviewConflict, ReviewQueueItem, SetExpertiseRequest,
+    },
+};
+
+pub struct ReviewService;
+
+impl ReviewService {
+    /// Get the talks assigned to the current reviewer (organizer only)
+    pub async fn get_my_queue(conference_id: Option<&str>) -> Result<Vec<ReviewQueueItem>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let url = match conference_id {
+            Some(id) => format!("/api/reviews/queue?conference_id={}", id),
+            None => "/api/reviews/queue".to_string(),
+        };
+
+        let response = Request::get(&url)
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<ReviewQueueItem>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Assign reviewers to a conference's submitted talks (organizer only)
+    pub async fn assign_reviewers(
+        conference_id: &str,
+        request: AssignReviewersRequest,
+    ) -> Result<AssignReviewersResponse, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/review-assignments", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<AssignReviewersResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Get review progress per reviewer and per talk (organizer only)
+    pub async fn get_completion_report(conference_id: &str) -> Result<ReviewCompletionReport, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!(
+            "/api/conferences/{}/review-assignments/report",
+            conference_id
+        ))
+        .header("Authorization", &format!("Bearer {}", token))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<ReviewCompletionReport>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Get the labels the current reviewer knows well (organizer only)
+    pub async fn get_my_expertise() -> Result<Vec<Label>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get("/api/reviews/expertise")
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<Label>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Replace the current reviewer's expertise labels (organizer only)
+    pub async fn set_my_expertise(label_ids: Vec<String>) -> Result<Vec<Label>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::put("/api/reviews/expertise")
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&SetExpertiseRequest { label_ids })
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<Label>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// List the current reviewer's declared conflicts of interest (organizer only)
+    pub async fn list_my_conflicts() -> Result<Vec<ReviewConflict>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get("/api/reviews/conflicts")
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<ReviewConflict>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Declare a conflict with a speaker, a talk or an email domain (organizer only)
+    pub async fn declare_conflict(request: DeclareConflictRequest) -> Result<ReviewConflict, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post("/api/reviews/conflicts")
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<ReviewConflict>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Withdraw a conflict declaration (organizer only)
+    pub async fn withdraw_conflict(conflict_id: &str) -> Result<(), String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::delete(&format!("/api/reviews/conflicts/{}", conflict_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() || response.status() == 204 {
+            Ok(())
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+}
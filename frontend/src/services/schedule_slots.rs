@@ -1,5 +1,5 @@
 use gloo_net::http::Request;
-use crate::{services::auth::AuthService, types::{ScheduleSlot, ErrorResponse, CreateScheduleSlotRequest, UpdateScheduleSlotRequest, AssignTalkRequest, PublicScheduleSlot}};
+use crate::{services::auth::AuthService, types::{ScheduleSlot, ErrorResponse, CreateScheduleSlotRequest, UpdateScheduleSlotRequest, AssignTalkRequest, PublicScheduleSlot, ScheduleConflictReport, ScheduleProposal, ScheduleProposalRequest, CommitScheduleRequest}};
 
 pub struct ScheduleSlotService;
 
@@ -189,4 +189,81 @@ impl ScheduleSlotService {
             Err(error.error)
         }
     }
+
+    /// List every error and warning in a conference's schedule (organizer only)
+    pub async fn get_conflicts(conference_id: &str) -> Result<ScheduleConflictReport, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/conferences/{}/schedule/conflicts", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<ScheduleConflictReport>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Propose where to put the talks not yet on the schedule (organizer only).
+    /// Nothing is saved.
+    pub async fn propose_schedule(conference_id: &str, request: ScheduleProposalRequest) -> Result<ScheduleProposal, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/schedule/proposal", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<ScheduleProposal>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Save the kept assignments from a proposal (organizer only)
+    pub async fn commit_schedule(conference_id: &str, request: CommitScheduleRequest) -> Result<Vec<ScheduleSlot>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/conferences/{}/schedule/commit", conference_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<ScheduleSlot>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
 }
@@ -2,13 +2,17 @@ use gloo_net::http::Request;
 
 use crate::{
     services::auth::AuthService,
-    types::{CreateTalkRequest, ErrorResponse, Talk, UpdateTalkRequest, ChangeStateRequest},
+    types::{
+        CreateTalkRequest, ErrorResponse, FormError, Talk, UpdateTalkRequest, ChangeStateRequest, PromoteTalkRequest,
+        InviteCoSpeakerRequest, SpeakerInvitation, TalkSpeaker, TalkFormat, SubmissionOptions,
+        TalkRevision, TalkRevisionDiff,
+    },
 };
 
 pub struct TalkService;
 
 impl TalkService {
-    pub async fn create_talk(request: CreateTalkRequest) -> Result<Talk, String> {
+    pub async fn create_talk(request: CreateTalkRequest) -> Result<Talk, FormError> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
         let response = Request::post("/api/talks")
@@ -30,7 +34,24 @@ impl TalkService {
                 .json::<ErrorResponse>()
                 .await
                 .map_err(|e| format!("Failed to parse error: {}", e))?;
-            Err(error.error)
+            Err(error.into())
+        }
+    }
+
+    /// Fetch the allowed talk durations (public)
+    pub async fn get_submission_options() -> Result<SubmissionOptions, String> {
+        let response = Request::get("/api/configuration/submission")
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<SubmissionOptions>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            Err(format!("Failed to load submission options: {}", response.status()))
         }
     }
 
@@ -82,7 +103,7 @@ impl TalkService {
         }
     }
 
-    pub async fn update_talk(id: &str, request: UpdateTalkRequest) -> Result<Talk, String> {
+    pub async fn update_talk(id: &str, request: UpdateTalkRequest) -> Result<Talk, FormError> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
         let response = Request::put(&format!("/api/talks/{}", id))
@@ -104,7 +125,7 @@ impl TalkService {
                 .json::<ErrorResponse>()
                 .await
                 .map_err(|e| format!("Failed to parse error: {}", e))?;
-            Err(error.error)
+            Err(error.into())
         }
     }
 
@@ -192,15 +213,141 @@ impl TalkService {
         }
     }
 
+    /// Submit a draft talk to the organizers
+    pub async fn submit_talk(id: &str) -> Result<Talk, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/talks/{}/submit", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let talk = response
+                .json::<Talk>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(talk)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Promote a waitlisted talk (organizer-only)
+    pub async fn promote_talk(id: &str, request: PromoteTalkRequest) -> Result<Talk, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/talks/{}/promote", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let talk = response
+                .json::<Talk>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(talk)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Withdraw an accepted talk, giving the organizers a reason
+    pub async fn withdraw_talk(id: &str, reason: String) -> Result<Talk, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let request = crate::types::WithdrawTalkRequest { reason };
+
+        let response = Request::post(&format!("/api/talks/{}/withdraw", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let talk = response
+                .json::<Talk>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(talk)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Reveal who submitted a talk under blind review (organizer only).
+    /// The reveal is logged on the server.
+    pub async fn reveal_speakers(id: &str, reason: Option<String>) -> Result<Vec<TalkSpeaker>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let request = crate::types::RevealSpeakersRequest { reason };
+
+        let response = Request::post(&format!("/api/talks/{}/reveal-speakers", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&request)
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let speakers = response
+                .json::<Vec<TalkSpeaker>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(speakers)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
     /// List all talks (organizer-only) with optional state filtering
     pub async fn list_all_talks(state_filter: Option<String>) -> Result<Vec<Talk>, String> {
+        Self::list_all_talks_filtered(state_filter, None).await
+    }
+
+    /// List all talks (organizer-only) filtered by state and/or session format
+    pub async fn list_all_talks_filtered(
+        state_filter: Option<String>,
+        format_filter: Option<TalkFormat>,
+    ) -> Result<Vec<Talk>, String> {
         let token = AuthService::get_token().ok_or("Not authenticated")?;
 
-        // Build URL with optional query parameter
-        let url = if let Some(state) = state_filter {
-            format!("/api/talks?state={}", state)
-        } else {
+        // Build URL with optional query parameters
+        let mut params = Vec::new();
+        if let Some(state) = state_filter {
+            params.push(format!("state={}", state));
+        }
+        if let Some(format) = format_filter {
+            params.push(format!("format={}", format.as_str()));
+        }
+        let url = if params.is_empty() {
             "/api/talks".to_string()
+        } else {
+            format!("/api/talks?{}", params.join("&"))
         };
 
         let response = Request::get(&url)
@@ -250,4 +397,150 @@ impl TalkService {
             Err(error.error)
         }
     }
+
+    /// Invite a co-speaker to a talk by email
+    pub async fn invite_co_speaker(id: &str, email: String) -> Result<TalkSpeaker, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!("/api/talks/{}/speakers", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&InviteCoSpeakerRequest { email })
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let speaker = response
+                .json::<TalkSpeaker>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(speaker)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Remove a co-speaker or cancel their invitation
+    pub async fn remove_co_speaker(id: &str, invitation_id: &str) -> Result<(), String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::delete(&format!("/api/talks/{}/speakers/{}", id, invitation_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            Ok(())
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Look up a co-speaker invitation by its token
+    pub async fn get_invitation(invite_token: &str) -> Result<SpeakerInvitation, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/speaker-invitations/{}", invite_token))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            let invitation = response
+                .json::<SpeakerInvitation>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            Ok(invitation)
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Accept or decline a co-speaker invitation ("accept" or "decline")
+    pub async fn respond_to_invitation(invite_token: &str, action: &str) -> Result<(), String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::post(&format!(
+            "/api/speaker-invitations/{}/{}",
+            invite_token, action
+        ))
+        .header("Authorization", &format!("Bearer {}", token))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            Ok(())
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// List a talk's revisions, oldest first
+    pub async fn list_revisions(talk_id: &str) -> Result<Vec<TalkRevision>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::get(&format!("/api/talks/{}/revisions", talk_id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<TalkRevision>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Field-level diff between two revisions of a talk
+    pub async fn diff_revisions(talk_id: &str, from: i32, to: i32) -> Result<TalkRevisionDiff, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let url = format!("/api/talks/{}/revisions/diff?from={}&to={}", talk_id, from, to);
+        let response = Request::get(&url)
+            .header("Authorization", &format!("Bearer {}", token))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<TalkRevisionDiff>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
 }
@@ -1,5 +1,5 @@
 use gloo_net::http::Request;
-use crate::{services::auth::AuthService, types::{Track, ErrorResponse, CreateTrackRequest, UpdateTrackRequest}};
+use crate::{services::auth::AuthService, types::{Track, ErrorResponse, CreateTrackRequest, UpdateTrackRequest, Label, SetTrackLabelsRequest}};
 
 pub struct TrackService;
 
@@ -118,4 +118,51 @@ impl TrackService {
             Err(error.error)
         }
     }
+
+    /// Get the labels a track is meant for (public endpoint)
+    pub async fn get_track_labels(id: &str) -> Result<Vec<Label>, String> {
+        let response = Request::get(&format!("/api/tracks/{}/labels", id))
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<Label>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
+
+    /// Replace the labels a track is meant for (organizer only)
+    pub async fn set_track_labels(id: &str, label_ids: Vec<String>) -> Result<Vec<Label>, String> {
+        let token = AuthService::get_token().ok_or("Not authenticated")?;
+
+        let response = Request::put(&format!("/api/tracks/{}/labels", id))
+            .header("Authorization", &format!("Bearer {}", token))
+            .json(&SetTrackLabelsRequest { label_ids })
+            .map_err(|e| format!("Failed to serialize request: {}", e))?
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+
+        if response.ok() {
+            response
+                .json::<Vec<Label>>()
+                .await
+                .map_err(|e| format!("Failed to parse response: {}", e))
+        } else {
+            let error = response
+                .json::<ErrorResponse>()
+                .await
+                .map_err(|e| format!("Failed to parse error: {}", e))?;
+            Err(error.error)
+        }
+    }
 }
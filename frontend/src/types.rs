@@ -35,15 +35,128 @@ pub struct AuthResponse {
 #[derive(Debug, Deserialize)]
 pub struct ErrorResponse {
     pub error: String,
+    #[serde(default)]
+    pub errors: Vec<FieldError>,
+}
+
+/// One invalid input reported by the API
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// A failed form submission: the overall message plus any per-field errors
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormError {
+    pub message: String,
+    pub fields: Vec<FieldError>,
+}
+
+impl FormError {
+    /// The first error reported for `field`, if any
+    pub fn for_field(&self, field: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|e| e.field == field)
+            .map(|e| e.message.as_str())
+    }
+}
+
+impl From<ErrorResponse> for FormError {
+    fn from(response: ErrorResponse) -> Self {
+        Self {
+            message: response.error,
+            fields: response.errors,
+        }
+    }
+}
+
+impl From<String> for FormError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            fields: Vec::new(),
+        }
+    }
+}
+
+impl From<&str> for FormError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum TalkState {
+    Draft,
     Submitted,
     Pending,
     Accepted,
     Rejected,
+    Withdrawn,
+    Waitlisted,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TalkFormat {
+    #[default]
+    Talk,
+    Workshop,
+    Lightning,
+    Panel,
+}
+
+impl TalkFormat {
+    pub const ALL: [TalkFormat; 4] = [
+        TalkFormat::Talk,
+        TalkFormat::Workshop,
+        TalkFormat::Lightning,
+        TalkFormat::Panel,
+    ];
+
+    /// Value used in query strings and select options
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TalkFormat::Talk => "talk",
+            TalkFormat::Workshop => "workshop",
+            TalkFormat::Lightning => "lightning",
+            TalkFormat::Panel => "panel",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TalkFormat::Talk => "Talk",
+            TalkFormat::Workshop => "Workshop",
+            TalkFormat::Lightning => "Lightning Talk",
+            TalkFormat::Panel => "Panel",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.as_str() == value)
+    }
+}
+
+/// Submission options published by the server
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SubmissionOptions {
+    pub talk_durations: Vec<u16>,
+    pub default_duration: u16,
+    #[serde(default)]
+    pub min_title_length: usize,
+    #[serde(default)]
+    pub max_title_length: usize,
+    #[serde(default)]
+    pub min_summary_length: usize,
+    #[serde(default)]
+    pub max_summary_length: usize,
+    #[serde(default)]
+    pub max_description_length: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -73,25 +186,139 @@ pub struct UpdateLabelRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Talk {
     pub id: String,
+    pub conference_id: String,
     pub speaker_id: String,
     pub title: String,
     pub short_summary: String,
     pub long_description: Option<String>,
     pub slides_url: Option<String>,
     pub state: TalkState,
+    #[serde(default = "default_duration_minutes")]
+    pub duration_minutes: i32,
+    #[serde(default)]
+    pub format: TalkFormat,
+    #[serde(default)]
+    pub custom_answers: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub withdrawal_reason: Option<String>,
+    #[serde(default)]
+    pub withdrawn_at: Option<String>,
+    #[serde(default)]
+    pub waitlist_rank: Option<i32>,
     pub submitted_at: String,
     pub updated_at: String,
     pub labels: Vec<Label>,
     pub speaker_name: String,
     pub speaker_email: String,
+    #[serde(default)]
+    pub speakers: Vec<TalkSpeaker>,
+    /// Speaker identity was removed for blind review
+    #[serde(default)]
+    pub speakers_hidden: bool,
+    /// Feedback organizers have released to the speakers
+    #[serde(default)]
+    pub feedback: Vec<SpeakerFeedback>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SpeakerFeedback {
+    pub body: String,
+    pub approved_at: String,
+}
+
+fn default_duration_minutes() -> i32 {
+    45
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalkRevision {
+    pub revision_number: i32,
+    pub title: String,
+    pub short_summary: String,
+    pub long_description: Option<String>,
+    pub slides_url: Option<String>,
+    pub duration_minutes: i32,
+    pub format: TalkFormat,
+    pub edited_by_name: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalkRevisionDiff {
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TalkSpeakerStatus {
+    Invited,
+    Accepted,
+    Declined,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TalkSpeaker {
+    pub invitation_id: Option<String>,
+    pub user_id: Option<String>,
+    pub full_name: String,
+    pub email: String,
+    pub is_primary: bool,
+    pub status: TalkSpeakerStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RevealSpeakersRequest {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InviteCoSpeakerRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SpeakerInvitation {
+    pub id: String,
+    pub talk_id: String,
+    pub talk_title: String,
+    pub conference_name: String,
+    pub invited_by_name: Option<String>,
+    pub email: String,
+    pub status: TalkSpeakerStatus,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CreateTalkRequest {
+    pub conference_id: Option<String>,
     pub title: String,
     pub short_summary: String,
     pub long_description: Option<String>,
     pub label_ids: Option<Vec<String>>,
+    pub duration_minutes: Option<i32>,
+    pub format: Option<TalkFormat>,
+    pub custom_answers: Option<serde_json::Map<String, serde_json::Value>>,
+    pub save_as_draft: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PromoteTalkRequest {
+    /// `Pending` or `Accepted`
+    pub target: TalkState,
+    pub slot_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WithdrawTalkRequest {
+    pub reason: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -100,6 +327,9 @@ pub struct UpdateTalkRequest {
     pub short_summary: Option<String>,
     pub long_description: Option<String>,
     pub slides_url: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub format: Option<TalkFormat>,
+    pub custom_answers: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -129,14 +359,47 @@ pub struct Rating {
     pub notes: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub talk_changed_since_rating: bool,
+    #[serde(default)]
+    pub scores: Vec<CriterionScore>,
+    #[serde(default)]
+    pub weighted_score: Option<f64>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct CreateRatingRequest {
-    pub rating: i32,
+    /// Single 1-5 rating, used when the conference has no rubric
+    pub rating: Option<i32>,
+    pub scores: Vec<CriterionScore>,
     pub notes: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct RubricCriterion {
+    pub id: String,
+    pub conference_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub weight: f64,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CriterionScore {
+    pub criterion_id: String,
+    pub score: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct CriterionAverage {
+    pub criterion_id: String,
+    pub name: String,
+    pub weight: f64,
+    pub average: Option<f64>,
+    pub score_count: i64,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct TalkRatingStats {
     pub talk_id: String,
@@ -146,6 +409,18 @@ pub struct TalkRatingStats {
     pub average_rating: Option<f64>,
     pub rating_count: i64,
     pub ratings: Vec<i32>,
+    #[serde(default)]
+    pub normalized_score: Option<f64>,
+    #[serde(default)]
+    pub trimmed_average: Option<f64>,
+    #[serde(default)]
+    pub weighted_average: Option<f64>,
+    #[serde(default)]
+    pub criteria_averages: Vec<CriterionAverage>,
+    #[serde(default)]
+    pub pairwise_score: Option<f64>,
+    #[serde(default)]
+    pub comparison_count: i64,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -155,8 +430,57 @@ pub struct RatingsStatisticsResponse {
     pub talks_with_ratings: i64,
     pub talks_without_ratings: i64,
     pub overall_average_rating: Option<f64>,
+    #[serde(default)]
+    pub overall_weighted_average: Option<f64>,
     pub rating_distribution: RatingDistribution,
+    #[serde(default)]
+    pub total_comparisons: i64,
     pub talk_stats: Vec<TalkRatingStats>,
+    #[serde(default)]
+    pub reviewer_bias: Vec<ReviewerBias>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewerBias {
+    pub reviewer_id: String,
+    pub reviewer_name: String,
+    pub rating_count: i64,
+    pub mean_rating: f64,
+    pub std_dev: f64,
+    pub bias: f64,
+}
+
+/// A talk as shown in a head-to-head comparison, without its speakers
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ComparisonTalk {
+    pub id: String,
+    pub title: String,
+    pub short_summary: String,
+    pub long_description: Option<String>,
+    pub format: TalkFormat,
+    pub duration_minutes: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NextComparison {
+    pub pair: Option<Vec<ComparisonTalk>>,
+    pub compared: usize,
+    pub remaining: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordComparisonRequest {
+    pub winner_id: String,
+    pub loser_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PairwiseComparison {
+    pub id: String,
+    pub reviewer_id: String,
+    pub winner_id: String,
+    pub loser_id: String,
+    pub created_at: String,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -168,6 +492,83 @@ pub struct RatingDistribution {
     pub five_star: i64,
 }
 
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AssignReviewersRequest {
+    pub reviews_per_talk: i32,
+    pub reviewer_ids: Option<Vec<String>>,
+    pub match_expertise: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AssignReviewersResponse {
+    pub new_assignments: usize,
+    pub understaffed_talk_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SetExpertiseRequest {
+    pub label_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewQueueItem {
+    pub talk_id: String,
+    pub conference_id: String,
+    pub title: String,
+    pub short_summary: String,
+    pub state: TalkState,
+    pub assigned_at: String,
+    pub rating: Option<i32>,
+    pub rated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewConflict {
+    pub id: String,
+    pub reviewer_id: String,
+    pub speaker_id: Option<String>,
+    pub speaker_name: Option<String>,
+    pub talk_id: Option<String>,
+    pub talk_title: Option<String>,
+    pub email_domain: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct DeclareConflictRequest {
+    pub speaker_id: Option<String>,
+    pub talk_id: Option<String>,
+    pub email_domain: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewerProgress {
+    pub reviewer_id: String,
+    pub reviewer_name: String,
+    pub assigned: i64,
+    pub completed: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalkReviewProgress {
+    pub talk_id: String,
+    pub title: String,
+    pub assigned: i64,
+    pub completed: i64,
+    pub total_ratings: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ReviewCompletionReport {
+    pub conference_id: String,
+    pub total_assignments: i64,
+    pub completed_assignments: i64,
+    pub reviewers: Vec<ReviewerProgress>,
+    pub talks: Vec<TalkReviewProgress>,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct DashboardStats {
     pub total_talks: i64,
@@ -183,6 +584,10 @@ pub struct TalksByState {
     pub pending: i64,
     pub accepted: i64,
     pub rejected: i64,
+    #[serde(default)]
+    pub withdrawn: i64,
+    #[serde(default)]
+    pub waitlisted: i64,
 }
 
 #[derive(Debug, Clone, Deserialize, PartialEq)]
@@ -215,6 +620,86 @@ pub struct Conference {
     pub is_active: bool,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default)]
+    pub submission_open: Option<String>,
+    #[serde(default)]
+    pub submission_close: Option<String>,
+    #[serde(default)]
+    pub submission_window: Option<SubmissionWindow>,
+    #[serde(default)]
+    pub blind_review: bool,
+    #[serde(default)]
+    pub review_deadline: Option<String>,
+    #[serde(default)]
+    pub decision_deadline: Option<String>,
+    #[serde(default)]
+    pub confirmation_deadline: Option<String>,
+    #[serde(default)]
+    pub timezone: String,
+    #[serde(default)]
+    pub review_phases: Option<ReviewPhases>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Text,
+    Select,
+    MultiSelect,
+    Checkbox,
+    Number,
+}
+
+/// Extra question on a conference's submission form
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomField {
+    pub id: String,
+    pub field_key: String,
+    pub label: String,
+    pub help_text: Option<String>,
+    pub field_type: CustomFieldType,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    pub position: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionWindowState {
+    NotYetOpen,
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SubmissionWindow {
+    pub opens_at: Option<String>,
+    pub closes_at: Option<String>,
+    pub state: SubmissionWindowState,
+    pub seconds_until_open: Option<i64>,
+    pub seconds_until_close: Option<i64>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewPhase {
+    Submission,
+    Review,
+    Decision,
+    Confirmation,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReviewPhases {
+    pub phase: ReviewPhase,
+    pub submission_close: Option<String>,
+    pub review_deadline: Option<String>,
+    pub decision_deadline: Option<String>,
+    pub confirmation_deadline: Option<String>,
+    pub ratings_open: bool,
+    pub decisions_open: bool,
+    pub message: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -242,6 +727,11 @@ pub struct UpdateTrackRequest {
     pub capacity: Option<i32>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct SetTrackLabelsRequest {
+    pub label_ids: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ScheduleSlot {
     pub id: String,
@@ -278,6 +768,98 @@ pub struct AssignTalkRequest {
     pub talk_id: String,
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduleConflict {
+    pub kind: String,
+    pub severity: ConflictSeverity,
+    pub message: String,
+    pub slot_ids: Vec<String>,
+    pub talk_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduleConflictReport {
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub conflicts: Vec<ScheduleConflict>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AvailabilityWindow {
+    pub available_date: String,
+    pub start_time: String,
+    pub end_time: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SetAvailabilityRequest {
+    pub talk_id: Option<String>,
+    pub windows: Vec<AvailabilityWindow>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SpeakerAvailability {
+    pub id: String,
+    pub user_id: String,
+    pub speaker_name: String,
+    pub talk_id: Option<String>,
+    pub talk_title: Option<String>,
+    pub available_date: String,
+    pub start_time: String,
+    pub end_time: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleProposalRequest {
+    pub include_pending: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ProposedAssignment {
+    pub slot_id: String,
+    pub talk_id: String,
+    pub talk_title: String,
+    pub track_id: String,
+    pub track_name: String,
+    pub slot_date: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub reasons: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct UnplacedTalk {
+    pub talk_id: String,
+    pub talk_title: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ScheduleProposal {
+    pub assignments: Vec<ProposedAssignment>,
+    pub unplaced: Vec<UnplacedTalk>,
+    pub pinned_count: usize,
+    pub warnings: Vec<ScheduleConflict>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SlotAssignment {
+    pub slot_id: String,
+    pub talk_id: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CommitScheduleRequest {
+    pub assignments: Vec<SlotAssignment>,
+}
+
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct PublicScheduleSlot {
     pub id: String,
@@ -344,3 +926,117 @@ pub struct BulkEmailResponse {
     pub failed_emails: usize,
     pub errors: Vec<String>,
 }
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalkComment {
+    pub id: String,
+    pub talk_id: String,
+    pub parent_id: Option<String>,
+    pub author_id: String,
+    pub author_name: String,
+    pub body: String,
+    pub mentioned_user_ids: Vec<String>,
+    pub created_at: String,
+    pub edited_at: Option<String>,
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CreateCommentRequest {
+    pub body: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct UpdateCommentRequest {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalkFeedback {
+    pub id: String,
+    pub talk_id: String,
+    pub author_id: String,
+    pub author_name: String,
+    pub body: String,
+    pub approved_by: Option<String>,
+    pub approved_by_name: Option<String>,
+    pub approved_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FeedbackRequest {
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionScore {
+    #[default]
+    Average,
+    Normalized,
+    Trimmed,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LabelQuota {
+    pub label_id: String,
+    pub max_selected: usize,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BulkDecisionRequest {
+    pub score: DecisionScore,
+    pub top_n: Option<usize>,
+    pub min_score: Option<f64>,
+    pub talk_ids: Vec<String>,
+    pub label_quotas: Vec<LabelQuota>,
+    pub others: Option<TalkState>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PlannedDecision {
+    pub talk_id: String,
+    pub talk_title: String,
+    pub score: Option<f64>,
+    pub from_state: TalkState,
+    pub to_state: Option<TalkState>,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DecisionEmail {
+    pub talk_id: String,
+    pub template_type: String,
+    pub to: String,
+    pub subject: Option<String>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct BulkDecisionPreview {
+    pub decisions: Vec<PlannedDecision>,
+    pub emails: Vec<DecisionEmail>,
+    pub emails_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DecisionChange {
+    pub talk_id: String,
+    pub from_state: TalkState,
+    pub to_state: TalkState,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CommitDecisionsRequest {
+    pub decisions: Vec<DecisionChange>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TalksListResponse {
+    pub talks: Vec<Talk>,
+}
@@ -0,0 +1,31 @@
+-- Tie every talk to the conference it was submitted to
+ALTER TABLE talks
+ADD COLUMN conference_id UUID REFERENCES conferences(id) ON DELETE CASCADE;
+
+-- Existing submissions belong to the active conference (or the most recent one)
+UPDATE talks
+SET conference_id = (
+    SELECT id FROM conferences
+    ORDER BY is_active DESC, start_date DESC
+    LIMIT 1
+)
+WHERE conference_id IS NULL;
+
+-- Talks left over without any conference get a placeholder to hold them
+DO $$
+DECLARE
+    legacy_conference_id UUID;
+BEGIN
+    IF EXISTS (SELECT 1 FROM talks WHERE conference_id IS NULL) THEN
+        INSERT INTO conferences (name, description, start_date, end_date, is_active)
+        VALUES ('Legacy Submissions', 'Talks submitted before conferences were tracked', CURRENT_DATE, CURRENT_DATE, false)
+        RETURNING id INTO legacy_conference_id;
+
+        UPDATE talks SET conference_id = legacy_conference_id WHERE conference_id IS NULL;
+    END IF;
+END $$;
+
+ALTER TABLE talks
+ALTER COLUMN conference_id SET NOT NULL;
+
+CREATE INDEX idx_talks_conference_id ON talks(conference_id);
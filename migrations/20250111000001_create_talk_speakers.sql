@@ -0,0 +1,23 @@
+-- Co-speakers on a talk. The primary speaker stays on talks.speaker_id;
+-- this table holds everyone invited to present alongside them.
+CREATE TYPE talk_speaker_status AS ENUM ('invited', 'accepted', 'declined');
+
+CREATE TABLE talk_speakers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE, -- Set once the invite is accepted
+    email VARCHAR(255) NOT NULL, -- Address the invitation was sent to
+    status talk_speaker_status NOT NULL DEFAULT 'invited',
+    invite_token VARCHAR(64) UNIQUE NOT NULL,
+    invited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    invited_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    responded_at TIMESTAMPTZ,
+    UNIQUE(talk_id, email),
+    UNIQUE(talk_id, user_id)
+);
+
+CREATE INDEX idx_talk_speakers_talk_id ON talk_speakers(talk_id);
+CREATE INDEX idx_talk_speakers_user_id ON talk_speakers(user_id);
+
+-- Invitation email sent to co-speakers
+ALTER TYPE email_template_type ADD VALUE IF NOT EXISTS 'co_speaker_invitation';
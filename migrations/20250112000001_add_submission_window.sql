@@ -0,0 +1,19 @@
+-- Per-conference call-for-papers window. NULL falls back to the
+-- submission_open / submission_close values in the config file.
+ALTER TABLE conferences
+ADD COLUMN submission_open TIMESTAMPTZ,
+ADD COLUMN submission_close TIMESTAMPTZ;
+
+-- Individual speakers can be allowed to submit after the CfP has closed
+CREATE TABLE submission_extensions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conference_id UUID NOT NULL REFERENCES conferences(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    extended_until TIMESTAMPTZ NOT NULL,
+    reason TEXT,
+    granted_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(conference_id, user_id)
+);
+
+CREATE INDEX idx_submission_extensions_conference_id ON submission_extensions(conference_id);
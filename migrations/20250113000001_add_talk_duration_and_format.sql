@@ -0,0 +1,9 @@
+-- Session format chosen by the speaker
+CREATE TYPE talk_format AS ENUM ('talk', 'workshop', 'lightning', 'panel');
+
+-- Existing talks get the default duration and format from config.default.toml
+ALTER TABLE talks
+ADD COLUMN duration_minutes INTEGER NOT NULL DEFAULT 45 CHECK (duration_minutes > 0),
+ADD COLUMN format talk_format NOT NULL DEFAULT 'talk';
+
+CREATE INDEX idx_talks_format ON talks(format);
@@ -0,0 +1,27 @@
+-- Snapshot of a talk's content after every edit
+CREATE TABLE talk_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    revision_number INTEGER NOT NULL,
+    title VARCHAR(500) NOT NULL,
+    short_summary TEXT NOT NULL,
+    long_description TEXT,
+    slides_url VARCHAR(1000),
+    duration_minutes INTEGER NOT NULL,
+    format talk_format NOT NULL,
+    edited_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(talk_id, revision_number)
+);
+
+CREATE INDEX idx_talk_revisions_talk_id ON talk_revisions(talk_id);
+CREATE INDEX idx_talk_revisions_created_at ON talk_revisions(created_at);
+
+-- Existing talks start their history with their current content
+INSERT INTO talk_revisions (
+    talk_id, revision_number, title, short_summary, long_description,
+    slides_url, duration_minutes, format, edited_by, created_at
+)
+SELECT id, 1, title, short_summary, long_description,
+       slides_url, duration_minutes, format, speaker_id, submitted_at
+FROM talks;
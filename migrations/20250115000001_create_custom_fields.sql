@@ -0,0 +1,24 @@
+-- Kinds of extra questions organizers can add to the submission form
+CREATE TYPE custom_field_type AS ENUM ('text', 'select', 'multi_select', 'checkbox', 'number');
+
+-- Per-conference custom questions on the submission form
+CREATE TABLE custom_fields (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conference_id UUID NOT NULL REFERENCES conferences(id) ON DELETE CASCADE,
+    field_key VARCHAR(100) NOT NULL, -- e.g., "target_audience"; key in talks.custom_answers
+    label VARCHAR(255) NOT NULL,
+    help_text TEXT,
+    field_type custom_field_type NOT NULL,
+    options TEXT[] NOT NULL DEFAULT '{}', -- choices for select and multi_select
+    is_required BOOLEAN NOT NULL DEFAULT FALSE,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(conference_id, field_key)
+);
+
+CREATE INDEX idx_custom_fields_conference_id ON custom_fields(conference_id);
+
+-- Speakers' answers, keyed by custom_fields.field_key
+ALTER TABLE talks
+ADD COLUMN custom_answers JSONB NOT NULL DEFAULT '{}';
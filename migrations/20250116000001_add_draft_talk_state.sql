@@ -0,0 +1,2 @@
+-- Speakers can save a talk as a draft before submitting it
+ALTER TYPE talk_state ADD VALUE IF NOT EXISTS 'draft' BEFORE 'submitted';
@@ -0,0 +1,7 @@
+-- Speakers can withdraw a talk after accepting it
+ALTER TYPE talk_state ADD VALUE IF NOT EXISTS 'withdrawn';
+
+-- Kept on the talk for reporting after it leaves the schedule
+ALTER TABLE talks
+ADD COLUMN withdrawal_reason TEXT,
+ADD COLUMN withdrawn_at TIMESTAMPTZ;
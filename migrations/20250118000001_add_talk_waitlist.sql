@@ -0,0 +1,11 @@
+-- Backup talks kept in reserve for no-shows
+ALTER TYPE talk_state ADD VALUE IF NOT EXISTS 'waitlisted';
+
+-- Position on the conference's waitlist (1 = first to be promoted)
+ALTER TABLE talks
+ADD COLUMN waitlist_rank INTEGER;
+
+CREATE INDEX idx_talks_waitlist_rank ON talks(conference_id, waitlist_rank)
+WHERE waitlist_rank IS NOT NULL;
+
+ALTER TYPE email_template_type ADD VALUE IF NOT EXISTS 'talk_waitlisted';
@@ -0,0 +1,24 @@
+-- Weighted review criteria per conference (e.g., relevance, technical depth)
+CREATE TABLE rubric_criteria (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conference_id UUID NOT NULL REFERENCES conferences(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    weight DOUBLE PRECISION NOT NULL DEFAULT 1.0 CHECK (weight > 0),
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(conference_id, name)
+);
+
+CREATE INDEX idx_rubric_criteria_conference_id ON rubric_criteria(conference_id);
+
+-- One 1-5 score per criterion; ratings.rating keeps the rounded weighted score
+CREATE TABLE rating_scores (
+    rating_id UUID NOT NULL REFERENCES ratings(id) ON DELETE CASCADE,
+    criterion_id UUID NOT NULL REFERENCES rubric_criteria(id) ON DELETE CASCADE,
+    score INTEGER NOT NULL CHECK (score >= 1 AND score <= 5),
+    PRIMARY KEY (rating_id, criterion_id)
+);
+
+CREATE INDEX idx_rating_scores_criterion_id ON rating_scores(criterion_id);
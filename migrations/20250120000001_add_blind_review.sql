@@ -0,0 +1,15 @@
+-- Hide speaker identity from reviewers during the review round
+ALTER TABLE conferences
+ADD COLUMN blind_review BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Audit log of organizers revealing who submitted a talk under blind review
+CREATE TABLE speaker_identity_reveals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    reason TEXT,
+    revealed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_speaker_identity_reveals_talk_id ON speaker_identity_reveals(talk_id);
+CREATE INDEX idx_speaker_identity_reveals_user_id ON speaker_identity_reveals(user_id);
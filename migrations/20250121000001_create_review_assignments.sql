@@ -0,0 +1,16 @@
+-- Talks handed to specific organizers for review
+CREATE TABLE review_assignments (
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    reviewer_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    assigned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (talk_id, reviewer_id)
+);
+
+CREATE INDEX idx_review_assignments_reviewer_id ON review_assignments(reviewer_id);
+
+-- Labels a reviewer knows well, used to match them with talks
+CREATE TABLE reviewer_expertise (
+    reviewer_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    label_id UUID NOT NULL REFERENCES labels(id) ON DELETE CASCADE,
+    PRIMARY KEY (reviewer_id, label_id)
+);
@@ -0,0 +1,55 @@
+-- Conflicts of interest declared by reviewers. Each row names exactly one of
+-- a speaker, a talk or an email domain.
+CREATE TABLE review_conflicts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    reviewer_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    speaker_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    talk_id UUID REFERENCES talks(id) ON DELETE CASCADE,
+    email_domain VARCHAR(255), -- Stored lowercase, without the @
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (num_nonnulls(speaker_id, talk_id, email_domain) = 1)
+);
+
+CREATE INDEX idx_review_conflicts_reviewer_id ON review_conflicts(reviewer_id);
+CREATE UNIQUE INDEX idx_review_conflicts_speaker
+    ON review_conflicts(reviewer_id, speaker_id) WHERE speaker_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_review_conflicts_talk
+    ON review_conflicts(reviewer_id, talk_id) WHERE talk_id IS NOT NULL;
+CREATE UNIQUE INDEX idx_review_conflicts_email_domain
+    ON review_conflicts(reviewer_id, email_domain) WHERE email_domain IS NOT NULL;
+
+-- Every (reviewer, talk) pair covered by a declaration. Speakers and domains
+-- match the primary speaker and accepted co-speakers.
+CREATE VIEW conflicted_reviews AS
+SELECT c.reviewer_id, c.talk_id
+FROM review_conflicts c
+WHERE c.talk_id IS NOT NULL
+UNION
+SELECT c.reviewer_id, t.id
+FROM review_conflicts c
+JOIN talks t ON t.speaker_id = c.speaker_id
+UNION
+SELECT c.reviewer_id, ts.talk_id
+FROM review_conflicts c
+JOIN talk_speakers ts ON ts.user_id = c.speaker_id AND ts.status = 'accepted'
+UNION
+SELECT c.reviewer_id, t.id
+FROM review_conflicts c
+JOIN users u ON LOWER(SPLIT_PART(u.email, '@', 2)) = c.email_domain
+JOIN talks t ON t.speaker_id = u.id
+UNION
+SELECT c.reviewer_id, ts.talk_id
+FROM review_conflicts c
+JOIN talk_speakers ts
+    ON LOWER(SPLIT_PART(ts.email, '@', 2)) = c.email_domain AND ts.status = 'accepted';
+
+-- Ratings that count towards a talk's results: a conflicted reviewer's
+-- rating is kept but left out
+CREATE VIEW unconflicted_ratings AS
+SELECT r.*
+FROM ratings r
+WHERE NOT EXISTS (
+    SELECT 1 FROM conflicted_reviews c
+    WHERE c.reviewer_id = r.organizer_id AND c.talk_id = r.talk_id
+);
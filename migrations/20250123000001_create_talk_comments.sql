@@ -0,0 +1,24 @@
+-- Organizer-only discussion on talks. Replies point at their parent comment;
+-- deleted comments are kept with an empty body so their replies stay threaded.
+CREATE TABLE talk_comments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES talk_comments(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    edited_at TIMESTAMPTZ,
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_talk_comments_talk_id ON talk_comments(talk_id, created_at);
+CREATE INDEX idx_talk_comments_parent_id ON talk_comments(parent_id);
+
+-- Organizers @mentioned in a comment
+CREATE TABLE talk_comment_mentions (
+    comment_id UUID NOT NULL REFERENCES talk_comments(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    PRIMARY KEY (comment_id, user_id)
+);
+
+CREATE INDEX idx_talk_comment_mentions_user_id ON talk_comment_mentions(user_id);
@@ -0,0 +1,15 @@
+-- Feedback organizers write for a talk's speakers, separate from the internal
+-- rating notes. Speakers only see it once an organizer has approved it and
+-- the talk has left the submitted state.
+CREATE TABLE speaker_feedback (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    talk_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    approved_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    approved_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_speaker_feedback_talk_id ON speaker_feedback(talk_id);
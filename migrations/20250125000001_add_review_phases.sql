@@ -0,0 +1,7 @@
+-- Deadlines that move a conference through its review cycle:
+-- submission -> review -> decision -> confirmation. The submission phase
+-- ends with the CfP window. NULL means the phase has no fixed end.
+ALTER TABLE conferences
+ADD COLUMN review_deadline TIMESTAMPTZ,
+ADD COLUMN decision_deadline TIMESTAMPTZ,
+ADD COLUMN confirmation_deadline TIMESTAMPTZ;
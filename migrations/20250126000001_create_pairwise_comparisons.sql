@@ -0,0 +1,26 @@
+-- Head-to-head review: a reviewer is shown two talks and picks the better
+-- one. Comparing the same pair again replaces the earlier answer.
+CREATE TABLE pairwise_comparisons (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    reviewer_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    winner_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    loser_id UUID NOT NULL REFERENCES talks(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (winner_id <> loser_id)
+);
+
+CREATE UNIQUE INDEX idx_pairwise_comparisons_pair ON pairwise_comparisons(
+    reviewer_id, LEAST(winner_id, loser_id), GREATEST(winner_id, loser_id)
+);
+CREATE INDEX idx_pairwise_comparisons_winner_id ON pairwise_comparisons(winner_id);
+CREATE INDEX idx_pairwise_comparisons_loser_id ON pairwise_comparisons(loser_id);
+
+-- Comparisons that count towards the ranking: left out when the reviewer is
+-- conflicted with either talk
+CREATE VIEW unconflicted_comparisons AS
+SELECT p.*
+FROM pairwise_comparisons p
+WHERE NOT EXISTS (
+    SELECT 1 FROM conflicted_reviews c
+    WHERE c.reviewer_id = p.reviewer_id AND c.talk_id IN (p.winner_id, p.loser_id)
+);
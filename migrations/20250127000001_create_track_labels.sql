@@ -0,0 +1,7 @@
+-- Topics a track is meant for. The auto-scheduler prefers placing talks in a
+-- track that shares one of their labels.
+CREATE TABLE track_labels (
+    track_id UUID NOT NULL REFERENCES tracks(id) ON DELETE CASCADE,
+    label_id UUID NOT NULL REFERENCES labels(id) ON DELETE CASCADE,
+    PRIMARY KEY (track_id, label_id)
+);
@@ -0,0 +1,18 @@
+-- When speakers can present. Windows with a talk_id apply to that talk only;
+-- windows without one apply to all of the speaker's talks in the conference
+-- that have no windows of their own. A speaker with no windows at all is
+-- treated as available at any time.
+CREATE TABLE speaker_availability (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    conference_id UUID NOT NULL REFERENCES conferences(id) ON DELETE CASCADE,
+    talk_id UUID REFERENCES talks(id) ON DELETE CASCADE,
+    available_date DATE NOT NULL,
+    start_time TIME NOT NULL,
+    end_time TIME NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (start_time < end_time)
+);
+
+CREATE INDEX idx_speaker_availability_user ON speaker_availability(user_id, conference_id);
+CREATE INDEX idx_speaker_availability_conference ON speaker_availability(conference_id);
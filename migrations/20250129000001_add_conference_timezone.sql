@@ -0,0 +1,5 @@
+-- The IANA time zone a conference's schedule is in. Slot dates and times
+-- are wall-clock times at the venue; calendar feeds convert them to UTC
+-- with this zone. Existing conferences are TXLF events in Austin.
+ALTER TABLE conferences
+ADD COLUMN timezone TEXT NOT NULL DEFAULT 'America/Chicago';
@@ -43,6 +43,14 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
         .route("/talks/{id}", delete(handlers::delete_talk))
         .route("/talks/{id}/upload-slides", post(handlers::upload_slides))
         .route("/talks/{id}/respond", post(handlers::respond_to_talk))
+        .route("/talks/{id}/submit", post(handlers::submit_talk))
+        .route("/talks/{id}/withdraw", post(handlers::withdraw_talk))
+        // Revision history routes
+        .route("/talks/{id}/revisions", get(handlers::list_talk_revisions))
+        .route(
+            "/talks/{id}/revisions/diff",
+            get(handlers::get_talk_revision_diff),
+        )
         // Talk-label routes
         .route("/talks/{id}/labels", get(handlers::get_talk_labels))
         .route("/talks/{id}/labels", post(handlers::add_labels_to_talk))
@@ -50,6 +58,34 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
             "/talks/{id}/labels/{label_id}",
             delete(handlers::remove_label_from_talk),
         )
+        // Co-speaker routes
+        .route("/talks/{id}/speakers", get(handlers::list_talk_speakers))
+        .route("/talks/{id}/speakers", post(handlers::invite_co_speaker))
+        .route(
+            "/talks/{id}/speakers/{invitation_id}",
+            delete(handlers::remove_co_speaker),
+        )
+        .route(
+            "/speaker-invitations/{token}",
+            get(handlers::get_speaker_invitation),
+        )
+        .route(
+            "/speaker-invitations/{token}/accept",
+            post(handlers::accept_speaker_invitation),
+        )
+        .route(
+            "/speaker-invitations/{token}/decline",
+            post(handlers::decline_speaker_invitation),
+        )
+        // Speaker availability routes
+        .route(
+            "/conferences/{id}/availability/mine",
+            get(handlers::get_my_availability),
+        )
+        .route(
+            "/conferences/{id}/availability/mine",
+            put(handlers::set_my_availability),
+        )
         .layer(axum_middleware::from_fn_with_state(
             state.clone(),
             middleware::auth_middleware,
@@ -60,6 +96,31 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
         .route("/dashboard/stats", get(handlers::get_dashboard_stats))
         .route("/talks", get(handlers::list_all_talks))
         .route("/talks/{id}/state", put(handlers::change_talk_state))
+        .route("/talks/{id}/promote", post(handlers::promote_talk))
+        // Blind review routes
+        .route(
+            "/talks/{id}/reveal-speakers",
+            post(handlers::reveal_talk_speakers),
+        )
+        .route(
+            "/conferences/{id}/identity-reveals",
+            get(handlers::list_identity_reveals),
+        )
+        // Waitlist routes
+        .route("/conferences/{id}/waitlist", get(handlers::list_waitlist))
+        .route(
+            "/conferences/{id}/waitlist",
+            put(handlers::reorder_waitlist),
+        )
+        // Bulk decision routes
+        .route(
+            "/conferences/{id}/decisions/preview",
+            post(handlers::preview_bulk_decision),
+        )
+        .route(
+            "/conferences/{id}/decisions",
+            post(handlers::commit_bulk_decision),
+        )
         .route("/labels", post(handlers::create_label))
         .route("/labels/{id}", put(handlers::update_label))
         .route("/labels/{id}", delete(handlers::delete_label))
@@ -69,14 +130,107 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
         .route("/talks/{id}/rate/mine", get(handlers::get_my_rating))
         .route("/talks/{id}/rate", delete(handlers::delete_rating))
         .route("/ratings/statistics", get(handlers::get_ratings_statistics))
+        // Pairwise comparison routes
+        .route(
+            "/conferences/{id}/comparisons/next",
+            get(handlers::get_next_comparison),
+        )
+        .route(
+            "/conferences/{id}/comparisons",
+            post(handlers::record_comparison),
+        )
+        // Reviewer assignment routes
+        .route(
+            "/conferences/{id}/review-assignments",
+            post(handlers::assign_reviewers),
+        )
+        .route(
+            "/conferences/{id}/review-assignments/report",
+            get(handlers::get_review_completion_report),
+        )
+        .route(
+            "/talks/{id}/reviewers/{reviewer_id}",
+            delete(handlers::unassign_reviewer),
+        )
+        .route("/reviews/queue", get(handlers::get_my_review_queue))
+        .route("/reviews/expertise", get(handlers::get_my_expertise))
+        .route("/reviews/expertise", put(handlers::set_my_expertise))
+        // Conflict of interest routes
+        .route("/reviews/conflicts", get(handlers::list_my_conflicts))
+        .route("/reviews/conflicts", post(handlers::declare_conflict))
+        .route(
+            "/reviews/conflicts/{id}",
+            delete(handlers::withdraw_conflict),
+        )
+        // Internal discussion routes
+        .route("/talks/{id}/comments", get(handlers::list_talk_comments))
+        .route("/talks/{id}/comments", post(handlers::create_talk_comment))
+        .route("/talk-comments/{id}", put(handlers::update_talk_comment))
+        .route("/talk-comments/{id}", delete(handlers::delete_talk_comment))
+        // Speaker feedback routes
+        .route("/talks/{id}/feedback", get(handlers::list_talk_feedback))
+        .route("/talks/{id}/feedback", post(handlers::create_talk_feedback))
+        .route(
+            "/speaker-feedback/{id}",
+            put(handlers::update_talk_feedback),
+        )
+        .route(
+            "/speaker-feedback/{id}",
+            delete(handlers::delete_talk_feedback),
+        )
+        .route(
+            "/speaker-feedback/{id}/approve",
+            post(handlers::approve_talk_feedback),
+        )
+        .route(
+            "/speaker-feedback/{id}/approve",
+            delete(handlers::unapprove_talk_feedback),
+        )
         // Conference routes (organizer only for CUD operations)
         .route("/conferences", post(handlers::create_conference))
         .route("/conferences/{id}", put(handlers::update_conference))
         .route("/conferences/{id}", delete(handlers::delete_conference))
+        .route(
+            "/conferences/{id}/extensions",
+            get(handlers::list_submission_extensions),
+        )
+        .route(
+            "/conferences/{id}/extensions",
+            post(handlers::grant_submission_extension),
+        )
+        .route(
+            "/conferences/{id}/extensions/{user_id}",
+            delete(handlers::revoke_submission_extension),
+        )
+        // Custom submission field routes (organizer only for CUD operations)
+        .route(
+            "/conferences/{id}/custom-fields",
+            post(handlers::create_custom_field),
+        )
+        .route("/custom-fields/{id}", put(handlers::update_custom_field))
+        .route("/custom-fields/{id}", delete(handlers::delete_custom_field))
+        // Review rubric routes (organizer only)
+        .route(
+            "/conferences/{id}/rubric",
+            get(handlers::list_rubric_criteria),
+        )
+        .route(
+            "/conferences/{id}/rubric",
+            post(handlers::create_rubric_criterion),
+        )
+        .route(
+            "/rubric-criteria/{id}",
+            put(handlers::update_rubric_criterion),
+        )
+        .route(
+            "/rubric-criteria/{id}",
+            delete(handlers::delete_rubric_criterion),
+        )
         // Track routes (organizer only for CUD operations)
         .route("/tracks", post(handlers::create_track))
         .route("/tracks/{id}", put(handlers::update_track))
         .route("/tracks/{id}", delete(handlers::delete_track))
+        .route("/tracks/{id}/labels", put(handlers::set_track_labels))
         // Schedule slot routes (organizer only for CUD operations)
         .route("/schedule-slots", post(handlers::create_schedule_slot))
         .route("/schedule-slots/{id}", put(handlers::update_schedule_slot))
@@ -92,6 +246,25 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
             "/schedule-slots/{id}/assign",
             delete(handlers::unassign_talk_from_slot),
         )
+        // Schedule conflict route (organizer only)
+        .route(
+            "/conferences/{id}/schedule/conflicts",
+            get(handlers::get_schedule_conflicts),
+        )
+        // Speaker availability route (organizer only)
+        .route(
+            "/conferences/{id}/availability",
+            get(handlers::list_speaker_availability),
+        )
+        // Auto-schedule routes (organizer only)
+        .route(
+            "/conferences/{id}/schedule/proposal",
+            post(handlers::propose_auto_schedule),
+        )
+        .route(
+            "/conferences/{id}/schedule/commit",
+            post(handlers::commit_auto_schedule),
+        )
         // Email template routes (organizer only)
         .route("/email-templates", get(handlers::list_email_templates))
         .route("/email-templates/{id}", get(handlers::get_email_template))
@@ -113,11 +286,12 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
         .route("/ai/create-labels", post(handlers::create_ai_labels))
         // Configuration route (organizer only)
         .route("/configuration", get(handlers::get_configuration))
+        // Layers run outermost-first: authenticate, then check the role
+        .layer(axum_middleware::from_fn(middleware::organizer_middleware))
         .layer(axum_middleware::from_fn_with_state(
             state.clone(),
             middleware::auth_middleware,
-        ))
-        .layer(axum_middleware::from_fn(middleware::organizer_middleware));
+        ));
 
     // Public API routes
     let api_routes = Router::new()
@@ -139,18 +313,41 @@ pub fn create_router(db: PgPool, config: Config) -> Router {
         .route("/auth/linkedin/callback", get(handlers::linkedin_callback))
         // Public label routes
         .route("/labels", get(handlers::list_labels))
+        // Public submission options (durations, length limits)
+        .route(
+            "/configuration/submission",
+            get(handlers::get_submission_options),
+        )
         // Public conference routes (read-only)
         .route("/conferences", get(handlers::list_conferences))
         .route("/conferences/active", get(handlers::get_active_conference))
         .route("/conferences/{id}", get(handlers::get_conference))
+        .route(
+            "/conferences/{id}/custom-fields",
+            get(handlers::list_custom_fields),
+        )
         // Public track routes (read-only)
         .route("/tracks", get(handlers::list_tracks))
         .route("/tracks/{id}", get(handlers::get_track))
+        .route("/tracks/{id}/labels", get(handlers::get_track_labels))
         // Public schedule slot routes (read-only)
         .route("/schedule-slots", get(handlers::list_schedule_slots))
         .route("/schedule-slots/{id}", get(handlers::get_schedule_slot))
         // Public schedule view (with talk details)
         .route("/schedule", get(handlers::get_public_schedule))
+        // Public calendar feeds (iCalendar)
+        .route("/schedule.ics", get(handlers::get_schedule_calendar))
+        .route(
+            "/tracks/{id}/schedule.ics",
+            get(handlers::get_track_calendar),
+        )
+        .route(
+            "/speakers/{id}/schedule.ics",
+            get(handlers::get_speaker_calendar),
+        )
+        // Public Frab/Pentabarf schedule export
+        .route("/schedule.xml", get(handlers::export_frab_xml))
+        .route("/schedule.json", get(handlers::export_frab_json))
         .merge(protected_routes)
         .merge(organizer_routes)
         .with_state(state);
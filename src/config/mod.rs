@@ -40,6 +40,8 @@ pub struct Config {
     pub jwt_secret: String,
     pub jwt_expiry_hours: i64,
     pub upload_dir: String,
+    /// Public base URL of the app, used for links in emails
+    pub app_url: String,
 
     // OAuth configuration (from environment)
     pub google_client_id: Option<String>,
@@ -108,6 +110,9 @@ impl Config {
         let upload_dir =
             std::env::var("UPLOAD_DIR").unwrap_or_else(|_| file_config.uploads.directory.clone());
 
+        let app_url = std::env::var("APP_URL")
+            .unwrap_or_else(|_| format!("http://localhost:{}", server_port));
+
         // OAuth configuration
         let google_client_id = std::env::var("GOOGLE_CLIENT_ID").ok();
         let google_client_secret = std::env::var("GOOGLE_CLIENT_SECRET").ok();
@@ -160,6 +165,7 @@ impl Config {
             jwt_secret,
             jwt_expiry_hours,
             upload_dir,
+            app_url,
             google_client_id,
             google_client_secret,
             google_redirect_url,
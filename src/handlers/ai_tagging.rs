@@ -5,11 +5,14 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
+use uuid::Uuid;
 
 use crate::{api::AppState, models::label::Label};
 
 #[derive(Debug, Deserialize)]
 pub struct AutoTagQuery {
+    /// Optional conference filter for talks to analyze
+    pub conference_id: Option<Uuid>,
     /// Optional state filter for talks to analyze
     pub state: Option<String>,
     /// AI provider to use: "claude" or "openai"
@@ -69,10 +72,10 @@ pub async fn auto_tag_with_claude(
             t.title,
             t.short_summary,
             t.long_description,
-            t.state::text as state,
-            u.full_name as speaker_name
+            t.state::text as state
         FROM talks t
-        INNER JOIN users u ON t.speaker_id = u.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
         "#,
     );
 
@@ -83,6 +86,8 @@ pub async fn auto_tag_with_claude(
             "pending" => "pending",
             "accepted" => "accepted",
             "rejected" => "rejected",
+            "withdrawn" => "withdrawn",
+            "waitlisted" => "waitlisted",
             _ => {
                 return Err((
                     StatusCode::BAD_REQUEST,
@@ -90,12 +95,13 @@ pub async fn auto_tag_with_claude(
                 ))
             }
         };
-        query.push_str(&format!(" WHERE t.state = '{}'::talk_state", state_str));
+        query.push_str(&format!(" AND t.state = '{}'::talk_state", state_str));
     }
 
     query.push_str(" ORDER BY t.submitted_at DESC LIMIT 50");
 
     let rows = sqlx::query(&query)
+        .bind(params.conference_id)
         .fetch_all(&state.db)
         .await
         .map_err(|e| {
@@ -112,7 +118,8 @@ pub async fn auto_tag_with_claude(
         ));
     }
 
-    // Build simplified JSON for Claude
+    // Build simplified JSON for Claude. Speaker identity is never sent, so
+    // prompts are safe under blind review.
     let mut talks = Vec::new();
     for row in rows {
         let talk = serde_json::json!({
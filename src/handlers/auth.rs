@@ -44,7 +44,7 @@ use crate::{
             GitHubUserInfo, GoogleUserInfo, LinkedInUserInfo, OAuthCallbackQuery,
         },
         user::UserResponse,
-        AuthResponse, Claims, LoginRequest, RegisterRequest, User,
+        validate_profile_fields, AuthResponse, Claims, LoginRequest, RegisterRequest, User,
     },
 };
 
@@ -52,21 +52,14 @@ pub async fn register(
     State(state): State<AppState>,
     Json(payload): Json<RegisterRequest>,
 ) -> Result<(StatusCode, Json<AuthResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Validate email format
-    if !payload.email.contains('@') {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Invalid email format")),
-        ));
-    }
-
-    // Validate password strength
-    if payload.password.len() < 8 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Password must be at least 8 characters")),
-        ));
-    }
+    validate_profile_fields(
+        &state.config.submission,
+        &payload.email,
+        payload.username.as_deref(),
+        &payload.password,
+        &payload.full_name,
+        payload.bio.as_deref(),
+    )?;
 
     // Check if user already exists
     let existing_user = sqlx::query_as::<_, User>("SELECT * FROM users WHERE email = $1")
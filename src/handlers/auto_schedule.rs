@@ -0,0 +1,254 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::schedule_conflicts::{fetch_schedule_snapshot, fetch_scheduled_talks},
+    models::{
+        auth::ErrorResponse, plan_schedule, CommitScheduleRequest, ConflictSeverity,
+        ScheduleProposal, ScheduleProposalRequest, ScheduleSlot, ScheduleSlotResponse,
+    },
+};
+
+/// Propose a schedule for the accepted talks that aren't on it yet
+/// (organizer only). Slots that already hold a talk are left alone and
+/// nothing is saved.
+pub async fn propose_auto_schedule(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<ScheduleProposalRequest>,
+) -> Result<Json<ScheduleProposal>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error proposing a schedule: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to propose a schedule")),
+        )
+    };
+
+    let mut conn = state.db.acquire().await.map_err(db_error)?;
+    let snapshot = fetch_schedule_snapshot(&mut conn, conference_id, Vec::new())
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            )
+        })?;
+
+    let candidate_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT t.id FROM talks t
+        WHERE t.conference_id = $1
+          AND (t.state = 'accepted' OR ($2 AND t.state = 'pending'))
+          AND NOT EXISTS(SELECT 1 FROM schedule_slots s WHERE s.talk_id = t.id)
+        "#,
+    )
+    .bind(conference_id)
+    .bind(payload.include_pending)
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(db_error)?;
+
+    let mut candidates: Vec<_> = fetch_scheduled_talks(&mut conn, &candidate_ids)
+        .await
+        .map_err(db_error)?
+        .into_values()
+        .collect();
+    candidates.sort_by(|a, b| a.title.cmp(&b.title).then(a.id.cmp(&b.id)));
+
+    let mut track_labels: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+    for (track_id, label_id) in sqlx::query_as::<_, (Uuid, Uuid)>(
+        r#"
+        SELECT tl.track_id, tl.label_id
+        FROM track_labels tl
+        JOIN tracks tr ON tl.track_id = tr.id
+        WHERE tr.conference_id = $1
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?
+    {
+        track_labels.entry(track_id).or_default().insert(label_id);
+    }
+
+    Ok(Json(plan_schedule(&snapshot, &candidates, &track_labels)))
+}
+
+/// Save the assignments an organizer kept from a proposal (organizer only).
+/// Only empty slots can be filled, and the whole batch is refused if it
+/// would put an error on the schedule.
+pub async fn commit_auto_schedule(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<CommitScheduleRequest>,
+) -> Result<Json<Vec<ScheduleSlotResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.assignments.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("No assignments to commit")),
+        ));
+    }
+
+    let mut slot_ids = HashSet::new();
+    let mut talk_ids = HashSet::new();
+    for assignment in &payload.assignments {
+        if !slot_ids.insert(assignment.slot_id) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("A slot can only be assigned one talk")),
+            ));
+        }
+        if !talk_ids.insert(assignment.talk_id) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("A talk can only be assigned one slot")),
+            ));
+        }
+    }
+    let slot_ids: Vec<Uuid> = slot_ids.into_iter().collect();
+    let talk_ids: Vec<Uuid> = talk_ids.into_iter().collect();
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error committing a schedule: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to commit the schedule")),
+        )
+    };
+
+    let found = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM talks WHERE id = ANY($1) AND conference_id = $2",
+    )
+    .bind(&talk_ids)
+    .bind(conference_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(db_error)?;
+    if found != talk_ids.len() as i64 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Talk not found in this conference")),
+        ));
+    }
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    // Lock the slots so nobody fills them while the batch is checked
+    let slots = sqlx::query_as::<_, ScheduleSlot>(
+        r#"
+        SELECT * FROM schedule_slots
+        WHERE id = ANY($1) AND conference_id = $2
+        FOR UPDATE
+        "#,
+    )
+    .bind(&slot_ids)
+    .bind(conference_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    if slots.len() != slot_ids.len() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Schedule slot not found")),
+        ));
+    }
+    if slots.iter().any(|s| s.talk_id.is_some()) {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "A slot already has a talk; unassign it first or propose again",
+            )),
+        ));
+    }
+
+    let talk_for: HashMap<Uuid, Uuid> = payload
+        .assignments
+        .iter()
+        .map(|a| (a.slot_id, a.talk_id))
+        .collect();
+    let proposed: Vec<ScheduleSlot> = slots
+        .into_iter()
+        .map(|slot| ScheduleSlot {
+            talk_id: talk_for.get(&slot.id).copied(),
+            ..slot
+        })
+        .collect();
+
+    // Read the schedule inside the transaction, after the slots are locked
+    let snapshot = fetch_schedule_snapshot(&mut tx, conference_id, proposed)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            )
+        })?;
+    let conflicts: Vec<_> = snapshot
+        .conflicts()
+        .into_iter()
+        .filter(|c| c.slot_ids.iter().any(|id| talk_for.contains_key(id)))
+        .collect();
+    let errors: Vec<&str> = conflicts
+        .iter()
+        .filter(|c| c.severity == ConflictSeverity::Error)
+        .map(|c| c.message.as_str())
+        .collect();
+    if !errors.is_empty() {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(errors.join("; "))),
+        ));
+    }
+
+    let now = Utc::now();
+    let mut updated = Vec::with_capacity(talk_for.len());
+    for (slot_id, talk_id) in &talk_for {
+        let slot = sqlx::query_as::<_, ScheduleSlot>(
+            r#"
+            UPDATE schedule_slots
+            SET talk_id = $1, updated_at = $2
+            WHERE id = $3
+            RETURNING *
+            "#,
+        )
+        .bind(talk_id)
+        .bind(now)
+        .bind(slot_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+        updated.push(slot);
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    updated.sort_by_key(|s| (s.slot_date, s.start_time));
+    let responses = updated
+        .into_iter()
+        .map(|slot| {
+            let warnings = conflicts
+                .iter()
+                .filter(|c| c.slot_ids.contains(&slot.id))
+                .cloned()
+                .collect();
+            ScheduleSlotResponse::from(slot).with_warnings(warnings)
+        })
+        .collect();
+
+    tracing::info!(
+        "Committed {} scheduled talks for conference {}",
+        talk_for.len(),
+        conference_id
+    );
+    Ok(Json(responses))
+}
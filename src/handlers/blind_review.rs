@@ -0,0 +1,223 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::talk_speakers::fetch_talk_speakers,
+    models::{
+        auth::ErrorResponse, RevealSpeakersRequest, SpeakerIdentityReveal, TalkResponse,
+        TalkSpeakerResponse, User,
+    },
+};
+
+/// Talks whose speakers are hidden from a user: talks in blind-review
+/// conferences that the user neither presents nor has revealed
+pub(crate) async fn hidden_speaker_talks(
+    db: &sqlx::PgPool,
+    user_id: Uuid,
+    conference_id: Option<Uuid>,
+) -> Result<HashSet<Uuid>, sqlx::Error> {
+    let talk_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT t.id FROM talks t
+        INNER JOIN conferences c ON t.conference_id = c.id
+        WHERE c.blind_review
+          AND ($2::uuid IS NULL OR t.conference_id = $2)
+          AND t.speaker_id <> $1
+          AND NOT EXISTS (
+              SELECT 1 FROM talk_speakers ts
+              WHERE ts.talk_id = t.id AND ts.user_id = $1 AND ts.status = 'accepted'
+          )
+          AND NOT EXISTS (
+              SELECT 1 FROM speaker_identity_reveals r
+              WHERE r.talk_id = t.id AND r.user_id = $1
+          )
+        "#,
+    )
+    .bind(user_id)
+    .bind(conference_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(talk_ids.into_iter().collect())
+}
+
+/// Whether a talk's speakers are hidden from a user
+pub(crate) async fn speakers_hidden(
+    db: &sqlx::PgPool,
+    user_id: Uuid,
+    talk_id: Uuid,
+) -> Result<bool, (StatusCode, Json<ErrorResponse>)> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM talks t
+            INNER JOIN conferences c ON t.conference_id = c.id
+            WHERE t.id = $2
+              AND c.blind_review
+              AND t.speaker_id <> $1
+              AND NOT EXISTS (
+                  SELECT 1 FROM talk_speakers ts
+                  WHERE ts.talk_id = t.id AND ts.user_id = $1 AND ts.status = 'accepted'
+              )
+              AND NOT EXISTS (
+                  SELECT 1 FROM speaker_identity_reveals r
+                  WHERE r.talk_id = t.id AND r.user_id = $1
+              )
+        )
+        "#,
+    )
+    .bind(user_id)
+    .bind(talk_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking blind review: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to check blind review")),
+        )
+    })
+}
+
+/// Anonymize the talks in `hidden`, leaving the rest untouched
+pub(crate) fn hide_speakers(
+    responses: Vec<TalkResponse>,
+    hidden: &HashSet<Uuid>,
+) -> Vec<TalkResponse> {
+    responses
+        .into_iter()
+        .map(|talk| {
+            if hidden.contains(&talk.id) {
+                talk.anonymized()
+            } else {
+                talk
+            }
+        })
+        .collect()
+}
+
+/// Reveal who submitted a talk under blind review (organizer only).
+/// Every reveal is logged, and the organizer sees the talk's speakers
+/// from then on.
+pub async fn reveal_talk_speakers(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    payload: Option<Json<RevealSpeakersRequest>>,
+) -> Result<Json<Vec<TalkSpeakerResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let blind_review = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT c.blind_review FROM talks t
+        INNER JOIN conferences c ON t.conference_id = c.id
+        WHERE t.id = $1 AND t.state <> 'draft'
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found")),
+        )
+    })?;
+
+    if !blind_review {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Blind review is not enabled for this talk's conference",
+            )),
+        ));
+    }
+
+    let reason = payload
+        .and_then(|Json(request)| request.reason)
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+
+    sqlx::query(
+        r#"
+        INSERT INTO speaker_identity_reveals (talk_id, user_id, reason)
+        VALUES ($1, $2, $3)
+        "#,
+    )
+    .bind(talk_id)
+    .bind(user.id)
+    .bind(&reason)
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error logging identity reveal: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to reveal speakers")),
+        )
+    })?;
+
+    tracing::info!(
+        "Speakers of talk {} revealed to {} ({})",
+        talk_id,
+        user.email,
+        reason.as_deref().unwrap_or("no reason given")
+    );
+
+    let speakers = fetch_talk_speakers(&state.db, talk_id).await.map_err(|e| {
+        tracing::error!("Database error fetching talk speakers: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk speakers")),
+        )
+    })?;
+
+    Ok(Json(speakers))
+}
+
+/// List who revealed which speakers in a conference, newest first (organizer only)
+pub async fn list_identity_reveals(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<SpeakerIdentityReveal>>, (StatusCode, Json<ErrorResponse>)> {
+    let reveals = sqlx::query_as::<_, SpeakerIdentityReveal>(
+        r#"
+        SELECT
+            r.id,
+            r.talk_id,
+            t.title as talk_title,
+            r.user_id,
+            u.full_name as user_name,
+            r.reason,
+            r.revealed_at
+        FROM speaker_identity_reveals r
+        INNER JOIN talks t ON r.talk_id = t.id
+        INNER JOIN users u ON r.user_id = u.id
+        WHERE t.conference_id = $1
+        ORDER BY r.revealed_at DESC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching identity reveals: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch identity reveals")),
+        )
+    })?;
+
+    Ok(Json(reveals))
+}
@@ -0,0 +1,313 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::{
+        blind_review::hidden_speaker_talks,
+        ratings::fetch_review_scores,
+        review_phases::ensure_decisions_open,
+        talks::{
+            notify_talk_speakers, set_talk_state, speaker_email_variables, state_change_template,
+        },
+    },
+    models::{
+        auth::ErrorResponse, normalize_scores, plan_decisions, trimmed_mean, BulkDecisionPreview,
+        BulkDecisionRequest, CommitDecisionsRequest, DecisionCandidate, DecisionEmail,
+        DecisionScore, Talk, TalkResponse, TalkState, TalksListResponse, User, ANONYMOUS_SPEAKER,
+    },
+};
+
+/// Helper function to fetch a conference's submitted talks with the score
+/// chosen to rank them
+async fn fetch_decision_candidates(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+    score: DecisionScore,
+) -> Result<Vec<DecisionCandidate>, sqlx::Error> {
+    #[derive(sqlx::FromRow)]
+    struct CandidateRow {
+        talk_id: Uuid,
+        talk_title: String,
+        average_rating: Option<f64>,
+        ratings: Vec<i32>,
+    }
+
+    let rows = sqlx::query_as::<_, CandidateRow>(
+        r#"
+        SELECT
+            t.id AS talk_id,
+            t.title AS talk_title,
+            AVG(r.rating)::float8 AS average_rating,
+            COALESCE(array_agg(r.rating) FILTER (WHERE r.id IS NOT NULL), '{}') AS ratings
+        FROM talks t
+        LEFT JOIN unconflicted_ratings r ON t.id = r.talk_id
+        WHERE t.conference_id = $1 AND t.state = 'submitted'
+        GROUP BY t.id, t.title, t.submitted_at
+        ORDER BY t.submitted_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await?;
+
+    #[derive(sqlx::FromRow)]
+    struct LabelRow {
+        talk_id: Uuid,
+        label_id: Uuid,
+        name: String,
+    }
+
+    let label_rows = sqlx::query_as::<_, LabelRow>(
+        r#"
+        SELECT tl.talk_id, l.id AS label_id, l.name
+        FROM talk_labels tl
+        JOIN labels l ON tl.label_id = l.id
+        JOIN talks t ON tl.talk_id = t.id
+        WHERE t.conference_id = $1 AND t.state = 'submitted'
+        ORDER BY l.name ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut labels: HashMap<Uuid, Vec<(Uuid, String)>> = HashMap::new();
+    for row in label_rows {
+        labels
+            .entry(row.talk_id)
+            .or_default()
+            .push((row.label_id, row.name));
+    }
+
+    let normalized = match score {
+        DecisionScore::Normalized => {
+            normalize_scores(&fetch_review_scores(db, Some(conference_id)).await?).talk_scores
+        }
+        _ => HashMap::new(),
+    };
+
+    Ok(rows
+        .into_iter()
+        .map(|row| DecisionCandidate {
+            score: match score {
+                DecisionScore::Average => row.average_rating,
+                DecisionScore::Normalized => normalized.get(&row.talk_id).copied(),
+                DecisionScore::Trimmed => trimmed_mean(&row.ratings),
+            },
+            labels: labels.remove(&row.talk_id).unwrap_or_default(),
+            talk_id: row.talk_id,
+            talk_title: row.talk_title,
+        })
+        .collect())
+}
+
+/// Work out what a bulk decision would do, including every email it would
+/// send, without changing anything (organizer only)
+pub async fn preview_bulk_decision(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<BulkDecisionRequest>,
+) -> Result<Json<BulkDecisionPreview>, (StatusCode, Json<ErrorResponse>)> {
+    payload.validate()?;
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error previewing bulk decision: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to preview decisions")),
+        )
+    };
+
+    let candidates = fetch_decision_candidates(&state.db, conference_id, payload.score)
+        .await
+        .map_err(db_error)?;
+    if let Some(missing) = payload
+        .talk_ids
+        .iter()
+        .find(|id| !candidates.iter().any(|c| c.talk_id == **id))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(format!(
+                "Talk {} is not a submitted talk of this conference",
+                missing
+            ))),
+        ));
+    }
+
+    let decisions = plan_decisions(candidates, &payload);
+
+    // Render the emails as change_talk_state would send them
+    let changed: Vec<Uuid> = decisions
+        .iter()
+        .filter(|d| d.to_state.is_some())
+        .map(|d| d.talk_id)
+        .collect();
+    let talks: HashMap<Uuid, Talk> =
+        sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = ANY($1)")
+            .bind(&changed)
+            .fetch_all(&state.db)
+            .await
+            .map_err(db_error)?
+            .into_iter()
+            .map(|talk| (talk.id, talk))
+            .collect();
+    let hidden = hidden_speaker_talks(&state.db, user.id, Some(conference_id))
+        .await
+        .map_err(db_error)?;
+
+    let mut templates = HashMap::new();
+    let mut emails = Vec::new();
+    for decision in &decisions {
+        let (Some(to_state), Some(talk)) = (&decision.to_state, talks.get(&decision.talk_id))
+        else {
+            continue;
+        };
+        let Some(template_type) = state_change_template(to_state) else {
+            continue;
+        };
+        // As the talk will be once the decision is committed
+        let talk = &Talk {
+            state: to_state.clone(),
+            ..talk.clone()
+        };
+        if !templates.contains_key(template_type) {
+            let template = state
+                .email_service
+                .get_template(conference_id, template_type)
+                .await
+                .ok();
+            templates.insert(template_type, template);
+        }
+        let template = templates[template_type].as_ref();
+
+        for mut variables in speaker_email_variables(&state, talk, payload.reason.clone()).await {
+            // Keep speakers anonymous to organizers who haven't revealed them
+            if hidden.contains(&talk.id) {
+                variables.speaker_name = ANONYMOUS_SPEAKER.to_string();
+                variables.speaker_email = ANONYMOUS_SPEAKER.to_string();
+            }
+            let render = |text: &str| state.email_service.render_template(text, &variables).ok();
+            emails.push(DecisionEmail {
+                talk_id: talk.id,
+                template_type: template_type.to_string(),
+                to: variables.speaker_email.clone(),
+                subject: template.and_then(|t| render(&t.subject)),
+                body: template.and_then(|t| render(&t.body)),
+            });
+        }
+    }
+
+    Ok(Json(BulkDecisionPreview {
+        decisions,
+        emails,
+        emails_enabled: state.email_service.is_configured(),
+    }))
+}
+
+/// Apply the transitions of a previewed bulk decision in one transaction,
+/// then email the speakers (organizer only). Fails without changing anything
+/// if a talk has moved on since the preview.
+pub async fn commit_bulk_decision(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<CommitDecisionsRequest>,
+) -> Result<Json<TalksListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.decisions.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("No decisions to commit")),
+        ));
+    }
+    ensure_decisions_open(&state, conference_id).await?;
+
+    for change in &payload.decisions {
+        if matches!(change.to_state, TalkState::Draft | TalkState::Withdrawn)
+            || !change.from_state.can_transition_to(&change.to_state)
+        {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(format!(
+                    "Invalid state transition for talk {}: cannot move from {:?} to {:?}",
+                    change.talk_id, change.from_state, change.to_state
+                ))),
+            ));
+        }
+    }
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error committing bulk decision: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to commit decisions")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    let mut updated_talks = Vec::with_capacity(payload.decisions.len());
+    for change in &payload.decisions {
+        let talk = sqlx::query_as::<_, Talk>(
+            r#"
+            SELECT * FROM talks
+            WHERE id = $1 AND conference_id = $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(change.talk_id)
+        .bind(conference_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new(format!(
+                    "Talk {} not found in this conference",
+                    change.talk_id
+                ))),
+            )
+        })?;
+
+        // Dropping the transaction rolls back everything applied so far
+        if talk.state != change.from_state {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new(format!(
+                    "\"{}\" has changed since the preview; preview the decision again",
+                    talk.title
+                ))),
+            ));
+        }
+
+        updated_talks.push(
+            set_talk_state(&mut tx, &talk, &change.to_state)
+                .await
+                .map_err(db_error)?,
+        );
+    }
+
+    tx.commit().await.map_err(db_error)?;
+
+    tracing::info!(
+        "Bulk decision applied to {} talks in conference {}",
+        updated_talks.len(),
+        conference_id
+    );
+
+    for talk in &updated_talks {
+        if let Some(template_type) = state_change_template(&talk.state) {
+            notify_talk_speakers(&state, talk, template_type, payload.reason.clone()).await;
+        }
+    }
+
+    Ok(Json(TalksListResponse {
+        talks: updated_talks.into_iter().map(TalkResponse::from).collect(),
+    }))
+}
@@ -3,10 +3,17 @@ use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use uuid::Uuid;
 
-use crate::{api::AppState, models::talk::TalkState, services::email::EmailVariables};
+use crate::{
+    api::AppState,
+    handlers::speaker_feedback::fetch_released_feedback,
+    models::{feedback_text, talk::TalkState},
+    services::email::EmailVariables,
+};
 
 #[derive(Debug, Deserialize)]
 pub struct BulkEmailRequest {
+    /// Filter recipients by conference
+    pub conference_id: Option<Uuid>,
     /// Filter recipients by talk state
     pub filter_by_state: Option<Vec<TalkState>>,
     /// Specific talk IDs to send to (overrides state filter)
@@ -33,6 +40,7 @@ struct EmailRecipient {
     speaker_email: String,
     talk_id: Uuid,
     talk_title: String,
+    conference_name: String,
 }
 
 pub async fn send_bulk_email(
@@ -71,11 +79,6 @@ pub async fn send_bulk_email(
         }));
     }
 
-    // Get active conference
-    let conference = crate::handlers::conferences::get_active_conference_internal(&state.db)
-        .await
-        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
-
     let mut emails_sent = 0;
     let mut failed_emails = 0;
     let mut errors = Vec::new();
@@ -109,10 +112,16 @@ pub async fn send_bulk_email(
             speaker_email: recipient.speaker_email.clone(),
             talk_title: recipient.talk_title.clone(),
             talk_id: recipient.talk_id.to_string(),
+            conference_name: Some(recipient.conference_name.clone()),
             reason: payload.additional_message.clone(),
             schedule_date: None,
             schedule_time: None,
             track_name: None,
+            invitation_url: None,
+            feedback: fetch_released_feedback(&state.db, recipient.talk_id)
+                .await
+                .ok()
+                .and_then(|feedback| feedback_text(&feedback)),
         };
 
         // Render subject and body with variables
@@ -147,7 +156,7 @@ pub async fn send_bulk_email(
                 &recipient.speaker_email,
                 &rendered_subject,
                 &rendered_body,
-                Some(conference.id),
+                payload.template_id,
                 Some(recipient.talk_id),
                 None,
             )
@@ -181,15 +190,22 @@ async fn fetch_recipients(
             u.full_name as speaker_name,
             u.email as speaker_email,
             t.id as talk_id,
-            t.title as talk_title
+            t.title as talk_title,
+            c.name as conference_name
         FROM talks t
         INNER JOIN users u ON t.speaker_id = u.id
-        WHERE 1=1
+        INNER JOIN conferences c ON t.conference_id = c.id
+        WHERE t.state <> 'draft'
         "#,
     );
 
     let mut conditions = Vec::new();
 
+    // Filter by conference if provided
+    if let Some(conference_id) = payload.conference_id {
+        conditions.push(format!("t.conference_id = '{}'", conference_id));
+    }
+
     // Filter by specific talk IDs if provided
     if let Some(talk_ids) = &payload.talk_ids {
         if !talk_ids.is_empty() {
@@ -209,6 +225,9 @@ async fn fetch_recipients(
                             TalkState::Pending => "pending",
                             TalkState::Accepted => "accepted",
                             TalkState::Rejected => "rejected",
+                            TalkState::Draft => "draft",
+                            TalkState::Withdrawn => "withdrawn",
+                            TalkState::Waitlisted => "waitlisted",
                         }
                     )
                 })
@@ -242,6 +261,9 @@ async fn fetch_recipients(
             talk_title: row
                 .try_get("talk_title")
                 .map_err(|e| format!("Failed to get talk_title: {}", e))?,
+            conference_name: row
+                .try_get("conference_name")
+                .map_err(|e| format!("Failed to get conference_name: {}", e))?,
         });
     }
 
@@ -9,11 +9,23 @@ use uuid::Uuid;
 use crate::{
     api::AppState,
     models::{
-        auth::ErrorResponse, Conference, ConferenceResponse, CreateConferenceRequest,
-        UpdateConferenceRequest,
+        auth::ErrorResponse, validate_conference_fields, Conference, ConferenceFields,
+        ConferenceResponse, CreateConferenceRequest, ReviewPhases, SubmissionWindow,
+        UpdateConferenceRequest, DEFAULT_TIMEZONE,
     },
 };
 
+/// Helper function to build a conference response with its effective CfP window
+/// and review phase
+fn conference_response(state: &AppState, conference: Conference) -> ConferenceResponse {
+    let now = Utc::now();
+    let window = SubmissionWindow::for_conference(&conference, &state.config.conference, now);
+    let phases = ReviewPhases::for_conference(&conference, &state.config.conference, now);
+    ConferenceResponse::from(conference)
+        .with_submission_window(window)
+        .with_review_phases(phases)
+}
+
 /// List all conferences (public endpoint)
 pub async fn list_conferences(
     State(state): State<AppState>,
@@ -36,12 +48,12 @@ pub async fn list_conferences(
 
     let responses: Vec<ConferenceResponse> = conferences
         .into_iter()
-        .map(ConferenceResponse::from)
+        .map(|conference| conference_response(&state, conference))
         .collect();
     Ok(Json(responses))
 }
 
-/// Get the active conference (public endpoint)
+/// Get the active conference along with whether its CfP is open (public endpoint)
 pub async fn get_active_conference(
     State(state): State<AppState>,
 ) -> Result<Json<ConferenceResponse>, (StatusCode, Json<ErrorResponse>)> {
@@ -70,7 +82,7 @@ pub async fn get_active_conference(
         )
     })?;
 
-    Ok(Json(ConferenceResponse::from(conference)))
+    Ok(Json(conference_response(&state, conference)))
 }
 
 /// Get a single conference by ID (public endpoint)
@@ -102,7 +114,7 @@ pub async fn get_conference(
         )
     })?;
 
-    Ok(Json(ConferenceResponse::from(conference)))
+    Ok(Json(conference_response(&state, conference)))
 }
 
 /// Create a new conference (organizer only)
@@ -110,27 +122,31 @@ pub async fn create_conference(
     State(state): State<AppState>,
     Json(payload): Json<CreateConferenceRequest>,
 ) -> Result<(StatusCode, Json<ConferenceResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Validate name
-    if payload.name.trim().is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Conference name is required")),
-        ));
-    }
-
-    // Validate dates
-    if payload.end_date < payload.start_date {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("End date must be after start date")),
-        ));
-    }
+    validate_conference_fields(
+        &state.config.submission,
+        &ConferenceFields {
+            name: Some(&payload.name),
+            description: payload.description.as_deref(),
+            location: payload.location.as_deref(),
+            start_date: payload.start_date,
+            end_date: payload.end_date,
+            submission_open: payload.submission_open,
+            submission_close: payload.submission_close,
+            review_deadline: payload.review_deadline,
+            decision_deadline: payload.decision_deadline,
+            confirmation_deadline: payload.confirmation_deadline,
+            timezone: payload.timezone.as_deref(),
+        },
+    )?;
 
     // Create the conference
     let conference = sqlx::query_as::<_, Conference>(
         r#"
-        INSERT INTO conferences (name, description, start_date, end_date, location, is_active)
-        VALUES ($1, $2, $3, $4, $5, true)
+        INSERT INTO conferences (name, description, start_date, end_date, location, is_active,
+                                 submission_open, submission_close, blind_review,
+                                 review_deadline, decision_deadline, confirmation_deadline,
+                                 timezone)
+        VALUES ($1, $2, $3, $4, $5, true, $6, $7, $8, $9, $10, $11, $12)
         RETURNING *
         "#,
     )
@@ -139,6 +155,18 @@ pub async fn create_conference(
     .bind(payload.start_date)
     .bind(payload.end_date)
     .bind(payload.location.as_ref().map(|s| s.trim()))
+    .bind(payload.submission_open)
+    .bind(payload.submission_close)
+    .bind(payload.blind_review)
+    .bind(payload.review_deadline)
+    .bind(payload.decision_deadline)
+    .bind(payload.confirmation_deadline)
+    .bind(
+        payload
+            .timezone
+            .as_deref()
+            .map_or(DEFAULT_TIMEZONE, |s| s.trim()),
+    )
     .fetch_one(&state.db)
     .await
     .map_err(|e| {
@@ -156,7 +184,7 @@ pub async fn create_conference(
     );
     Ok((
         StatusCode::CREATED,
-        Json(ConferenceResponse::from(conference)),
+        Json(conference_response(&state, conference)),
     ))
 }
 
@@ -191,26 +219,40 @@ pub async fn update_conference(
         )
     })?;
 
-    // Validate name if provided
-    if let Some(ref name) = payload.name {
-        if name.trim().is_empty() {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new("Conference name cannot be empty")),
-            ));
-        }
-    }
-
-    // Validate dates if provided
     let start_date = payload.start_date.unwrap_or(existing_conference.start_date);
     let end_date = payload.end_date.unwrap_or(existing_conference.end_date);
+    let submission_open = payload
+        .submission_open
+        .unwrap_or(existing_conference.submission_open);
+    let submission_close = payload
+        .submission_close
+        .unwrap_or(existing_conference.submission_close);
+    let review_deadline = payload
+        .review_deadline
+        .unwrap_or(existing_conference.review_deadline);
+    let decision_deadline = payload
+        .decision_deadline
+        .unwrap_or(existing_conference.decision_deadline);
+    let confirmation_deadline = payload
+        .confirmation_deadline
+        .unwrap_or(existing_conference.confirmation_deadline);
 
-    if end_date < start_date {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("End date must be after start date")),
-        ));
-    }
+    validate_conference_fields(
+        &state.config.submission,
+        &ConferenceFields {
+            name: payload.name.as_deref(),
+            description: payload.description.as_deref(),
+            location: payload.location.as_deref(),
+            start_date,
+            end_date,
+            submission_open,
+            submission_close,
+            review_deadline,
+            decision_deadline,
+            confirmation_deadline,
+            timezone: payload.timezone.as_deref(),
+        },
+    )?;
 
     // Update the conference
     let name = payload
@@ -229,13 +271,24 @@ pub async fn update_conference(
         .map(|s| Some(s.trim()))
         .unwrap_or(existing_conference.location.as_deref());
     let is_active = payload.is_active.unwrap_or(existing_conference.is_active);
+    let blind_review = payload
+        .blind_review
+        .unwrap_or(existing_conference.blind_review);
+    let timezone = payload
+        .timezone
+        .as_ref()
+        .map(|s| s.trim())
+        .unwrap_or(&existing_conference.timezone);
 
     let updated_conference = sqlx::query_as::<_, Conference>(
         r#"
         UPDATE conferences
         SET name = $1, description = $2, start_date = $3, end_date = $4,
-            location = $5, is_active = $6, updated_at = $7
-        WHERE id = $8
+            location = $5, is_active = $6, submission_open = $7,
+            submission_close = $8, blind_review = $9, review_deadline = $10,
+            decision_deadline = $11, confirmation_deadline = $12, timezone = $13,
+            updated_at = $14
+        WHERE id = $15
         RETURNING *
         "#,
     )
@@ -245,6 +298,13 @@ pub async fn update_conference(
     .bind(end_date)
     .bind(location)
     .bind(is_active)
+    .bind(submission_open)
+    .bind(submission_close)
+    .bind(blind_review)
+    .bind(review_deadline)
+    .bind(decision_deadline)
+    .bind(confirmation_deadline)
+    .bind(timezone)
     .bind(Utc::now())
     .bind(conference_id)
     .fetch_one(&state.db)
@@ -262,7 +322,7 @@ pub async fn update_conference(
         updated_conference.name,
         updated_conference.id
     );
-    Ok(Json(ConferenceResponse::from(updated_conference)))
+    Ok(Json(conference_response(&state, updated_conference)))
 }
 
 /// Delete a conference (organizer only)
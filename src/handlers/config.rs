@@ -1,7 +1,7 @@
 use axum::{extract::State, http::StatusCode, Json};
 use serde::Serialize;
 
-use crate::api::AppState;
+use crate::{api::AppState, config::SubmissionConfig};
 
 #[derive(Debug, Serialize)]
 pub struct ConfigResponse {
@@ -75,6 +75,22 @@ pub struct SubmissionInfo {
     pub default_duration: u16,
 }
 
+impl From<&SubmissionConfig> for SubmissionInfo {
+    fn from(submission: &SubmissionConfig) -> Self {
+        Self {
+            min_title_length: submission.min_title_length,
+            max_title_length: submission.max_title_length,
+            min_summary_length: submission.min_summary_length,
+            max_summary_length: submission.max_summary_length,
+            max_description_length: submission.max_description_length,
+            max_slide_size_mb: submission.max_slide_size_mb,
+            allowed_slide_formats: submission.allowed_slide_formats.clone(),
+            talk_durations: submission.talk_durations.clone(),
+            default_duration: submission.default_duration,
+        }
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct EmailInfo {
     pub from_name: String,
@@ -168,17 +184,7 @@ pub async fn get_configuration(
             enable_schedule_builder: config.features.enable_schedule_builder,
             require_speaker_confirmation: config.features.require_speaker_confirmation,
         },
-        submission: SubmissionInfo {
-            min_title_length: config.submission.min_title_length,
-            max_title_length: config.submission.max_title_length,
-            min_summary_length: config.submission.min_summary_length,
-            max_summary_length: config.submission.max_summary_length,
-            max_description_length: config.submission.max_description_length,
-            max_slide_size_mb: config.submission.max_slide_size_mb,
-            allowed_slide_formats: config.submission.allowed_slide_formats.clone(),
-            talk_durations: config.submission.talk_durations.clone(),
-            default_duration: config.submission.default_duration,
-        },
+        submission: SubmissionInfo::from(&config.submission),
         email: EmailInfo {
             from_name: config.email_config.from_name.clone(),
             from_email: config.email_config.from_email.clone(),
@@ -225,3 +231,8 @@ pub async fn get_configuration(
 
     Ok(Json(response))
 }
+
+/// Submission limits and options that speakers need to fill in the submit form (public endpoint)
+pub async fn get_submission_options(State(state): State<AppState>) -> Json<SubmissionInfo> {
+    Json(SubmissionInfo::from(&state.config.submission))
+}
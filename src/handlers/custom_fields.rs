@@ -0,0 +1,296 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, validate_custom_answers, CreateCustomFieldRequest, CustomField,
+        UpdateCustomFieldRequest,
+    },
+};
+
+// Size of the field_key column
+const MAX_FIELD_KEY_LENGTH: usize = 100;
+
+/// Fetch a conference's custom fields in form order
+pub(crate) async fn fetch_custom_fields(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+) -> Result<Vec<CustomField>, sqlx::Error> {
+    sqlx::query_as::<_, CustomField>(
+        r#"
+        SELECT * FROM custom_fields
+        WHERE conference_id = $1
+        ORDER BY position ASC, created_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Validate a talk's custom answers against its conference's fields.
+/// Required fields are skipped for drafts and checked again on submit.
+pub(crate) async fn validate_talk_custom_answers(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+    answers: &Value,
+    enforce_required: bool,
+) -> Result<Value, (StatusCode, Json<ErrorResponse>)> {
+    let fields = fetch_custom_fields(db, conference_id).await.map_err(|e| {
+        tracing::error!("Database error fetching custom fields: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch custom fields")),
+        )
+    })?;
+
+    validate_custom_answers(&fields, answers, enforce_required)
+        .map(Value::Object)
+        .map_err(Into::into)
+}
+
+/// Helper function to validate the parts of a field definition
+fn validate_field_definition(
+    label: &str,
+    options: &[String],
+    has_options: bool,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if label.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Label is required")),
+        ));
+    }
+
+    if has_options && options.iter().all(|o| o.trim().is_empty()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Select and multi-select fields need at least one option",
+            )),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Trim options and drop empty ones
+fn clean_options(options: Vec<String>) -> Vec<String> {
+    options
+        .into_iter()
+        .map(|o| o.trim().to_string())
+        .filter(|o| !o.is_empty())
+        .collect()
+}
+
+/// List the custom fields for a conference (public endpoint)
+pub async fn list_custom_fields(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<CustomField>>, (StatusCode, Json<ErrorResponse>)> {
+    let fields = fetch_custom_fields(&state.db, conference_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching custom fields: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch custom fields")),
+            )
+        })?;
+
+    Ok(Json(fields))
+}
+
+/// Add a custom field to a conference's submission form (organizer only)
+pub async fn create_custom_field(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<CreateCustomFieldRequest>,
+) -> Result<(StatusCode, Json<CustomField>), (StatusCode, Json<ErrorResponse>)> {
+    let field_key = payload.field_key.trim();
+    if field_key.is_empty()
+        || field_key.len() > MAX_FIELD_KEY_LENGTH
+        || !field_key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(format!(
+                "Field key must be up to {} lowercase letters, digits and underscores",
+                MAX_FIELD_KEY_LENGTH
+            ))),
+        ));
+    }
+
+    let options = if payload.field_type.has_options() {
+        clean_options(payload.options.unwrap_or_default())
+    } else {
+        Vec::new()
+    };
+    validate_field_definition(&payload.label, &options, payload.field_type.has_options())?;
+
+    let field = sqlx::query_as::<_, CustomField>(
+        r#"
+        INSERT INTO custom_fields (conference_id, field_key, label, help_text, field_type,
+                                   options, is_required, position)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING *
+        "#,
+    )
+    .bind(conference_id)
+    .bind(field_key)
+    .bind(payload.label.trim())
+    .bind(payload.help_text.as_ref().map(|s| s.trim()))
+    .bind(payload.field_type)
+    .bind(&options)
+    .bind(payload.is_required.unwrap_or(false))
+    .bind(payload.position.unwrap_or(0))
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error creating custom field: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new(
+                    "A custom field with this key already exists for the conference",
+                )),
+            ),
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to create custom field")),
+            ),
+        }
+    })?;
+
+    Ok((StatusCode::CREATED, Json(field)))
+}
+
+/// Update a custom field (organizer only)
+pub async fn update_custom_field(
+    State(state): State<AppState>,
+    Path(field_id): Path<Uuid>,
+    Json(payload): Json<UpdateCustomFieldRequest>,
+) -> Result<Json<CustomField>, (StatusCode, Json<ErrorResponse>)> {
+    let existing = sqlx::query_as::<_, CustomField>("SELECT * FROM custom_fields WHERE id = $1")
+        .bind(field_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching custom field: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch custom field")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Custom field not found")),
+            )
+        })?;
+
+    let label = payload.label.unwrap_or(existing.label);
+    let help_text = match payload.help_text {
+        Some(text) => Some(text.trim().to_string()).filter(|t| !t.is_empty()),
+        None => existing.help_text,
+    };
+    let options = match payload.options {
+        Some(options) if existing.field_type.has_options() => clean_options(options),
+        _ => existing.options,
+    };
+    validate_field_definition(&label, &options, existing.field_type.has_options())?;
+
+    let field = sqlx::query_as::<_, CustomField>(
+        r#"
+        UPDATE custom_fields
+        SET label = $1,
+            help_text = $2,
+            options = $3,
+            is_required = $4,
+            position = $5,
+            updated_at = NOW()
+        WHERE id = $6
+        RETURNING *
+        "#,
+    )
+    .bind(label.trim())
+    .bind(help_text)
+    .bind(&options)
+    .bind(payload.is_required.unwrap_or(existing.is_required))
+    .bind(payload.position.unwrap_or(existing.position))
+    .bind(field_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error updating custom field: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to update custom field")),
+        )
+    })?;
+
+    Ok(Json(field))
+}
+
+/// Delete a custom field (organizer only), along with the answers stored for it
+pub async fn delete_custom_field(
+    State(state): State<AppState>,
+    Path(field_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error deleting custom field: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to delete custom field")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    let deleted = sqlx::query_as::<_, (Uuid, String)>(
+        r#"
+        DELETE FROM custom_fields
+        WHERE id = $1
+        RETURNING conference_id, field_key
+        "#,
+    )
+    .bind(field_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    let (conference_id, field_key) = deleted.ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Custom field not found")),
+        )
+    })?;
+
+    sqlx::query(
+        r#"
+        UPDATE talks
+        SET custom_answers = custom_answers - $1
+        WHERE conference_id = $2
+        "#,
+    )
+    .bind(&field_key)
+    .bind(conference_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
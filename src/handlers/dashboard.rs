@@ -1,4 +1,8 @@
-use axum::{extract::State, http::StatusCode, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 use sqlx::FromRow;
@@ -6,7 +10,8 @@ use uuid::Uuid;
 
 use crate::{
     api::AppState,
-    models::{auth::ErrorResponse, TalkState},
+    handlers::blind_review::hidden_speaker_talks,
+    models::{auth::ErrorResponse, ConferenceFilterQuery, TalkState, User, ANONYMOUS_SPEAKER},
 };
 
 #[derive(Debug, Serialize)]
@@ -24,6 +29,8 @@ pub struct TalksByState {
     pub pending: i64,
     pub accepted: i64,
     pub rejected: i64,
+    pub withdrawn: i64,
+    pub waitlisted: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -45,30 +52,42 @@ pub struct RecentTalk {
     pub average_rating: Option<f64>,
 }
 
-/// Get dashboard statistics (organizer only)
+/// Get dashboard statistics (organizer only), optionally scoped to one conference
 pub async fn get_dashboard_stats(
     State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<ConferenceFilterQuery>,
 ) -> Result<Json<DashboardStats>, (StatusCode, Json<ErrorResponse>)> {
-    // Get total talks count
-    let total_talks: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM talks")
-        .fetch_one(&state.db)
-        .await
-        .map_err(|e| {
-            tracing::error!("Database error fetching total talks: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::new("Failed to fetch statistics")),
-            )
-        })?;
+    // Get total talks count (drafts haven't been submitted yet)
+    let total_talks: (i64,) = sqlx::query_as(
+        r#"
+        SELECT COUNT(*) FROM talks
+        WHERE state <> 'draft'
+          AND ($1::uuid IS NULL OR conference_id = $1)
+        "#,
+    )
+    .bind(query.conference_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching total talks: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch statistics")),
+        )
+    })?;
 
     // Get talks by state
     let state_counts: Vec<(TalkState, i64)> = sqlx::query_as(
         r#"
         SELECT state, COUNT(*) as count
         FROM talks
+        WHERE state <> 'draft'
+          AND ($1::uuid IS NULL OR conference_id = $1)
         GROUP BY state
         "#,
     )
+    .bind(query.conference_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| {
@@ -84,6 +103,8 @@ pub async fn get_dashboard_stats(
         pending: 0,
         accepted: 0,
         rejected: 0,
+        withdrawn: 0,
+        waitlisted: 0,
     };
 
     for (talk_state, count) in state_counts {
@@ -92,6 +113,9 @@ pub async fn get_dashboard_stats(
             TalkState::Pending => talks_by_state.pending = count,
             TalkState::Accepted => talks_by_state.accepted = count,
             TalkState::Rejected => talks_by_state.rejected = count,
+            TalkState::Withdrawn => talks_by_state.withdrawn = count,
+            TalkState::Waitlisted => talks_by_state.waitlisted = count,
+            TalkState::Draft => {}
         }
     }
 
@@ -102,10 +126,16 @@ pub async fn get_dashboard_stats(
             COUNT(r.id) as total_ratings,
             AVG(r.rating) as average_rating,
             COUNT(DISTINCT r.talk_id) as talks_with_ratings,
-            (SELECT COUNT(*) FROM talks) - COUNT(DISTINCT r.talk_id) as talks_without_ratings
-        FROM ratings r
+            (SELECT COUNT(*) FROM talks
+             WHERE state <> 'draft' AND ($1::uuid IS NULL OR conference_id = $1))
+                - COUNT(DISTINCT r.talk_id) as talks_without_ratings
+        FROM unconflicted_ratings r
+        JOIN talks t ON r.talk_id = t.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
         "#,
     )
+    .bind(query.conference_id)
     .fetch_one(&state.db)
     .await
     .map_err(|e| {
@@ -124,7 +154,7 @@ pub async fn get_dashboard_stats(
     };
 
     // Get recent submissions (last 10 talks)
-    let recent_submissions: Vec<RecentTalk> = sqlx::query_as(
+    let mut recent_submissions: Vec<RecentTalk> = sqlx::query_as(
         r#"
         SELECT
             t.id,
@@ -136,12 +166,15 @@ pub async fn get_dashboard_stats(
             AVG(r.rating) as average_rating
         FROM talks t
         JOIN users u ON t.speaker_id = u.id
-        LEFT JOIN ratings r ON t.id = r.talk_id
+        LEFT JOIN unconflicted_ratings r ON t.id = r.talk_id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
         GROUP BY t.id, t.title, u.full_name, t.state, t.submitted_at
         ORDER BY t.submitted_at DESC
         LIMIT 10
         "#,
     )
+    .bind(query.conference_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| {
@@ -152,6 +185,22 @@ pub async fn get_dashboard_stats(
         )
     })?;
 
+    let hidden = hidden_speaker_talks(&state.db, user.id, query.conference_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking blind review: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch statistics")),
+            )
+        })?;
+    for talk in recent_submissions
+        .iter_mut()
+        .filter(|talk| hidden.contains(&talk.id))
+    {
+        talk.speaker_name = ANONYMOUS_SPEAKER.to_string();
+    }
+
     // Unrated talks count
     let unrated_talks = rating_stats.talks_without_ratings;
 
@@ -107,6 +107,8 @@ pub async fn create_email_template(
         "talk_rejected",
         "talk_pending",
         "schedule_notification",
+        "co_speaker_invitation",
+        "talk_waitlisted",
         "custom",
     ];
     if !valid_types.contains(&payload.template_type.as_str()) {
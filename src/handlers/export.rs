@@ -1,16 +1,22 @@
 use axum::{
     extract::{Query, State},
     http::StatusCode,
-    Json,
+    Extension, Json,
 };
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use uuid::Uuid;
 
-use crate::api::AppState;
+use crate::{
+    api::AppState,
+    handlers::{blind_review::hidden_speaker_talks, ratings::fetch_review_scores},
+    models::{normalize_scores, trimmed_mean, User, ANONYMOUS_SPEAKER},
+};
 
 #[derive(Debug, Deserialize)]
 pub struct ExportQuery {
+    /// Optional conference filter
+    pub conference_id: Option<Uuid>,
     /// Optional state filter
     pub state: Option<String>,
     /// Export format (json or csv)
@@ -20,16 +26,32 @@ pub struct ExportQuery {
 #[derive(Debug, Serialize)]
 pub struct ExportedTalk {
     pub id: String,
+    pub conference_id: String,
+    pub conference_name: String,
     pub title: String,
     pub short_summary: String,
     pub long_description: Option<String>,
     pub speaker_name: String,
     pub speaker_email: String,
+    pub co_speakers: Vec<ExportedCoSpeaker>,
     pub state: String,
+    pub format: String,
+    pub duration_minutes: i32,
+    /// Answers to the conference's custom fields, keyed by field key
+    pub custom_answers: serde_json::Value,
     pub submitted_at: String,
     pub labels: Vec<String>,
     pub average_rating: Option<f64>,
     pub rating_count: i64,
+    /// Mean of per-reviewer z-scores, see the ratings statistics
+    pub normalized_score: Option<f64>,
+    pub trimmed_average: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExportedCoSpeaker {
+    pub name: String,
+    pub email: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -41,6 +63,7 @@ pub struct ExportResponse {
 
 pub async fn export_talks(
     State(state): State<AppState>,
+    Extension(user): Extension<User>,
     Query(params): Query<ExportQuery>,
 ) -> Result<Json<ExportResponse>, (StatusCode, String)> {
     // Build query based on filters
@@ -48,10 +71,15 @@ pub async fn export_talks(
         r#"
         SELECT
             t.id,
+            t.conference_id,
+            c.name as conference_name,
             t.title,
             t.short_summary,
             t.long_description,
             t.state::text as state,
+            t.format::text as format,
+            t.duration_minutes,
+            t.custom_answers,
             t.submitted_at,
             u.full_name as speaker_name,
             u.email as speaker_email,
@@ -62,10 +90,22 @@ pub async fn export_talks(
                  WHERE tl.talk_id = t.id),
                 '[]'::json
             ) as labels,
-            (SELECT AVG(rating)::float FROM ratings WHERE talk_id = t.id) as average_rating,
-            (SELECT COUNT(*)::bigint FROM ratings WHERE talk_id = t.id) as rating_count
+            COALESCE(
+                (SELECT json_agg(json_build_object('name', cu.full_name, 'email', cu.email)
+                                 ORDER BY ts.invited_at)
+                 FROM talk_speakers ts
+                 INNER JOIN users cu ON ts.user_id = cu.id
+                 WHERE ts.talk_id = t.id AND ts.status = 'accepted'),
+                '[]'::json
+            ) as co_speakers,
+            (SELECT AVG(rating)::float FROM unconflicted_ratings WHERE talk_id = t.id) as average_rating,
+            (SELECT COUNT(*)::bigint FROM unconflicted_ratings WHERE talk_id = t.id) as rating_count,
+            (SELECT array_agg(rating) FROM unconflicted_ratings WHERE talk_id = t.id) as ratings
         FROM talks t
         INNER JOIN users u ON t.speaker_id = u.id
+        INNER JOIN conferences c ON t.conference_id = c.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
         "#,
     );
 
@@ -76,6 +116,8 @@ pub async fn export_talks(
             "pending" => "pending",
             "accepted" => "accepted",
             "rejected" => "rejected",
+            "withdrawn" => "withdrawn",
+            "waitlisted" => "waitlisted",
             _ => {
                 return Err((
                     StatusCode::BAD_REQUEST,
@@ -83,12 +125,13 @@ pub async fn export_talks(
                 ))
             }
         };
-        query.push_str(&format!(" WHERE t.state = '{}'::talk_state", state_str));
+        query.push_str(&format!(" AND t.state = '{}'::talk_state", state_str));
     }
 
     query.push_str(" ORDER BY t.submitted_at DESC");
 
     let rows = sqlx::query(&query)
+        .bind(params.conference_id)
         .fetch_all(&state.db)
         .await
         .map_err(|e| {
@@ -98,8 +141,45 @@ pub async fn export_talks(
             )
         })?;
 
+    // Blind review: leave out who submitted talks the exporter hasn't revealed
+    let hidden = hidden_speaker_talks(&state.db, user.id, params.conference_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Database error: {}", e),
+            )
+        })?;
+
+    let normalization = normalize_scores(
+        &fetch_review_scores(&state.db, params.conference_id)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Database error: {}", e),
+                )
+            })?,
+    );
+
     let mut talks = Vec::new();
     for row in rows {
+        let id: Uuid = row.try_get("id").map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to get id: {}", e),
+            )
+        })?;
+        let ratings: Vec<i32> = row
+            .try_get::<Option<Vec<i32>>, _>("ratings")
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to get ratings: {}", e),
+                )
+            })?
+            .unwrap_or_default();
+
         let labels_json: serde_json::Value = row.try_get("labels").map_err(|e| {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -109,16 +189,33 @@ pub async fn export_talks(
 
         let labels: Vec<String> = serde_json::from_value(labels_json).unwrap_or_default();
 
+        let co_speakers_json: serde_json::Value = row.try_get("co_speakers").map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to get co_speakers: {}", e),
+            )
+        })?;
+
+        let co_speakers: Vec<ExportedCoSpeaker> =
+            serde_json::from_value(co_speakers_json).unwrap_or_default();
+
         talks.push(ExportedTalk {
-            id: row
-                .try_get::<Uuid, _>("id")
+            id: id.to_string(),
+            conference_id: row
+                .try_get::<Uuid, _>("conference_id")
                 .map(|id| id.to_string())
                 .map_err(|e| {
                     (
                         StatusCode::INTERNAL_SERVER_ERROR,
-                        format!("Failed to get id: {}", e),
+                        format!("Failed to get conference_id: {}", e),
                     )
                 })?,
+            conference_name: row.try_get("conference_name").map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to get conference_name: {}", e),
+                )
+            })?,
             title: row.try_get("title").map_err(|e| {
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
@@ -144,12 +241,31 @@ pub async fn export_talks(
                     format!("Failed to get speaker_email: {}", e),
                 )
             })?,
+            co_speakers,
             state: row.try_get("state").map_err(|e| {
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     format!("Failed to get state: {}", e),
                 )
             })?,
+            format: row.try_get("format").map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to get format: {}", e),
+                )
+            })?,
+            duration_minutes: row.try_get("duration_minutes").map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to get duration_minutes: {}", e),
+                )
+            })?,
+            custom_answers: row.try_get("custom_answers").map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to get custom_answers: {}", e),
+                )
+            })?,
             submitted_at: row
                 .try_get::<chrono::NaiveDateTime, _>("submitted_at")
                 .map(|dt| dt.to_string())
@@ -162,9 +278,20 @@ pub async fn export_talks(
             labels,
             average_rating: row.try_get("average_rating").ok(),
             rating_count: row.try_get("rating_count").unwrap_or(0),
+            normalized_score: normalization.talk_scores.get(&id).copied(),
+            trimmed_average: trimmed_mean(&ratings),
         });
     }
 
+    for talk in talks
+        .iter_mut()
+        .filter(|talk| Uuid::parse_str(&talk.id).is_ok_and(|id| hidden.contains(&id)))
+    {
+        talk.speaker_name = ANONYMOUS_SPEAKER.to_string();
+        talk.speaker_email = String::new();
+        talk.co_speakers = Vec::new();
+    }
+
     let total_count = talks.len();
     let exported_at = chrono::Utc::now().to_rfc3339();
 
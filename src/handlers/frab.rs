@@ -0,0 +1,116 @@
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    api::AppState,
+    handlers::ical::{conference_not_found, conference_timezone, fetch_feed_conference},
+    models::{
+        auth::ErrorResponse, build_frab_schedule, render_frab_xml, ConferenceFilterQuery,
+        FrabExport, ScheduleExportTalk, Track,
+    },
+};
+
+/// Helper function to build the Frab export for the requested conference,
+/// or the active one
+async fn fetch_frab_export(
+    state: &AppState,
+    query: ConferenceFilterQuery,
+) -> Result<FrabExport, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error exporting schedule: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to export schedule")),
+        )
+    };
+
+    let conference = fetch_feed_conference(&state.db, query.conference_id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(conference_not_found)?;
+
+    let tracks = sqlx::query_as::<_, Track>("SELECT * FROM tracks WHERE conference_id = $1")
+        .bind(conference.id)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?;
+
+    let talks = sqlx::query_as::<_, ScheduleExportTalk>(
+        r#"
+        SELECT
+            ss.id AS slot_id,
+            tk.id AS talk_id,
+            tk.title,
+            tk.short_summary,
+            tk.format,
+            sp.speaker_ids,
+            sp.speakers,
+            ARRAY(
+                SELECT l.name::text FROM talk_labels tl
+                JOIN labels l ON tl.label_id = l.id
+                WHERE tl.talk_id = tk.id
+                ORDER BY l.name
+            ) AS labels,
+            ss.track_id,
+            ss.slot_date,
+            ss.start_time,
+            ss.end_time,
+            GREATEST(ss.updated_at, tk.updated_at) AS updated_at
+        FROM schedule_slots ss
+        JOIN talks tk ON ss.talk_id = tk.id
+        CROSS JOIN LATERAL (
+            SELECT
+                array_agg(s.id ORDER BY s.position, s.invited_at) AS speaker_ids,
+                array_agg(s.full_name::text ORDER BY s.position, s.invited_at) AS speakers
+            FROM (
+                SELECT u.id, u.full_name, 0 AS position, NULL::timestamptz AS invited_at
+                FROM users u WHERE u.id = tk.speaker_id
+                UNION ALL
+                SELECT cu.id, cu.full_name, 1, ts.invited_at
+                FROM talk_speakers ts
+                JOIN users cu ON ts.user_id = cu.id
+                WHERE ts.talk_id = tk.id AND ts.status = 'accepted'
+            ) s
+        ) sp
+        WHERE ss.conference_id = $1 AND tk.state = 'accepted'
+        "#,
+    )
+    .bind(conference.id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(build_frab_schedule(
+        &conference,
+        conference_timezone(&conference),
+        &state.config.conference.website,
+        &tracks,
+        &talks,
+    ))
+}
+
+/// Export the schedule as a Frab/Pentabarf schedule.xml (public endpoint)
+pub async fn export_frab_xml(
+    State(state): State<AppState>,
+    Query(query): Query<ConferenceFilterQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let export = fetch_frab_export(&state, query).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+        render_frab_xml(&export),
+    ))
+}
+
+/// Export the schedule in the Frab JSON format (public endpoint)
+pub async fn export_frab_json(
+    State(state): State<AppState>,
+    Query(query): Query<ConferenceFilterQuery>,
+) -> Result<Json<FrabExport>, (StatusCode, Json<ErrorResponse>)> {
+    let export = fetch_frab_export(&state, query).await?;
+    Ok(Json(export))
+}
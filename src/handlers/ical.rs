@@ -0,0 +1,270 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono_tz::Tz;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, render_calendar, CalendarEvent, CalendarFeed, Conference,
+        ConferenceFilterQuery,
+    },
+};
+
+/// Helper function to fetch the talks on a conference's schedule, optionally
+/// only those in one track or given by one speaker
+async fn fetch_calendar_events(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+    track_id: Option<Uuid>,
+    speaker_id: Option<Uuid>,
+) -> Result<Vec<CalendarEvent>, sqlx::Error> {
+    sqlx::query_as::<_, CalendarEvent>(
+        r#"
+        SELECT
+            ss.id AS slot_id,
+            tk.id AS talk_id,
+            tk.title,
+            tk.short_summary,
+            ARRAY(
+                SELECT s.full_name::text FROM (
+                    SELECT u.full_name, 0 AS position, NULL::timestamptz AS invited_at
+                    FROM users u WHERE u.id = tk.speaker_id
+                    UNION ALL
+                    SELECT cu.full_name, 1, ts.invited_at
+                    FROM talk_speakers ts
+                    JOIN users cu ON ts.user_id = cu.id
+                    WHERE ts.talk_id = tk.id AND ts.status = 'accepted'
+                ) s
+                ORDER BY s.position, s.invited_at
+            ) AS speakers,
+            ARRAY(
+                SELECT l.name::text FROM talk_labels tl
+                JOIN labels l ON tl.label_id = l.id
+                WHERE tl.talk_id = tk.id
+                ORDER BY l.name
+            ) AS labels,
+            t.name AS track_name,
+            ss.slot_date,
+            ss.start_time,
+            ss.end_time,
+            GREATEST(ss.updated_at, tk.updated_at) AS updated_at
+        FROM schedule_slots ss
+        JOIN tracks t ON ss.track_id = t.id
+        JOIN talks tk ON ss.talk_id = tk.id
+        WHERE ss.conference_id = $1
+          AND tk.state = 'accepted'
+          AND ($2::uuid IS NULL OR ss.track_id = $2)
+          AND (
+              $3::uuid IS NULL
+              OR tk.speaker_id = $3
+              OR EXISTS(
+                  SELECT 1 FROM talk_speakers ts
+                  WHERE ts.talk_id = tk.id AND ts.user_id = $3 AND ts.status = 'accepted'
+              )
+          )
+        ORDER BY ss.slot_date ASC, ss.start_time ASC, t.name ASC
+        "#,
+    )
+    .bind(conference_id)
+    .bind(track_id)
+    .bind(speaker_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Helper function to find the conference a feed is for: the one asked for,
+/// or else the active conference
+pub(crate) async fn fetch_feed_conference(
+    db: &sqlx::PgPool,
+    conference_id: Option<Uuid>,
+) -> Result<Option<Conference>, sqlx::Error> {
+    sqlx::query_as::<_, Conference>(
+        r#"
+        SELECT * FROM conferences
+        WHERE ($1::uuid IS NULL AND is_active = true) OR id = $1
+        ORDER BY start_date DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_optional(db)
+    .await
+}
+
+/// Helper function to read a conference's time zone, falling back to UTC
+pub(crate) fn conference_timezone(conference: &Conference) -> Tz {
+    conference.timezone.parse::<Tz>().unwrap_or_else(|_| {
+        tracing::warn!(
+            "Conference {} has an unknown time zone {:?}; using UTC",
+            conference.id,
+            conference.timezone
+        );
+        Tz::UTC
+    })
+}
+
+/// Helper function to build the feed document and its response headers
+fn calendar_response(
+    state: &AppState,
+    conference: &Conference,
+    name: String,
+    events: Vec<CalendarEvent>,
+) -> impl IntoResponse {
+    let timezone = conference_timezone(conference);
+    // "https://example.org/2025" -> "example.org"
+    let domain = state
+        .config
+        .conference
+        .website
+        .split("://")
+        .last()
+        .and_then(|rest| rest.split('/').next())
+        .filter(|host| !host.is_empty())
+        .unwrap_or("localhost")
+        .to_string();
+
+    let feed = CalendarFeed {
+        name,
+        timezone,
+        domain,
+        publisher: state.config.conference.short_name.clone(),
+        events,
+    };
+    (
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        render_calendar(&feed),
+    )
+}
+
+pub(crate) fn conference_not_found() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ErrorResponse::new("Conference not found")),
+    )
+}
+
+/// Get the whole schedule as an iCalendar feed (public endpoint)
+pub async fn get_schedule_calendar(
+    State(state): State<AppState>,
+    Query(query): Query<ConferenceFilterQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error building schedule calendar: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to build calendar")),
+        )
+    };
+
+    let conference = fetch_feed_conference(&state.db, query.conference_id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(conference_not_found)?;
+    let events = fetch_calendar_events(&state.db, conference.id, None, None)
+        .await
+        .map_err(db_error)?;
+
+    let name = conference.name.clone();
+    Ok(calendar_response(&state, &conference, name, events))
+}
+
+/// Get one track's schedule as an iCalendar feed (public endpoint)
+pub async fn get_track_calendar(
+    State(state): State<AppState>,
+    Path(track_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error building track calendar: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to build calendar")),
+        )
+    };
+
+    let (conference_id, track_name) =
+        sqlx::query_as::<_, (Uuid, String)>("SELECT conference_id, name FROM tracks WHERE id = $1")
+            .bind(track_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new("Track not found")),
+                )
+            })?;
+    let conference = fetch_feed_conference(&state.db, Some(conference_id))
+        .await
+        .map_err(db_error)?
+        .ok_or_else(conference_not_found)?;
+    let events = fetch_calendar_events(&state.db, conference.id, Some(track_id), None)
+        .await
+        .map_err(db_error)?;
+
+    let name = format!("{} - {}", conference.name, track_name);
+    Ok(calendar_response(&state, &conference, name, events))
+}
+
+/// Get the talks one speaker gives as an iCalendar feed (public endpoint)
+pub async fn get_speaker_calendar(
+    State(state): State<AppState>,
+    Path(speaker_id): Path<Uuid>,
+    Query(query): Query<ConferenceFilterQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error building speaker calendar: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to build calendar")),
+        )
+    };
+
+    let conference = fetch_feed_conference(&state.db, query.conference_id)
+        .await
+        .map_err(db_error)?
+        .ok_or_else(conference_not_found)?;
+
+    // Only people presenting a scheduled talk of the conference have a feed
+    let speaker_name = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT u.full_name FROM users u
+        WHERE u.id = $1
+          AND EXISTS(
+              SELECT 1 FROM schedule_slots ss
+              JOIN talks tk ON ss.talk_id = tk.id
+              WHERE ss.conference_id = $2
+                AND tk.state = 'accepted'
+                AND (
+                    tk.speaker_id = u.id
+                    OR EXISTS(
+                        SELECT 1 FROM talk_speakers ts
+                        WHERE ts.talk_id = tk.id AND ts.user_id = u.id
+                          AND ts.status = 'accepted'
+                    )
+                )
+          )
+        "#,
+    )
+    .bind(speaker_id)
+    .bind(conference.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Speaker not found")),
+        )
+    })?;
+    let events = fetch_calendar_events(&state.db, conference.id, None, Some(speaker_id))
+        .await
+        .map_err(db_error)?;
+
+    let name = format!("{} - {}", conference.name, speaker_name);
+    Ok(calendar_response(&state, &conference, name, events))
+}
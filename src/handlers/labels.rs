@@ -7,9 +7,10 @@ use uuid::Uuid;
 
 use crate::{
     api::AppState,
+    handlers::talk_speakers::is_talk_speaker,
     models::{
-        auth::ErrorResponse, AddLabelToTalkRequest, CreateLabelRequest, Label, LabelResponse,
-        UpdateLabelRequest, User,
+        auth::ErrorResponse, validate_label_fields, AddLabelToTalkRequest, CreateLabelRequest,
+        Label, LabelResponse, UpdateLabelRequest, User,
     },
 };
 
@@ -42,25 +43,12 @@ pub async fn create_label(
     State(state): State<AppState>,
     Json(payload): Json<CreateLabelRequest>,
 ) -> Result<(StatusCode, Json<LabelResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Validate name
-    if payload.name.trim().is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Label name is required")),
-        ));
-    }
-
-    // Validate color format if provided (hex color)
-    if let Some(ref color) = payload.color {
-        if !color.starts_with('#') || (color.len() != 4 && color.len() != 7) {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new(
-                    "Color must be a valid hex color (e.g., #FF5733 or #F57)",
-                )),
-            ));
-        }
-    }
+    validate_label_fields(
+        &state.config.submission,
+        Some(&payload.name),
+        payload.description.as_deref(),
+        payload.color.as_deref(),
+    )?;
 
     // Create the label
     let label = sqlx::query_as::<_, Label>(
@@ -126,27 +114,12 @@ pub async fn update_label(
         )
     })?;
 
-    // Validate name if provided
-    if let Some(ref name) = payload.name {
-        if name.trim().is_empty() {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new("Label name cannot be empty")),
-            ));
-        }
-    }
-
-    // Validate color if provided
-    if let Some(ref color) = payload.color {
-        if !color.starts_with('#') || (color.len() != 4 && color.len() != 7) {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new(
-                    "Color must be a valid hex color (e.g., #FF5733 or #F57)",
-                )),
-            ));
-        }
-    }
+    validate_label_fields(
+        &state.config.submission,
+        payload.name.as_deref(),
+        payload.description.as_deref(),
+        payload.color.as_deref(),
+    )?;
 
     // Update the label
     let name = payload
@@ -272,7 +245,10 @@ pub async fn get_talk_labels(
     })?;
 
     // Check permission
-    if speaker_id != user.id && !user.is_organizer {
+    if speaker_id != user.id
+        && !user.is_organizer
+        && !is_talk_speaker(&state.db, talk_id, user.id).await?
+    {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -337,7 +313,10 @@ pub async fn add_labels_to_talk(
     })?;
 
     // Check permission
-    if speaker_id != user.id && !user.is_organizer {
+    if speaker_id != user.id
+        && !user.is_organizer
+        && !is_talk_speaker(&state.db, talk_id, user.id).await?
+    {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -431,7 +410,10 @@ pub async fn remove_label_from_talk(
     })?;
 
     // Check permission
-    if speaker_id != user.id && !user.is_organizer {
+    if speaker_id != user.id
+        && !user.is_organizer
+        && !is_talk_speaker(&state.db, talk_id, user.id).await?
+    {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
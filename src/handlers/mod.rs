@@ -1,16 +1,35 @@
 pub mod ai_tagging;
 pub mod auth;
+pub mod auto_schedule;
+pub mod blind_review;
+pub mod bulk_decisions;
 pub mod bulk_email;
 pub mod conferences;
 pub mod config;
+pub mod custom_fields;
 pub mod dashboard;
 pub mod email_templates;
 pub mod export;
+pub mod frab;
+pub mod ical;
 pub mod labels;
+pub mod pairwise_comparisons;
 pub mod ratings;
+pub mod review_assignments;
+pub mod review_conflicts;
+pub mod review_phases;
+pub mod rubrics;
+pub mod schedule_conflicts;
 pub mod schedule_slots;
+pub mod speaker_availability;
+pub mod speaker_feedback;
+pub mod submission_window;
+pub mod talk_comments;
+pub mod talk_revisions;
+pub mod talk_speakers;
 pub mod talks;
 pub mod tracks;
+pub mod waitlist;
 
 pub use ai_tagging::{auto_tag_with_claude, create_ai_labels};
 pub use auth::{
@@ -18,31 +37,71 @@ pub use auth::{
     github_callback, google_authorize, google_callback, linkedin_authorize, linkedin_callback,
     login, register,
 };
+pub use auto_schedule::{commit_auto_schedule, propose_auto_schedule};
+pub use blind_review::{list_identity_reveals, reveal_talk_speakers};
+pub use bulk_decisions::{commit_bulk_decision, preview_bulk_decision};
 pub use bulk_email::send_bulk_email;
 pub use conferences::{
     create_conference, delete_conference, get_active_conference, get_conference, list_conferences,
     update_conference,
 };
-pub use config::get_configuration;
+pub use config::{get_configuration, get_submission_options};
+pub use custom_fields::{
+    create_custom_field, delete_custom_field, list_custom_fields, update_custom_field,
+};
 pub use dashboard::get_dashboard_stats;
 pub use email_templates::{
     create_email_template, delete_email_template, get_email_template, list_email_templates,
     update_email_template,
 };
 pub use export::export_talks;
+pub use frab::{export_frab_json, export_frab_xml};
+pub use ical::{get_schedule_calendar, get_speaker_calendar, get_track_calendar};
 pub use labels::{
     add_labels_to_talk, create_label, delete_label, get_talk_labels, list_labels,
     remove_label_from_talk, update_label,
 };
+pub use pairwise_comparisons::{get_next_comparison, record_comparison};
 pub use ratings::{
     create_or_update_rating, delete_rating, get_my_rating, get_ratings_statistics, get_talk_ratings,
 };
+pub use review_assignments::{
+    assign_reviewers, get_my_expertise, get_my_review_queue, get_review_completion_report,
+    set_my_expertise, unassign_reviewer,
+};
+pub use review_conflicts::{declare_conflict, list_my_conflicts, withdraw_conflict};
+pub use rubrics::{
+    create_rubric_criterion, delete_rubric_criterion, list_rubric_criteria, update_rubric_criterion,
+};
+pub use schedule_conflicts::get_schedule_conflicts;
 pub use schedule_slots::{
     assign_talk_to_slot, create_schedule_slot, delete_schedule_slot, get_public_schedule,
     get_schedule_slot, list_schedule_slots, unassign_talk_from_slot, update_schedule_slot,
 };
+pub use speaker_availability::{
+    get_my_availability, list_speaker_availability, set_my_availability,
+};
+pub use speaker_feedback::{
+    approve_talk_feedback, create_talk_feedback, delete_talk_feedback, list_talk_feedback,
+    unapprove_talk_feedback, update_talk_feedback,
+};
+pub use submission_window::{
+    grant_submission_extension, list_submission_extensions, revoke_submission_extension,
+};
+pub use talk_comments::{
+    create_talk_comment, delete_talk_comment, list_talk_comments, update_talk_comment,
+};
+pub use talk_revisions::{get_talk_revision_diff, list_talk_revisions};
+pub use talk_speakers::{
+    accept_speaker_invitation, decline_speaker_invitation, get_speaker_invitation,
+    invite_co_speaker, list_talk_speakers, remove_co_speaker,
+};
 pub use talks::{
     change_talk_state, create_talk, delete_talk, get_my_talks, get_talk, list_all_talks,
-    respond_to_talk, update_talk, upload_slides,
+    respond_to_talk, submit_talk, update_talk, upload_slides, withdraw_talk,
+};
+pub use tracks::{
+    create_track, delete_track, get_track, get_track_labels, list_tracks, set_track_labels,
+    update_track,
 };
-pub use tracks::{create_track, delete_track, get_track, list_tracks, update_track};
+pub use waitlist::{list_waitlist, promote_talk, reorder_waitlist};
@@ -0,0 +1,198 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::{review_conflicts::check_review_access, review_phases::ensure_ratings_open},
+    models::{
+        auth::ErrorResponse, next_pair, pair_key, ComparisonTalk, NextComparison,
+        PairwiseComparison, RecordComparisonRequest, Talk, User,
+    },
+};
+
+/// Helper function to fetch the `(winner, loser)` comparisons that count
+/// towards the pairwise ranking
+pub(crate) async fn fetch_comparisons(
+    db: &sqlx::PgPool,
+    conference_id: Option<Uuid>,
+) -> Result<Vec<(Uuid, Uuid)>, sqlx::Error> {
+    sqlx::query_as::<_, (Uuid, Uuid)>(
+        r#"
+        SELECT p.winner_id, p.loser_id
+        FROM unconflicted_comparisons p
+        JOIN talks t ON p.winner_id = t.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
+        ORDER BY p.created_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Pick the next two submitted talks for the current reviewer to compare
+/// (organizer only). Talks they speak on or are conflicted with are skipped.
+pub async fn get_next_comparison(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<NextComparison>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error choosing next comparison: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch the next comparison")),
+        )
+    };
+
+    let talks = sqlx::query_as::<_, Talk>(
+        r#"
+        SELECT t.* FROM talks t
+        WHERE t.conference_id = $1
+          AND t.state = 'submitted'
+          AND t.speaker_id <> $2
+          AND NOT EXISTS(
+              SELECT 1 FROM talk_speakers ts
+              WHERE ts.talk_id = t.id AND ts.user_id = $2 AND ts.status = 'accepted'
+          )
+          AND NOT EXISTS(
+              SELECT 1 FROM conflicted_reviews c
+              WHERE c.talk_id = t.id AND c.reviewer_id = $2
+          )
+        ORDER BY t.submitted_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .bind(user.id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    let comparisons = fetch_comparisons(&state.db, Some(conference_id))
+        .await
+        .map_err(db_error)?;
+    let seen: HashSet<(Uuid, Uuid)> = sqlx::query_as::<_, (Uuid, Uuid)>(
+        r#"
+        SELECT p.winner_id, p.loser_id
+        FROM pairwise_comparisons p
+        JOIN talks t ON p.winner_id = t.id
+        WHERE p.reviewer_id = $1 AND t.conference_id = $2
+        "#,
+    )
+    .bind(user.id)
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?
+    .into_iter()
+    .map(|(winner, loser)| pair_key(winner, loser))
+    .collect();
+
+    let ids: Vec<Uuid> = talks.iter().map(|t| t.id).collect();
+    let compared = ids
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| ids[i + 1..].iter().map(move |b| pair_key(*a, *b)))
+        .filter(|key| seen.contains(key))
+        .count();
+    let total = ids.len() * ids.len().saturating_sub(1) / 2;
+
+    let pair = next_pair(&ids, &comparisons, &seen);
+    let mut talks: HashMap<Uuid, Talk> = talks.into_iter().map(|t| (t.id, t)).collect();
+    let pair = pair.and_then(|(first, second)| {
+        Some([
+            ComparisonTalk::from(talks.remove(&first)?),
+            ComparisonTalk::from(talks.remove(&second)?),
+        ])
+    });
+
+    Ok(Json(NextComparison {
+        pair,
+        compared,
+        remaining: total - compared,
+    }))
+}
+
+/// Record which of two talks the current reviewer prefers (organizer only).
+/// Comparing the same pair again replaces the earlier verdict.
+pub async fn record_comparison(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<RecordComparisonRequest>,
+) -> Result<(StatusCode, Json<PairwiseComparison>), (StatusCode, Json<ErrorResponse>)> {
+    if payload.winner_id == payload.loser_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("A talk can't be compared with itself")),
+        ));
+    }
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error recording comparison: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to record comparison")),
+        )
+    };
+
+    // Only the talks get_next_comparison draws from can be compared
+    let (found, submitted) = sqlx::query_as::<_, (i64, i64)>(
+        r#"
+        SELECT COUNT(*), COUNT(*) FILTER (WHERE state = 'submitted')
+        FROM talks
+        WHERE id IN ($1, $2) AND conference_id = $3 AND state <> 'draft'
+        "#,
+    )
+    .bind(payload.winner_id)
+    .bind(payload.loser_id)
+    .bind(conference_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(db_error)?;
+    if found != 2 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found in this conference")),
+        ));
+    }
+    if submitted != 2 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Only talks still awaiting a decision can be compared",
+            )),
+        ));
+    }
+
+    ensure_ratings_open(&state, conference_id).await?;
+    check_review_access(&state.db, payload.winner_id, user.id).await?;
+    check_review_access(&state.db, payload.loser_id, user.id).await?;
+
+    let comparison = sqlx::query_as::<_, PairwiseComparison>(
+        r#"
+        INSERT INTO pairwise_comparisons (reviewer_id, winner_id, loser_id)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (reviewer_id, LEAST(winner_id, loser_id), GREATEST(winner_id, loser_id))
+        DO UPDATE SET
+            winner_id = EXCLUDED.winner_id,
+            loser_id = EXCLUDED.loser_id,
+            created_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(user.id)
+    .bind(payload.winner_id)
+    .bind(payload.loser_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    Ok((StatusCode::CREATED, Json(comparison)))
+}
@@ -1,18 +1,104 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     Extension, Json,
 };
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::{
     api::AppState,
+    handlers::{
+        blind_review::hidden_speaker_talks,
+        pairwise_comparisons::fetch_comparisons,
+        review_conflicts::has_conflict,
+        review_phases::ensure_ratings_open,
+        rubrics::{fetch_rating_scores, fetch_rubric},
+    },
     models::{
-        auth::ErrorResponse, CreateRatingRequest, Rating, RatingDistribution, RatingResponse,
-        RatingsStatisticsResponse, TalkRatingStats, User,
+        auth::ErrorResponse, bradley_terry, criterion_averages, normalize_scores, rank_talks,
+        trimmed_mean, validate_scores, weighted_score, CreateRatingRequest, CriterionScore, Rating,
+        RatingDistribution, RatingResponse, RatingScore, RatingsStatisticsQuery,
+        RatingsStatisticsResponse, ReviewScore, RubricCriterion, TalkRatingStats, User,
+        ANONYMOUS_SPEAKER,
     },
 };
 
+/// Helper function to average a list of scores
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Helper function to find the conference of a talk that can be rated
+async fn fetch_rated_talk_conference(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+) -> Result<Uuid, (StatusCode, Json<ErrorResponse>)> {
+    sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT conference_id FROM talks WHERE id = $1 AND state <> 'draft'
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking talk existence: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to verify talk")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found")),
+        )
+    })
+}
+
+/// Fetch every counted rating of non-draft talks, optionally scoped to one
+/// conference, for score normalization
+pub(crate) async fn fetch_review_scores(
+    db: &sqlx::PgPool,
+    conference_id: Option<Uuid>,
+) -> Result<Vec<ReviewScore>, sqlx::Error> {
+    sqlx::query_as::<_, ReviewScore>(
+        r#"
+        SELECT r.talk_id, r.organizer_id AS reviewer_id, u.full_name AS reviewer_name, r.rating
+        FROM unconflicted_ratings r
+        JOIN talks t ON r.talk_id = t.id
+        JOIN users u ON r.organizer_id = u.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Whether the talk has a revision newer than the given time
+async fn talk_changed_since(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+    since: DateTime<Utc>,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM talk_revisions
+            WHERE talk_id = $1 AND created_at > $2
+        )
+        "#,
+    )
+    .bind(talk_id)
+    .bind(since)
+    .fetch_one(db)
+    .await
+}
+
 /// Create or update a rating for a talk (organizer only)
 /// This is an upsert operation - if the organizer has already rated this talk, it updates the existing rating
 pub async fn create_or_update_rating(
@@ -21,37 +107,67 @@ pub async fn create_or_update_rating(
     Path(talk_id): Path<Uuid>,
     Json(payload): Json<CreateRatingRequest>,
 ) -> Result<Json<RatingResponse>, (StatusCode, Json<ErrorResponse>)> {
-    // Validate rating is within range (1-5)
-    if payload.rating < 1 || payload.rating > 5 {
+    let conference_id = fetch_rated_talk_conference(&state.db, talk_id).await?;
+    ensure_ratings_open(&state, conference_id).await?;
+
+    let conflicted = has_conflict(&state.db, user.id, talk_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking review conflicts: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to save rating")),
+            )
+        })?;
+    if conflicted {
         return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Rating must be between 1 and 5")),
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You have declared a conflict of interest with this talk",
+            )),
         ));
     }
 
-    // Check if the talk exists
-    let talk_exists = sqlx::query_scalar::<_, bool>(
-        r#"
-        SELECT EXISTS(SELECT 1 FROM talks WHERE id = $1)
-        "#,
-    )
-    .bind(talk_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error checking talk existence: {}", e);
+    let rubric = fetch_rubric(&state.db, conference_id).await.map_err(|e| {
+        tracing::error!("Database error fetching rubric: {}", e);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to verify talk")),
+            Json(ErrorResponse::new("Failed to fetch rubric")),
         )
     })?;
 
-    if !talk_exists {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new("Talk not found")),
-        ));
-    }
+    // With a rubric the overall rating is derived from the criterion scores
+    let overall_rating = if rubric.is_empty() {
+        if !payload.scores.is_empty() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "This conference has no review rubric; send a single rating",
+                )),
+            ));
+        }
+        match payload.rating {
+            Some(rating) if (1..=5).contains(&rating) => rating,
+            _ => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ErrorResponse::new("Rating must be between 1 and 5")),
+                ))
+            }
+        }
+    } else {
+        validate_scores(&rubric, &payload.scores)
+            .map_err(|message| (StatusCode::BAD_REQUEST, Json(ErrorResponse::new(message))))?
+    };
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error creating/updating rating: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to save rating")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
 
     // Upsert the rating (insert or update if already exists)
     let rating = sqlx::query_as::<_, Rating>(
@@ -68,20 +184,38 @@ pub async fn create_or_update_rating(
     )
     .bind(talk_id)
     .bind(user.id)
-    .bind(payload.rating)
+    .bind(overall_rating)
     .bind(payload.notes.as_ref().map(|s| s.trim()))
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| {
-        tracing::error!("Database error creating/updating rating: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to save rating")),
+    .map_err(db_error)?;
+
+    // Replace the criterion scores
+    sqlx::query("DELETE FROM rating_scores WHERE rating_id = $1")
+        .bind(rating.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    for score in &payload.scores {
+        sqlx::query(
+            r#"
+            INSERT INTO rating_scores (rating_id, criterion_id, score)
+            VALUES ($1, $2, $3)
+            "#,
         )
-    })?;
+        .bind(rating.id)
+        .bind(score.criterion_id)
+        .bind(score.score)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+
+    tx.commit().await.map_err(db_error)?;
 
     let response = RatingResponse::from(rating)
-        .with_organizer_info(user.full_name.clone(), user.email.clone());
+        .with_organizer_info(user.full_name.clone(), user.email.clone())
+        .with_scores(&rubric, payload.scores);
 
     Ok(Json(response))
 }
@@ -91,29 +225,7 @@ pub async fn get_talk_ratings(
     State(state): State<AppState>,
     Path(talk_id): Path<Uuid>,
 ) -> Result<Json<Vec<RatingResponse>>, (StatusCode, Json<ErrorResponse>)> {
-    // Check if the talk exists
-    let talk_exists = sqlx::query_scalar::<_, bool>(
-        r#"
-        SELECT EXISTS(SELECT 1 FROM talks WHERE id = $1)
-        "#,
-    )
-    .bind(talk_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error checking talk existence: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to verify talk")),
-        )
-    })?;
-
-    if !talk_exists {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new("Talk not found")),
-        ));
-    }
+    let conference_id = fetch_rated_talk_conference(&state.db, talk_id).await?;
 
     // Fetch all ratings for the talk with organizer information in a single query (optimized)
     #[derive(sqlx::FromRow)]
@@ -127,6 +239,7 @@ pub async fn get_talk_ratings(
         updated_at: chrono::DateTime<chrono::Utc>,
         organizer_name: String,
         organizer_email: String,
+        talk_changed_since_rating: bool,
     }
 
     let ratings_with_organizers = sqlx::query_as::<_, RatingWithOrganizer>(
@@ -140,8 +253,12 @@ pub async fn get_talk_ratings(
             r.created_at,
             r.updated_at,
             u.full_name as organizer_name,
-            u.email as organizer_email
-        FROM ratings r
+            u.email as organizer_email,
+            EXISTS(
+                SELECT 1 FROM talk_revisions tr
+                WHERE tr.talk_id = r.talk_id AND tr.created_at > r.updated_at
+            ) as talk_changed_since_rating
+        FROM unconflicted_ratings r
         JOIN users u ON r.organizer_id = u.id
         WHERE r.talk_id = $1
         ORDER BY r.created_at DESC
@@ -158,19 +275,41 @@ pub async fn get_talk_ratings(
         )
     })?;
 
+    let scores_error = |e: sqlx::Error| {
+        tracing::error!("Database error fetching rating scores: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch ratings")),
+        )
+    };
+    let rubric = fetch_rubric(&state.db, conference_id)
+        .await
+        .map_err(scores_error)?;
+    let rating_ids: Vec<Uuid> = ratings_with_organizers.iter().map(|r| r.id).collect();
+    let mut scores = fetch_rating_scores(&state.db, &rating_ids)
+        .await
+        .map_err(scores_error)?;
+
     // Convert to response format
     let responses: Vec<RatingResponse> = ratings_with_organizers
         .into_iter()
-        .map(|r| RatingResponse {
-            id: r.id,
-            talk_id: r.talk_id,
-            organizer_id: r.organizer_id,
-            organizer_name: r.organizer_name,
-            organizer_email: r.organizer_email,
-            rating: r.rating,
-            notes: r.notes,
-            created_at: r.created_at,
-            updated_at: r.updated_at,
+        .map(|r| {
+            let rating_scores = scores.remove(&r.id).unwrap_or_default();
+            RatingResponse {
+                id: r.id,
+                talk_id: r.talk_id,
+                organizer_id: r.organizer_id,
+                organizer_name: r.organizer_name,
+                organizer_email: r.organizer_email,
+                rating: r.rating,
+                notes: r.notes,
+                created_at: r.created_at,
+                updated_at: r.updated_at,
+                talk_changed_since_rating: r.talk_changed_since_rating,
+                scores: Vec::new(),
+                weighted_score: None,
+            }
+            .with_scores(&rubric, rating_scores)
         })
         .collect();
 
@@ -183,6 +322,9 @@ pub async fn delete_rating(
     Extension(user): Extension<User>,
     Path(talk_id): Path<Uuid>,
 ) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let conference_id = fetch_rated_talk_conference(&state.db, talk_id).await?;
+    ensure_ratings_open(&state, conference_id).await?;
+
     // Delete the rating
     let result = sqlx::query(
         r#"
@@ -240,8 +382,29 @@ pub async fn get_my_rating(
 
     match rating {
         Some(rating) => {
+            let db_error = |e: sqlx::Error| {
+                tracing::error!("Database error fetching rating details: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("Failed to fetch rating")),
+                )
+            };
+            let talk_changed = talk_changed_since(&state.db, talk_id, rating.updated_at)
+                .await
+                .map_err(db_error)?;
+            let conference_id = fetch_rated_talk_conference(&state.db, talk_id).await?;
+            let rubric = fetch_rubric(&state.db, conference_id)
+                .await
+                .map_err(db_error)?;
+            let scores = fetch_rating_scores(&state.db, &[rating.id])
+                .await
+                .map_err(db_error)?
+                .remove(&rating.id)
+                .unwrap_or_default();
             let response = RatingResponse::from(rating)
-                .with_organizer_info(user.full_name.clone(), user.email.clone());
+                .with_organizer_info(user.full_name.clone(), user.email.clone())
+                .with_talk_changed(talk_changed)
+                .with_scores(&rubric, scores);
             Ok(Json(response))
         }
         None => Err((
@@ -251,16 +414,23 @@ pub async fn get_my_rating(
     }
 }
 
-/// Get aggregated ratings statistics for all talks (organizer only)
+/// Get aggregated ratings statistics for all talks (organizer only),
+/// optionally scoped to one conference. Ratings are also normalized per
+/// reviewer so harsh and lenient reviewers can be compared.
 pub async fn get_ratings_statistics(
     State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<RatingsStatisticsQuery>,
 ) -> Result<Json<RatingsStatisticsResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Get total talks count
     let total_talks = sqlx::query_scalar::<_, i64>(
         r#"
         SELECT COUNT(*) FROM talks
+        WHERE state <> 'draft'
+          AND ($1::uuid IS NULL OR conference_id = $1)
         "#,
     )
+    .bind(query.conference_id)
     .fetch_one(&state.db)
     .await
     .map_err(|e| {
@@ -274,9 +444,12 @@ pub async fn get_ratings_statistics(
     // Get total ratings count
     let total_ratings = sqlx::query_scalar::<_, i64>(
         r#"
-        SELECT COUNT(*) FROM ratings
+        SELECT COUNT(*) FROM unconflicted_ratings r
+        JOIN talks t ON r.talk_id = t.id
+        WHERE ($1::uuid IS NULL OR t.conference_id = $1)
         "#,
     )
+    .bind(query.conference_id)
     .fetch_one(&state.db)
     .await
     .map_err(|e| {
@@ -296,12 +469,15 @@ pub async fn get_ratings_statistics(
 
     let distribution_rows = sqlx::query_as::<_, DistributionRow>(
         r#"
-        SELECT rating, COUNT(*) as count
-        FROM ratings
-        GROUP BY rating
-        ORDER BY rating
+        SELECT r.rating, COUNT(*) as count
+        FROM unconflicted_ratings r
+        JOIN talks t ON r.talk_id = t.id
+        WHERE ($1::uuid IS NULL OR t.conference_id = $1)
+        GROUP BY r.rating
+        ORDER BY r.rating
         "#,
     )
+    .bind(query.conference_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| {
@@ -335,6 +511,7 @@ pub async fn get_ratings_statistics(
     #[derive(sqlx::FromRow)]
     struct TalkStatsRow {
         talk_id: Uuid,
+        conference_id: Uuid,
         talk_title: String,
         speaker_name: String,
         state: String,
@@ -347,6 +524,7 @@ pub async fn get_ratings_statistics(
         r#"
         SELECT
             t.id as talk_id,
+            t.conference_id,
             t.title as talk_title,
             u.full_name as speaker_name,
             t.state::text as state,
@@ -359,11 +537,14 @@ pub async fn get_ratings_statistics(
             )::text as ratings_json
         FROM talks t
         JOIN users u ON t.speaker_id = u.id
-        LEFT JOIN ratings r ON t.id = r.talk_id
-        GROUP BY t.id, t.title, u.full_name, t.state
+        LEFT JOIN unconflicted_ratings r ON t.id = r.talk_id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
+        GROUP BY t.id, t.conference_id, t.title, u.full_name, t.state
         ORDER BY rating_count DESC, rating_sum DESC NULLS LAST, t.title
         "#,
     )
+    .bind(query.conference_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| {
@@ -374,9 +555,75 @@ pub async fn get_ratings_statistics(
         )
     })?;
 
+    // Rubric scores, grouped by talk and then by rating
+    let details_error = |e: sqlx::Error| {
+        tracing::error!("Database error fetching statistics details: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch statistics")),
+        )
+    };
+    let criteria = sqlx::query_as::<_, RubricCriterion>(
+        r#"
+        SELECT * FROM rubric_criteria
+        WHERE ($1::uuid IS NULL OR conference_id = $1)
+        ORDER BY position ASC, created_at ASC
+        "#,
+    )
+    .bind(query.conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(details_error)?;
+
+    #[derive(sqlx::FromRow)]
+    struct TalkScoreRow {
+        talk_id: Uuid,
+        #[sqlx(flatten)]
+        score: RatingScore,
+    }
+
+    let score_rows = sqlx::query_as::<_, TalkScoreRow>(
+        r#"
+        SELECT r.talk_id, rs.rating_id, rs.criterion_id, rs.score
+        FROM rating_scores rs
+        JOIN unconflicted_ratings r ON rs.rating_id = r.id
+        JOIN talks t ON r.talk_id = t.id
+        WHERE t.state <> 'draft'
+          AND ($1::uuid IS NULL OR t.conference_id = $1)
+        "#,
+    )
+    .bind(query.conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(details_error)?;
+
+    let mut talk_scores: HashMap<Uuid, HashMap<Uuid, Vec<CriterionScore>>> = HashMap::new();
+    for row in score_rows {
+        talk_scores
+            .entry(row.talk_id)
+            .or_default()
+            .entry(row.score.rating_id)
+            .or_default()
+            .push(CriterionScore::from(row.score));
+    }
+
+    let hidden = hidden_speaker_talks(&state.db, user.id, query.conference_id)
+        .await
+        .map_err(details_error)?;
+    let normalization = normalize_scores(
+        &fetch_review_scores(&state.db, query.conference_id)
+            .await
+            .map_err(details_error)?,
+    );
+    let comparisons = fetch_comparisons(&state.db, query.conference_id)
+        .await
+        .map_err(details_error)?;
+    let pairwise = bradley_terry(&comparisons);
+
     let mut talk_stats = Vec::new();
     let mut talks_with_ratings = 0i64;
     let mut total_rating_sum = 0i64;
+    let mut all_weighted_scores = Vec::new();
 
     for row in talk_stats_rows {
         let average_rating = if row.rating_count > 0 {
@@ -393,17 +640,45 @@ pub async fn get_ratings_statistics(
             .and_then(|json| serde_json::from_str(&json).ok())
             .unwrap_or_default();
 
+        let rubric: Vec<RubricCriterion> = criteria
+            .iter()
+            .filter(|c| c.conference_id == row.conference_id)
+            .cloned()
+            .collect();
+        let rating_scores: Vec<Vec<CriterionScore>> = talk_scores
+            .remove(&row.talk_id)
+            .map(|by_rating| by_rating.into_values().collect())
+            .unwrap_or_default();
+        let weighted_scores: Vec<f64> = rating_scores
+            .iter()
+            .filter_map(|scores| weighted_score(&rubric, scores))
+            .collect();
+        let weighted_average = mean(&weighted_scores);
+        all_weighted_scores.extend(weighted_scores);
+
         talk_stats.push(TalkRatingStats {
             talk_id: row.talk_id,
             talk_title: row.talk_title,
-            speaker_name: row.speaker_name,
+            speaker_name: if hidden.contains(&row.talk_id) {
+                ANONYMOUS_SPEAKER.to_string()
+            } else {
+                row.speaker_name
+            },
             state: row.state,
             average_rating,
             rating_count: row.rating_count,
+            normalized_score: normalization.talk_scores.get(&row.talk_id).copied(),
+            trimmed_average: trimmed_mean(&ratings),
             ratings,
+            weighted_average,
+            criteria_averages: criterion_averages(&rubric, &rating_scores),
+            pairwise_score: pairwise.get(&row.talk_id).map(|p| p.rating),
+            comparison_count: pairwise.get(&row.talk_id).map_or(0, |p| p.comparisons),
         });
     }
 
+    rank_talks(&mut talk_stats, query.sort);
+
     let overall_average_rating = if total_ratings > 0 {
         Some(total_rating_sum as f64 / total_ratings as f64)
     } else {
@@ -418,7 +693,10 @@ pub async fn get_ratings_statistics(
         talks_with_ratings,
         talks_without_ratings,
         overall_average_rating,
+        overall_weighted_average: mean(&all_weighted_scores),
         rating_distribution,
+        total_comparisons: comparisons.len() as i64,
         talk_stats,
+        reviewer_bias: normalization.reviewers,
     }))
 }
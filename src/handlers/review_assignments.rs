@@ -0,0 +1,456 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, plan_review_assignments, AssignReviewersRequest,
+        AssignReviewersResponse, AssignableTalk, ConferenceFilterQuery, Label, LabelResponse,
+        ReviewCompletionReport, ReviewQueueItem, ReviewerLoad, ReviewerProgress,
+        SetExpertiseRequest, TalkReviewProgress, User, ValidationErrors,
+    },
+};
+
+const MAX_REVIEWS_PER_TALK: i32 = 20;
+
+/// Group `(key, value)` rows into a map of lists
+fn group_pairs(rows: Vec<(Uuid, Uuid)>) -> HashMap<Uuid, Vec<Uuid>> {
+    let mut grouped: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (key, value) in rows {
+        grouped.entry(key).or_default().push(value);
+    }
+    grouped
+}
+
+/// Assign reviewers to a conference's submitted talks (organizer only).
+/// Existing assignments are kept and count towards each reviewer's load.
+pub async fn assign_reviewers(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<AssignReviewersRequest>,
+) -> Result<Json<AssignReviewersResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error assigning reviewers: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to assign reviewers")),
+        )
+    };
+
+    if !(1..=MAX_REVIEWS_PER_TALK).contains(&payload.reviews_per_talk) {
+        let mut errors = ValidationErrors::new();
+        errors.add(
+            "reviews_per_talk",
+            "invalid_range",
+            format!(
+                "Reviews per talk must be between 1 and {}",
+                MAX_REVIEWS_PER_TALK
+            ),
+        );
+        return Err(errors.into());
+    }
+
+    let conference_exists =
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM conferences WHERE id = $1)")
+            .bind(conference_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(db_error)?;
+    if !conference_exists {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Conference not found")),
+        ));
+    }
+
+    let reviewer_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        SELECT id FROM users
+        WHERE is_organizer = true AND ($1::uuid[] IS NULL OR id = ANY($1))
+        "#,
+    )
+    .bind(payload.reviewer_ids.as_deref())
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    if let Some(requested) = &payload.reviewer_ids {
+        if requested.iter().any(|id| !reviewer_ids.contains(id)) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("Reviewers must be organizers")),
+            ));
+        }
+    }
+
+    let talk_ids = sqlx::query_scalar::<_, Uuid>(
+        "SELECT id FROM talks WHERE conference_id = $1 AND state = 'submitted'",
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    let mut labels = group_pairs(
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            "SELECT talk_id, label_id FROM talk_labels WHERE talk_id = ANY($1)",
+        )
+        .bind(&talk_ids)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?,
+    );
+    let mut assigned = group_pairs(
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            "SELECT talk_id, reviewer_id FROM review_assignments WHERE talk_id = ANY($1)",
+        )
+        .bind(&talk_ids)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?,
+    );
+    // Speakers never review their own talks, nor do conflicted reviewers
+    let mut excluded = group_pairs(
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            r#"
+            SELECT id, speaker_id FROM talks WHERE id = ANY($1)
+            UNION
+            SELECT talk_id, user_id FROM talk_speakers
+            WHERE talk_id = ANY($1) AND user_id IS NOT NULL
+            UNION
+            SELECT talk_id, reviewer_id FROM conflicted_reviews WHERE talk_id = ANY($1)
+            "#,
+        )
+        .bind(&talk_ids)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?,
+    );
+
+    let talks: Vec<AssignableTalk> = talk_ids
+        .iter()
+        .map(|id| AssignableTalk {
+            talk_id: *id,
+            label_ids: labels.remove(id).unwrap_or_default(),
+            assigned: assigned.remove(id).unwrap_or_default(),
+            excluded: excluded.remove(id).unwrap_or_default(),
+        })
+        .collect();
+
+    let loads: HashMap<Uuid, i64> = sqlx::query_as::<_, (Uuid, i64)>(
+        r#"
+        SELECT ra.reviewer_id, COUNT(*)
+        FROM review_assignments ra
+        JOIN talks t ON ra.talk_id = t.id
+        WHERE t.conference_id = $1
+        GROUP BY ra.reviewer_id
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?
+    .into_iter()
+    .collect();
+    let mut expertise = group_pairs(
+        sqlx::query_as::<_, (Uuid, Uuid)>(
+            "SELECT reviewer_id, label_id FROM reviewer_expertise WHERE reviewer_id = ANY($1)",
+        )
+        .bind(&reviewer_ids)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?,
+    );
+
+    let reviewers: Vec<ReviewerLoad> = reviewer_ids
+        .iter()
+        .map(|id| ReviewerLoad {
+            reviewer_id: *id,
+            assigned: loads.get(id).copied().unwrap_or(0) as usize,
+            expertise: expertise.remove(id).unwrap_or_default(),
+        })
+        .collect();
+
+    let reviews_per_talk = payload.reviews_per_talk as usize;
+    let planned = plan_review_assignments(
+        &talks,
+        &reviewers,
+        reviews_per_talk,
+        payload.match_expertise,
+    );
+
+    // Count only the rows actually inserted; a concurrent run may have
+    // assigned some of the planned pairs already
+    let mut added: HashMap<Uuid, usize> = HashMap::new();
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+    for (talk_id, reviewer_id) in &planned {
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO review_assignments (talk_id, reviewer_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(talk_id)
+        .bind(reviewer_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?
+        .rows_affected();
+        *added.entry(*talk_id).or_default() += inserted as usize;
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    let understaffed_talk_ids = talks
+        .iter()
+        .filter(|talk| {
+            let added = added.get(&talk.talk_id).copied().unwrap_or(0);
+            talk.assigned.len() + added < reviews_per_talk
+        })
+        .map(|talk| talk.talk_id)
+        .collect();
+
+    Ok(Json(AssignReviewersResponse {
+        new_assignments: added.values().sum(),
+        understaffed_talk_ids,
+    }))
+}
+
+/// Remove a reviewer from a talk (organizer only). Any rating they already
+/// gave is kept.
+pub async fn unassign_reviewer(
+    State(state): State<AppState>,
+    Path((talk_id, reviewer_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let result =
+        sqlx::query("DELETE FROM review_assignments WHERE talk_id = $1 AND reviewer_id = $2")
+            .bind(talk_id)
+            .bind(reviewer_id)
+            .execute(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error removing review assignment: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("Failed to remove review assignment")),
+                )
+            })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Review assignment not found")),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get how far reviewers are through their assignments for a conference
+/// (organizer only). An assignment is complete once the reviewer has rated
+/// the talk.
+pub async fn get_review_completion_report(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<ReviewCompletionReport>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error fetching review completion report: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new(
+                "Failed to fetch review completion report",
+            )),
+        )
+    };
+
+    let reviewers = sqlx::query_as::<_, ReviewerProgress>(
+        r#"
+        SELECT
+            u.id AS reviewer_id,
+            u.full_name AS reviewer_name,
+            COUNT(*) AS assigned,
+            COUNT(r.id) AS completed
+        FROM review_assignments ra
+        JOIN talks t ON ra.talk_id = t.id
+        JOIN users u ON ra.reviewer_id = u.id
+        LEFT JOIN ratings r ON r.talk_id = ra.talk_id AND r.organizer_id = ra.reviewer_id
+        WHERE t.conference_id = $1
+          AND NOT EXISTS (
+              SELECT 1 FROM conflicted_reviews c
+              WHERE c.reviewer_id = ra.reviewer_id AND c.talk_id = ra.talk_id
+          )
+        GROUP BY u.id, u.full_name
+        ORDER BY u.full_name ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    let talks = sqlx::query_as::<_, TalkReviewProgress>(
+        r#"
+        SELECT
+            t.id AS talk_id,
+            t.title,
+            COUNT(ra.reviewer_id) AS assigned,
+            COUNT(r.id) AS completed,
+            (SELECT COUNT(*) FROM unconflicted_ratings WHERE talk_id = t.id) AS total_ratings
+        FROM talks t
+        LEFT JOIN review_assignments ra
+            ON ra.talk_id = t.id
+            AND NOT EXISTS (
+                SELECT 1 FROM conflicted_reviews c
+                WHERE c.reviewer_id = ra.reviewer_id AND c.talk_id = ra.talk_id
+            )
+        LEFT JOIN ratings r ON r.talk_id = ra.talk_id AND r.organizer_id = ra.reviewer_id
+        WHERE t.conference_id = $1
+          AND (t.state = 'submitted' OR ra.talk_id IS NOT NULL)
+        GROUP BY t.id, t.title
+        ORDER BY COUNT(r.id)::float / GREATEST(COUNT(ra.reviewer_id), 1) ASC, t.title ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(db_error)?;
+
+    Ok(Json(ReviewCompletionReport {
+        conference_id,
+        total_assignments: reviewers.iter().map(|r| r.assigned).sum(),
+        completed_assignments: reviewers.iter().map(|r| r.completed).sum(),
+        reviewers,
+        talks,
+    }))
+}
+
+/// List the talks assigned to the current reviewer, unrated ones first
+pub async fn get_my_review_queue(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<ConferenceFilterQuery>,
+) -> Result<Json<Vec<ReviewQueueItem>>, (StatusCode, Json<ErrorResponse>)> {
+    let queue = sqlx::query_as::<_, ReviewQueueItem>(
+        r#"
+        SELECT
+            t.id AS talk_id,
+            t.conference_id,
+            t.title,
+            t.short_summary,
+            t.state,
+            ra.assigned_at,
+            r.rating,
+            r.updated_at AS rated_at
+        FROM review_assignments ra
+        JOIN talks t ON ra.talk_id = t.id
+        LEFT JOIN ratings r ON r.talk_id = ra.talk_id AND r.organizer_id = ra.reviewer_id
+        WHERE ra.reviewer_id = $1
+          AND ($2::uuid IS NULL OR t.conference_id = $2)
+          AND NOT EXISTS (
+              SELECT 1 FROM conflicted_reviews c
+              WHERE c.reviewer_id = ra.reviewer_id AND c.talk_id = ra.talk_id
+          )
+        ORDER BY (r.id IS NOT NULL) ASC, ra.assigned_at ASC, t.title ASC
+        "#,
+    )
+    .bind(user.id)
+    .bind(query.conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching review queue: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch review queue")),
+        )
+    })?;
+
+    Ok(Json(queue))
+}
+
+/// Helper function to fetch the labels a reviewer knows well
+async fn fetch_expertise(
+    db: &sqlx::PgPool,
+    reviewer_id: Uuid,
+) -> Result<Vec<LabelResponse>, sqlx::Error> {
+    let labels = sqlx::query_as::<_, Label>(
+        r#"
+        SELECT l.* FROM labels l
+        JOIN reviewer_expertise re ON re.label_id = l.id
+        WHERE re.reviewer_id = $1
+        ORDER BY l.name ASC
+        "#,
+    )
+    .bind(reviewer_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(labels.into_iter().map(LabelResponse::from).collect())
+}
+
+/// Get the current reviewer's expertise labels
+pub async fn get_my_expertise(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<LabelResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let labels = fetch_expertise(&state.db, user.id).await.map_err(|e| {
+        tracing::error!("Database error fetching reviewer expertise: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch expertise")),
+        )
+    })?;
+
+    Ok(Json(labels))
+}
+
+/// Replace the current reviewer's expertise labels
+pub async fn set_my_expertise(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<SetExpertiseRequest>,
+) -> Result<Json<Vec<LabelResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating reviewer expertise: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("Unknown label")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to update expertise")),
+            ),
+        }
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+    sqlx::query("DELETE FROM reviewer_expertise WHERE reviewer_id = $1")
+        .bind(user.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    sqlx::query(
+        r#"
+        INSERT INTO reviewer_expertise (reviewer_id, label_id)
+        SELECT $1, UNNEST($2::uuid[])
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(user.id)
+    .bind(&payload.label_ids)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
+
+    let labels = fetch_expertise(&state.db, user.id)
+        .await
+        .map_err(db_error)?;
+    Ok(Json(labels))
+}
@@ -0,0 +1,275 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, normalize_email_domain, DeclareConflictRequest, ReviewConflict, User,
+        ValidationErrors,
+    },
+};
+
+/// Whether a reviewer has declared a conflict covering a talk
+pub(crate) async fn has_conflict(
+    db: &sqlx::PgPool,
+    reviewer_id: Uuid,
+    talk_id: Uuid,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM conflicted_reviews
+            WHERE reviewer_id = $1 AND talk_id = $2
+        )
+        "#,
+    )
+    .bind(reviewer_id)
+    .bind(talk_id)
+    .fetch_one(db)
+    .await
+}
+
+/// Make sure an organizer may review a talk: read its internal discussion
+/// and speaker feedback. The talk's own speakers and reviewers conflicted
+/// with it are kept out.
+pub(crate) async fn check_review_access(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let excluded = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT
+            t.speaker_id = $2
+            OR EXISTS(
+                SELECT 1 FROM talk_speakers ts
+                WHERE ts.talk_id = t.id AND ts.user_id = $2 AND ts.status = 'accepted'
+            )
+            OR EXISTS(
+                SELECT 1 FROM conflicted_reviews c
+                WHERE c.talk_id = t.id AND c.reviewer_id = $2
+            )
+        FROM talks t
+        WHERE t.id = $1
+        "#,
+    )
+    .bind(talk_id)
+    .bind(user_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking review access: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to check review access")),
+        )
+    })?;
+
+    match excluded {
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found")),
+        )),
+        Some(true) => Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You are a speaker on or have a conflict with this talk",
+            )),
+        )),
+        Some(false) => Ok(()),
+    }
+}
+
+/// Helper function to fetch a reviewer's conflicts, or just one of them
+async fn fetch_conflicts(
+    db: &sqlx::PgPool,
+    reviewer_id: Uuid,
+    conflict_id: Option<Uuid>,
+) -> Result<Vec<ReviewConflict>, sqlx::Error> {
+    sqlx::query_as::<_, ReviewConflict>(
+        r#"
+        SELECT
+            c.id,
+            c.reviewer_id,
+            c.speaker_id,
+            u.full_name AS speaker_name,
+            c.talk_id,
+            t.title AS talk_title,
+            c.email_domain,
+            c.reason,
+            c.created_at
+        FROM review_conflicts c
+        LEFT JOIN users u ON c.speaker_id = u.id
+        LEFT JOIN talks t ON c.talk_id = t.id
+        WHERE c.reviewer_id = $1
+          AND ($2::uuid IS NULL OR c.id = $2)
+        ORDER BY c.created_at DESC
+        "#,
+    )
+    .bind(reviewer_id)
+    .bind(conflict_id)
+    .fetch_all(db)
+    .await
+}
+
+/// List the current reviewer's declared conflicts of interest
+pub async fn list_my_conflicts(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<ReviewConflict>>, (StatusCode, Json<ErrorResponse>)> {
+    let conflicts = fetch_conflicts(&state.db, user.id, None)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching review conflicts: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch conflicts")),
+            )
+        })?;
+
+    Ok(Json(conflicts))
+}
+
+/// Declare a conflict of interest with a speaker, a talk or an email domain.
+/// The reviewer is taken off any covered talks they were assigned to.
+pub async fn declare_conflict(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<DeclareConflictRequest>,
+) -> Result<(StatusCode, Json<ReviewConflict>), (StatusCode, Json<ErrorResponse>)> {
+    let targets = [
+        payload.speaker_id.is_some(),
+        payload.talk_id.is_some(),
+        payload.email_domain.is_some(),
+    ];
+    if targets.iter().filter(|&&set| set).count() != 1 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Declare a conflict with exactly one of speaker_id, talk_id or email_domain",
+            )),
+        ));
+    }
+
+    let email_domain = match payload.email_domain.as_deref() {
+        Some(domain) => match normalize_email_domain(domain) {
+            Some(domain) => Some(domain),
+            None => {
+                let mut errors = ValidationErrors::new();
+                errors.add(
+                    "email_domain",
+                    "invalid_format",
+                    "Email domain must look like example.com",
+                );
+                return Err(errors.into());
+            }
+        },
+        None => None,
+    };
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error declaring review conflict: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new(
+                    "You have already declared this conflict",
+                )),
+            ),
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Speaker or talk not found")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to declare conflict")),
+            ),
+        }
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    let conflict_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO review_conflicts (reviewer_id, speaker_id, talk_id, email_domain, reason)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+    )
+    .bind(user.id)
+    .bind(payload.speaker_id)
+    .bind(payload.talk_id)
+    .bind(email_domain)
+    .bind(
+        payload
+            .reason
+            .as_ref()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty()),
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    sqlx::query(
+        r#"
+        DELETE FROM review_assignments ra
+        USING conflicted_reviews c
+        WHERE ra.reviewer_id = $1
+          AND c.reviewer_id = ra.reviewer_id
+          AND c.talk_id = ra.talk_id
+        "#,
+    )
+    .bind(user.id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    let conflict = fetch_conflicts(&state.db, user.id, Some(conflict_id))
+        .await
+        .map_err(db_error)?
+        .pop()
+        .ok_or_else(|| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to declare conflict")),
+            )
+        })?;
+
+    Ok((StatusCode::CREATED, Json(conflict)))
+}
+
+/// Withdraw one of the current reviewer's conflict declarations
+pub async fn withdraw_conflict(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conflict_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let result = sqlx::query("DELETE FROM review_conflicts WHERE id = $1 AND reviewer_id = $2")
+        .bind(conflict_id)
+        .bind(user.id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error withdrawing review conflict: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to withdraw conflict")),
+            )
+        })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Conflict not found")),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
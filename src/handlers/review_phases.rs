@@ -0,0 +1,74 @@
+use axum::{http::StatusCode, Json};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{auth::ErrorResponse, Conference, ReviewPhases},
+};
+
+/// Helper function to resolve the current review phase of a conference
+async fn fetch_review_phases(
+    state: &AppState,
+    conference_id: Uuid,
+) -> Result<ReviewPhases, (StatusCode, Json<ErrorResponse>)> {
+    let conference = sqlx::query_as::<_, Conference>("SELECT * FROM conferences WHERE id = $1")
+        .bind(conference_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching conference: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to check review phase")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            )
+        })?;
+
+    Ok(ReviewPhases::for_conference(
+        &conference,
+        &state.config.conference,
+        Utc::now(),
+    ))
+}
+
+/// Refuse rating changes once the conference's review deadline has passed
+pub(crate) async fn ensure_ratings_open(
+    state: &AppState,
+    conference_id: Uuid,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let phases = fetch_review_phases(state, conference_id).await?;
+    if phases.ratings_open {
+        return Ok(());
+    }
+
+    let message = match phases.review_deadline {
+        Some(deadline) => format!("Reviews closed at {}", deadline.to_rfc3339()),
+        None => "Reviews are closed".to_string(),
+    };
+    Err((StatusCode::FORBIDDEN, Json(ErrorResponse::new(message))))
+}
+
+/// Refuse accepting, rejecting or waitlisting talks outside the decision phase
+pub(crate) async fn ensure_decisions_open(
+    state: &AppState,
+    conference_id: Uuid,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let phases = fetch_review_phases(state, conference_id).await?;
+    if phases.decisions_open {
+        return Ok(());
+    }
+
+    Err((
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse::new(format!(
+            "Talk states can only be changed in the decision phase ({})",
+            phases.message
+        ))),
+    ))
+}
@@ -0,0 +1,239 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, validate_rubric_criterion_fields, CreateRubricCriterionRequest,
+        CriterionScore, RatingScore, RubricCriterion, UpdateRubricCriterionRequest,
+    },
+};
+
+/// Fetch a conference's rubric in display order
+pub(crate) async fn fetch_rubric(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+) -> Result<Vec<RubricCriterion>, sqlx::Error> {
+    sqlx::query_as::<_, RubricCriterion>(
+        r#"
+        SELECT * FROM rubric_criteria
+        WHERE conference_id = $1
+        ORDER BY position ASC, created_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Fetch the criterion scores of the given ratings, grouped by rating
+pub(crate) async fn fetch_rating_scores(
+    db: &sqlx::PgPool,
+    rating_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<CriterionScore>>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, RatingScore>(
+        r#"
+        SELECT rs.rating_id, rs.criterion_id, rs.score
+        FROM rating_scores rs
+        JOIN rubric_criteria c ON rs.criterion_id = c.id
+        WHERE rs.rating_id = ANY($1)
+        ORDER BY c.position ASC, c.created_at ASC
+        "#,
+    )
+    .bind(rating_ids)
+    .fetch_all(db)
+    .await?;
+
+    let mut scores: HashMap<Uuid, Vec<CriterionScore>> = HashMap::new();
+    for row in rows {
+        scores
+            .entry(row.rating_id)
+            .or_default()
+            .push(CriterionScore::from(row));
+    }
+    Ok(scores)
+}
+
+/// List the review rubric for a conference (organizer only)
+pub async fn list_rubric_criteria(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<RubricCriterion>>, (StatusCode, Json<ErrorResponse>)> {
+    let criteria = fetch_rubric(&state.db, conference_id).await.map_err(|e| {
+        tracing::error!("Database error fetching rubric: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch rubric")),
+        )
+    })?;
+
+    Ok(Json(criteria))
+}
+
+/// Add a criterion to a conference's review rubric (organizer only)
+pub async fn create_rubric_criterion(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<CreateRubricCriterionRequest>,
+) -> Result<(StatusCode, Json<RubricCriterion>), (StatusCode, Json<ErrorResponse>)> {
+    validate_rubric_criterion_fields(
+        &state.config.submission,
+        Some(&payload.name),
+        payload.description.as_deref(),
+        payload.weight,
+    )?;
+
+    let criterion = sqlx::query_as::<_, RubricCriterion>(
+        r#"
+        INSERT INTO rubric_criteria (conference_id, name, description, weight, position)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING *
+        "#,
+    )
+    .bind(conference_id)
+    .bind(payload.name.trim())
+    .bind(
+        payload
+            .description
+            .as_ref()
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty()),
+    )
+    .bind(payload.weight.unwrap_or(1.0))
+    .bind(payload.position.unwrap_or(0))
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error creating rubric criterion: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new(
+                    "A criterion with this name already exists for the conference",
+                )),
+            ),
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to create rubric criterion")),
+            ),
+        }
+    })?;
+
+    Ok((StatusCode::CREATED, Json(criterion)))
+}
+
+/// Update a rubric criterion (organizer only)
+pub async fn update_rubric_criterion(
+    State(state): State<AppState>,
+    Path(criterion_id): Path<Uuid>,
+    Json(payload): Json<UpdateRubricCriterionRequest>,
+) -> Result<Json<RubricCriterion>, (StatusCode, Json<ErrorResponse>)> {
+    let existing =
+        sqlx::query_as::<_, RubricCriterion>("SELECT * FROM rubric_criteria WHERE id = $1")
+            .bind(criterion_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error fetching rubric criterion: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("Failed to fetch rubric criterion")),
+                )
+            })?
+            .ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new("Rubric criterion not found")),
+                )
+            })?;
+
+    validate_rubric_criterion_fields(
+        &state.config.submission,
+        payload.name.as_deref(),
+        payload.description.as_deref(),
+        payload.weight,
+    )?;
+
+    let name = payload
+        .name
+        .as_ref()
+        .map(|s| s.trim())
+        .unwrap_or(&existing.name);
+    let description = match payload.description {
+        Some(text) => Some(text.trim().to_string()).filter(|t| !t.is_empty()),
+        None => existing.description,
+    };
+
+    let criterion = sqlx::query_as::<_, RubricCriterion>(
+        r#"
+        UPDATE rubric_criteria
+        SET name = $1,
+            description = $2,
+            weight = $3,
+            position = $4,
+            updated_at = NOW()
+        WHERE id = $5
+        RETURNING *
+        "#,
+    )
+    .bind(name)
+    .bind(description)
+    .bind(payload.weight.unwrap_or(existing.weight))
+    .bind(payload.position.unwrap_or(existing.position))
+    .bind(criterion_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error updating rubric criterion: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new(
+                    "A criterion with this name already exists for the conference",
+                )),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to update rubric criterion")),
+            ),
+        }
+    })?;
+
+    Ok(Json(criterion))
+}
+
+/// Delete a rubric criterion (organizer only), along with the scores given for it
+pub async fn delete_rubric_criterion(
+    State(state): State<AppState>,
+    Path(criterion_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let result = sqlx::query("DELETE FROM rubric_criteria WHERE id = $1")
+        .bind(criterion_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error deleting rubric criterion: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to delete rubric criterion")),
+            )
+        })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Rubric criterion not found")),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,257 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDate;
+use sqlx::PgConnection;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, AvailabilityWindow, ConflictSeverity, ScheduleConflict,
+        ScheduleConflictReport, ScheduleSlot, ScheduleSnapshot, ScheduledTalk, SpeakerAvailability,
+        TalkState,
+    },
+};
+
+/// Helper function to load talks with everyone presenting them, their
+/// labels and each speaker's availability, keyed by talk id
+pub(crate) async fn fetch_scheduled_talks(
+    conn: &mut PgConnection,
+    talk_ids: &[Uuid],
+) -> Result<HashMap<Uuid, ScheduledTalk>, sqlx::Error> {
+    #[derive(sqlx::FromRow)]
+    struct TalkRow {
+        id: Uuid,
+        conference_id: Uuid,
+        title: String,
+        state: TalkState,
+        duration_minutes: i32,
+        label_ids: Vec<Uuid>,
+    }
+
+    let talks = sqlx::query_as::<_, TalkRow>(
+        r#"
+        SELECT
+            t.id, t.conference_id, t.title, t.state, t.duration_minutes,
+            COALESCE(array_agg(tl.label_id) FILTER (WHERE tl.label_id IS NOT NULL), '{}') AS label_ids
+        FROM talks t
+        LEFT JOIN talk_labels tl ON tl.talk_id = t.id
+        WHERE t.id = ANY($1)
+        GROUP BY t.id
+        "#,
+    )
+    .bind(talk_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let speaker_rows = sqlx::query_as::<_, (Uuid, Uuid, String)>(
+        r#"
+        SELECT t.id, u.id, u.full_name
+        FROM talks t
+        JOIN users u ON t.speaker_id = u.id
+        WHERE t.id = ANY($1)
+        UNION
+        SELECT ts.talk_id, u.id, u.full_name
+        FROM talk_speakers ts
+        JOIN users u ON ts.user_id = u.id
+        WHERE ts.talk_id = ANY($1) AND ts.status = 'accepted'
+        "#,
+    )
+    .bind(talk_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut speakers: HashMap<Uuid, Vec<(Uuid, String)>> = HashMap::new();
+    for (talk_id, user_id, name) in speaker_rows {
+        speakers.entry(talk_id).or_default().push((user_id, name));
+    }
+
+    let speaker_ids: Vec<Uuid> = speakers
+        .values()
+        .flatten()
+        .map(|(user_id, _)| *user_id)
+        .collect();
+    let windows = sqlx::query_as::<_, SpeakerAvailability>(
+        r#"
+        SELECT * FROM speaker_availability
+        WHERE talk_id = ANY($1) OR (talk_id IS NULL AND user_id = ANY($2))
+        "#,
+    )
+    .bind(talk_ids)
+    .bind(&speaker_ids)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    // A speaker's windows for a talk override their general ones
+    let availability_for = |talk: &TalkRow, user_id: Uuid| -> Vec<AvailabilityWindow> {
+        let for_talk: Vec<AvailabilityWindow> = windows
+            .iter()
+            .filter(|w| w.user_id == user_id && w.talk_id == Some(talk.id))
+            .map(AvailabilityWindow::from)
+            .collect();
+        if !for_talk.is_empty() {
+            return for_talk;
+        }
+        windows
+            .iter()
+            .filter(|w| {
+                w.user_id == user_id && w.talk_id.is_none() && w.conference_id == talk.conference_id
+            })
+            .map(AvailabilityWindow::from)
+            .collect()
+    };
+
+    Ok(talks
+        .into_iter()
+        .map(|talk| {
+            let speakers = speakers.remove(&talk.id).unwrap_or_default();
+            let availability = speakers
+                .iter()
+                .map(|(user_id, _)| (*user_id, availability_for(&talk, *user_id)))
+                .filter(|(_, windows)| !windows.is_empty())
+                .collect();
+            (
+                talk.id,
+                ScheduledTalk {
+                    id: talk.id,
+                    speakers,
+                    availability,
+                    title: talk.title,
+                    state: talk.state,
+                    duration_minutes: talk.duration_minutes,
+                    label_ids: talk.label_ids,
+                },
+            )
+        })
+        .collect())
+}
+
+/// Helper function to load a conference's schedule, with each `proposed`
+/// slot put in place of the stored slot with its id. `None` if the
+/// conference doesn't exist.
+pub(crate) async fn fetch_schedule_snapshot(
+    conn: &mut PgConnection,
+    conference_id: Uuid,
+    proposed: Vec<ScheduleSlot>,
+) -> Result<Option<ScheduleSnapshot>, sqlx::Error> {
+    let Some((start_date, end_date)) = sqlx::query_as::<_, (NaiveDate, NaiveDate)>(
+        "SELECT start_date, end_date FROM conferences WHERE id = $1",
+    )
+    .bind(conference_id)
+    .fetch_optional(&mut *conn)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let slots =
+        sqlx::query_as::<_, ScheduleSlot>("SELECT * FROM schedule_slots WHERE conference_id = $1")
+            .bind(conference_id)
+            .fetch_all(&mut *conn)
+            .await?;
+
+    let track_names: HashMap<Uuid, String> =
+        sqlx::query_as::<_, (Uuid, String)>("SELECT id, name FROM tracks WHERE conference_id = $1")
+            .bind(conference_id)
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut snapshot = ScheduleSnapshot {
+        start_date,
+        end_date,
+        slots,
+        talks: HashMap::new(),
+        track_names,
+    };
+    for slot in proposed {
+        snapshot.apply(slot);
+    }
+
+    let talk_ids: Vec<Uuid> = snapshot.slots.iter().filter_map(|s| s.talk_id).collect();
+    snapshot.talks = fetch_scheduled_talks(&mut *conn, &talk_ids).await?;
+
+    Ok(Some(snapshot))
+}
+
+fn conflict_check_error(e: sqlx::Error) -> (StatusCode, Json<ErrorResponse>) {
+    tracing::error!("Database error checking schedule conflicts: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::new("Failed to check schedule conflicts")),
+    )
+}
+
+/// Check a new or changed slot against the rest of its conference's
+/// schedule. Refuses it when that would cause an error; otherwise returns
+/// the warnings it is involved in.
+pub(crate) async fn check_slot_conflicts(
+    db: &sqlx::PgPool,
+    slot: &ScheduleSlot,
+) -> Result<Vec<ScheduleConflict>, (StatusCode, Json<ErrorResponse>)> {
+    let mut conn = db.acquire().await.map_err(conflict_check_error)?;
+    check_slot_conflicts_in(&mut conn, slot).await
+}
+
+/// As [`check_slot_conflicts`], inside the caller's transaction so the
+/// check sees the schedule the slot will be written to
+pub(crate) async fn check_slot_conflicts_in(
+    conn: &mut PgConnection,
+    slot: &ScheduleSlot,
+) -> Result<Vec<ScheduleConflict>, (StatusCode, Json<ErrorResponse>)> {
+    let snapshot = fetch_schedule_snapshot(conn, slot.conference_id, vec![slot.clone()])
+        .await
+        .map_err(conflict_check_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("Invalid conference ID")),
+            )
+        })?;
+
+    let (errors, warnings): (Vec<_>, Vec<_>) = snapshot
+        .conflicts_for_slot(slot.id)
+        .into_iter()
+        .partition(|c| c.severity == ConflictSeverity::Error);
+    if !errors.is_empty() {
+        let messages: Vec<&str> = errors.iter().map(|c| c.message.as_str()).collect();
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(messages.join("; "))),
+        ));
+    }
+
+    Ok(warnings)
+}
+
+/// List every error and warning in a conference's schedule (organizer only)
+pub async fn get_schedule_conflicts(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<ScheduleConflictReport>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error fetching schedule conflicts: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch schedule conflicts")),
+        )
+    };
+
+    let mut conn = state.db.acquire().await.map_err(db_error)?;
+    let snapshot = fetch_schedule_snapshot(&mut conn, conference_id, Vec::new())
+        .await
+        .map_err(db_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference not found")),
+            )
+        })?;
+
+    Ok(Json(ScheduleConflictReport::from(snapshot.conflicts())))
+}
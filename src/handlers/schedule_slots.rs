@@ -3,18 +3,69 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use chrono::Utc;
+use chrono::{NaiveTime, Utc};
 use sqlx::Row;
 use uuid::Uuid;
 
 use crate::{
     api::AppState,
+    handlers::schedule_conflicts::check_slot_conflicts,
     models::{
         auth::ErrorResponse, AssignTalkRequest, CreateScheduleSlotRequest, PublicScheduleSlot,
         PublicScheduleTalk, ScheduleSlot, ScheduleSlotResponse, UpdateScheduleSlotRequest,
     },
 };
 
+/// Helper function to refuse placing a talk in a slot shorter than the talk
+pub(crate) async fn ensure_talk_fits_slot(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let duration = sqlx::query_scalar::<_, i32>(
+        "SELECT duration_minutes FROM talks WHERE id = $1 AND state NOT IN ('draft', 'withdrawn')",
+    )
+    .bind(talk_id)
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching talk duration: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Invalid talk ID")),
+        )
+    })?;
+
+    ensure_duration_fits_slot(duration, start_time, end_time)
+}
+
+/// Helper function to refuse a talk length longer than a slot
+pub(crate) fn ensure_duration_fits_slot(
+    duration: i32,
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let slot_minutes = (end_time - start_time).num_minutes();
+    if i64::from(duration) > slot_minutes {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(format!(
+                "Talk runs {} minutes but the slot is only {} minutes long",
+                duration, slot_minutes
+            ))),
+        ));
+    }
+
+    Ok(())
+}
+
 /// List all schedule slots (public endpoint)
 pub async fn list_schedule_slots(
     State(state): State<AppState>,
@@ -85,14 +136,29 @@ pub async fn create_schedule_slot(
         ));
     }
 
+    let now = Utc::now();
+    let proposed = ScheduleSlot {
+        id: Uuid::new_v4(),
+        conference_id: payload.conference_id,
+        track_id: payload.track_id,
+        talk_id: None,
+        slot_date: payload.slot_date,
+        start_time: payload.start_time,
+        end_time: payload.end_time,
+        created_at: now,
+        updated_at: now,
+    };
+    let warnings = check_slot_conflicts(&state.db, &proposed).await?;
+
     // Create the schedule slot
     let slot = sqlx::query_as::<_, ScheduleSlot>(
         r#"
-        INSERT INTO schedule_slots (conference_id, track_id, slot_date, start_time, end_time)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO schedule_slots (id, conference_id, track_id, slot_date, start_time, end_time)
+        VALUES ($1, $2, $3, $4, $5, $6)
         RETURNING *
         "#,
     )
+    .bind(proposed.id)
     .bind(payload.conference_id)
     .bind(payload.track_id)
     .bind(payload.slot_date)
@@ -124,7 +190,10 @@ pub async fn create_schedule_slot(
         slot.start_time,
         slot.end_time
     );
-    Ok((StatusCode::CREATED, Json(ScheduleSlotResponse::from(slot))))
+    Ok((
+        StatusCode::CREATED,
+        Json(ScheduleSlotResponse::from(slot).with_warnings(warnings)),
+    ))
 }
 
 /// Update a schedule slot (organizer only)
@@ -173,6 +242,23 @@ pub async fn update_schedule_slot(
         ));
     }
 
+    if let Some(talk_id) = talk_id {
+        ensure_talk_fits_slot(&state.db, talk_id, start_time, end_time).await?;
+    }
+
+    let warnings = check_slot_conflicts(
+        &state.db,
+        &ScheduleSlot {
+            track_id,
+            talk_id,
+            slot_date,
+            start_time,
+            end_time,
+            ..existing_slot
+        },
+    )
+    .await?;
+
     // Update the slot
     let updated_slot = sqlx::query_as::<_, ScheduleSlot>(
         r#"
@@ -209,7 +295,9 @@ pub async fn update_schedule_slot(
     })?;
 
     tracing::info!("Schedule slot updated: {}", updated_slot.id);
-    Ok(Json(ScheduleSlotResponse::from(updated_slot)))
+    Ok(Json(
+        ScheduleSlotResponse::from(updated_slot).with_warnings(warnings),
+    ))
 }
 
 /// Delete a schedule slot (organizer only)
@@ -269,12 +357,29 @@ pub async fn assign_talk_to_slot(
         )
     })?;
 
-    if existing_slot.is_none() {
-        return Err((
+    let existing_slot = existing_slot.ok_or_else(|| {
+        (
             StatusCode::NOT_FOUND,
             Json(ErrorResponse::new("Schedule slot not found")),
-        ));
-    }
+        )
+    })?;
+
+    ensure_talk_fits_slot(
+        &state.db,
+        payload.talk_id,
+        existing_slot.start_time,
+        existing_slot.end_time,
+    )
+    .await?;
+
+    let warnings = check_slot_conflicts(
+        &state.db,
+        &ScheduleSlot {
+            talk_id: Some(payload.talk_id),
+            ..existing_slot
+        },
+    )
+    .await?;
 
     // Update the slot with the talk assignment
     let updated_slot = sqlx::query_as::<_, ScheduleSlot>(
@@ -312,7 +417,9 @@ pub async fn assign_talk_to_slot(
         payload.talk_id,
         slot_id
     );
-    Ok(Json(ScheduleSlotResponse::from(updated_slot)))
+    Ok(Json(
+        ScheduleSlotResponse::from(updated_slot).with_warnings(warnings),
+    ))
 }
 
 /// Unassign a talk from a schedule slot (organizer only)
@@ -370,7 +477,12 @@ pub async fn get_public_schedule(
             ss.talk_id,
             tk.title as talk_title,
             tk.short_summary as talk_summary,
-            u.full_name as speaker_name
+            CONCAT_WS(', ', u.full_name, (
+                SELECT string_agg(cu.full_name, ', ' ORDER BY ts.invited_at)
+                FROM talk_speakers ts
+                INNER JOIN users cu ON ts.user_id = cu.id
+                WHERE ts.talk_id = tk.id AND ts.status = 'accepted'
+            )) as speaker_name
         FROM schedule_slots ss
         INNER JOIN tracks t ON ss.track_id = t.id
         LEFT JOIN talks tk ON ss.talk_id = tk.id
@@ -0,0 +1,178 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{auth::ErrorResponse, SetAvailabilityRequest, SpeakerAvailabilityResponse, User},
+};
+
+/// Helper function to fetch a conference's availability windows, all of
+/// them or one speaker's
+async fn fetch_availability(
+    db: &sqlx::PgPool,
+    conference_id: Uuid,
+    user_id: Option<Uuid>,
+) -> Result<Vec<SpeakerAvailabilityResponse>, sqlx::Error> {
+    sqlx::query_as::<_, SpeakerAvailabilityResponse>(
+        r#"
+        SELECT
+            a.id, a.user_id, u.full_name AS speaker_name,
+            a.talk_id, t.title AS talk_title,
+            a.available_date, a.start_time, a.end_time
+        FROM speaker_availability a
+        JOIN users u ON a.user_id = u.id
+        LEFT JOIN talks t ON a.talk_id = t.id
+        WHERE a.conference_id = $1
+          AND ($2::uuid IS NULL OR a.user_id = $2)
+        ORDER BY u.full_name ASC, t.title ASC NULLS FIRST, a.available_date ASC, a.start_time ASC
+        "#,
+    )
+    .bind(conference_id)
+    .bind(user_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Get the current speaker's availability for a conference
+pub async fn get_my_availability(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<SpeakerAvailabilityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let windows = fetch_availability(&state.db, conference_id, Some(user.id))
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching availability: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch availability")),
+            )
+        })?;
+
+    Ok(Json(windows))
+}
+
+/// Replace the current speaker's availability for a conference, either
+/// their general windows or those for one of their talks
+pub async fn set_my_availability(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<SetAvailabilityRequest>,
+) -> Result<Json<Vec<SpeakerAvailabilityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating availability: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to update availability")),
+        )
+    };
+
+    let (start_date, end_date) = sqlx::query_as::<_, (NaiveDate, NaiveDate)>(
+        "SELECT start_date, end_date FROM conferences WHERE id = $1",
+    )
+    .bind(conference_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Conference not found")),
+        )
+    })?;
+
+    payload.validate(start_date, end_date)?;
+
+    if let Some(talk_id) = payload.talk_id {
+        let presents = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM talks t
+                WHERE t.id = $1 AND t.conference_id = $2
+                  AND (
+                      t.speaker_id = $3
+                      OR EXISTS(
+                          SELECT 1 FROM talk_speakers ts
+                          WHERE ts.talk_id = t.id AND ts.user_id = $3 AND ts.status = 'accepted'
+                      )
+                  )
+            )
+            "#,
+        )
+        .bind(talk_id)
+        .bind(conference_id)
+        .bind(user.id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(db_error)?;
+        if !presents {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Talk not found")),
+            ));
+        }
+    }
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+    sqlx::query(
+        r#"
+        DELETE FROM speaker_availability
+        WHERE user_id = $1 AND conference_id = $2
+          AND talk_id IS NOT DISTINCT FROM $3
+        "#,
+    )
+    .bind(user.id)
+    .bind(conference_id)
+    .bind(payload.talk_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    for window in &payload.windows {
+        sqlx::query(
+            r#"
+            INSERT INTO speaker_availability
+                (user_id, conference_id, talk_id, available_date, start_time, end_time)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(user.id)
+        .bind(conference_id)
+        .bind(payload.talk_id)
+        .bind(window.available_date)
+        .bind(window.start_time)
+        .bind(window.end_time)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    let windows = fetch_availability(&state.db, conference_id, Some(user.id))
+        .await
+        .map_err(db_error)?;
+    Ok(Json(windows))
+}
+
+/// List every speaker's availability for a conference (organizer only)
+pub async fn list_speaker_availability(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<SpeakerAvailabilityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let windows = fetch_availability(&state.db, conference_id, None)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching speaker availability: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch speaker availability")),
+            )
+        })?;
+
+    Ok(Json(windows))
+}
@@ -0,0 +1,291 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::review_conflicts::check_review_access,
+    models::{
+        auth::ErrorResponse, validate_comment_body, FeedbackRequest, SpeakerFeedback, TalkFeedback,
+        User,
+    },
+};
+
+/// Feedback speakers may see: approved, on a talk that has been decided
+pub(crate) async fn fetch_released_feedback(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+) -> Result<Vec<SpeakerFeedback>, sqlx::Error> {
+    sqlx::query_as::<_, SpeakerFeedback>(
+        r#"
+        SELECT f.body, f.approved_at
+        FROM speaker_feedback f
+        JOIN talks t ON f.talk_id = t.id
+        WHERE f.talk_id = $1
+          AND f.approved_at IS NOT NULL
+          AND t.state NOT IN ('draft', 'submitted')
+        ORDER BY f.approved_at
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Approved feedback on a talk, whether or not its state releases it yet
+pub(crate) async fn fetch_approved_feedback(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+) -> Result<Vec<SpeakerFeedback>, sqlx::Error> {
+    sqlx::query_as::<_, SpeakerFeedback>(
+        r#"
+        SELECT body, approved_at
+        FROM speaker_feedback
+        WHERE talk_id = $1 AND approved_at IS NOT NULL
+        ORDER BY approved_at
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Helper function to fetch a talk's feedback, or just one entry
+async fn fetch_feedback(
+    db: &sqlx::PgPool,
+    talk_id: Option<Uuid>,
+    feedback_id: Option<Uuid>,
+) -> Result<Vec<TalkFeedback>, sqlx::Error> {
+    sqlx::query_as::<_, TalkFeedback>(
+        r#"
+        SELECT
+            f.id,
+            f.talk_id,
+            f.author_id,
+            a.full_name AS author_name,
+            f.body,
+            f.approved_by,
+            ap.full_name AS approved_by_name,
+            f.approved_at,
+            f.created_at,
+            f.updated_at
+        FROM speaker_feedback f
+        JOIN users a ON f.author_id = a.id
+        LEFT JOIN users ap ON f.approved_by = ap.id
+        WHERE ($1::uuid IS NULL OR f.talk_id = $1)
+          AND ($2::uuid IS NULL OR f.id = $2)
+        ORDER BY f.created_at
+        "#,
+    )
+    .bind(talk_id)
+    .bind(feedback_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Helper function to fetch one feedback entry
+async fn fetch_one_feedback(
+    db: &sqlx::PgPool,
+    feedback_id: Uuid,
+) -> Result<TalkFeedback, (StatusCode, Json<ErrorResponse>)> {
+    fetch_feedback(db, None, Some(feedback_id))
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching speaker feedback: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch feedback")),
+            )
+        })?
+        .pop()
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Feedback not found")),
+            )
+        })
+}
+
+/// Helper function to check the organizer may work on a feedback entry's talk
+async fn check_feedback_access(
+    db: &sqlx::PgPool,
+    feedback_id: Uuid,
+    user: &User,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let feedback = fetch_one_feedback(db, feedback_id).await?;
+    check_review_access(db, feedback.talk_id, user.id).await
+}
+
+/// List all feedback written for a talk's speakers, approved or not
+/// (organizer only)
+pub async fn list_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+) -> Result<Json<Vec<TalkFeedback>>, (StatusCode, Json<ErrorResponse>)> {
+    check_review_access(&state.db, talk_id, user.id).await?;
+
+    let feedback = fetch_feedback(&state.db, Some(talk_id), None)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching speaker feedback: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch feedback")),
+            )
+        })?;
+
+    Ok(Json(feedback))
+}
+
+/// Write feedback for a talk's speakers. It stays hidden until approved.
+pub async fn create_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    Json(payload): Json<FeedbackRequest>,
+) -> Result<(StatusCode, Json<TalkFeedback>), (StatusCode, Json<ErrorResponse>)> {
+    check_review_access(&state.db, talk_id, user.id).await?;
+    validate_comment_body(&state.config.submission, &payload.body)?;
+
+    let feedback_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO speaker_feedback (talk_id, author_id, body)
+        VALUES ($1, $2, $3)
+        RETURNING id
+        "#,
+    )
+    .bind(talk_id)
+    .bind(user.id)
+    .bind(payload.body.trim())
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error creating speaker feedback: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to create feedback")),
+        )
+    })?;
+
+    let feedback = fetch_one_feedback(&state.db, feedback_id).await?;
+
+    Ok((StatusCode::CREATED, Json(feedback)))
+}
+
+/// Edit feedback. Any approval is withdrawn so the new text is checked again.
+pub async fn update_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(feedback_id): Path<Uuid>,
+    Json(payload): Json<FeedbackRequest>,
+) -> Result<Json<TalkFeedback>, (StatusCode, Json<ErrorResponse>)> {
+    check_feedback_access(&state.db, feedback_id, &user).await?;
+    validate_comment_body(&state.config.submission, &payload.body)?;
+
+    sqlx::query(
+        r#"
+        UPDATE speaker_feedback
+        SET body = $2, approved_by = NULL, approved_at = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feedback_id)
+    .bind(payload.body.trim())
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error updating speaker feedback: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to update feedback")),
+        )
+    })?;
+
+    fetch_one_feedback(&state.db, feedback_id).await.map(Json)
+}
+
+/// Approve feedback for release to the talk's speakers
+pub async fn approve_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(feedback_id): Path<Uuid>,
+) -> Result<Json<TalkFeedback>, (StatusCode, Json<ErrorResponse>)> {
+    check_feedback_access(&state.db, feedback_id, &user).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE speaker_feedback
+        SET approved_by = $2, approved_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feedback_id)
+    .bind(user.id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error approving speaker feedback: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to approve feedback")),
+        )
+    })?;
+
+    fetch_one_feedback(&state.db, feedback_id).await.map(Json)
+}
+
+/// Withdraw the approval of feedback, hiding it from speakers again
+pub async fn unapprove_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(feedback_id): Path<Uuid>,
+) -> Result<Json<TalkFeedback>, (StatusCode, Json<ErrorResponse>)> {
+    check_feedback_access(&state.db, feedback_id, &user).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE speaker_feedback
+        SET approved_by = NULL, approved_at = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+    )
+    .bind(feedback_id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error withdrawing feedback approval: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to withdraw approval")),
+        )
+    })?;
+
+    fetch_one_feedback(&state.db, feedback_id).await.map(Json)
+}
+
+/// Delete feedback
+pub async fn delete_talk_feedback(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(feedback_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    check_feedback_access(&state.db, feedback_id, &user).await?;
+
+    sqlx::query("DELETE FROM speaker_feedback WHERE id = $1")
+        .bind(feedback_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error deleting speaker feedback: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to delete feedback")),
+            )
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
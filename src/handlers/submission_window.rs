@@ -0,0 +1,188 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    models::{
+        auth::ErrorResponse, Conference, GrantExtensionRequest, SubmissionExtension,
+        SubmissionExtensionResponse, SubmissionWindow, SubmissionWindowState, User,
+    },
+};
+
+/// Refuse submissions and edits outside the conference's CfP window, unless
+/// the speaker has been granted an extension that is still running
+pub(crate) async fn ensure_submissions_open(
+    state: &AppState,
+    conference: &Conference,
+    user_id: Uuid,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let now = Utc::now();
+    let window = SubmissionWindow::for_conference(conference, &state.config.conference, now);
+
+    if window.is_open() {
+        return Ok(());
+    }
+
+    // Extensions only apply once the CfP has closed
+    if window.state == SubmissionWindowState::Closed {
+        let extension = sqlx::query_as::<_, SubmissionExtension>(
+            r#"
+            SELECT * FROM submission_extensions
+            WHERE conference_id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(conference.id)
+        .bind(user_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching submission extension: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to check submission window")),
+            )
+        })?;
+
+        if extension.is_some_and(|ext| now <= ext.extended_until) {
+            return Ok(());
+        }
+    }
+
+    Err((
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse::new(window.message)),
+    ))
+}
+
+/// List the extensions granted for a conference (organizer only)
+pub async fn list_submission_extensions(
+    State(state): State<AppState>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<Vec<SubmissionExtensionResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let extensions = sqlx::query_as::<_, SubmissionExtensionResponse>(
+        r#"
+        SELECT
+            se.id,
+            se.conference_id,
+            se.user_id,
+            u.full_name as speaker_name,
+            u.email as speaker_email,
+            se.extended_until,
+            se.reason,
+            se.granted_by,
+            se.created_at
+        FROM submission_extensions se
+        INNER JOIN users u ON se.user_id = u.id
+        WHERE se.conference_id = $1
+        ORDER BY se.extended_until ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching submission extensions: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch submission extensions")),
+        )
+    })?;
+
+    Ok(Json(extensions))
+}
+
+/// Grant a speaker extra time to submit (organizer only).
+/// Granting again for the same speaker replaces the previous extension.
+pub async fn grant_submission_extension(
+    State(state): State<AppState>,
+    Extension(organizer): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<GrantExtensionRequest>,
+) -> Result<(StatusCode, Json<SubmissionExtension>), (StatusCode, Json<ErrorResponse>)> {
+    if payload.extended_until <= Utc::now() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Extension must end in the future")),
+        ));
+    }
+
+    let extension = sqlx::query_as::<_, SubmissionExtension>(
+        r#"
+        INSERT INTO submission_extensions (conference_id, user_id, extended_until, reason, granted_by)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (conference_id, user_id) DO UPDATE
+        SET extended_until = EXCLUDED.extended_until,
+            reason = EXCLUDED.reason,
+            granted_by = EXCLUDED.granted_by,
+            created_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(conference_id)
+    .bind(payload.user_id)
+    .bind(payload.extended_until)
+    .bind(payload.reason.as_ref().map(|s| s.trim()))
+    .bind(organizer.id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error granting submission extension: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Conference or user not found")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to grant submission extension")),
+            ),
+        }
+    })?;
+
+    tracing::info!(
+        "Submission extension granted to user {} for conference {} until {}",
+        extension.user_id,
+        extension.conference_id,
+        extension.extended_until
+    );
+
+    Ok((StatusCode::CREATED, Json(extension)))
+}
+
+/// Revoke a speaker's extension (organizer only)
+pub async fn revoke_submission_extension(
+    State(state): State<AppState>,
+    Path((conference_id, user_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let result = sqlx::query(
+        r#"
+        DELETE FROM submission_extensions
+        WHERE conference_id = $1 AND user_id = $2
+        "#,
+    )
+    .bind(conference_id)
+    .bind(user_id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error revoking submission extension: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to revoke submission extension")),
+        )
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Submission extension not found")),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
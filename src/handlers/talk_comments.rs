@@ -0,0 +1,365 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::review_conflicts::check_review_access,
+    models::{
+        auth::ErrorResponse, extract_mentions, validate_comment_body, CreateCommentRequest,
+        MentionCandidate, TalkComment, UpdateCommentRequest, User,
+    },
+};
+
+fn internal_error(message: &str) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ErrorResponse::new(message)),
+    )
+}
+
+/// Helper function to fetch a talk's comments, or just one of them
+async fn fetch_comments(
+    db: &sqlx::PgPool,
+    talk_id: Option<Uuid>,
+    comment_id: Option<Uuid>,
+) -> Result<Vec<TalkComment>, sqlx::Error> {
+    sqlx::query_as::<_, TalkComment>(
+        r#"
+        SELECT
+            c.id,
+            c.talk_id,
+            c.parent_id,
+            c.author_id,
+            u.full_name AS author_name,
+            c.body,
+            COALESCE(
+                (SELECT array_agg(m.user_id) FROM talk_comment_mentions m WHERE m.comment_id = c.id),
+                '{}'
+            ) AS mentioned_user_ids,
+            c.created_at,
+            c.edited_at,
+            c.deleted_at
+        FROM talk_comments c
+        JOIN users u ON c.author_id = u.id
+        WHERE ($1::uuid IS NULL OR c.talk_id = $1)
+          AND ($2::uuid IS NULL OR c.id = $2)
+        ORDER BY c.created_at
+        "#,
+    )
+    .bind(talk_id)
+    .bind(comment_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Organizers who can be mentioned in a talk's discussion
+async fn mention_candidates(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+    author_id: Uuid,
+) -> Result<Vec<MentionCandidate>, sqlx::Error> {
+    sqlx::query_as::<_, MentionCandidate>(
+        r#"
+        SELECT u.id, u.username, u.email, u.full_name
+        FROM users u
+        JOIN talks t ON t.id = $1
+        WHERE u.is_organizer
+          AND u.id <> $2
+          AND u.id <> t.speaker_id
+          AND NOT EXISTS(
+              SELECT 1 FROM talk_speakers ts
+              WHERE ts.talk_id = t.id AND ts.user_id = u.id AND ts.status = 'accepted'
+          )
+          AND NOT EXISTS(
+              SELECT 1 FROM conflicted_reviews c
+              WHERE c.talk_id = t.id AND c.reviewer_id = u.id
+          )
+        "#,
+    )
+    .bind(talk_id)
+    .bind(author_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Email the organizers newly mentioned in a comment
+async fn notify_mentioned(
+    state: &AppState,
+    comment: &TalkComment,
+    mentioned: &[&MentionCandidate],
+    author: &User,
+) {
+    if mentioned.is_empty() || !state.email_service.is_configured() {
+        return;
+    }
+
+    let talk_title = sqlx::query_scalar::<_, String>("SELECT title FROM talks WHERE id = $1")
+        .bind(comment.talk_id)
+        .fetch_optional(&state.db)
+        .await
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    let subject = format!("{} mentioned you on \"{}\"", author.full_name, talk_title);
+    let talks_url = format!(
+        "{}/organizer/talks",
+        state.config.app_url.trim_end_matches('/')
+    );
+
+    for organizer in mentioned {
+        let body = format!(
+            "Hi {},\n\n{} mentioned you in the discussion of \"{}\":\n\n{}\n\n\
+             Reply from the organizer talk list:\n{}\n",
+            organizer.full_name, author.full_name, talk_title, comment.body, talks_url
+        );
+
+        if let Err(e) = state
+            .email_service
+            .send_email(
+                &organizer.email,
+                &subject,
+                &body,
+                None,
+                Some(comment.talk_id),
+                Some(author.id),
+            )
+            .await
+        {
+            tracing::warn!("Failed to send mention notification: {}", e);
+        }
+    }
+}
+
+/// List the internal discussion on a talk (organizer only)
+pub async fn list_talk_comments(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+) -> Result<Json<Vec<TalkComment>>, (StatusCode, Json<ErrorResponse>)> {
+    check_review_access(&state.db, talk_id, user.id).await?;
+
+    let comments = fetch_comments(&state.db, Some(talk_id), None)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk comments: {}", e);
+            internal_error("Failed to fetch comments")
+        })?;
+
+    Ok(Json(comments))
+}
+
+/// Comment on a talk or reply to a comment, emailing any @mentioned organizers
+pub async fn create_talk_comment(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    Json(payload): Json<CreateCommentRequest>,
+) -> Result<(StatusCode, Json<TalkComment>), (StatusCode, Json<ErrorResponse>)> {
+    check_review_access(&state.db, talk_id, user.id).await?;
+    validate_comment_body(&state.config.submission, &payload.body)?;
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error creating talk comment: {}", e);
+        internal_error("Failed to create comment")
+    };
+
+    if let Some(parent_id) = payload.parent_id {
+        let parent_talk_id =
+            sqlx::query_scalar::<_, Uuid>("SELECT talk_id FROM talk_comments WHERE id = $1")
+                .bind(parent_id)
+                .fetch_optional(&state.db)
+                .await
+                .map_err(db_error)?;
+
+        if parent_talk_id != Some(talk_id) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "Replies must be to a comment on the same talk",
+                )),
+            ));
+        }
+    }
+
+    let candidates = mention_candidates(&state.db, talk_id, user.id)
+        .await
+        .map_err(db_error)?;
+    let mentioned_ids = extract_mentions(&payload.body, &candidates);
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    let comment_id = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO talk_comments (talk_id, parent_id, author_id, body)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id
+        "#,
+    )
+    .bind(talk_id)
+    .bind(payload.parent_id)
+    .bind(user.id)
+    .bind(payload.body.trim())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    sqlx::query(
+        "INSERT INTO talk_comment_mentions (comment_id, user_id) SELECT $1, unnest($2::uuid[])",
+    )
+    .bind(comment_id)
+    .bind(&mentioned_ids)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    let comment = fetch_comments(&state.db, None, Some(comment_id))
+        .await
+        .map_err(db_error)?
+        .pop()
+        .ok_or_else(|| internal_error("Failed to create comment"))?;
+
+    let mentioned: Vec<&MentionCandidate> = candidates
+        .iter()
+        .filter(|candidate| mentioned_ids.contains(&candidate.id))
+        .collect();
+    notify_mentioned(&state, &comment, &mentioned, &user).await;
+
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// Helper function to load a comment the current user wrote, for changes
+async fn fetch_own_comment(
+    state: &AppState,
+    comment_id: Uuid,
+    user: &User,
+) -> Result<TalkComment, (StatusCode, Json<ErrorResponse>)> {
+    let comment = fetch_comments(&state.db, None, Some(comment_id))
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk comment: {}", e);
+            internal_error("Failed to fetch comment")
+        })?
+        .pop()
+        .filter(|comment| comment.deleted_at.is_none())
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Comment not found")),
+            )
+        })?;
+
+    check_review_access(&state.db, comment.talk_id, user.id).await?;
+
+    if comment.author_id != user.id {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new("You can only change your own comments")),
+        ));
+    }
+
+    Ok(comment)
+}
+
+/// Edit one of your comments. Only organizers who weren't mentioned before
+/// are emailed.
+pub async fn update_talk_comment(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(comment_id): Path<Uuid>,
+    Json(payload): Json<UpdateCommentRequest>,
+) -> Result<Json<TalkComment>, (StatusCode, Json<ErrorResponse>)> {
+    let existing = fetch_own_comment(&state, comment_id, &user).await?;
+    validate_comment_body(&state.config.submission, &payload.body)?;
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating talk comment: {}", e);
+        internal_error("Failed to update comment")
+    };
+
+    let candidates = mention_candidates(&state.db, existing.talk_id, user.id)
+        .await
+        .map_err(db_error)?;
+    let mentioned_ids = extract_mentions(&payload.body, &candidates);
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    sqlx::query("UPDATE talk_comments SET body = $2, edited_at = NOW() WHERE id = $1")
+        .bind(comment_id)
+        .bind(payload.body.trim())
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    sqlx::query("DELETE FROM talk_comment_mentions WHERE comment_id = $1")
+        .bind(comment_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    sqlx::query(
+        "INSERT INTO talk_comment_mentions (comment_id, user_id) SELECT $1, unnest($2::uuid[])",
+    )
+    .bind(comment_id)
+    .bind(&mentioned_ids)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    let comment = fetch_comments(&state.db, None, Some(comment_id))
+        .await
+        .map_err(db_error)?
+        .pop()
+        .ok_or_else(|| internal_error("Failed to update comment"))?;
+
+    let newly_mentioned: Vec<&MentionCandidate> = candidates
+        .iter()
+        .filter(|candidate| {
+            mentioned_ids.contains(&candidate.id)
+                && !existing.mentioned_user_ids.contains(&candidate.id)
+        })
+        .collect();
+    notify_mentioned(&state, &comment, &newly_mentioned, &user).await;
+
+    Ok(Json(comment))
+}
+
+/// Delete one of your comments. Its replies stay in the thread.
+pub async fn delete_talk_comment(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    fetch_own_comment(&state, comment_id, &user).await?;
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error deleting talk comment: {}", e);
+        internal_error("Failed to delete comment")
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    sqlx::query("UPDATE talk_comments SET body = '', deleted_at = NOW() WHERE id = $1")
+        .bind(comment_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    sqlx::query("DELETE FROM talk_comment_mentions WHERE comment_id = $1")
+        .bind(comment_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
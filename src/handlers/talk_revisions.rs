@@ -0,0 +1,193 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::{blind_review::speakers_hidden, talk_speakers::is_talk_speaker},
+    models::{
+        auth::ErrorResponse, RevisionDiffQuery, Talk, TalkRevision, TalkRevisionDiff,
+        TalkRevisionResponse, User,
+    },
+};
+
+/// Store the talk's current content as its next revision. Runs in the
+/// caller's transaction and locks the talk row first, so concurrent edits
+/// number their revisions one after the other.
+pub(crate) async fn record_talk_revision(
+    conn: &mut PgConnection,
+    talk: &Talk,
+    edited_by: Uuid,
+) -> Result<TalkRevision, sqlx::Error> {
+    sqlx::query("SELECT id FROM talks WHERE id = $1 FOR UPDATE")
+        .bind(talk.id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query_as::<_, TalkRevision>(
+        r#"
+        INSERT INTO talk_revisions (
+            talk_id, revision_number, title, short_summary, long_description,
+            slides_url, duration_minutes, format, edited_by
+        )
+        SELECT $1, COALESCE(MAX(revision_number), 0) + 1, $2, $3, $4, $5, $6, $7, $8
+        FROM talk_revisions
+        WHERE talk_id = $1
+        RETURNING *
+        "#,
+    )
+    .bind(talk.id)
+    .bind(&talk.title)
+    .bind(&talk.short_summary)
+    .bind(&talk.long_description)
+    .bind(&talk.slides_url)
+    .bind(talk.duration_minutes)
+    .bind(talk.format)
+    .bind(edited_by)
+    .fetch_one(conn)
+    .await
+}
+
+/// Check the talk exists and the user may see its history (speakers and organizers)
+async fn ensure_can_view_revisions(
+    state: &AppState,
+    talk_id: Uuid,
+    user: &User,
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let talk_exists = sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS(SELECT 1 FROM talks WHERE id = $1)
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking talk existence: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to verify talk")),
+        )
+    })?;
+
+    if !talk_exists {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found")),
+        ));
+    }
+
+    if !user.is_organizer && !is_talk_speaker(&state.db, talk_id, user.id).await? {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You don't have permission to view this talk",
+            )),
+        ));
+    }
+
+    Ok(())
+}
+
+/// List a talk's revisions, oldest first
+pub async fn list_talk_revisions(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+) -> Result<Json<Vec<TalkRevisionResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    ensure_can_view_revisions(&state, talk_id, &user).await?;
+
+    let mut revisions = sqlx::query_as::<_, TalkRevisionResponse>(
+        r#"
+        SELECT
+            tr.revision_number,
+            tr.title,
+            tr.short_summary,
+            tr.long_description,
+            tr.slides_url,
+            tr.duration_minutes,
+            tr.format,
+            tr.edited_by,
+            u.full_name as edited_by_name,
+            tr.created_at
+        FROM talk_revisions tr
+        LEFT JOIN users u ON tr.edited_by = u.id
+        WHERE tr.talk_id = $1
+        ORDER BY tr.revision_number ASC
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching talk revisions: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk revisions")),
+        )
+    })?;
+
+    // Under blind review the editor is usually the speaker
+    if speakers_hidden(&state.db, user.id, talk_id).await? {
+        for revision in &mut revisions {
+            revision.edited_by = None;
+            revision.edited_by_name = None;
+        }
+    }
+
+    Ok(Json(revisions))
+}
+
+/// Field-level diff between two revisions of a talk
+pub async fn get_talk_revision_diff(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    Query(query): Query<RevisionDiffQuery>,
+) -> Result<Json<TalkRevisionDiff>, (StatusCode, Json<ErrorResponse>)> {
+    ensure_can_view_revisions(&state, talk_id, &user).await?;
+
+    let revisions = sqlx::query_as::<_, TalkRevision>(
+        r#"
+        SELECT * FROM talk_revisions
+        WHERE talk_id = $1 AND revision_number IN ($2, $3)
+        "#,
+    )
+    .bind(talk_id)
+    .bind(query.from)
+    .bind(query.to)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching talk revisions: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk revisions")),
+        )
+    })?;
+
+    let find = |number: i32| {
+        revisions
+            .iter()
+            .find(|r| r.revision_number == number)
+            .ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new(format!("Revision {} not found", number))),
+                )
+            })
+    };
+    let from = find(query.from)?;
+    let to = find(query.to)?;
+
+    Ok(Json(TalkRevisionDiff {
+        talk_id,
+        from_revision: from.revision_number,
+        to_revision: to.revision_number,
+        changes: from.diff(to),
+    }))
+}
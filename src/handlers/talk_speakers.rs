@@ -0,0 +1,563 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::blind_review::speakers_hidden,
+    models::{
+        auth::ErrorResponse, InviteCoSpeakerRequest, SpeakerInvitationResponse, Talk, TalkSpeaker,
+        TalkSpeakerResponse, TalkSpeakerStatus, User,
+    },
+    services::email::EmailVariables,
+};
+
+/// Check whether a user presents a talk, either as its primary speaker or
+/// as a co-speaker who accepted their invitation
+pub(crate) async fn is_talk_speaker(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, (StatusCode, Json<ErrorResponse>)> {
+    sqlx::query_scalar::<_, bool>(
+        r#"
+        SELECT EXISTS (
+            SELECT 1 FROM talks WHERE id = $1 AND speaker_id = $2
+        ) OR EXISTS (
+            SELECT 1 FROM talk_speakers
+            WHERE talk_id = $1 AND user_id = $2 AND status = 'accepted'
+        )
+        "#,
+    )
+    .bind(talk_id)
+    .bind(user_id)
+    .fetch_one(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error checking talk speakers: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to verify talk ownership")),
+        )
+    })
+}
+
+/// Fetch every speaker on a talk, primary speaker first.
+/// Declined invitations are left out.
+pub(crate) async fn fetch_talk_speakers(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+) -> Result<Vec<TalkSpeakerResponse>, sqlx::Error> {
+    sqlx::query_as::<_, TalkSpeakerResponse>(
+        r#"
+        SELECT * FROM (
+            SELECT
+                NULL::uuid as invitation_id,
+                u.id as user_id,
+                u.full_name,
+                u.email,
+                true as is_primary,
+                'accepted'::talk_speaker_status as status,
+                t.submitted_at as sort_at
+            FROM talks t
+            INNER JOIN users u ON t.speaker_id = u.id
+            WHERE t.id = $1
+            UNION ALL
+            SELECT
+                ts.id as invitation_id,
+                ts.user_id,
+                COALESCE(u.full_name, ts.email) as full_name,
+                COALESCE(u.email, ts.email) as email,
+                false as is_primary,
+                ts.status,
+                ts.invited_at as sort_at
+            FROM talk_speakers ts
+            LEFT JOIN users u ON ts.user_id = u.id
+            WHERE ts.talk_id = $1 AND ts.status != 'declined'
+        ) speakers
+        ORDER BY is_primary DESC, sort_at ASC
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Helper function to fetch a talk by ID or return 404
+async fn fetch_talk(
+    db: &sqlx::PgPool,
+    talk_id: Uuid,
+) -> Result<Talk, (StatusCode, Json<ErrorResponse>)> {
+    sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talk")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Talk not found")),
+            )
+        })
+}
+
+/// Helper function to fetch a pending invitation by its token
+async fn fetch_invitation(
+    db: &sqlx::PgPool,
+    token: &str,
+) -> Result<TalkSpeaker, (StatusCode, Json<ErrorResponse>)> {
+    sqlx::query_as::<_, TalkSpeaker>("SELECT * FROM talk_speakers WHERE invite_token = $1")
+        .bind(token)
+        .fetch_optional(db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching invitation: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch invitation")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Invitation not found")),
+            )
+        })
+}
+
+/// List the speakers on a talk
+pub async fn list_talk_speakers(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+) -> Result<Json<Vec<TalkSpeakerResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    fetch_talk(&state.db, talk_id).await?;
+
+    if !user.is_organizer && !is_talk_speaker(&state.db, talk_id, user.id).await? {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You don't have permission to view this talk's speakers",
+            )),
+        ));
+    }
+
+    if speakers_hidden(&state.db, user.id, talk_id).await? {
+        return Ok(Json(Vec::new()));
+    }
+
+    let speakers = fetch_talk_speakers(&state.db, talk_id).await.map_err(|e| {
+        tracing::error!("Database error fetching talk speakers: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch talk speakers")),
+        )
+    })?;
+
+    Ok(Json(speakers))
+}
+
+/// Invite a co-speaker to a talk by email
+pub async fn invite_co_speaker(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    Json(payload): Json<InviteCoSpeakerRequest>,
+) -> Result<(StatusCode, Json<TalkSpeakerResponse>), (StatusCode, Json<ErrorResponse>)> {
+    let talk = fetch_talk(&state.db, talk_id).await?;
+
+    if !is_talk_speaker(&state.db, talk_id, user.id).await? {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "You can only invite co-speakers to your own talk submissions",
+            )),
+        ));
+    }
+
+    let email = payload.email.trim().to_lowercase();
+    if email.is_empty() || !email.contains('@') {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("A valid email address is required")),
+        ));
+    }
+
+    // Refuse to invite someone who already presents this talk
+    let already_speaking = fetch_talk_speakers(&state.db, talk_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk speakers: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talk speakers")),
+            )
+        })?
+        .iter()
+        .any(|s| s.email.to_lowercase() == email);
+    if already_speaking {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "This person is already a speaker on this talk",
+            )),
+        ));
+    }
+
+    let invite_token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+
+    // A previously declined invitation is re-issued with a fresh token.
+    // Pending and accepted ones are left alone: the address may belong to a
+    // co-speaker who accepted under a different account email.
+    let invitation = sqlx::query_as::<_, TalkSpeaker>(
+        r#"
+        INSERT INTO talk_speakers (talk_id, email, invite_token, invited_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (talk_id, email) DO UPDATE
+        SET status = 'invited',
+            user_id = NULL,
+            invite_token = EXCLUDED.invite_token,
+            invited_by = EXCLUDED.invited_by,
+            invited_at = NOW(),
+            responded_at = NULL
+        WHERE talk_speakers.status = 'declined'
+        RETURNING *
+        "#,
+    )
+    .bind(talk_id)
+    .bind(&email)
+    .bind(&invite_token)
+    .bind(user.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error creating invitation: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to create invitation")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "This address has already been invited to this talk",
+            )),
+        )
+    })?;
+
+    send_invitation_email(&state, &talk, &invitation, &user).await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(TalkSpeakerResponse {
+            invitation_id: Some(invitation.id),
+            user_id: None,
+            full_name: invitation.email.clone(),
+            email: invitation.email,
+            is_primary: false,
+            status: invitation.status,
+        }),
+    ))
+}
+
+/// Email the invitation link to a co-speaker. Uses the conference's
+/// co_speaker_invitation template when one exists.
+async fn send_invitation_email(
+    state: &AppState,
+    talk: &Talk,
+    invitation: &TalkSpeaker,
+    inviter: &User,
+) {
+    if !state.email_service.is_configured() {
+        return;
+    }
+
+    let conference_name =
+        sqlx::query_scalar::<_, String>("SELECT name FROM conferences WHERE id = $1")
+            .bind(talk.conference_id)
+            .fetch_optional(&state.db)
+            .await
+            .ok()
+            .flatten();
+
+    let invitation_url = format!(
+        "{}/speaker-invitations/{}",
+        state.config.app_url.trim_end_matches('/'),
+        invitation.invite_token
+    );
+
+    let variables = EmailVariables {
+        speaker_name: inviter.full_name.clone(),
+        speaker_email: invitation.email.clone(),
+        talk_title: talk.title.clone(),
+        talk_id: talk.id.to_string(),
+        conference_name,
+        reason: None,
+        schedule_date: None,
+        schedule_time: None,
+        track_name: None,
+        invitation_url: Some(invitation_url.clone()),
+        feedback: None,
+    };
+
+    let result = if state
+        .email_service
+        .get_template(talk.conference_id, "co_speaker_invitation")
+        .await
+        .is_ok()
+    {
+        state
+            .email_service
+            .send_templated_email(
+                talk.conference_id,
+                "co_speaker_invitation",
+                &invitation.email,
+                variables,
+                Some(talk.id),
+                Some(inviter.id),
+            )
+            .await
+    } else {
+        let subject = format!("You've been invited to co-present \"{}\"", talk.title);
+        let body = format!(
+            "{} has invited you to present \"{}\" with them.\n\n\
+             Accept or decline the invitation here:\n{}\n",
+            inviter.full_name, talk.title, invitation_url
+        );
+        state
+            .email_service
+            .send_email(
+                &invitation.email,
+                &subject,
+                &body,
+                None,
+                Some(talk.id),
+                Some(inviter.id),
+            )
+            .await
+    };
+
+    if let Err(e) = result {
+        tracing::warn!("Failed to send co-speaker invitation: {}", e);
+    }
+}
+
+/// Remove a co-speaker or cancel their invitation. The primary speaker can
+/// remove anyone; co-speakers can only remove themselves.
+pub async fn remove_co_speaker(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path((talk_id, invitation_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let talk = fetch_talk(&state.db, talk_id).await?;
+
+    let invitation = sqlx::query_as::<_, TalkSpeaker>(
+        "SELECT * FROM talk_speakers WHERE id = $1 AND talk_id = $2",
+    )
+    .bind(invitation_id)
+    .bind(talk_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching co-speaker: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch co-speaker")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Co-speaker not found")),
+        )
+    })?;
+
+    if talk.speaker_id != user.id && invitation.user_id != Some(user.id) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "Only the primary speaker can remove co-speakers",
+            )),
+        ));
+    }
+
+    sqlx::query("DELETE FROM talk_speakers WHERE id = $1")
+        .bind(invitation_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error removing co-speaker: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to remove co-speaker")),
+            )
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Look up an invitation so the invitee can decide whether to accept it
+pub async fn get_speaker_invitation(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<SpeakerInvitationResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let invitation = sqlx::query_as::<_, SpeakerInvitationResponse>(
+        r#"
+        SELECT
+            ts.id,
+            ts.talk_id,
+            t.title as talk_title,
+            c.name as conference_name,
+            u.full_name as invited_by_name,
+            ts.email,
+            ts.status
+        FROM talk_speakers ts
+        INNER JOIN talks t ON ts.talk_id = t.id
+        INNER JOIN conferences c ON t.conference_id = c.id
+        LEFT JOIN users u ON ts.invited_by = u.id
+        WHERE ts.invite_token = $1
+        "#,
+    )
+    .bind(&token)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error fetching invitation: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch invitation")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Invitation not found")),
+        )
+    })?;
+
+    Ok(Json(invitation))
+}
+
+/// Accept a co-speaker invitation as the current user
+pub async fn accept_speaker_invitation(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(token): Path<String>,
+) -> Result<Json<TalkSpeakerResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let invitation = fetch_invitation(&state.db, &token).await?;
+
+    if invitation.status != TalkSpeakerStatus::Invited {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "This invitation has already been responded to",
+            )),
+        ));
+    }
+
+    if is_talk_speaker(&state.db, invitation.talk_id, user.id).await? {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new("You are already a speaker on this talk")),
+        ));
+    }
+
+    let accepted = sqlx::query_as::<_, TalkSpeaker>(
+        r#"
+        UPDATE talk_speakers
+        SET status = 'accepted',
+            user_id = $1,
+            responded_at = $2
+        WHERE id = $3 AND status = 'invited'
+        RETURNING *
+        "#,
+    )
+    .bind(user.id)
+    .bind(Utc::now())
+    .bind(invitation.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error accepting invitation: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to accept invitation")),
+        )
+    })?
+    // Someone else responded between the check above and now
+    .ok_or_else(|| {
+        (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "This invitation has already been responded to",
+            )),
+        )
+    })?;
+
+    Ok(Json(TalkSpeakerResponse {
+        invitation_id: Some(accepted.id),
+        user_id: accepted.user_id,
+        full_name: user.full_name,
+        email: user.email,
+        is_primary: false,
+        status: accepted.status,
+    }))
+}
+
+/// Decline a co-speaker invitation
+pub async fn decline_speaker_invitation(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
+    let invitation = fetch_invitation(&state.db, &token).await?;
+
+    if invitation.status != TalkSpeakerStatus::Invited {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "This invitation has already been responded to",
+            )),
+        ));
+    }
+
+    let declined = sqlx::query(
+        r#"
+        UPDATE talk_speakers
+        SET status = 'declined',
+            responded_at = $1
+        WHERE id = $2 AND status = 'invited'
+        "#,
+    )
+    .bind(Utc::now())
+    .bind(invitation.id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error declining invitation: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to decline invitation")),
+        )
+    })?;
+    if declined.rows_affected() == 0 {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "This invitation has already been responded to",
+            )),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -3,23 +3,74 @@ use axum::{
     http::StatusCode,
     Extension, Json,
 };
-use chrono::Utc;
+use chrono::{NaiveTime, Utc};
 use serde::Deserialize;
+use sqlx::PgConnection;
 use std::path::PathBuf;
 use uuid::Uuid;
 
 use crate::{
     api::AppState,
+    handlers::{
+        blind_review::{hidden_speaker_talks, hide_speakers, speakers_hidden},
+        custom_fields::validate_talk_custom_answers,
+        review_phases::ensure_decisions_open,
+        schedule_slots::ensure_duration_fits_slot,
+        speaker_feedback::{fetch_approved_feedback, fetch_released_feedback},
+        submission_window::ensure_submissions_open,
+        talk_revisions::record_talk_revision,
+        talk_speakers::{fetch_talk_speakers, is_talk_speaker},
+        waitlist::close_waitlist_gap,
+    },
     models::{
-        auth::ErrorResponse, ChangeStateRequest, CreateTalkRequest, Label, LabelResponse,
-        RespondToTalkRequest, Talk, TalkAction, TalkResponse, TalkState, TalksListResponse,
-        UpdateTalkRequest, User,
+        auth::ErrorResponse, feedback_text, validate_talk_fields, ChangeStateRequest, Conference,
+        ConferenceFilterQuery, CreateTalkRequest, Label, LabelResponse, RespondToTalkRequest, Talk,
+        TalkAction, TalkFormat, TalkResponse, TalkSpeakerStatus, TalkState, TalksListResponse,
+        UpdateTalkRequest, User, WithdrawTalkRequest,
     },
+    services::email::EmailVariables,
 };
 
 #[derive(Debug, Deserialize)]
 pub struct ListTalksQuery {
     pub state: Option<String>,
+    pub conference_id: Option<Uuid>,
+    pub format: Option<TalkFormat>,
+    pub duration_minutes: Option<i32>,
+}
+
+/// Helper function to resolve the conference a new talk is submitted to.
+/// Falls back to the active conference when none is requested.
+async fn resolve_submission_conference(
+    db: &sqlx::PgPool,
+    conference_id: Option<Uuid>,
+) -> Result<Conference, (StatusCode, Json<ErrorResponse>)> {
+    let conference = match conference_id {
+        Some(id) => sqlx::query_as::<_, Conference>("SELECT * FROM conferences WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error fetching conference: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::new("Failed to fetch conference")),
+                )
+            })?,
+        None => crate::handlers::conferences::get_active_conference_internal(db)
+            .await
+            .ok(),
+    };
+
+    conference.ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(match conference_id {
+                Some(_) => "Conference not found",
+                None => "No active conference is accepting submissions",
+            })),
+        )
+    })
 }
 
 /// Helper function to fetch labels for a talk
@@ -42,57 +93,174 @@ async fn fetch_talk_labels(
     Ok(labels.into_iter().map(LabelResponse::from).collect())
 }
 
+/// Template emailed to a talk's speakers when an organizer moves it to `state`
+pub(crate) fn state_change_template(state: &TalkState) -> Option<&'static str> {
+    match state {
+        TalkState::Pending => Some("talk_pending"),
+        TalkState::Accepted => Some("talk_accepted"),
+        TalkState::Rejected => Some("talk_rejected"),
+        TalkState::Waitlisted => Some("talk_waitlisted"),
+        _ => None, // No email for other states
+    }
+}
+
+/// Helper function to build the email variables for each of a talk's
+/// accepted speakers
+pub(crate) async fn speaker_email_variables(
+    state: &AppState,
+    talk: &Talk,
+    reason: Option<String>,
+) -> Vec<EmailVariables> {
+    let speakers = fetch_talk_speakers(&state.db, talk.id)
+        .await
+        .unwrap_or_default();
+
+    let conference_name =
+        sqlx::query_scalar::<_, String>("SELECT name FROM conferences WHERE id = $1")
+            .bind(talk.conference_id)
+            .fetch_optional(&state.db)
+            .await
+            .ok()
+            .flatten();
+
+    // Released by the state the email announces, so a preview of a decision
+    // not applied yet renders the feedback the real email will carry
+    let feedback = if matches!(talk.state, TalkState::Draft | TalkState::Submitted) {
+        None
+    } else {
+        fetch_approved_feedback(&state.db, talk.id)
+            .await
+            .ok()
+            .and_then(|feedback| feedback_text(&feedback))
+    };
+
+    // Pending invitations haven't joined the talk yet
+    speakers
+        .into_iter()
+        .filter(|s| s.status == TalkSpeakerStatus::Accepted)
+        .map(|speaker| EmailVariables {
+            speaker_name: speaker.full_name,
+            speaker_email: speaker.email,
+            talk_title: talk.title.clone(),
+            talk_id: talk.id.to_string(),
+            conference_name: conference_name.clone(),
+            reason: reason.clone(),
+            schedule_date: None,
+            schedule_time: None,
+            track_name: None,
+            invitation_url: None,
+            feedback: feedback.clone(),
+        })
+        .collect()
+}
+
+/// Helper function to email a talk's accepted speakers using a template.
+/// Failures are logged and never fail the request.
+pub(crate) async fn notify_talk_speakers(
+    state: &AppState,
+    talk: &Talk,
+    template_type: &str,
+    reason: Option<String>,
+) {
+    if !state.email_service.is_configured() {
+        return;
+    }
+
+    for variables in speaker_email_variables(state, talk, reason).await {
+        let to_email = variables.speaker_email.clone();
+        let email_result = state
+            .email_service
+            .send_templated_email(
+                talk.conference_id,
+                template_type,
+                &to_email,
+                variables,
+                Some(talk.id),
+                None, // No specific sender (system-generated)
+            )
+            .await;
+
+        if let Err(e) = email_result {
+            tracing::warn!("Failed to send email notification: {}", e);
+        }
+    }
+}
+
 /// Create a new talk submission
 pub async fn create_talk(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
     Json(payload): Json<CreateTalkRequest>,
 ) -> Result<(StatusCode, Json<TalkResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Validate required fields
-    if payload.title.trim().is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Title is required")),
-        ));
-    }
+    let duration_minutes = payload
+        .duration_minutes
+        .unwrap_or_else(|| i32::from(state.config.submission.default_duration));
+    validate_talk_fields(
+        &state.config.submission,
+        Some(&payload.title),
+        Some(&payload.short_summary),
+        payload.long_description.as_deref(),
+        Some(duration_minutes),
+        payload.save_as_draft,
+    )?;
+
+    let conference = resolve_submission_conference(&state.db, payload.conference_id).await?;
+    ensure_submissions_open(&state, &conference, user.id).await?;
+
+    // Required custom fields apply even when no answers were sent,
+    // unless the talk is only being saved as a draft
+    let custom_answers = validate_talk_custom_answers(
+        &state.db,
+        conference.id,
+        payload
+            .custom_answers
+            .as_ref()
+            .unwrap_or(&serde_json::Value::Null),
+        !payload.save_as_draft,
+    )
+    .await?;
 
-    if payload.short_summary.trim().is_empty() {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Short summary is required")),
-        ));
-    }
+    let initial_state = if payload.save_as_draft {
+        TalkState::Draft
+    } else {
+        TalkState::Submitted
+    };
 
-    // Title length validation
-    if payload.title.len() > 500 {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::new("Title must be 500 characters or less")),
-        ));
-    }
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error creating talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to create talk")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
 
-    // Create the talk
+    // Create the talk and its first revision
     let talk = sqlx::query_as::<_, Talk>(
         r#"
-        INSERT INTO talks (speaker_id, title, short_summary, long_description, state)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO talks (conference_id, speaker_id, title, short_summary, long_description, state,
+                           duration_minutes, format, custom_answers)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
         RETURNING *
         "#,
     )
+    .bind(conference.id)
     .bind(user.id)
     .bind(payload.title.trim())
     .bind(payload.short_summary.trim())
     .bind(payload.long_description.as_ref().map(|s| s.trim()))
-    .bind(TalkState::Submitted)
-    .fetch_one(&state.db)
+    .bind(initial_state)
+    .bind(duration_minutes)
+    .bind(payload.format.unwrap_or(TalkFormat::Talk))
+    .bind(custom_answers)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| {
-        tracing::error!("Database error creating talk: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to create talk")),
-        )
-    })?;
+    .map_err(db_error)?;
+
+    record_talk_revision(&mut tx, &talk, user.id)
+        .await
+        .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
 
     // Add labels if provided
     if let Some(label_ids) = payload.label_ids {
@@ -112,6 +280,10 @@ pub async fn create_talk(
         }
     }
 
+    if talk.state == TalkState::Submitted {
+        notify_talk_speakers(&state, &talk, "submission_confirmation", None).await;
+    }
+
     // Fetch labels and return
     let labels = fetch_talk_labels(&state.db, talk.id)
         .await
@@ -121,19 +293,25 @@ pub async fn create_talk(
     Ok((StatusCode::CREATED, Json(response)))
 }
 
-/// Get all talks for the current user
+/// Get all talks the current user presents, optionally scoped to one conference
 pub async fn get_my_talks(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
+    Query(query): Query<ConferenceFilterQuery>,
 ) -> Result<Json<TalksListResponse>, (StatusCode, Json<ErrorResponse>)> {
     let talks = sqlx::query_as::<_, Talk>(
         r#"
         SELECT * FROM talks
-        WHERE speaker_id = $1
+        WHERE (speaker_id = $1 OR id IN (
+                SELECT talk_id FROM talk_speakers
+                WHERE user_id = $1 AND status = 'accepted'
+              ))
+          AND ($2::uuid IS NULL OR conference_id = $2)
         ORDER BY submitted_at DESC
         "#,
     )
     .bind(user.id)
+    .bind(query.conference_id)
     .fetch_all(&state.db)
     .await
     .map_err(|e| {
@@ -144,13 +322,24 @@ pub async fn get_my_talks(
         )
     })?;
 
-    // Fetch labels for each talk
+    // Fetch labels and speakers for each talk
     let mut responses = Vec::new();
     for talk in talks {
         let labels = fetch_talk_labels(&state.db, talk.id)
             .await
             .unwrap_or_default();
-        responses.push(TalkResponse::from(talk).with_labels(labels));
+        let speakers = fetch_talk_speakers(&state.db, talk.id)
+            .await
+            .unwrap_or_default();
+        let feedback = fetch_released_feedback(&state.db, talk.id)
+            .await
+            .unwrap_or_default();
+        responses.push(
+            TalkResponse::from(talk)
+                .with_labels(labels)
+                .with_speakers(speakers)
+                .with_feedback(feedback),
+        );
     }
 
     Ok(Json(TalksListResponse { talks: responses }))
@@ -186,8 +375,16 @@ pub async fn get_talk(
         )
     })?;
 
-    // Check if user can view this talk (speaker or organizer)
-    if talk.speaker_id != user.id && !user.is_organizer {
+    // Check if user can view this talk (speaker, co-speaker or organizer).
+    // Drafts stay private to their speakers until submitted.
+    let is_speaker = is_talk_speaker(&state.db, talk.id, user.id).await?;
+    if !is_speaker && talk.state == TalkState::Draft {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Talk not found")),
+        ));
+    }
+    if !user.is_organizer && !is_speaker {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -196,16 +393,30 @@ pub async fn get_talk(
         ));
     }
 
-    // Fetch labels for the talk
+    // Fetch labels and speakers for the talk
     let labels = fetch_talk_labels(&state.db, talk.id)
         .await
         .unwrap_or_default();
-    let response = TalkResponse::from(talk).with_labels(labels);
+    let speakers = fetch_talk_speakers(&state.db, talk.id)
+        .await
+        .unwrap_or_default();
+    let feedback = fetch_released_feedback(&state.db, talk.id)
+        .await
+        .unwrap_or_default();
+    let response = TalkResponse::from(talk)
+        .with_labels(labels)
+        .with_speakers(speakers)
+        .with_feedback(feedback);
+
+    // Under blind review, organizers only see speakers they revealed
+    if !is_speaker && speakers_hidden(&state.db, user.id, talk_id).await? {
+        return Ok(Json(response.anonymized()));
+    }
 
     Ok(Json(response))
 }
 
-/// Update a talk (only by the speaker who created it)
+/// Update a talk (by its speaker or an accepted co-speaker)
 pub async fn update_talk(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
@@ -238,7 +449,7 @@ pub async fn update_talk(
     })?;
 
     // Verify ownership
-    if existing_talk.speaker_id != user.id {
+    if !is_talk_speaker(&state.db, talk_id, user.id).await? {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -247,31 +458,28 @@ pub async fn update_talk(
         ));
     }
 
-    // Validate title length if provided
-    if let Some(ref title) = payload.title {
-        if title.trim().is_empty() {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new("Title cannot be empty")),
-            ));
-        }
-        if title.len() > 500 {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new("Title must be 500 characters or less")),
-            ));
-        }
-    }
+    // Edits follow the CfP window, including the primary speaker's extension
+    let conference = sqlx::query_as::<_, Conference>("SELECT * FROM conferences WHERE id = $1")
+        .bind(existing_talk.conference_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching conference: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch conference")),
+            )
+        })?;
+    ensure_submissions_open(&state, &conference, existing_talk.speaker_id).await?;
 
-    // Validate short summary if provided
-    if let Some(ref summary) = payload.short_summary {
-        if summary.trim().is_empty() {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::new("Short summary cannot be empty")),
-            ));
-        }
-    }
+    validate_talk_fields(
+        &state.config.submission,
+        payload.title.as_deref(),
+        payload.short_summary.as_deref(),
+        payload.long_description.as_deref(),
+        payload.duration_minutes,
+        existing_talk.state == TalkState::Draft,
+    )?;
 
     // Build the update query dynamically based on what's provided
     let title = payload
@@ -292,6 +500,54 @@ pub async fn update_talk(
         Some(url) => Some(url),
         None => existing_talk.slides_url.clone(),
     };
+    let duration_minutes = payload
+        .duration_minutes
+        .unwrap_or(existing_talk.duration_minutes);
+    let format = payload.format.unwrap_or(existing_talk.format);
+    let custom_answers = match payload.custom_answers {
+        Some(answers) => {
+            validate_talk_custom_answers(
+                &state.db,
+                existing_talk.conference_id,
+                &answers,
+                existing_talk.state != TalkState::Draft,
+            )
+            .await?
+        }
+        None => existing_talk.custom_answers.clone(),
+    };
+
+    // Only edits that change the content start a new revision
+    let content_changed = title != existing_talk.title
+        || short_summary != existing_talk.short_summary
+        || long_description != existing_talk.long_description
+        || slides_url != existing_talk.slides_url
+        || duration_minutes != existing_talk.duration_minutes
+        || format != existing_talk.format;
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to update talk")),
+        )
+    };
+
+    // A talk already on the schedule has to keep fitting its slots
+    if duration_minutes != existing_talk.duration_minutes {
+        let slots = sqlx::query_as::<_, (NaiveTime, NaiveTime)>(
+            "SELECT start_time, end_time FROM schedule_slots WHERE talk_id = $1",
+        )
+        .bind(talk_id)
+        .fetch_all(&state.db)
+        .await
+        .map_err(db_error)?;
+        for (start_time, end_time) in slots {
+            ensure_duration_fits_slot(duration_minutes, start_time, end_time)?;
+        }
+    }
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
 
     let updated_talk = sqlx::query_as::<_, Talk>(
         r#"
@@ -300,8 +556,11 @@ pub async fn update_talk(
             short_summary = $2,
             long_description = $3,
             slides_url = $4,
-            updated_at = $5
-        WHERE id = $6
+            duration_minutes = $5,
+            format = $6,
+            custom_answers = $7,
+            updated_at = $8
+        WHERE id = $9
         RETURNING *
         "#,
     )
@@ -309,19 +568,130 @@ pub async fn update_talk(
     .bind(short_summary)
     .bind(long_description)
     .bind(slides_url)
+    .bind(duration_minutes)
+    .bind(format)
+    .bind(custom_answers)
     .bind(Utc::now())
     .bind(talk_id)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    if content_changed {
+        record_talk_revision(&mut tx, &updated_talk, user.id)
+            .await
+            .map_err(db_error)?;
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    Ok(Json(TalkResponse::from(updated_talk)))
+}
+
+/// Submit a draft talk to the organizers (by its speaker or an accepted co-speaker)
+pub async fn submit_talk(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+) -> Result<Json<TalkResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let talk = sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talk")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Talk not found")),
+            )
+        })?;
+
+    if !is_talk_speaker(&state.db, talk_id, user.id).await? {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new("You can only submit your own talks")),
+        ));
+    }
+
+    if talk.state != TalkState::Draft {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Only draft talks can be submitted")),
+        ));
+    }
+
+    let conference = sqlx::query_as::<_, Conference>("SELECT * FROM conferences WHERE id = $1")
+        .bind(talk.conference_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching conference: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch conference")),
+            )
+        })?;
+    ensure_submissions_open(&state, &conference, talk.speaker_id).await?;
+
+    // Drafts may be incomplete, submissions may not
+    validate_talk_fields(
+        &state.config.submission,
+        Some(&talk.title),
+        Some(&talk.short_summary),
+        talk.long_description.as_deref(),
+        None,
+        false,
+    )?;
+    validate_talk_custom_answers(&state.db, talk.conference_id, &talk.custom_answers, true).await?;
+
+    let submitted_talk = sqlx::query_as::<_, Talk>(
+        r#"
+        UPDATE talks
+        SET state = $1,
+            submitted_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $2 AND state = $3
+        RETURNING *
+        "#,
+    )
+    .bind(TalkState::Submitted)
+    .bind(talk_id)
+    .bind(TalkState::Draft)
+    .fetch_optional(&state.db)
     .await
     .map_err(|e| {
-        tracing::error!("Database error updating talk: {}", e);
+        tracing::error!("Database error submitting talk: {}", e);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to update talk")),
+            Json(ErrorResponse::new("Failed to submit talk")),
+        )
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Only draft talks can be submitted")),
         )
     })?;
 
-    Ok(Json(TalkResponse::from(updated_talk)))
+    notify_talk_speakers(&state, &submitted_talk, "submission_confirmation", None).await;
+
+    let labels = fetch_talk_labels(&state.db, talk_id)
+        .await
+        .unwrap_or_default();
+    let speakers = fetch_talk_speakers(&state.db, talk_id)
+        .await
+        .unwrap_or_default();
+
+    Ok(Json(
+        TalkResponse::from(submitted_talk)
+            .with_labels(labels)
+            .with_speakers(speakers),
+    ))
 }
 
 /// Delete a talk (only by the speaker who created it)
@@ -414,7 +784,7 @@ pub async fn upload_slides(
     })?;
 
     // Verify ownership
-    if talk.speaker_id != user.id {
+    if !is_talk_speaker(&state.db, talk.id, user.id).await? {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -504,6 +874,15 @@ pub async fn upload_slides(
         )
     })?;
 
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to update talk")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
     // Update the talk with the slides URL
     let updated_talk = sqlx::query_as::<_, Talk>(
         r#"
@@ -517,15 +896,14 @@ pub async fn upload_slides(
     .bind(&slides_url)
     .bind(Utc::now())
     .bind(talk_id)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
-    .map_err(|e| {
-        tracing::error!("Database error updating talk: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::new("Failed to update talk")),
-        )
-    })?;
+    .map_err(db_error)?;
+
+    record_talk_revision(&mut tx, &updated_talk, user.id)
+        .await
+        .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
 
     Ok(Json(TalkResponse::from(updated_talk)))
 }
@@ -562,8 +940,8 @@ pub async fn respond_to_talk(
         )
     })?;
 
-    // Verify ownership - only the speaker can respond
-    if talk.speaker_id != user.id {
+    // Verify ownership - only the talk's speakers can respond
+    if !is_talk_speaker(&state.db, talk.id, user.id).await? {
         return Err((
             StatusCode::FORBIDDEN,
             Json(ErrorResponse::new(
@@ -614,6 +992,216 @@ pub async fn respond_to_talk(
     Ok(Json(TalkResponse::from(updated_talk)))
 }
 
+/// Helper function to tell every organizer that a speaker withdrew a talk
+async fn notify_organizers_of_withdrawal(
+    state: &AppState,
+    talk: &Talk,
+    withdrawn_by: &User,
+    freed_slots: &[(chrono::NaiveDate, chrono::NaiveTime, String)],
+) {
+    if !state.email_service.is_configured() {
+        return;
+    }
+
+    let organizers = sqlx::query_as::<_, (String,)>(
+        "SELECT email FROM users WHERE is_organizer = true ORDER BY email",
+    )
+    .fetch_all(&state.db)
+    .await
+    .unwrap_or_default();
+
+    let subject = format!("Talk withdrawn: \"{}\"", talk.title);
+    let mut body = format!(
+        "{} has withdrawn the accepted talk \"{}\".\n\nReason:\n{}\n",
+        withdrawn_by.full_name,
+        talk.title,
+        talk.withdrawal_reason.as_deref().unwrap_or_default()
+    );
+    if !freed_slots.is_empty() {
+        body.push_str("\nThese schedule slots are now free:\n");
+        for (date, start_time, track_name) in freed_slots {
+            body.push_str(&format!(
+                "- {} {} ({})\n",
+                date,
+                start_time.format("%H:%M"),
+                track_name
+            ));
+        }
+    }
+
+    for (email,) in organizers {
+        if let Err(e) = state
+            .email_service
+            .send_email(
+                &email,
+                &subject,
+                &body,
+                None,
+                Some(talk.id),
+                Some(withdrawn_by.id),
+            )
+            .await
+        {
+            tracing::warn!("Failed to send withdrawal notification: {}", e);
+        }
+    }
+}
+
+/// Withdraw an accepted talk (by its speaker or an accepted co-speaker).
+/// The talk is kept for reporting but leaves the schedule.
+pub async fn withdraw_talk(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(talk_id): Path<Uuid>,
+    Json(payload): Json<WithdrawTalkRequest>,
+) -> Result<Json<TalkResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let reason = payload.reason.trim();
+    if reason.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "A reason is required to withdraw a talk",
+            )),
+        ));
+    }
+
+    let talk = sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talk")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Talk not found")),
+            )
+        })?;
+
+    if !is_talk_speaker(&state.db, talk_id, user.id).await? {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new("You can only withdraw your own talks")),
+        ));
+    }
+
+    if !talk.state.can_transition_to(&TalkState::Withdrawn) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Only accepted talks can be withdrawn")),
+        ));
+    }
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error withdrawing talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to withdraw talk")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    // Only withdraw the talk if nobody changed its state since it was checked
+    let withdrawn_talk = sqlx::query_as::<_, Talk>(
+        r#"
+        UPDATE talks
+        SET state = $1,
+            withdrawal_reason = $2,
+            withdrawn_at = NOW(),
+            updated_at = NOW()
+        WHERE id = $3 AND state = $4
+        RETURNING *
+        "#,
+    )
+    .bind(TalkState::Withdrawn)
+    .bind(reason)
+    .bind(talk_id)
+    .bind(&talk.state)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(db_error)?
+    .ok_or_else(|| {
+        (
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "The talk's state has just changed; reload it and try again",
+            )),
+        )
+    })?;
+
+    // Free the talk's place in the schedule
+    let freed_slots = sqlx::query_as::<_, (chrono::NaiveDate, chrono::NaiveTime, String)>(
+        r#"
+        WITH freed AS (
+            UPDATE schedule_slots
+            SET talk_id = NULL, updated_at = NOW()
+            WHERE talk_id = $1
+            RETURNING slot_date, start_time, track_id
+        )
+        SELECT f.slot_date, f.start_time, tr.name
+        FROM freed f
+        INNER JOIN tracks tr ON f.track_id = tr.id
+        ORDER BY f.slot_date, f.start_time
+        "#,
+    )
+    .bind(talk_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    tracing::info!(
+        "Talk {} withdrawn by {} ({} schedule slots freed)",
+        talk_id,
+        user.id,
+        freed_slots.len()
+    );
+
+    notify_organizers_of_withdrawal(&state, &withdrawn_talk, &user, &freed_slots).await;
+
+    Ok(Json(TalkResponse::from(withdrawn_talk)))
+}
+
+/// Move a talk to a new state on behalf of an organizer. Newly waitlisted
+/// talks go to the end of the waitlist.
+pub(crate) async fn set_talk_state(
+    conn: &mut PgConnection,
+    talk: &Talk,
+    new_state: &TalkState,
+) -> Result<Talk, sqlx::Error> {
+    let updated_talk = sqlx::query_as::<_, Talk>(
+        r#"
+        UPDATE talks
+        SET state = $1,
+            updated_at = $2,
+            waitlist_rank = CASE
+                WHEN $1::talk_state = 'waitlisted' THEN (
+                    SELECT COALESCE(MAX(w.waitlist_rank), 0) + 1
+                    FROM talks w
+                    WHERE w.conference_id = talks.conference_id AND w.state = 'waitlisted'
+                )
+                ELSE NULL
+            END
+        WHERE id = $3
+        RETURNING *
+        "#,
+    )
+    .bind(new_state)
+    .bind(Utc::now())
+    .bind(talk.id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    close_waitlist_gap(conn, talk).await?;
+    Ok(updated_talk)
+}
+
 /// Change talk state (organizer-only)
 pub async fn change_talk_state(
     State(state): State<AppState>,
@@ -645,6 +1233,24 @@ pub async fn change_talk_state(
         )
     })?;
 
+    if talk.state == TalkState::Draft {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Draft talks can only be submitted by their speakers",
+            )),
+        ));
+    }
+    if payload.new_state == TalkState::Withdrawn {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "Talks can only be withdrawn by their speakers",
+            )),
+        ));
+    }
+    ensure_decisions_open(&state, talk.conference_id).await?;
+
     // Validate state transition
     if !talk.state.can_transition_to(&payload.new_state) {
         return Err((
@@ -656,28 +1262,19 @@ pub async fn change_talk_state(
         ));
     }
 
-    // Update the talk state
-    let updated_talk = sqlx::query_as::<_, Talk>(
-        r#"
-        UPDATE talks
-        SET state = $1,
-            updated_at = $2
-        WHERE id = $3
-        RETURNING *
-        "#,
-    )
-    .bind(&payload.new_state)
-    .bind(Utc::now())
-    .bind(talk_id)
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| {
+    let db_error = |e: sqlx::Error| {
         tracing::error!("Database error updating talk state: {}", e);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse::new("Failed to update talk state")),
         )
-    })?;
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    let updated_talk = set_talk_state(&mut tx, &talk, &payload.new_state)
+        .await
+        .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
 
     tracing::info!(
         "Talk {} state changed from {:?} to {:?}{}",
@@ -691,72 +1288,19 @@ pub async fn change_talk_state(
             .unwrap_or_default()
     );
 
-    // Send email notification to speaker about state change
-    if state.email_service.is_configured() {
-        // Fetch speaker info
-        let speaker_result: Result<(String, String), sqlx::Error> = sqlx::query_as(
-            r#"
-            SELECT full_name, email FROM users
-            WHERE id = $1
-            "#,
-        )
-        .bind(updated_talk.speaker_id)
-        .fetch_one(&state.db)
-        .await;
-
-        if let Ok((speaker_name, speaker_email)) = speaker_result {
-            // Determine template type based on new state
-            let template_type = match payload.new_state {
-                TalkState::Pending => "talk_pending",
-                TalkState::Accepted => "talk_accepted",
-                TalkState::Rejected => "talk_rejected",
-                _ => "", // No email for other states
-            };
-
-            if !template_type.is_empty() {
-                // Get conference_id from talk (we'll need to add this to Talk struct or fetch separately)
-                // For now, use a default/active conference
-                if let Ok(conf) =
-                    crate::handlers::conferences::get_active_conference_internal(&state.db).await
-                {
-                    let variables = crate::services::email::EmailVariables {
-                        speaker_name,
-                        speaker_email: speaker_email.clone(),
-                        talk_title: updated_talk.title.clone(),
-                        talk_id: updated_talk.id.to_string(),
-                        reason: payload.reason.clone(),
-                        schedule_date: None,
-                        schedule_time: None,
-                        track_name: None,
-                    };
-
-                    // Send email asynchronously (don't block on errors)
-                    let email_result = state
-                        .email_service
-                        .send_templated_email(
-                            conf.id,
-                            template_type,
-                            &speaker_email,
-                            variables,
-                            Some(updated_talk.id),
-                            None, // No specific sender (system-generated)
-                        )
-                        .await;
-
-                    if let Err(e) = email_result {
-                        tracing::warn!("Failed to send email notification: {}", e);
-                    }
-                }
-            }
-        }
+    // Send email notification to every speaker about the state change
+    if let Some(template_type) = state_change_template(&payload.new_state) {
+        notify_talk_speakers(&state, &updated_talk, template_type, payload.reason.clone()).await;
     }
 
     Ok(Json(TalkResponse::from(updated_talk)))
 }
 
-/// List all talks (organizer-only) with optional state filtering
+/// List all talks (organizer-only) with optional state, conference, format and
+/// duration filtering
 pub async fn list_all_talks(
     State(state): State<AppState>,
+    Extension(user): Extension<User>,
     Query(query): Query<ListTalksQuery>,
 ) -> Result<Json<TalksListResponse>, (StatusCode, Json<ErrorResponse>)> {
     // Build query with optional state filtering
@@ -767,11 +1311,13 @@ pub async fn list_all_talks(
             "pending" => TalkState::Pending,
             "accepted" => TalkState::Accepted,
             "rejected" => TalkState::Rejected,
+            "withdrawn" => TalkState::Withdrawn,
+            "waitlisted" => TalkState::Waitlisted,
             _ => {
                 return Err((
                     StatusCode::BAD_REQUEST,
                     Json(ErrorResponse::new(
-                        "Invalid state filter. Use: submitted, pending, accepted, or rejected",
+                        "Invalid state filter. Use: submitted, pending, accepted, rejected, withdrawn, or waitlisted",
                     )),
                 ));
             }
@@ -781,19 +1327,32 @@ pub async fn list_all_talks(
             r#"
             SELECT t.* FROM talks t
             WHERE t.state = $1
+              AND ($2::uuid IS NULL OR t.conference_id = $2)
+              AND ($3::talk_format IS NULL OR t.format = $3)
+              AND ($4::int IS NULL OR t.duration_minutes = $4)
             ORDER BY t.submitted_at DESC
             "#,
         )
         .bind(talk_state)
+        .bind(query.conference_id)
+        .bind(query.format)
+        .bind(query.duration_minutes)
         .fetch_all(&state.db)
         .await
     } else {
         sqlx::query_as::<_, Talk>(
             r#"
             SELECT t.* FROM talks t
+            WHERE t.state <> 'draft'
+              AND ($1::uuid IS NULL OR t.conference_id = $1)
+              AND ($2::talk_format IS NULL OR t.format = $2)
+              AND ($3::int IS NULL OR t.duration_minutes = $3)
             ORDER BY t.submitted_at DESC
             "#,
         )
+        .bind(query.conference_id)
+        .bind(query.format)
+        .bind(query.duration_minutes)
         .fetch_all(&state.db)
         .await
     }
@@ -831,13 +1390,30 @@ pub async fn list_all_talks(
             .await
             .unwrap_or_default();
 
+        let speakers = fetch_talk_speakers(&state.db, talk.id)
+            .await
+            .unwrap_or_default();
+
         // Build response with speaker info and labels
         let response = TalkResponse::from(talk)
             .with_speaker_info(speaker.0, speaker.1)
-            .with_labels(labels);
+            .with_labels(labels)
+            .with_speakers(speakers);
 
         responses.push(response);
     }
 
-    Ok(Json(TalksListResponse { talks: responses }))
+    let hidden = hidden_speaker_talks(&state.db, user.id, query.conference_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error checking blind review: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talks")),
+            )
+        })?;
+
+    Ok(Json(TalksListResponse {
+        talks: hide_speakers(responses, &hidden),
+    }))
 }
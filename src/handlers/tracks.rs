@@ -7,7 +7,10 @@ use uuid::Uuid;
 
 use crate::{
     api::AppState,
-    models::{auth::ErrorResponse, CreateTrackRequest, Track, TrackResponse, UpdateTrackRequest},
+    models::{
+        auth::ErrorResponse, CreateTrackRequest, Label, LabelResponse, SetTrackLabelsRequest,
+        Track, TrackResponse, UpdateTrackRequest,
+    },
 };
 
 /// List all tracks (public endpoint)
@@ -250,3 +253,98 @@ pub async fn delete_track(
     tracing::info!("Track deleted: {}", track_id);
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Helper function to fetch the labels a track is meant for
+async fn fetch_track_labels(
+    db: &sqlx::PgPool,
+    track_id: Uuid,
+) -> Result<Vec<LabelResponse>, sqlx::Error> {
+    let labels = sqlx::query_as::<_, Label>(
+        r#"
+        SELECT l.* FROM labels l
+        JOIN track_labels tl ON tl.label_id = l.id
+        WHERE tl.track_id = $1
+        ORDER BY l.name ASC
+        "#,
+    )
+    .bind(track_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(labels.into_iter().map(LabelResponse::from).collect())
+}
+
+/// Get the labels a track is meant for (public endpoint)
+pub async fn get_track_labels(
+    State(state): State<AppState>,
+    Path(track_id): Path<Uuid>,
+) -> Result<Json<Vec<LabelResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let labels = fetch_track_labels(&state.db, track_id).await.map_err(|e| {
+        tracing::error!("Database error fetching track labels: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch track labels")),
+        )
+    })?;
+
+    Ok(Json(labels))
+}
+
+/// Replace the labels a track is meant for (organizer only). The
+/// auto-scheduler prefers to place talks with these labels in the track.
+pub async fn set_track_labels(
+    State(state): State<AppState>,
+    Path(track_id): Path<Uuid>,
+    Json(payload): Json<SetTrackLabelsRequest>,
+) -> Result<Json<Vec<LabelResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error updating track labels: {}", e);
+        match e {
+            sqlx::Error::Database(db_err) if db_err.is_foreign_key_violation() => (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new("Unknown label")),
+            ),
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to update track labels")),
+            ),
+        }
+    };
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+    let exists = sqlx::query_scalar::<_, bool>("SELECT EXISTS(SELECT 1 FROM tracks WHERE id = $1)")
+        .bind(track_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    if !exists {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new("Track not found")),
+        ));
+    }
+
+    sqlx::query("DELETE FROM track_labels WHERE track_id = $1")
+        .bind(track_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    sqlx::query(
+        r#"
+        INSERT INTO track_labels (track_id, label_id)
+        SELECT $1, UNNEST($2::uuid[])
+        ON CONFLICT DO NOTHING
+        "#,
+    )
+    .bind(track_id)
+    .bind(&payload.label_ids)
+    .execute(&mut *tx)
+    .await
+    .map_err(db_error)?;
+    tx.commit().await.map_err(db_error)?;
+
+    let labels = fetch_track_labels(&state.db, track_id)
+        .await
+        .map_err(db_error)?;
+    Ok(Json(labels))
+}
@@ -0,0 +1,318 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    api::AppState,
+    handlers::{
+        blind_review::{hidden_speaker_talks, hide_speakers},
+        schedule_conflicts::check_slot_conflicts_in,
+        schedule_slots::ensure_talk_fits_slot,
+        talk_speakers::fetch_talk_speakers,
+        talks::notify_talk_speakers,
+    },
+    models::{
+        auth::ErrorResponse, PromoteTalkRequest, PromoteTarget, ReorderWaitlistRequest,
+        ScheduleSlot, Talk, TalkResponse, TalkState, TalksListResponse, User,
+    },
+};
+
+/// Move the talks ranked below `talk` up one place once it leaves the waitlist
+pub(crate) async fn close_waitlist_gap(
+    conn: &mut PgConnection,
+    talk: &Talk,
+) -> Result<(), sqlx::Error> {
+    let Some(rank) = talk.waitlist_rank else {
+        return Ok(());
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE talks
+        SET waitlist_rank = waitlist_rank - 1
+        WHERE conference_id = $1 AND state = 'waitlisted' AND waitlist_rank > $2
+        "#,
+    )
+    .bind(talk.conference_id)
+    .bind(rank)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Helper function to fetch a conference's waitlist in rank order
+async fn fetch_waitlist(db: &sqlx::PgPool, conference_id: Uuid) -> Result<Vec<Talk>, sqlx::Error> {
+    sqlx::query_as::<_, Talk>(
+        r#"
+        SELECT * FROM talks
+        WHERE conference_id = $1 AND state = 'waitlisted'
+        ORDER BY waitlist_rank ASC, submitted_at ASC
+        "#,
+    )
+    .bind(conference_id)
+    .fetch_all(db)
+    .await
+}
+
+/// Helper function to build the list response for a waitlist, hiding
+/// speakers the organizer hasn't revealed under blind review
+async fn waitlist_response(
+    db: &sqlx::PgPool,
+    user: &User,
+    conference_id: Uuid,
+    talks: Vec<Talk>,
+) -> Result<Json<TalksListResponse>, sqlx::Error> {
+    let hidden = hidden_speaker_talks(db, user.id, Some(conference_id)).await?;
+    let mut responses = Vec::with_capacity(talks.len());
+    for talk in talks {
+        let speakers = fetch_talk_speakers(db, talk.id).await.unwrap_or_default();
+        responses.push(TalkResponse::from(talk).with_speakers(speakers));
+    }
+    Ok(Json(TalksListResponse {
+        talks: hide_speakers(responses, &hidden),
+    }))
+}
+
+/// List a conference's waitlisted talks in promotion order (organizer only)
+pub async fn list_waitlist(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+) -> Result<Json<TalksListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error fetching waitlist: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to fetch waitlist")),
+        )
+    };
+
+    let talks = fetch_waitlist(&state.db, conference_id)
+        .await
+        .map_err(db_error)?;
+
+    waitlist_response(&state.db, &user, conference_id, talks)
+        .await
+        .map_err(db_error)
+}
+
+/// Reorder a conference's waitlist (organizer only). The request must list
+/// every waitlisted talk exactly once.
+pub async fn reorder_waitlist(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(conference_id): Path<Uuid>,
+    Json(payload): Json<ReorderWaitlistRequest>,
+) -> Result<Json<TalksListResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error reordering waitlist: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to reorder waitlist")),
+        )
+    };
+
+    let current = fetch_waitlist(&state.db, conference_id)
+        .await
+        .map_err(db_error)?;
+
+    let mut requested = payload.talk_ids.clone();
+    requested.sort();
+    requested.dedup();
+    let mut expected: Vec<Uuid> = current.iter().map(|t| t.id).collect();
+    expected.sort();
+    if requested.len() != payload.talk_ids.len() || requested != expected {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "The new order must list every waitlisted talk exactly once",
+            )),
+        ));
+    }
+
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+    for (position, talk_id) in payload.talk_ids.iter().enumerate() {
+        sqlx::query("UPDATE talks SET waitlist_rank = $1 WHERE id = $2")
+            .bind(position as i32 + 1)
+            .bind(talk_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+    }
+    tx.commit().await.map_err(db_error)?;
+
+    let talks = fetch_waitlist(&state.db, conference_id)
+        .await
+        .map_err(db_error)?;
+    waitlist_response(&state.db, &user, conference_id, talks)
+        .await
+        .map_err(db_error)
+}
+
+/// Promote a waitlisted talk to pending or accepted (organizer only),
+/// optionally placing it straight into a free schedule slot
+pub async fn promote_talk(
+    State(state): State<AppState>,
+    Path(talk_id): Path<Uuid>,
+    Json(payload): Json<PromoteTalkRequest>,
+) -> Result<Json<TalkResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let talk = sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error fetching talk: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("Failed to fetch talk")),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new("Talk not found")),
+            )
+        })?;
+
+    if talk.state != TalkState::Waitlisted {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("Only waitlisted talks can be promoted")),
+        ));
+    }
+
+    let slot = match payload.slot_id {
+        Some(slot_id) => {
+            let slot =
+                sqlx::query_as::<_, ScheduleSlot>("SELECT * FROM schedule_slots WHERE id = $1")
+                    .bind(slot_id)
+                    .fetch_optional(&state.db)
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Database error fetching schedule slot: {}", e);
+                        (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            Json(ErrorResponse::new("Failed to fetch schedule slot")),
+                        )
+                    })?
+                    .filter(|slot| slot.conference_id == talk.conference_id)
+                    .ok_or_else(|| {
+                        (
+                            StatusCode::NOT_FOUND,
+                            Json(ErrorResponse::new("Schedule slot not found")),
+                        )
+                    })?;
+            ensure_talk_fits_slot(&state.db, talk_id, slot.start_time, slot.end_time).await?;
+            Some(slot)
+        }
+        None => None,
+    };
+
+    let new_state = match payload.target {
+        PromoteTarget::Pending => TalkState::Pending,
+        PromoteTarget::Accepted => TalkState::Accepted,
+    };
+
+    let db_error = |e: sqlx::Error| {
+        tracing::error!("Database error promoting talk: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("Failed to promote talk")),
+        )
+    };
+    let mut tx = state.db.begin().await.map_err(db_error)?;
+
+    // Lock the talk and read it again, so a concurrent promotion, withdrawal
+    // or reordering can't leave this one acting on a stale state or rank
+    let talk = sqlx::query_as::<_, Talk>("SELECT * FROM talks WHERE id = $1 FOR UPDATE")
+        .bind(talk_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(db_error)?;
+    if talk.state != TalkState::Waitlisted {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "The talk's state has just changed; reload it and try again",
+            )),
+        ));
+    }
+
+    let promoted_talk = sqlx::query_as::<_, Talk>(
+        r#"
+        UPDATE talks
+        SET state = $1,
+            waitlist_rank = NULL,
+            updated_at = NOW()
+        WHERE id = $2
+        RETURNING *
+        "#,
+    )
+    .bind(&new_state)
+    .bind(talk_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(db_error)?;
+
+    close_waitlist_gap(&mut tx, &talk).await.map_err(db_error)?;
+
+    if let Some(slot) = slot {
+        // Check the schedule as it stands inside this transaction; warnings
+        // don't stop a promotion, errors do
+        let slot_id = slot.id;
+        check_slot_conflicts_in(
+            &mut tx,
+            &ScheduleSlot {
+                talk_id: Some(talk_id),
+                ..slot
+            },
+        )
+        .await?;
+
+        // Only fill the slot if nothing took it in the meantime
+        let assigned = sqlx::query(
+            r#"
+            UPDATE schedule_slots
+            SET talk_id = $1, updated_at = NOW()
+            WHERE id = $2 AND talk_id IS NULL
+            "#,
+        )
+        .bind(talk_id)
+        .bind(slot_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+        if assigned.rows_affected() == 0 {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ErrorResponse::new("Schedule slot is already taken")),
+            ));
+        }
+    }
+
+    tx.commit().await.map_err(db_error)?;
+
+    tracing::info!(
+        "Waitlisted talk {} promoted to {:?}{}",
+        talk_id,
+        new_state,
+        payload
+            .slot_id
+            .map(|id| format!(" in schedule slot {}", id))
+            .unwrap_or_default()
+    );
+
+    let template_type = match new_state {
+        TalkState::Accepted => "talk_accepted",
+        _ => "talk_pending",
+    };
+    notify_talk_speakers(&state, &promoted_talk, template_type, None).await;
+
+    Ok(Json(TalkResponse::from(promoted_talk)))
+}
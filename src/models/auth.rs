@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use uuid::Uuid;
 
+use super::validation::FieldError;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Type, PartialEq)]
 #[sqlx(type_name = "auth_provider_type", rename_all = "lowercase")]
 pub enum AuthProviderType {
@@ -126,12 +128,30 @@ pub struct Claims {
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
     pub error: String,
+    /// Per-input problems, set when a request fails validation
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
 }
 
 impl ErrorResponse {
     pub fn new(error: impl Into<String>) -> Self {
         Self {
             error: error.into(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Build a validation error, summarizing the field errors in `error`
+    /// for clients that only show a single message
+    pub fn with_field_errors(errors: Vec<FieldError>) -> Self {
+        let summary = errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ");
+        Self {
+            error: summary,
+            errors,
         }
     }
 }
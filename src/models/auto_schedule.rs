@@ -0,0 +1,502 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::schedule_conflict::{minutes_between, overlaps, CHANGEOVER_MINUTES};
+use super::{ScheduleConflict, ScheduleSlot, ScheduleSnapshot, ScheduledTalk};
+
+/// Bonus for a slot in a track meant for one of the talk's labels
+const AFFINITY_BONUS: i64 = 10;
+/// Penalty for a slot in a track meant only for other labels
+const OFF_TOPIC_PENALTY: i64 = 5;
+/// Penalty per talk already on that day sharing one of the talk's labels
+const SAME_DAY_TOPIC_PENALTY: i64 = 3;
+/// Penalty for leaving a speaker less than `CHANGEOVER_MINUTES` to change rooms
+const CHANGEOVER_PENALTY: i64 = 4;
+/// One point off for each this many minutes a slot is longer than the talk
+const SLACK_MINUTES_PER_POINT: i64 = 15;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ScheduleProposalRequest {
+    /// Also place talks still waiting for their speaker to confirm
+    #[serde(default)]
+    pub include_pending: bool,
+}
+
+/// A talk the scheduler would put in a free slot
+#[derive(Debug, Clone, Serialize)]
+pub struct ProposedAssignment {
+    pub slot_id: Uuid,
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub track_id: Uuid,
+    pub track_name: String,
+    pub slot_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    /// Why this slot was picked, for organizers reviewing the proposal
+    pub reasons: Vec<String>,
+}
+
+/// A talk the scheduler found no slot for
+#[derive(Debug, Clone, Serialize)]
+pub struct UnplacedTalk {
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub reason: String,
+}
+
+/// A proposed schedule. Nothing is saved until the assignments are committed.
+#[derive(Debug, Serialize)]
+pub struct ScheduleProposal {
+    /// In schedule order
+    pub assignments: Vec<ProposedAssignment>,
+    pub unplaced: Vec<UnplacedTalk>,
+    /// Slots that already had a talk and were left alone
+    pub pinned_count: usize,
+    /// Warnings the proposed assignments would cause
+    pub warnings: Vec<ScheduleConflict>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct SlotAssignment {
+    pub slot_id: Uuid,
+    pub talk_id: Uuid,
+}
+
+/// The assignments an organizer kept, possibly tweaked, from a proposal
+#[derive(Debug, Deserialize)]
+pub struct CommitScheduleRequest {
+    pub assignments: Vec<SlotAssignment>,
+}
+
+/// Scheduling state: the slots taken so far and the talks in them
+struct Board<'a> {
+    track_labels: &'a HashMap<Uuid, HashSet<Uuid>>,
+    /// Every slot holding a talk, pinned or placed, with its talk
+    taken: Vec<(&'a ScheduleSlot, &'a ScheduledTalk)>,
+    placed: HashSet<Uuid>,
+}
+
+impl<'a> Board<'a> {
+    fn is_free(&self, slot: &ScheduleSlot) -> bool {
+        slot.talk_id.is_none() && !self.placed.contains(&slot.id)
+    }
+
+    fn shares_speaker(a: &ScheduledTalk, b: &ScheduledTalk) -> bool {
+        a.speakers
+            .iter()
+            .any(|(id, _)| b.speakers.iter().any(|(other, _)| other == id))
+    }
+
+    fn speaker_clash(&self, slot: &ScheduleSlot, talk: &ScheduledTalk) -> bool {
+        self.taken.iter().any(|(other, other_talk)| {
+            overlaps(slot, other) && Self::shares_speaker(talk, other_talk)
+        })
+    }
+
+    fn tight_changeover(&self, slot: &ScheduleSlot, talk: &ScheduledTalk) -> bool {
+        self.taken.iter().any(|(other, other_talk)| {
+            other.slot_date == slot.slot_date
+                && other.track_id != slot.track_id
+                && Self::shares_speaker(talk, other_talk)
+                && {
+                    let gap = if other.end_time <= slot.start_time {
+                        minutes_between(other.end_time, slot.start_time)
+                    } else {
+                        minutes_between(slot.end_time, other.start_time)
+                    };
+                    (0..CHANGEOVER_MINUTES).contains(&gap)
+                }
+        })
+    }
+
+    /// Talks on the slot's day sharing a label with `talk`
+    fn same_day_topics(&self, slot: &ScheduleSlot, talk: &ScheduledTalk) -> i64 {
+        self.taken
+            .iter()
+            .filter(|(other, other_talk)| {
+                other.slot_date == slot.slot_date
+                    && other_talk
+                        .label_ids
+                        .iter()
+                        .any(|label| talk.label_ids.contains(label))
+            })
+            .count() as i64
+    }
+
+    /// How well `slot` suits `talk`, with the reasons worth telling the
+    /// organizer. Higher is better.
+    fn score(&self, slot: &ScheduleSlot, talk: &ScheduledTalk) -> (i64, Vec<String>) {
+        let mut score = 0;
+        let mut reasons = Vec::new();
+
+        if let Some(labels) = self.track_labels.get(&slot.track_id) {
+            if !labels.is_empty() {
+                if talk.label_ids.iter().any(|label| labels.contains(label)) {
+                    score += AFFINITY_BONUS;
+                    reasons.push("The track is meant for its topic".to_string());
+                } else {
+                    score -= OFF_TOPIC_PENALTY;
+                }
+            }
+        }
+
+        let same_day = self.same_day_topics(slot, talk);
+        if same_day > 0 {
+            score -= SAME_DAY_TOPIC_PENALTY * same_day;
+            reasons.push(format!("{} other talk(s) on its topic that day", same_day));
+        }
+
+        if self.tight_changeover(slot, talk) {
+            score -= CHANGEOVER_PENALTY;
+            reasons.push("A speaker has a tight changeover".to_string());
+        }
+
+        let slack =
+            minutes_between(slot.start_time, slot.end_time) - i64::from(talk.duration_minutes);
+        score -= slack / SLACK_MINUTES_PER_POINT;
+
+        (score, reasons)
+    }
+}
+
+/// Propose where to put `candidates` in the free slots of `snapshot`.
+/// Slots that already hold a talk stay as they are. Talks with the fewest
+/// fitting slots go first, longer talks breaking ties; each takes the slot
+/// that best matches its track's labels (`track_labels`, by track id)
+/// while spreading talks on the same topic across days. A slot only fits
+/// if it is long enough, falls within every speaker's availability and
+/// none of the talk's speakers is presenting at the same time.
+pub fn plan_schedule(
+    snapshot: &ScheduleSnapshot,
+    candidates: &[ScheduledTalk],
+    track_labels: &HashMap<Uuid, HashSet<Uuid>>,
+) -> ScheduleProposal {
+    let mut slots: Vec<&ScheduleSlot> = snapshot
+        .slots
+        .iter()
+        .filter(|s| s.slot_date >= snapshot.start_date && s.slot_date <= snapshot.end_date)
+        .collect();
+    slots.sort_by_key(|s| (s.slot_date, s.start_time, s.track_id));
+
+    let mut board = Board {
+        track_labels,
+        taken: snapshot
+            .slots
+            .iter()
+            .filter_map(|s| Some((s, snapshot.talks.get(&s.talk_id?)?)))
+            .collect(),
+        placed: HashSet::new(),
+    };
+    let pinned_count = snapshot
+        .slots
+        .iter()
+        .filter(|s| s.talk_id.is_some())
+        .count();
+
+    let long_enough = |slot: &ScheduleSlot, talk: &ScheduledTalk| {
+        minutes_between(slot.start_time, slot.end_time) >= i64::from(talk.duration_minutes)
+    };
+    let speakers_available =
+        |slot: &ScheduleSlot, talk: &ScheduledTalk| talk.availability_misses(slot).is_empty();
+
+    let mut placements: Vec<(&ScheduleSlot, &ScheduledTalk, Vec<String>)> = Vec::new();
+    let mut unplaced = Vec::new();
+    let mut remaining: Vec<&ScheduledTalk> = candidates.iter().collect();
+
+    while !remaining.is_empty() {
+        let fitting: Vec<Vec<&ScheduleSlot>> = remaining
+            .iter()
+            .map(|talk| {
+                slots
+                    .iter()
+                    .copied()
+                    .filter(|slot| {
+                        board.is_free(slot)
+                            && long_enough(slot, talk)
+                            && speakers_available(slot, talk)
+                            && !board.speaker_clash(slot, talk)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // min_by_key keeps the first of equals, so ties go in candidate order
+        let Some(next) = (0..remaining.len()).min_by_key(|&i| {
+            (
+                fitting[i].len(),
+                std::cmp::Reverse(remaining[i].duration_minutes),
+            )
+        }) else {
+            break;
+        };
+        let talk = remaining.remove(next);
+
+        if fitting[next].is_empty() {
+            let free: Vec<&ScheduleSlot> = slots
+                .iter()
+                .copied()
+                .filter(|slot| board.is_free(slot) && long_enough(slot, talk))
+                .collect();
+            let reason = if free.is_empty() {
+                format!(
+                    "No free slot is long enough for {} minutes",
+                    talk.duration_minutes
+                )
+            } else if free.iter().any(|slot| speakers_available(slot, talk)) {
+                "Every free slot that fits clashes with another talk by the same speaker"
+                    .to_string()
+            } else {
+                "No free slot long enough is within its speakers' availability".to_string()
+            };
+            unplaced.push(UnplacedTalk {
+                talk_id: talk.id,
+                talk_title: talk.title.clone(),
+                reason,
+            });
+            continue;
+        }
+
+        // Ties go to the earliest slot
+        let mut best: Option<(i64, &ScheduleSlot, Vec<String>)> = None;
+        for &slot in &fitting[next] {
+            let (score, reasons) = board.score(slot, talk);
+            if best.as_ref().is_none_or(|(top, _, _)| score > *top) {
+                best = Some((score, slot, reasons));
+            }
+        }
+        let Some((_, slot, mut reasons)) = best else {
+            continue;
+        };
+        if fitting[next].len() == 1 {
+            reasons.insert(0, "The only free slot that fits".to_string());
+        }
+
+        board.placed.insert(slot.id);
+        board.taken.push((slot, talk));
+        placements.push((slot, talk, reasons));
+    }
+
+    // Check the finished schedule with the conflict engine
+    let mut proposed = snapshot.clone();
+    for (slot, talk, _) in &placements {
+        proposed.talks.insert(talk.id, (*talk).clone());
+        proposed.apply(ScheduleSlot {
+            talk_id: Some(talk.id),
+            ..(*slot).clone()
+        });
+    }
+    let placed_slots: HashSet<Uuid> = placements.iter().map(|(slot, _, _)| slot.id).collect();
+    let warnings = proposed
+        .conflicts()
+        .into_iter()
+        .filter(|c| c.slot_ids.iter().any(|id| placed_slots.contains(id)))
+        .collect();
+
+    placements.sort_by_key(|(slot, _, _)| (slot.slot_date, slot.start_time, slot.track_id));
+    let assignments = placements
+        .into_iter()
+        .map(|(slot, talk, reasons)| ProposedAssignment {
+            slot_id: slot.id,
+            talk_id: talk.id,
+            talk_title: talk.title.clone(),
+            track_id: slot.track_id,
+            track_name: snapshot
+                .track_names
+                .get(&slot.track_id)
+                .cloned()
+                .unwrap_or_default(),
+            slot_date: slot.slot_date,
+            start_time: slot.start_time,
+            end_time: slot.end_time,
+            reasons,
+        })
+        .collect();
+
+    ScheduleProposal {
+        assignments,
+        unplaced,
+        pinned_count,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AvailabilityWindow, TalkState};
+    use chrono::Utc;
+
+    fn day(n: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, n).unwrap()
+    }
+
+    fn slot(track_id: Uuid, date: NaiveDate, start: u32, end: u32) -> ScheduleSlot {
+        ScheduleSlot {
+            id: Uuid::new_v4(),
+            conference_id: Uuid::nil(),
+            track_id,
+            talk_id: None,
+            slot_date: date,
+            start_time: NaiveTime::from_hms_opt(start / 100, start % 100, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end / 100, end % 100, 0).unwrap(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn talk(title: &str, duration: i32, speakers: &[Uuid], labels: &[Uuid]) -> ScheduledTalk {
+        ScheduledTalk {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            state: TalkState::Accepted,
+            duration_minutes: duration,
+            speakers: speakers.iter().map(|&id| (id, "Ada".to_string())).collect(),
+            label_ids: labels.to_vec(),
+            availability: HashMap::new(),
+        }
+    }
+
+    fn snapshot(slots: Vec<ScheduleSlot>, pinned: Vec<ScheduledTalk>) -> ScheduleSnapshot {
+        ScheduleSnapshot {
+            start_date: day(1),
+            end_date: day(2),
+            slots,
+            talks: pinned.into_iter().map(|t| (t.id, t)).collect(),
+            track_names: HashMap::new(),
+        }
+    }
+
+    fn slot_of(proposal: &ScheduleProposal, talk: &ScheduledTalk) -> Option<Uuid> {
+        proposal
+            .assignments
+            .iter()
+            .find(|a| a.talk_id == talk.id)
+            .map(|a| a.slot_id)
+    }
+
+    #[test]
+    fn talks_go_to_tracks_meant_for_their_topic() {
+        let (rust, web) = (Uuid::new_v4(), Uuid::new_v4());
+        let (rust_track, web_track) = (Uuid::new_v4(), Uuid::new_v4());
+        let rust_slot = slot(rust_track, day(1), 900, 1000);
+        let web_slot = slot(web_track, day(1), 900, 1000);
+        let track_labels = HashMap::from([
+            (rust_track, HashSet::from([rust])),
+            (web_track, HashSet::from([web])),
+        ]);
+        let a = talk("Web", 45, &[Uuid::new_v4()], &[web]);
+        let b = talk("Rust", 45, &[Uuid::new_v4()], &[rust]);
+
+        let proposal = plan_schedule(
+            &snapshot(vec![rust_slot.clone(), web_slot.clone()], vec![]),
+            &[a.clone(), b.clone()],
+            &track_labels,
+        );
+        assert_eq!(slot_of(&proposal, &a), Some(web_slot.id));
+        assert_eq!(slot_of(&proposal, &b), Some(rust_slot.id));
+        assert!(proposal.unplaced.is_empty());
+    }
+
+    #[test]
+    fn pinned_slots_stay_and_speakers_are_not_double_booked() {
+        let ada = Uuid::new_v4();
+        let pinned = talk("Pinned", 45, &[ada], &[]);
+        let mut taken = slot(Uuid::new_v4(), day(1), 900, 1000);
+        taken.talk_id = Some(pinned.id);
+        let clashing = slot(Uuid::new_v4(), day(1), 900, 1000);
+        let later = slot(Uuid::new_v4(), day(1), 1100, 1200);
+        let co_speaking = talk("Co-speaking", 45, &[Uuid::new_v4(), ada], &[]);
+
+        let proposal = plan_schedule(
+            &snapshot(vec![taken.clone(), clashing, later.clone()], vec![pinned]),
+            std::slice::from_ref(&co_speaking),
+            &HashMap::new(),
+        );
+        assert_eq!(proposal.pinned_count, 1);
+        assert_eq!(proposal.assignments.len(), 1);
+        assert_eq!(slot_of(&proposal, &co_speaking), Some(later.id));
+        assert!(proposal.warnings.is_empty());
+    }
+
+    #[test]
+    fn a_topic_is_spread_across_days() {
+        let label = Uuid::new_v4();
+        let track = Uuid::new_v4();
+        let first_day = slot(track, day(1), 900, 1000);
+        let also_first_day = slot(track, day(1), 1000, 1100);
+        let second_day = slot(track, day(2), 1000, 1100);
+        let a = talk("A", 45, &[Uuid::new_v4()], &[label]);
+        let b = talk("B", 45, &[Uuid::new_v4()], &[label]);
+
+        let proposal = plan_schedule(
+            &snapshot(
+                vec![first_day.clone(), also_first_day, second_day.clone()],
+                vec![],
+            ),
+            &[a.clone(), b.clone()],
+            &HashMap::new(),
+        );
+        assert_eq!(slot_of(&proposal, &a), Some(first_day.id));
+        assert_eq!(slot_of(&proposal, &b), Some(second_day.id));
+    }
+
+    #[test]
+    fn speakers_are_only_placed_when_available() {
+        let ada = Uuid::new_v4();
+        let track = Uuid::new_v4();
+        let first_day = slot(track, day(1), 900, 1000);
+        let second_day = slot(track, day(2), 900, 1000);
+        let mut a = talk("A", 45, &[ada], &[]);
+        a.availability.insert(
+            ada,
+            vec![AvailabilityWindow {
+                available_date: day(2),
+                start_time: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                end_time: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            }],
+        );
+        let mut b = a.clone();
+        b.id = Uuid::new_v4();
+
+        let proposal = plan_schedule(
+            &snapshot(vec![first_day, second_day.clone()], vec![]),
+            &[a.clone(), b.clone()],
+            &HashMap::new(),
+        );
+        assert_eq!(slot_of(&proposal, &a), Some(second_day.id));
+        assert_eq!(proposal.unplaced.len(), 1);
+        assert_eq!(
+            proposal.unplaced[0].reason,
+            "No free slot long enough is within its speakers' availability"
+        );
+    }
+
+    #[test]
+    fn talks_without_a_fitting_slot_are_reported() {
+        let ada = Uuid::new_v4();
+        let track = Uuid::new_v4();
+        let short = slot(track, day(1), 900, 930);
+        let long = slot(track, day(1), 1000, 1100);
+        let keynote = talk("Keynote", 90, &[Uuid::new_v4()], &[]);
+        let lightning = talk("Lightning", 30, &[ada], &[]);
+        let workshop = talk("Workshop", 60, &[ada], &[]);
+
+        let proposal = plan_schedule(
+            &snapshot(vec![short.clone(), long.clone()], vec![]),
+            &[keynote.clone(), lightning.clone(), workshop.clone()],
+            &HashMap::new(),
+        );
+        // The workshop only fits the long slot, so it goes first
+        assert_eq!(slot_of(&proposal, &workshop), Some(long.id));
+        assert_eq!(slot_of(&proposal, &lightning), Some(short.id));
+        assert_eq!(proposal.unplaced.len(), 1);
+        assert_eq!(proposal.unplaced[0].talk_id, keynote.id);
+        assert_eq!(
+            proposal.unplaced[0].reason,
+            "No free slot is long enough for 90 minutes"
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Shown in place of a speaker's name while their identity is hidden
+pub const ANONYMOUS_SPEAKER: &str = "Anonymous speaker";
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RevealSpeakersRequest {
+    /// Why the organizer needs to know who submitted the talk
+    pub reason: Option<String>,
+}
+
+/// One entry in a conference's identity reveal log
+#[derive(Debug, Serialize, FromRow)]
+pub struct SpeakerIdentityReveal {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub user_id: Uuid,
+    pub user_name: String,
+    pub reason: Option<String>,
+    pub revealed_at: DateTime<Utc>,
+}
@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::{validation::ValidationErrors, TalkState};
+
+/// Which score ranks talks for a bulk decision
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DecisionScore {
+    #[default]
+    Average,
+    Normalized,
+    Trimmed,
+}
+
+/// Upper bound on how many talks with a label one bulk decision selects
+#[derive(Debug, Clone, Deserialize)]
+pub struct LabelQuota {
+    pub label_id: Uuid,
+    pub max_selected: usize,
+}
+
+/// Rules choosing which submitted talks to accept. Talks in `talk_ids` are
+/// always selected; the rest are taken in score order while they meet
+/// `min_score`, `top_n` has room and none of their labels is over quota.
+#[derive(Debug, Deserialize)]
+pub struct BulkDecisionRequest {
+    #[serde(default)]
+    pub score: DecisionScore,
+    pub top_n: Option<usize>,
+    pub min_score: Option<f64>,
+    #[serde(default)]
+    pub talk_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub label_quotas: Vec<LabelQuota>,
+    /// State for submitted talks that aren't selected (`rejected` or
+    /// `waitlisted`); they stay submitted when unset
+    pub others: Option<TalkState>,
+    /// Passed to the emails as `{{reason}}`
+    pub reason: Option<String>,
+}
+
+impl BulkDecisionRequest {
+    pub fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        if self.top_n.is_none() && self.min_score.is_none() && self.talk_ids.is_empty() {
+            errors.add(
+                "top_n",
+                "required",
+                "Choose talks with top_n, min_score or talk_ids",
+            );
+        }
+        if let Some(others) = &self.others {
+            if !matches!(others, TalkState::Rejected | TalkState::Waitlisted) {
+                errors.add(
+                    "others",
+                    "invalid_format",
+                    "Talks that aren't selected can only be rejected or waitlisted",
+                );
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+/// A submitted talk that a bulk decision can select
+#[derive(Debug, Clone)]
+pub struct DecisionCandidate {
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub score: Option<f64>,
+    pub labels: Vec<(Uuid, String)>,
+}
+
+/// What a bulk decision does with one talk
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedDecision {
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub score: Option<f64>,
+    pub from_state: TalkState,
+    /// `None` when the talk is left as it is
+    pub to_state: Option<TalkState>,
+    /// Why the talk was or wasn't selected
+    pub note: String,
+}
+
+/// An email that committing the decision would send
+#[derive(Debug, Clone, Serialize)]
+pub struct DecisionEmail {
+    pub talk_id: Uuid,
+    pub template_type: String,
+    pub to: String,
+    pub subject: Option<String>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkDecisionPreview {
+    pub decisions: Vec<PlannedDecision>,
+    pub emails: Vec<DecisionEmail>,
+    /// Whether email is configured; when it isn't, nothing will be sent
+    pub emails_enabled: bool,
+}
+
+/// One state change to apply, as returned by the preview
+#[derive(Debug, Deserialize)]
+pub struct DecisionChange {
+    pub talk_id: Uuid,
+    pub from_state: TalkState,
+    pub to_state: TalkState,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitDecisionsRequest {
+    pub decisions: Vec<DecisionChange>,
+    pub reason: Option<String>,
+}
+
+/// Talks selected so far, overall and per label
+#[derive(Default)]
+struct Selection {
+    count: usize,
+    per_label: HashMap<Uuid, usize>,
+}
+
+impl Selection {
+    fn add(&mut self, candidate: &DecisionCandidate) {
+        self.count += 1;
+        for (label_id, _) in &candidate.labels {
+            *self.per_label.entry(*label_id).or_default() += 1;
+        }
+    }
+
+    fn with_label(&self, label_id: &Uuid) -> usize {
+        self.per_label.get(label_id).copied().unwrap_or(0)
+    }
+}
+
+/// Decide every candidate, best score first. Selected talks become pending;
+/// the others get `request.others` or are left alone.
+pub fn plan_decisions(
+    mut candidates: Vec<DecisionCandidate>,
+    request: &BulkDecisionRequest,
+) -> Vec<PlannedDecision> {
+    candidates.sort_by(|a, b| match (a.score, b.score) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+
+    let explicit: HashSet<Uuid> = request.talk_ids.iter().copied().collect();
+    let quotas: HashMap<Uuid, usize> = request
+        .label_quotas
+        .iter()
+        .map(|q| (q.label_id, q.max_selected))
+        .collect();
+    let ranked = request.top_n.is_some() || request.min_score.is_some();
+
+    let mut selected = Selection::default();
+
+    // Explicitly listed talks go first so they count towards the limits
+    let mut notes: HashMap<Uuid, (bool, String)> = HashMap::new();
+    for candidate in candidates.iter().filter(|c| explicit.contains(&c.talk_id)) {
+        selected.add(candidate);
+        notes.insert(candidate.talk_id, (true, "Listed explicitly".to_string()));
+    }
+
+    let mut rank = 0;
+    for candidate in candidates.iter().filter(|c| !explicit.contains(&c.talk_id)) {
+        rank += 1;
+        let rejection = if !ranked {
+            Some("Not listed".to_string())
+        } else if request
+            .min_score
+            .is_some_and(|min| candidate.score.is_none_or(|score| score < min))
+        {
+            Some(match candidate.score {
+                Some(_) => "Below the score threshold".to_string(),
+                None => "Not rated".to_string(),
+            })
+        } else if request.top_n.is_some_and(|n| selected.count >= n) {
+            Some("Outside the top talks".to_string())
+        } else {
+            candidate
+                .labels
+                .iter()
+                .find(|(label_id, _)| {
+                    quotas
+                        .get(label_id)
+                        .is_some_and(|&max| selected.with_label(label_id) >= max)
+                })
+                .map(|(_, name)| format!("Quota for label \"{}\" reached", name))
+        };
+
+        let note = match rejection {
+            Some(note) => (false, note),
+            None => {
+                selected.add(candidate);
+                (true, format!("Ranked #{}", rank))
+            }
+        };
+        notes.insert(candidate.talk_id, note);
+    }
+
+    candidates
+        .into_iter()
+        .map(|candidate| {
+            let (selected, note) = notes.remove(&candidate.talk_id).unwrap_or_default();
+            PlannedDecision {
+                to_state: if selected {
+                    Some(TalkState::Pending)
+                } else {
+                    request.others.clone()
+                },
+                talk_id: candidate.talk_id,
+                talk_title: candidate.talk_title,
+                score: candidate.score,
+                from_state: TalkState::Submitted,
+                note,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(title: &str, score: Option<f64>, labels: &[Uuid]) -> DecisionCandidate {
+        DecisionCandidate {
+            talk_id: Uuid::new_v4(),
+            talk_title: title.to_string(),
+            score,
+            labels: labels
+                .iter()
+                .map(|&id| (id, "Security".to_string()))
+                .collect(),
+        }
+    }
+
+    fn request() -> BulkDecisionRequest {
+        BulkDecisionRequest {
+            score: DecisionScore::Average,
+            top_n: None,
+            min_score: None,
+            talk_ids: Vec::new(),
+            label_quotas: Vec::new(),
+            others: None,
+            reason: None,
+        }
+    }
+
+    fn selected(plan: &[PlannedDecision]) -> Vec<&str> {
+        plan.iter()
+            .filter(|d| d.to_state == Some(TalkState::Pending))
+            .map(|d| d.talk_title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn top_n_with_threshold_and_quota() {
+        let security = Uuid::new_v4();
+        let candidates = vec![
+            candidate("C", Some(3.0), &[]),
+            candidate("A", Some(4.5), &[security]),
+            candidate("B", Some(4.0), &[security]),
+            candidate("D", Some(2.0), &[]),
+            candidate("E", None, &[]),
+        ];
+        let request = BulkDecisionRequest {
+            top_n: Some(3),
+            min_score: Some(2.5),
+            label_quotas: vec![LabelQuota {
+                label_id: security,
+                max_selected: 1,
+            }],
+            others: Some(TalkState::Rejected),
+            ..request()
+        };
+
+        let plan = plan_decisions(candidates, &request);
+        assert_eq!(selected(&plan), vec!["A", "C"]);
+        assert_eq!(plan[1].note, "Quota for label \"Security\" reached");
+        assert_eq!(plan[1].to_state, Some(TalkState::Rejected));
+        assert_eq!(plan[3].note, "Below the score threshold");
+        assert_eq!(plan[4].note, "Not rated");
+    }
+
+    #[test]
+    fn explicit_list_counts_towards_top_n() {
+        let candidates = vec![
+            candidate("A", Some(5.0), &[]),
+            candidate("B", Some(4.0), &[]),
+            candidate("C", Some(1.0), &[]),
+        ];
+        let request = BulkDecisionRequest {
+            top_n: Some(2),
+            talk_ids: vec![candidates[2].talk_id],
+            ..request()
+        };
+
+        let plan = plan_decisions(candidates, &request);
+        assert_eq!(selected(&plan), vec!["A", "C"]);
+        assert_eq!(plan[1].to_state, None);
+    }
+
+    #[test]
+    fn request_needs_a_rule() {
+        assert!(request().validate().is_err());
+        let request = BulkDecisionRequest {
+            top_n: Some(10),
+            others: Some(TalkState::Accepted),
+            ..request()
+        };
+        assert_eq!(request.validate().unwrap_err().errors()[0].field, "others");
+    }
+}
@@ -1,8 +1,14 @@
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::{ReviewPhases, SubmissionWindow};
+
+/// Time zone for new conferences that don't give one, matching the
+/// column default
+pub const DEFAULT_TIMEZONE: &str = "America/Chicago";
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Conference {
     pub id: Uuid,
@@ -14,6 +20,18 @@ pub struct Conference {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub submission_open: Option<DateTime<Utc>>,
+    pub submission_close: Option<DateTime<Utc>>,
+    /// Hide speaker identity from reviewers until an organizer reveals it
+    pub blind_review: bool,
+    /// Ratings lock and decisions open after this
+    pub review_deadline: Option<DateTime<Utc>>,
+    /// Decisions lock after this
+    pub decision_deadline: Option<DateTime<Utc>>,
+    /// When speakers should have confirmed their talks
+    pub confirmation_deadline: Option<DateTime<Utc>>,
+    /// IANA time zone that slot times are local to
+    pub timezone: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -23,6 +41,15 @@ pub struct CreateConferenceRequest {
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
     pub location: Option<String>,
+    pub submission_open: Option<DateTime<Utc>>,
+    pub submission_close: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub blind_review: bool,
+    pub review_deadline: Option<DateTime<Utc>>,
+    pub decision_deadline: Option<DateTime<Utc>>,
+    pub confirmation_deadline: Option<DateTime<Utc>>,
+    /// Defaults to [`DEFAULT_TIMEZONE`] when unset
+    pub timezone: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,6 +60,35 @@ pub struct UpdateConferenceRequest {
     pub end_date: Option<NaiveDate>,
     pub location: Option<String>,
     pub is_active: Option<bool>,
+    /// Left out keeps the current value; `null` clears it
+    #[serde(default, deserialize_with = "nullable")]
+    pub submission_open: Option<Option<DateTime<Utc>>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub submission_close: Option<Option<DateTime<Utc>>>,
+    pub blind_review: Option<bool>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub review_deadline: Option<Option<DateTime<Utc>>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub decision_deadline: Option<Option<DateTime<Utc>>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub confirmation_deadline: Option<Option<DateTime<Utc>>>,
+    pub timezone: Option<String>,
+}
+
+/// Deserialize a field that may be left out (`None`), set to `null` to
+/// clear it (`Some(None)`), or given a value (`Some(Some(_))`)
+fn nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Query parameters for endpoints that can be scoped to a single conference
+#[derive(Debug, Default, Deserialize)]
+pub struct ConferenceFilterQuery {
+    pub conference_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
@@ -46,6 +102,19 @@ pub struct ConferenceResponse {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub submission_open: Option<DateTime<Utc>>,
+    pub submission_close: Option<DateTime<Utc>>,
+    pub blind_review: bool,
+    pub review_deadline: Option<DateTime<Utc>>,
+    pub decision_deadline: Option<DateTime<Utc>>,
+    pub confirmation_deadline: Option<DateTime<Utc>>,
+    pub timezone: String,
+    /// Effective CfP window, including config fallbacks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submission_window: Option<SubmissionWindow>,
+    /// Current review phase and what it allows
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_phases: Option<ReviewPhases>,
 }
 
 impl From<Conference> for ConferenceResponse {
@@ -60,6 +129,27 @@ impl From<Conference> for ConferenceResponse {
             is_active: conference.is_active,
             created_at: conference.created_at,
             updated_at: conference.updated_at,
+            submission_open: conference.submission_open,
+            submission_close: conference.submission_close,
+            blind_review: conference.blind_review,
+            review_deadline: conference.review_deadline,
+            decision_deadline: conference.decision_deadline,
+            confirmation_deadline: conference.confirmation_deadline,
+            timezone: conference.timezone,
+            submission_window: None, // Will be populated by handlers when needed
+            review_phases: None,
         }
     }
 }
+
+impl ConferenceResponse {
+    pub fn with_submission_window(mut self, window: SubmissionWindow) -> Self {
+        self.submission_window = Some(window);
+        self
+    }
+
+    pub fn with_review_phases(mut self, phases: ReviewPhases) -> Self {
+        self.review_phases = Some(phases);
+        self
+    }
+}
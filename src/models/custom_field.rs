@@ -0,0 +1,302 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::validation::ValidationErrors;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "custom_field_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CustomFieldType {
+    Text,
+    Select,
+    MultiSelect,
+    Checkbox,
+    Number,
+}
+
+impl CustomFieldType {
+    /// Whether the field picks from a list of options
+    pub fn has_options(&self) -> bool {
+        matches!(self, CustomFieldType::Select | CustomFieldType::MultiSelect)
+    }
+}
+
+/// An extra question on a conference's submission form
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CustomField {
+    pub id: Uuid,
+    pub conference_id: Uuid,
+    pub field_key: String,
+    pub label: String,
+    pub help_text: Option<String>,
+    pub field_type: CustomFieldType,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCustomFieldRequest {
+    /// Key the answer is stored under in `talks.custom_answers`
+    pub field_key: String,
+    pub label: String,
+    pub help_text: Option<String>,
+    pub field_type: CustomFieldType,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
+
+/// The key and type are fixed once created so stored answers stay valid
+#[derive(Debug, Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub label: Option<String>,
+    pub help_text: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
+
+impl CustomField {
+    /// Check one answer against the field, returning it normalized.
+    /// `None` means the field was left blank. Required fields are only
+    /// enforced when `enforce_required` is set (drafts may be incomplete).
+    /// Errors carry a [`FieldError`](super::FieldError) code and message.
+    fn validate_answer(
+        &self,
+        value: &Value,
+        enforce_required: bool,
+    ) -> Result<Option<Value>, (&'static str, String)> {
+        let answer = match (self.field_type, value) {
+            (_, Value::Null) => None,
+            (CustomFieldType::Text, Value::String(s)) => {
+                let s = s.trim();
+                (!s.is_empty()).then(|| Value::String(s.to_string()))
+            }
+            (CustomFieldType::Select, Value::String(s)) => {
+                if s.is_empty() {
+                    None
+                } else if self.options.contains(s) {
+                    Some(value.clone())
+                } else {
+                    return Err((
+                        "invalid_format",
+                        format!(
+                            "'{}' must be one of: {}",
+                            self.label,
+                            self.options.join(", ")
+                        ),
+                    ));
+                }
+            }
+            (CustomFieldType::MultiSelect, Value::Array(items)) => {
+                let mut selected = Vec::with_capacity(items.len());
+                for item in items {
+                    match item.as_str() {
+                        Some(s) if self.options.iter().any(|o| o == s) => {
+                            selected.push(Value::String(s.to_string()))
+                        }
+                        _ => {
+                            return Err((
+                                "invalid_format",
+                                format!(
+                                    "'{}' only accepts: {}",
+                                    self.label,
+                                    self.options.join(", ")
+                                ),
+                            ))
+                        }
+                    }
+                }
+                (!selected.is_empty()).then_some(Value::Array(selected))
+            }
+            // A required checkbox has to be ticked (e.g. "I agree to the code of conduct")
+            (CustomFieldType::Checkbox, Value::Bool(checked)) => {
+                (*checked || !self.is_required || !enforce_required)
+                    .then_some(Value::Bool(*checked))
+            }
+            (CustomFieldType::Number, Value::Number(_)) => Some(value.clone()),
+            _ => {
+                let expected = match self.field_type {
+                    CustomFieldType::Text | CustomFieldType::Select => "text",
+                    CustomFieldType::MultiSelect => "a list of options",
+                    CustomFieldType::Checkbox => "true or false",
+                    CustomFieldType::Number => "a number",
+                };
+                return Err((
+                    "invalid_format",
+                    format!("'{}' must be {}", self.label, expected),
+                ));
+            }
+        };
+
+        if answer.is_none() && self.is_required && enforce_required {
+            return Err(("required", format!("'{}' is required", self.label)));
+        }
+        Ok(answer)
+    }
+}
+
+/// Validate a talk's answers against the conference's custom fields.
+/// Unknown keys are rejected and blank answers are dropped. Every problem
+/// is reported under `custom_answers.<field_key>`.
+pub fn validate_custom_answers(
+    fields: &[CustomField],
+    answers: &Value,
+    enforce_required: bool,
+) -> Result<Map<String, Value>, ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let empty = Map::new();
+    let answers = match answers {
+        Value::Object(map) => map,
+        Value::Null => &empty,
+        _ => {
+            errors.add(
+                "custom_answers",
+                "invalid_format",
+                "Custom answers must be an object",
+            );
+            return Err(errors);
+        }
+    };
+
+    for unknown in answers
+        .keys()
+        .filter(|key| !fields.iter().any(|f| &f.field_key == *key))
+    {
+        errors.add(
+            &format!("custom_answers.{}", unknown),
+            "invalid_format",
+            format!("Unknown custom field: {}", unknown),
+        );
+    }
+
+    let mut normalized = Map::new();
+    for field in fields {
+        let value = answers.get(&field.field_key).unwrap_or(&Value::Null);
+        match field.validate_answer(value, enforce_required) {
+            Ok(Some(answer)) => {
+                normalized.insert(field.field_key.clone(), answer);
+            }
+            Ok(None) => {}
+            Err((code, message)) => errors.add(
+                &format!("custom_answers.{}", field.field_key),
+                code,
+                message,
+            ),
+        }
+    }
+
+    errors.into_result().map(|()| normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(key: &str, field_type: CustomFieldType, required: bool) -> CustomField {
+        CustomField {
+            id: Uuid::new_v4(),
+            conference_id: Uuid::nil(),
+            field_key: key.to_string(),
+            label: key.to_string(),
+            help_text: None,
+            field_type,
+            options: vec!["Beginner".to_string(), "Advanced".to_string()],
+            is_required: required,
+            position: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn accepts_valid_answers_and_drops_blanks() {
+        let fields = vec![
+            field("audience", CustomFieldType::Select, true),
+            field("topics", CustomFieldType::MultiSelect, false),
+            field("given_before", CustomFieldType::Checkbox, false),
+            field("attendees", CustomFieldType::Number, false),
+            field("equipment", CustomFieldType::Text, false),
+        ];
+
+        let answers = validate_custom_answers(
+            &fields,
+            &json!({
+                "audience": "Beginner",
+                "topics": ["Advanced"],
+                "given_before": false,
+                "attendees": 30,
+                "equipment": "  "
+            }),
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(answers.get("audience"), Some(&json!("Beginner")));
+        assert_eq!(answers.get("topics"), Some(&json!(["Advanced"])));
+        assert_eq!(answers.get("given_before"), Some(&json!(false)));
+        assert_eq!(answers.get("attendees"), Some(&json!(30)));
+        assert!(!answers.contains_key("equipment"));
+    }
+
+    #[test]
+    fn rejects_missing_required_answers() {
+        let fields = vec![field("audience", CustomFieldType::Select, true)];
+        assert!(validate_custom_answers(&fields, &json!({}), true).is_err());
+
+        let fields = vec![field("coc", CustomFieldType::Checkbox, true)];
+        assert!(validate_custom_answers(&fields, &json!({ "coc": false }), true).is_err());
+        assert!(validate_custom_answers(&fields, &json!({ "coc": true }), true).is_ok());
+
+        // Drafts may leave required fields blank
+        assert!(validate_custom_answers(&fields, &json!({}), false).is_ok());
+        assert!(validate_custom_answers(&fields, &json!({ "coc": false }), false).is_ok());
+    }
+
+    #[test]
+    fn rejects_wrong_types_unknown_keys_and_options() {
+        let fields = vec![
+            field("audience", CustomFieldType::Select, false),
+            field("attendees", CustomFieldType::Number, false),
+        ];
+
+        assert!(validate_custom_answers(&fields, &json!({ "audience": "Expert" }), true).is_err());
+        assert!(validate_custom_answers(&fields, &json!({ "attendees": "thirty" }), true).is_err());
+        assert!(validate_custom_answers(&fields, &json!({ "dietary": "none" }), true).is_err());
+    }
+
+    #[test]
+    fn reports_each_answer_under_its_key() {
+        let fields = vec![
+            field("audience", CustomFieldType::Select, true),
+            field("attendees", CustomFieldType::Number, false),
+        ];
+
+        let errors = validate_custom_answers(
+            &fields,
+            &json!({ "attendees": "thirty", "dietary": "none" }),
+            true,
+        )
+        .unwrap_err();
+        let codes: Vec<(&str, &str)> = errors
+            .errors()
+            .iter()
+            .map(|e| (e.field.as_str(), e.code))
+            .collect();
+        assert_eq!(
+            codes,
+            vec![
+                ("custom_answers.dietary", "invalid_format"),
+                ("custom_answers.audience", "required"),
+                ("custom_answers.attendees", "invalid_format"),
+            ]
+        );
+    }
+}
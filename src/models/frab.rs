@@ -0,0 +1,625 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use serde::Serialize;
+use sqlx::FromRow;
+use std::collections::{BTreeMap, HashSet};
+use uuid::Uuid;
+
+use super::{ical::venue_time, Conference, TalkFormat, Track};
+
+/// A scheduled talk with what the Frab export needs about it
+#[derive(Debug, Clone, FromRow)]
+pub struct ScheduleExportTalk {
+    pub slot_id: Uuid,
+    pub talk_id: Uuid,
+    pub title: String,
+    pub short_summary: String,
+    pub format: TalkFormat,
+    /// Primary speaker first, then accepted co-speakers, matching `speakers`
+    pub speaker_ids: Vec<Uuid>,
+    pub speakers: Vec<String>,
+    pub labels: Vec<String>,
+    pub track_id: Uuid,
+    pub slot_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The schedule in the Frab/Pentabarf format, serialized as the JSON
+/// variant or rendered as schedule.xml with [`render_frab_xml`]
+#[derive(Debug, Serialize)]
+pub struct FrabExport {
+    pub schedule: FrabSchedule,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabSchedule {
+    /// Changes whenever the schedule does, so consumers can tell they're
+    /// out of date
+    pub version: String,
+    pub base_url: String,
+    pub conference: FrabConference,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabConference {
+    pub acronym: String,
+    pub title: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    #[serde(rename = "daysCount")]
+    pub days_count: usize,
+    /// Grid every slot starts and ends on, as "HH:MM"
+    pub timeslot_duration: String,
+    pub time_zone_name: String,
+    pub rooms: Vec<FrabRoom>,
+    pub days: Vec<FrabDay>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabRoom {
+    /// The track name, numbered when several tracks share it, since
+    /// consumers tell rooms apart by name
+    pub name: String,
+    pub guid: Uuid,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabDay {
+    /// Starts at 1
+    pub index: usize,
+    pub date: NaiveDate,
+    pub day_start: DateTime<FixedOffset>,
+    pub day_end: DateTime<FixedOffset>,
+    /// Every room, by name, with its events in start order
+    pub rooms: BTreeMap<String, Vec<FrabEvent>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabEvent {
+    pub id: u32,
+    pub guid: Uuid,
+    pub date: DateTime<FixedOffset>,
+    /// "HH:MM" at the venue
+    pub start: String,
+    /// "HH:MM"
+    pub duration: String,
+    pub room: String,
+    pub slug: String,
+    pub title: String,
+    /// The first label, for apps that group events by a single track
+    pub track: Option<String>,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    #[serde(rename = "abstract")]
+    pub abstract_text: String,
+    pub persons: Vec<FrabPerson>,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FrabPerson {
+    pub id: u32,
+    pub guid: Uuid,
+    pub public_name: String,
+}
+
+/// Frab ids are integers; derive a stable one from a UUID
+fn frab_id(id: Uuid) -> u32 {
+    let bytes = id.as_bytes();
+    u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) & 0x7fff_ffff
+}
+
+fn format_minutes(minutes: i64) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// "Rust & You: Part 2" -> "rust-you-part-2"
+fn slugify(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Build the export for a conference's scheduled talks. Every conference
+/// day is listed, along with any day a slot was placed outside them, and
+/// every day lists every room.
+///
+/// Event ids come from the talk so they survive it being moved; a talk in
+/// several slots takes its later slots' ids from the slots instead.
+pub fn build_frab_schedule(
+    conference: &Conference,
+    timezone: Tz,
+    base_url: &str,
+    tracks: &[Track],
+    talks: &[ScheduleExportTalk],
+) -> FrabExport {
+    let acronym = slugify(&conference.name);
+
+    let mut tracks: Vec<&Track> = tracks.iter().collect();
+    tracks.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+    let mut taken = HashSet::new();
+    let room_names: BTreeMap<Uuid, String> = tracks
+        .iter()
+        .map(|t| {
+            let mut name = t.name.clone();
+            let mut n = 1;
+            while !taken.insert(name.clone()) {
+                n += 1;
+                name = format!("{} ({})", t.name, n);
+            }
+            (t.id, name)
+        })
+        .collect();
+
+    let mut dates: Vec<NaiveDate> = conference
+        .start_date
+        .iter_days()
+        .take_while(|d| *d <= conference.end_date)
+        .collect();
+    dates.extend(talks.iter().map(|t| t.slot_date));
+    dates.sort();
+    dates.dedup();
+
+    let mut talks: Vec<&ScheduleExportTalk> = talks
+        .iter()
+        .filter(|t| room_names.contains_key(&t.track_id))
+        .collect();
+    talks.sort_by_key(|t| (t.slot_date, t.start_time, t.talk_id));
+
+    let mut grid = 0;
+    let mut seen = HashSet::new();
+    let days = dates
+        .iter()
+        .enumerate()
+        .map(|(i, &date)| {
+            let that_day: Vec<&&ScheduleExportTalk> =
+                talks.iter().filter(|t| t.slot_date == date).collect();
+            let day_start = that_day
+                .iter()
+                .map(|t| t.start_time)
+                .min()
+                .unwrap_or(NaiveTime::MIN);
+            let day_end = that_day
+                .iter()
+                .map(|t| t.end_time)
+                .max()
+                .unwrap_or_else(|| NaiveTime::from_hms_opt(23, 59, 0).unwrap());
+
+            let mut rooms: BTreeMap<Uuid, Vec<FrabEvent>> =
+                tracks.iter().map(|t| (t.id, Vec::new())).collect();
+            for talk in that_day {
+                let room = &room_names[&talk.track_id];
+                let duration = (talk.end_time - talk.start_time).num_minutes();
+                let start_minutes = (talk.start_time - NaiveTime::MIN).num_minutes();
+                grid = gcd(gcd(grid, duration), start_minutes);
+
+                let guid = if seen.insert(talk.talk_id) {
+                    talk.talk_id
+                } else {
+                    talk.slot_id
+                };
+                let id = frab_id(guid);
+                let persons = talk
+                    .speaker_ids
+                    .iter()
+                    .zip(&talk.speakers)
+                    .map(|(&speaker_id, name)| FrabPerson {
+                        id: frab_id(speaker_id),
+                        guid: speaker_id,
+                        public_name: name.clone(),
+                    })
+                    .collect();
+                rooms.entry(talk.track_id).or_default().push(FrabEvent {
+                    id,
+                    guid,
+                    date: venue_time(timezone, date, talk.start_time).fixed_offset(),
+                    start: talk.start_time.format("%H:%M").to_string(),
+                    duration: format_minutes(duration),
+                    room: room.clone(),
+                    slug: format!("{}-{}-{}", acronym, id, slugify(&talk.title)),
+                    title: talk.title.clone(),
+                    track: talk.labels.first().cloned(),
+                    event_type: talk.format.as_str().to_string(),
+                    abstract_text: talk.short_summary.clone(),
+                    persons,
+                    labels: talk.labels.clone(),
+                });
+            }
+
+            FrabDay {
+                index: i + 1,
+                date,
+                day_start: venue_time(timezone, date, day_start).fixed_offset(),
+                day_end: venue_time(timezone, date, day_end).fixed_offset(),
+                rooms: rooms
+                    .into_iter()
+                    .map(|(track_id, events)| (room_names[&track_id].clone(), events))
+                    .collect(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let last_change = talks
+        .iter()
+        .map(|t| t.updated_at)
+        .fold(conference.updated_at, DateTime::max);
+
+    FrabExport {
+        schedule: FrabSchedule {
+            version: last_change.format("%Y-%m-%d %H:%M:%S").to_string(),
+            base_url: base_url.to_string(),
+            conference: FrabConference {
+                acronym,
+                title: conference.name.clone(),
+                start: conference.start_date,
+                end: conference.end_date,
+                days_count: days.len(),
+                // Five minutes when there's nothing to go by
+                timeslot_duration: format_minutes(if grid == 0 { 5 } else { grid }),
+                time_zone_name: timezone.name().to_string(),
+                rooms: tracks
+                    .iter()
+                    .map(|t| FrabRoom {
+                        name: room_names[&t.id].clone(),
+                        guid: t.id,
+                    })
+                    .collect(),
+                days,
+            },
+        },
+    }
+}
+
+/// Escape markup and drop the control characters XML 1.0 can't carry at
+/// all, even as character references
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            '\0'..='\u{1F}' | '\u{FFFE}' | '\u{FFFF}' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn push_element(out: &mut String, depth: usize, name: &str, text: &str) {
+    out.push_str(&format!(
+        "{}<{}>{}</{}>\n",
+        "  ".repeat(depth),
+        name,
+        escape_xml(text),
+        name
+    ));
+}
+
+/// Render the export as a Frab/Pentabarf schedule.xml document
+pub fn render_frab_xml(export: &FrabExport) -> String {
+    let schedule = &export.schedule;
+    let conference = &schedule.conference;
+    // Room names are unique, so they identify the track
+    let guids: BTreeMap<&str, Uuid> = conference
+        .rooms
+        .iter()
+        .map(|r| (r.name.as_str(), r.guid))
+        .collect();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<schedule>\n");
+    push_element(&mut out, 1, "version", &schedule.version);
+    out.push_str("  <conference>\n");
+    push_element(&mut out, 2, "acronym", &conference.acronym);
+    push_element(&mut out, 2, "title", &conference.title);
+    push_element(&mut out, 2, "start", &conference.start.to_string());
+    push_element(&mut out, 2, "end", &conference.end.to_string());
+    push_element(&mut out, 2, "days", &conference.days_count.to_string());
+    push_element(
+        &mut out,
+        2,
+        "timeslot_duration",
+        &conference.timeslot_duration,
+    );
+    push_element(&mut out, 2, "base_url", &schedule.base_url);
+    push_element(&mut out, 2, "time_zone_name", &conference.time_zone_name);
+    out.push_str("  </conference>\n");
+
+    for day in &conference.days {
+        out.push_str(&format!(
+            "  <day index=\"{}\" date=\"{}\" start=\"{}\" end=\"{}\">\n",
+            day.index,
+            day.date,
+            day.day_start.to_rfc3339(),
+            day.day_end.to_rfc3339()
+        ));
+        for (room, events) in &day.rooms {
+            let guid = guids.get(room.as_str()).copied().unwrap_or_default();
+            out.push_str(&format!(
+                "    <room name=\"{}\" guid=\"{}\">\n",
+                escape_xml(room),
+                guid
+            ));
+            for event in events {
+                out.push_str(&format!(
+                    "      <event id=\"{}\" guid=\"{}\">\n",
+                    event.id, event.guid
+                ));
+                push_element(&mut out, 4, "date", &event.date.to_rfc3339());
+                push_element(&mut out, 4, "start", &event.start);
+                push_element(&mut out, 4, "duration", &event.duration);
+                push_element(&mut out, 4, "room", &event.room);
+                push_element(&mut out, 4, "slug", &event.slug);
+                push_element(&mut out, 4, "title", &event.title);
+                push_element(&mut out, 4, "track", event.track.as_deref().unwrap_or(""));
+                push_element(&mut out, 4, "type", &event.event_type);
+                push_element(&mut out, 4, "abstract", &event.abstract_text);
+                out.push_str("        <persons>\n");
+                for person in &event.persons {
+                    out.push_str(&format!(
+                        "          <person id=\"{}\" guid=\"{}\">{}</person>\n",
+                        person.id,
+                        person.guid,
+                        escape_xml(&person.public_name)
+                    ));
+                }
+                out.push_str("        </persons>\n");
+                out.push_str("        <labels>\n");
+                for label in &event.labels {
+                    push_element(&mut out, 5, "label", label);
+                }
+                out.push_str("        </labels>\n");
+                out.push_str("      </event>\n");
+            }
+            out.push_str("    </room>\n");
+        }
+        out.push_str("  </day>\n");
+    }
+
+    out.push_str("</schedule>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(n: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 4, n).unwrap()
+    }
+
+    fn time(hhmm: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hhmm / 100, hhmm % 100, 0).unwrap()
+    }
+
+    fn at(date: NaiveDate) -> DateTime<Utc> {
+        date.and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+
+    fn conference() -> Conference {
+        Conference {
+            id: Uuid::new_v4(),
+            name: "Texas Linux Fest 2025".to_string(),
+            description: None,
+            start_date: day(18),
+            end_date: day(19),
+            location: None,
+            is_active: true,
+            created_at: at(day(1)),
+            updated_at: at(day(1)),
+            submission_open: None,
+            submission_close: None,
+            blind_review: false,
+            review_deadline: None,
+            decision_deadline: None,
+            confirmation_deadline: None,
+            timezone: "America/Chicago".to_string(),
+        }
+    }
+
+    fn track(conference: &Conference, name: &str) -> Track {
+        Track {
+            id: Uuid::new_v4(),
+            conference_id: conference.id,
+            name: name.to_string(),
+            description: None,
+            capacity: None,
+            created_at: at(day(1)),
+        }
+    }
+
+    fn talk(track: &Track, title: &str, start: u32, end: u32) -> ScheduleExportTalk {
+        ScheduleExportTalk {
+            slot_id: Uuid::new_v4(),
+            talk_id: Uuid::new_v4(),
+            title: title.to_string(),
+            short_summary: "A tour <of> the kernel".to_string(),
+            format: TalkFormat::Talk,
+            speaker_ids: vec![Uuid::new_v4()],
+            speakers: vec!["Ada & Co".to_string()],
+            labels: vec!["Kernel".to_string(), "Linux".to_string()],
+            track_id: track.id,
+            slot_date: day(18),
+            start_time: time(start),
+            end_time: time(end),
+            updated_at: at(day(5)),
+        }
+    }
+
+    #[test]
+    fn builds_days_rooms_and_events() {
+        let conference = conference();
+        let main = track(&conference, "Main Hall");
+        let workshop = track(&conference, "Workshop Room");
+        let talks = [
+            talk(&main, "Rust & You: Part 2", 1030, 1115),
+            talk(&main, "Opening", 900, 930),
+        ];
+        let export = build_frab_schedule(
+            &conference,
+            chrono_tz::America::Chicago,
+            "https://texaslinuxfest.org",
+            &[workshop.clone(), main.clone()],
+            &talks,
+        );
+        let schedule = &export.schedule;
+        assert_eq!(schedule.version, "2025-04-05 12:00:00");
+
+        let conf = &schedule.conference;
+        assert_eq!(conf.acronym, "texas-linux-fest-2025");
+        assert_eq!(conf.days_count, 2);
+        assert_eq!(conf.timeslot_duration, "00:15");
+        assert_eq!(conf.rooms[0].name, "Main Hall");
+
+        let first = &conf.days[0];
+        assert_eq!(first.index, 1);
+        assert_eq!(first.day_start.to_rfc3339(), "2025-04-18T09:00:00-05:00");
+        assert_eq!(first.day_end.to_rfc3339(), "2025-04-18T11:15:00-05:00");
+        assert!(first.rooms["Workshop Room"].is_empty());
+
+        let events = &first.rooms["Main Hall"];
+        assert_eq!(events[0].title, "Opening");
+        let event = &events[1];
+        assert_eq!(event.start, "10:30");
+        assert_eq!(event.duration, "00:45");
+        assert_eq!(event.date.to_rfc3339(), "2025-04-18T10:30:00-05:00");
+        assert_eq!(
+            event.slug,
+            format!("texas-linux-fest-2025-{}-rust-you-part-2", event.id)
+        );
+        assert_eq!(event.track.as_deref(), Some("Kernel"));
+        assert_eq!(event.id, frab_id(talks[0].talk_id));
+        assert_eq!(event.persons[0].public_name, "Ada & Co");
+
+        assert!(conf.days[1].rooms["Main Hall"].is_empty());
+    }
+
+    #[test]
+    fn repeated_talks_get_their_own_ids() {
+        let conference = conference();
+        let main = track(&conference, "Main Hall");
+        let first = talk(&main, "Workshop", 900, 1000);
+        let second = ScheduleExportTalk {
+            slot_id: Uuid::new_v4(),
+            start_time: time(1000),
+            end_time: time(1100),
+            ..first.clone()
+        };
+        let export = build_frab_schedule(
+            &conference,
+            chrono_tz::America::Chicago,
+            "",
+            std::slice::from_ref(&main),
+            &[second.clone(), first.clone()],
+        );
+        let events = &export.schedule.conference.days[0].rooms["Main Hall"];
+        assert_eq!(events[0].guid, first.talk_id);
+        assert_eq!(events[1].guid, second.slot_id);
+        assert_ne!(events[0].id, events[1].id);
+    }
+
+    #[test]
+    fn xml_is_escaped() {
+        let conference = conference();
+        let main = track(&conference, "Main \"Big\" Hall");
+        let talks = [talk(&main, "Rust & You", 900, 1000)];
+        let export = build_frab_schedule(
+            &conference,
+            chrono_tz::America::Chicago,
+            "https://texaslinuxfest.org",
+            std::slice::from_ref(&main),
+            &talks,
+        );
+        let xml = render_frab_xml(&export);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<schedule>\n"));
+        assert!(xml.contains("<time_zone_name>America/Chicago</time_zone_name>"));
+        assert!(xml.contains(&format!(
+            "<room name=\"Main &quot;Big&quot; Hall\" guid=\"{}\">",
+            main.id
+        )));
+        assert!(xml.contains("<title>Rust &amp; You</title>"));
+        assert!(xml.contains("<abstract>A tour &lt;of&gt; the kernel</abstract>"));
+        assert!(xml.contains(">Ada &amp; Co</person>"));
+        assert!(xml.contains("<label>Linux</label>"));
+        assert!(xml.ends_with("</schedule>\n"));
+    }
+
+    #[test]
+    fn same_named_tracks_stay_separate_rooms() {
+        let conference = conference();
+        let first = track(&conference, "Main Hall");
+        let second = track(&conference, "Main Hall");
+        let talks = [
+            talk(&first, "Opening", 900, 930),
+            talk(&second, "Closing", 1600, 1630),
+        ];
+        let export = build_frab_schedule(
+            &conference,
+            chrono_tz::America::Chicago,
+            "",
+            &[first.clone(), second.clone()],
+            &talks,
+        );
+        let conf = &export.schedule.conference;
+        let rooms: Vec<(&str, Uuid)> = conf
+            .rooms
+            .iter()
+            .map(|r| (r.name.as_str(), r.guid))
+            .collect();
+        assert_eq!(rooms.len(), 2);
+        assert_eq!(rooms[0].0, "Main Hall");
+        assert_eq!(rooms[1].0, "Main Hall (2)");
+
+        let day = &conf.days[0];
+        assert_eq!(day.rooms.len(), 2);
+        for (name, guid) in rooms {
+            let title = if guid == first.id {
+                "Opening"
+            } else {
+                "Closing"
+            };
+            let events = &day.rooms[name];
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].title, title);
+            assert_eq!(events[0].room, name);
+        }
+
+        let xml = render_frab_xml(&export);
+        assert!(xml.contains(&format!(
+            "<room name=\"Main Hall\" guid=\"{}\">",
+            conf.rooms[0].guid
+        )));
+        assert!(xml.contains(&format!(
+            "<room name=\"Main Hall (2)\" guid=\"{}\">",
+            conf.rooms[1].guid
+        )));
+    }
+
+    #[test]
+    fn xml_drops_illegal_control_characters() {
+        assert_eq!(
+            escape_xml("Tabs\tand\r\nlines\x0B\x0C\x00 stay\u{FFFE}"),
+            "Tabs\tand\r\nlines stay"
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use chrono::{DateTime, Duration, LocalResult, NaiveDate, NaiveTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use sqlx::FromRow;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Content lines longer than this many octets are folded (RFC 5545 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// A scheduled talk as it appears in a calendar feed
+#[derive(Debug, Clone, FromRow)]
+pub struct CalendarEvent {
+    pub slot_id: Uuid,
+    pub talk_id: Uuid,
+    pub title: String,
+    pub short_summary: String,
+    /// Primary speaker first, then accepted co-speakers
+    pub speakers: Vec<String>,
+    pub labels: Vec<String>,
+    pub track_name: String,
+    pub slot_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    /// When the slot or the talk last changed
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A calendar for a conference, or the part of it in one track or given by
+/// one speaker
+#[derive(Debug, Clone)]
+pub struct CalendarFeed {
+    pub name: String,
+    /// Zone the slot dates and times are wall-clock times in
+    pub timezone: Tz,
+    /// Makes event UIDs globally unique, e.g. "texaslinuxfest.org"
+    pub domain: String,
+    /// Who publishes the feed, for PRODID
+    pub publisher: String,
+    pub events: Vec<CalendarEvent>,
+}
+
+/// A wall-clock time at the venue. Ambiguous times take the earlier
+/// instant, and times skipped by a daylight-saving jump keep the offset
+/// from before the jump.
+pub(super) fn venue_time(timezone: Tz, date: NaiveDate, time: NaiveTime) -> DateTime<Tz> {
+    let local = date.and_time(time);
+    match timezone.from_local_datetime(&local) {
+        LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => at,
+        LocalResult::None => {
+            let offset = timezone.offset_from_utc_datetime(&local).fix();
+            timezone
+                .from_utc_datetime(&(local - Duration::seconds(offset.local_minus_utc().into())))
+        }
+    }
+}
+
+fn to_utc(timezone: Tz, date: NaiveDate, time: NaiveTime) -> DateTime<Utc> {
+    venue_time(timezone, date, time).with_timezone(&Utc)
+}
+
+fn format_utc(at: DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value (RFC 5545 3.3.11)
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folding it so no line is longer than 75 octets
+/// and no character is split
+fn push_line(out: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space counts towards the continuation line
+            octets = 1;
+        }
+        out.push(c);
+        octets += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Render a feed as an iCalendar (RFC 5545) document.
+///
+/// Times are written in UTC so clients need no time zone definitions. UIDs
+/// come from the talk, so a talk moved to another slot updates the event
+/// subscribers already have; a talk given in several slots gets one event
+/// per slot, numbered in schedule order.
+pub fn render_calendar(feed: &CalendarFeed) -> String {
+    let mut events: Vec<&CalendarEvent> = feed.events.iter().collect();
+    events.sort_by(|a, b| {
+        (a.slot_date, a.start_time, &a.track_name, a.slot_id).cmp(&(
+            b.slot_date,
+            b.start_time,
+            &b.track_name,
+            b.slot_id,
+        ))
+    });
+
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(
+        &mut out,
+        &format!("PRODID:-//{}//Call for Papers//EN", feed.publisher),
+    );
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(
+        &mut out,
+        &format!("X-WR-CALNAME:{}", escape_text(&feed.name)),
+    );
+    push_line(&mut out, &format!("X-WR-TIMEZONE:{}", feed.timezone.name()));
+
+    let mut occurrences: HashMap<Uuid, usize> = HashMap::new();
+    for event in events {
+        let occurrence = occurrences.entry(event.talk_id).or_default();
+        *occurrence += 1;
+        let uid = if *occurrence == 1 {
+            format!("talk-{}@{}", event.talk_id, feed.domain)
+        } else {
+            format!("talk-{}-{}@{}", event.talk_id, occurrence, feed.domain)
+        };
+
+        let mut description = event.short_summary.trim().to_string();
+        if !event.speakers.is_empty() {
+            if !description.is_empty() {
+                description.push_str("\n\n");
+            }
+            description.push_str(&format!("Speakers: {}", event.speakers.join(", ")));
+        }
+
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", uid));
+        push_line(
+            &mut out,
+            &format!("DTSTAMP:{}", format_utc(event.updated_at)),
+        );
+        push_line(
+            &mut out,
+            &format!("LAST-MODIFIED:{}", format_utc(event.updated_at)),
+        );
+        push_line(
+            &mut out,
+            &format!(
+                "DTSTART:{}",
+                format_utc(to_utc(feed.timezone, event.slot_date, event.start_time))
+            ),
+        );
+        push_line(
+            &mut out,
+            &format!(
+                "DTEND:{}",
+                format_utc(to_utc(feed.timezone, event.slot_date, event.end_time))
+            ),
+        );
+        push_line(&mut out, &format!("SUMMARY:{}", escape_text(&event.title)));
+        if !description.is_empty() {
+            push_line(
+                &mut out,
+                &format!("DESCRIPTION:{}", escape_text(&description)),
+            );
+        }
+        push_line(
+            &mut out,
+            &format!("LOCATION:{}", escape_text(&event.track_name)),
+        );
+        if !event.labels.is_empty() {
+            let categories: Vec<String> = event.labels.iter().map(|l| escape_text(l)).collect();
+            push_line(&mut out, &format!("CATEGORIES:{}", categories.join(",")));
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hhmm: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hhmm / 100, hhmm % 100, 0).unwrap()
+    }
+
+    fn event(talk_id: Uuid, date: NaiveDate, start: u32, end: u32) -> CalendarEvent {
+        CalendarEvent {
+            slot_id: Uuid::new_v4(),
+            talk_id,
+            title: "Kernels, Containers; and You".to_string(),
+            short_summary: "A tour.\nBring a laptop".to_string(),
+            speakers: vec!["Ada".to_string(), "Linus".to_string()],
+            labels: vec!["Linux".to_string(), "Cloud".to_string()],
+            track_name: "Main Hall".to_string(),
+            slot_date: date,
+            start_time: time(start),
+            end_time: time(end),
+            updated_at: NaiveDate::from_ymd_opt(2025, 5, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_utc(),
+        }
+    }
+
+    fn feed(events: Vec<CalendarEvent>) -> CalendarFeed {
+        CalendarFeed {
+            name: "TXLF 2025".to_string(),
+            timezone: chrono_tz::America::Chicago,
+            domain: "texaslinuxfest.org".to_string(),
+            publisher: "TXLF".to_string(),
+            events,
+        }
+    }
+
+    #[test]
+    fn times_follow_daylight_saving() {
+        let chicago = chrono_tz::America::Chicago;
+        let summer = NaiveDate::from_ymd_opt(2025, 7, 12).unwrap();
+        let winter = NaiveDate::from_ymd_opt(2025, 1, 11).unwrap();
+        assert_eq!(
+            format_utc(to_utc(chicago, summer, time(900))),
+            "20250712T140000Z"
+        );
+        assert_eq!(
+            format_utc(to_utc(chicago, winter, time(900))),
+            "20250111T150000Z"
+        );
+
+        // 02:30 doesn't exist on the night clocks go forward
+        let spring_forward = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+        assert_eq!(
+            format_utc(to_utc(chicago, spring_forward, time(230))),
+            "20250309T083000Z"
+        );
+    }
+
+    #[test]
+    fn uids_are_stable_per_talk() {
+        let talk_id = Uuid::new_v4();
+        let day = NaiveDate::from_ymd_opt(2025, 7, 12).unwrap();
+        let first = render_calendar(&feed(vec![event(talk_id, day, 900, 1000)]));
+        // Moving the talk keeps its UID
+        let moved = render_calendar(&feed(vec![event(talk_id, day, 1400, 1500)]));
+        let uid = format!("UID:talk-{}@texaslinuxfest.org\r\n", talk_id);
+        assert!(first.contains(&uid));
+        assert!(moved.contains(&uid));
+
+        // A second slot for the same talk gets its own UID
+        let twice = render_calendar(&feed(vec![
+            event(talk_id, day, 1400, 1500),
+            event(talk_id, day, 900, 1000),
+        ]));
+        assert_eq!(twice.matches("BEGIN:VEVENT").count(), 2);
+        assert!(twice.contains(&uid));
+        assert!(twice.contains(&format!("UID:talk-{}-2@texaslinuxfest.org", talk_id)));
+    }
+
+    #[test]
+    fn text_is_escaped_and_lines_folded() {
+        let day = NaiveDate::from_ymd_opt(2025, 7, 12).unwrap();
+        let mut long = event(Uuid::new_v4(), day, 900, 1000);
+        long.title = "é".repeat(60);
+        let calendar = render_calendar(&feed(vec![long.clone()]));
+
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(calendar.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+        assert!(calendar.contains("X-WR-TIMEZONE:America/Chicago\r\n"));
+        assert!(calendar.contains("DTSTART:20250712T140000Z\r\n"));
+        assert!(calendar
+            .contains("DESCRIPTION:A tour.\\nBring a laptop\\n\\nSpeakers: Ada\\, Linus\r\n"));
+        assert!(calendar.contains("CATEGORIES:Linux,Cloud\r\n"));
+        assert!(calendar.split("\r\n").all(|line| line.len() <= 75));
+
+        // Unfolding restores the title without splitting characters
+        let unfolded = calendar.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("SUMMARY:{}\r\n", long.title)));
+
+        long.title = "Kernels, Containers; and You".to_string();
+        let calendar = render_calendar(&feed(vec![long]));
+        assert!(calendar.contains("SUMMARY:Kernels\\, Containers\\; and You\r\n"));
+    }
+}
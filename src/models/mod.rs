@@ -1,34 +1,123 @@
 pub mod auth;
+pub mod auto_schedule;
+pub mod blind_review;
+pub mod bulk_decision;
 pub mod conference;
+pub mod custom_field;
 pub mod email_template;
+pub mod frab;
+pub mod ical;
 pub mod label;
+pub mod pairwise_comparison;
 pub mod rating;
+pub mod review_assignment;
+pub mod review_conflict;
+pub mod review_phase;
+pub mod rubric;
+pub mod schedule_conflict;
 pub mod schedule_slot;
+pub mod score_normalization;
+pub mod speaker_availability;
+pub mod speaker_feedback;
+pub mod submission_window;
 pub mod talk;
+pub mod talk_comment;
+pub mod talk_revision;
+pub mod talk_speaker;
 pub mod track;
 pub mod user;
+pub mod validation;
 
 pub use auth::{AuthResponse, Claims, LoginRequest, RegisterRequest};
+pub use auto_schedule::{
+    plan_schedule, CommitScheduleRequest, ProposedAssignment, ScheduleProposal,
+    ScheduleProposalRequest, SlotAssignment, UnplacedTalk,
+};
+pub use blind_review::{RevealSpeakersRequest, SpeakerIdentityReveal, ANONYMOUS_SPEAKER};
+pub use bulk_decision::{
+    plan_decisions, BulkDecisionPreview, BulkDecisionRequest, CommitDecisionsRequest,
+    DecisionCandidate, DecisionChange, DecisionEmail, DecisionScore, LabelQuota, PlannedDecision,
+};
 pub use conference::{
-    Conference, ConferenceResponse, CreateConferenceRequest, UpdateConferenceRequest,
+    Conference, ConferenceFilterQuery, ConferenceResponse, CreateConferenceRequest,
+    UpdateConferenceRequest, DEFAULT_TIMEZONE,
+};
+pub use custom_field::{
+    validate_custom_answers, CreateCustomFieldRequest, CustomField, CustomFieldType,
+    UpdateCustomFieldRequest,
 };
 pub use email_template::{
     CreateEmailTemplateRequest, EmailTemplate, EmailTemplateResponse, UpdateEmailTemplateRequest,
 };
+pub use frab::{
+    build_frab_schedule, render_frab_xml, FrabConference, FrabDay, FrabEvent, FrabExport,
+    FrabPerson, FrabRoom, FrabSchedule, ScheduleExportTalk,
+};
+pub use ical::{render_calendar, CalendarEvent, CalendarFeed};
 pub use label::{
     AddLabelToTalkRequest, CreateLabelRequest, Label, LabelResponse, TalkLabel, UpdateLabelRequest,
 };
+pub use pairwise_comparison::{
+    bradley_terry, next_pair, pair_key, ComparisonTalk, NextComparison, PairwiseComparison,
+    PairwiseScore, RecordComparisonRequest,
+};
 pub use rating::{
-    CreateRatingRequest, Rating, RatingDistribution, RatingResponse, RatingsStatisticsResponse,
-    TalkRatingStats, UpdateRatingRequest,
+    CreateRatingRequest, Rating, RatingDistribution, RatingResponse, RatingsStatisticsQuery,
+    RatingsStatisticsResponse, TalkRatingStats, UpdateRatingRequest,
+};
+pub use review_assignment::{
+    plan_review_assignments, AssignReviewersRequest, AssignReviewersResponse, AssignableTalk,
+    ReviewCompletionReport, ReviewQueueItem, ReviewerLoad, ReviewerProgress, SetExpertiseRequest,
+    TalkReviewProgress,
+};
+pub use review_conflict::{normalize_email_domain, DeclareConflictRequest, ReviewConflict};
+pub use review_phase::{ReviewPhase, ReviewPhases};
+pub use rubric::{
+    criterion_averages, validate_scores, weighted_score, CreateRubricCriterionRequest,
+    CriterionAverage, CriterionScore, RatingScore, RubricCriterion, UpdateRubricCriterionRequest,
+};
+pub use schedule_conflict::{
+    ConflictKind, ConflictSeverity, ScheduleConflict, ScheduleConflictReport, ScheduleSnapshot,
+    ScheduledTalk,
 };
 pub use schedule_slot::{
     AssignTalkRequest, CreateScheduleSlotRequest, PublicScheduleSlot, PublicScheduleTalk,
     ScheduleSlot, ScheduleSlotResponse, UpdateScheduleSlotRequest,
 };
+pub use score_normalization::{
+    normalize_scores, rank_talks, trimmed_mean, RankingSort, ReviewScore, ReviewerBias,
+    ScoreNormalization,
+};
+pub use speaker_availability::{
+    availability_fit, describe_windows, AvailabilityFit, AvailabilityWindow,
+    SetAvailabilityRequest, SpeakerAvailability, SpeakerAvailabilityResponse,
+};
+pub use speaker_feedback::{feedback_text, FeedbackRequest, SpeakerFeedback, TalkFeedback};
+pub use submission_window::{
+    GrantExtensionRequest, SubmissionExtension, SubmissionExtensionResponse, SubmissionWindow,
+    SubmissionWindowState,
+};
 pub use talk::{
-    ChangeStateRequest, CreateTalkRequest, RespondToTalkRequest, Talk, TalkAction, TalkResponse,
-    TalkState, TalksListResponse, UpdateTalkRequest,
+    ChangeStateRequest, CreateTalkRequest, PromoteTalkRequest, PromoteTarget,
+    ReorderWaitlistRequest, RespondToTalkRequest, Talk, TalkAction, TalkFormat, TalkResponse,
+    TalkState, TalksListResponse, UpdateTalkRequest, WithdrawTalkRequest,
+};
+pub use talk_comment::{
+    extract_mentions, CreateCommentRequest, MentionCandidate, TalkComment, UpdateCommentRequest,
+};
+pub use talk_revision::{
+    FieldChange, RevisionDiffQuery, TalkRevision, TalkRevisionDiff, TalkRevisionResponse,
+};
+pub use talk_speaker::{
+    InviteCoSpeakerRequest, SpeakerInvitationResponse, TalkSpeaker, TalkSpeakerResponse,
+    TalkSpeakerStatus,
+};
+pub use track::{
+    CreateTrackRequest, SetTrackLabelsRequest, Track, TrackResponse, UpdateTrackRequest,
 };
-pub use track::{CreateTrackRequest, Track, TrackResponse, UpdateTrackRequest};
 pub use user::User;
+pub use validation::{
+    validate_comment_body, validate_conference_fields, validate_label_fields,
+    validate_profile_fields, validate_rubric_criterion_fields, validate_talk_fields,
+    ConferenceFields, FieldError, ValidationErrors,
+};
@@ -0,0 +1,269 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::{Talk, TalkFormat};
+
+/// Rating of a talk that wins exactly as often as it loses
+pub const BASE_RATING: f64 = 1500.0;
+
+/// Virtual games each talk plays against an average talk, one won and one
+/// lost. Keeps a talk that only ever won (or lost) from drifting off to
+/// infinity and pulls sparsely compared talks towards the middle.
+const PRIOR_GAMES: f64 = 1.0;
+
+const MAX_ITERATIONS: usize = 500;
+const TOLERANCE: f64 = 1e-9;
+
+/// One reviewer's verdict on a pair of talks
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct PairwiseComparison {
+    pub id: Uuid,
+    pub reviewer_id: Uuid,
+    pub winner_id: Uuid,
+    pub loser_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordComparisonRequest {
+    pub winner_id: Uuid,
+    pub loser_id: Uuid,
+}
+
+/// A talk as shown in a comparison. Speakers are left out so the choice
+/// rests on the proposal alone.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparisonTalk {
+    pub id: Uuid,
+    pub title: String,
+    pub short_summary: String,
+    pub long_description: Option<String>,
+    pub format: TalkFormat,
+    pub duration_minutes: i32,
+}
+
+impl From<Talk> for ComparisonTalk {
+    fn from(talk: Talk) -> Self {
+        Self {
+            id: talk.id,
+            title: talk.title,
+            short_summary: talk.short_summary,
+            long_description: talk.long_description,
+            format: talk.format,
+            duration_minutes: talk.duration_minutes,
+        }
+    }
+}
+
+/// The next two talks for a reviewer to compare, with their progress
+#[derive(Debug, Serialize)]
+pub struct NextComparison {
+    /// `None` once the reviewer has compared every pair
+    pub pair: Option<[ComparisonTalk; 2]>,
+    /// Pairs this reviewer has compared
+    pub compared: usize,
+    /// Pairs this reviewer hasn't compared yet
+    pub remaining: usize,
+}
+
+/// A talk's standing from head-to-head comparisons
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairwiseScore {
+    /// Elo-scale strength: a talk rated 400 above another is expected to
+    /// win ten times out of eleven
+    pub rating: f64,
+    pub comparisons: i64,
+    pub wins: i64,
+}
+
+/// The same key for a pair whichever way round it was compared
+pub fn pair_key(a: Uuid, b: Uuid) -> (Uuid, Uuid) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Fit a Bradley-Terry model to `(winner, loser)` comparisons with the
+/// minorization-maximization algorithm and report each talk's strength on
+/// the Elo scale. Talks that were never compared are left out.
+pub fn bradley_terry(comparisons: &[(Uuid, Uuid)]) -> HashMap<Uuid, PairwiseScore> {
+    let mut index: HashMap<Uuid, usize> = HashMap::new();
+    for &(winner, loser) in comparisons {
+        for talk in [winner, loser] {
+            let next = index.len();
+            index.entry(talk).or_insert(next);
+        }
+    }
+
+    let n = index.len();
+    let mut wins = vec![0i64; n];
+    let mut games = vec![0i64; n];
+    let mut opponents: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+    for &(winner, loser) in comparisons {
+        let (w, l) = (index[&winner], index[&loser]);
+        wins[w] += 1;
+        games[w] += 1;
+        games[l] += 1;
+        *opponents[w].entry(l).or_default() += 1.0;
+        *opponents[l].entry(w).or_default() += 1.0;
+    }
+
+    // The prior's average opponent has a fixed strength of 1, which also
+    // pins the scale so no renormalization is needed
+    let mut strength = vec![1.0f64; n];
+    for _ in 0..MAX_ITERATIONS {
+        let next: Vec<f64> = (0..n)
+            .map(|i| {
+                let expected: f64 = opponents[i]
+                    .iter()
+                    .map(|(&j, &count)| count / (strength[i] + strength[j]))
+                    .sum::<f64>()
+                    + 2.0 * PRIOR_GAMES / (strength[i] + 1.0);
+                (wins[i] as f64 + PRIOR_GAMES) / expected
+            })
+            .collect();
+
+        let change = next
+            .iter()
+            .zip(&strength)
+            .map(|(a, b)| (a.ln() - b.ln()).abs())
+            .fold(0.0, f64::max);
+        strength = next;
+        if change < TOLERANCE {
+            break;
+        }
+    }
+
+    index
+        .into_iter()
+        .map(|(talk, i)| {
+            (
+                talk,
+                PairwiseScore {
+                    rating: BASE_RATING + 400.0 * strength[i].log10(),
+                    comparisons: games[i],
+                    wins: wins[i],
+                },
+            )
+        })
+        .collect()
+}
+
+/// Choose the next pair for a reviewer. Talks with the fewest comparisons
+/// go first so every talk is covered early; the partner is preferably one
+/// it hasn't met yet, then the least compared, then the closest in
+/// strength, where a verdict tells us the most. `seen` holds the
+/// `pair_key`s the reviewer has already compared.
+pub fn next_pair(
+    talks: &[Uuid],
+    comparisons: &[(Uuid, Uuid)],
+    seen: &HashSet<(Uuid, Uuid)>,
+) -> Option<(Uuid, Uuid)> {
+    let scores = bradley_terry(comparisons);
+    let count = |talk: &Uuid| scores.get(talk).map_or(0, |s| s.comparisons);
+    let rating = |talk: &Uuid| scores.get(talk).map_or(BASE_RATING, |s| s.rating);
+
+    let mut pair_counts: HashMap<(Uuid, Uuid), usize> = HashMap::new();
+    for &(winner, loser) in comparisons {
+        *pair_counts.entry(pair_key(winner, loser)).or_default() += 1;
+    }
+
+    // Stable sort, so ties keep the order the talks were given in
+    let mut order: Vec<&Uuid> = talks.iter().collect();
+    order.sort_by_key(|talk| count(talk));
+
+    order.into_iter().find_map(|first| {
+        talks
+            .iter()
+            .filter(|other| *other != first && !seen.contains(&pair_key(*first, **other)))
+            .min_by(|a, b| {
+                let key = |other: &Uuid| {
+                    (
+                        pair_counts
+                            .get(&pair_key(*first, *other))
+                            .copied()
+                            .unwrap_or(0),
+                        count(other),
+                    )
+                };
+                key(a).cmp(&key(b)).then_with(|| {
+                    (rating(a) - rating(first))
+                        .abs()
+                        .total_cmp(&(rating(b) - rating(first)).abs())
+                })
+            })
+            .map(|second| (*first, *second))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn talks(n: usize) -> Vec<Uuid> {
+        (0..n).map(|_| Uuid::new_v4()).collect()
+    }
+
+    #[test]
+    fn bradley_terry_orders_by_wins() {
+        let t = talks(3);
+        let comparisons = vec![(t[0], t[1]), (t[0], t[1]), (t[1], t[2]), (t[1], t[2])];
+        let scores = bradley_terry(&comparisons);
+
+        assert!(scores[&t[0]].rating > scores[&t[1]].rating);
+        assert!(scores[&t[1]].rating > scores[&t[2]].rating);
+        assert!((scores[&t[1]].rating - BASE_RATING).abs() < 1e-6);
+        assert_eq!(scores[&t[1]].comparisons, 4);
+        assert_eq!(scores[&t[0]].wins, 2);
+    }
+
+    #[test]
+    fn a_single_win_stays_finite_and_symmetric() {
+        let t = talks(2);
+        let scores = bradley_terry(&[(t[0], t[1])]);
+
+        let (winner, loser) = (scores[&t[0]].rating, scores[&t[1]].rating);
+        assert!(winner.is_finite() && winner > BASE_RATING);
+        assert!((winner + loser - 2.0 * BASE_RATING).abs() < 1e-6);
+    }
+
+    #[test]
+    fn next_pair_covers_every_talk_first() {
+        let t = talks(4);
+        let mut comparisons = Vec::new();
+        let mut seen = HashSet::new();
+
+        assert_eq!(next_pair(&t, &comparisons, &seen), Some((t[0], t[1])));
+        comparisons.push((t[0], t[1]));
+        seen.insert(pair_key(t[0], t[1]));
+
+        assert_eq!(next_pair(&t, &comparisons, &seen), Some((t[2], t[3])));
+        comparisons.push((t[2], t[3]));
+        seen.insert(pair_key(t[2], t[3]));
+
+        // Then the two winners meet, as the closest unplayed match
+        assert_eq!(next_pair(&t, &comparisons, &seen), Some((t[0], t[2])));
+    }
+
+    #[test]
+    fn next_pair_skips_pairs_the_reviewer_has_seen() {
+        let t = talks(3);
+        let comparisons = vec![(t[0], t[1]), (t[1], t[2])];
+        let mut seen: HashSet<_> = comparisons.iter().map(|&(a, b)| pair_key(a, b)).collect();
+
+        // Another reviewer's verdicts count towards coverage but don't block
+        assert_eq!(
+            next_pair(&t, &comparisons, &HashSet::new()),
+            Some((t[0], t[2]))
+        );
+        assert_eq!(next_pair(&t, &comparisons, &seen), Some((t[0], t[2])));
+
+        seen.insert(pair_key(t[0], t[2]));
+        assert_eq!(next_pair(&t, &comparisons, &seen), None);
+    }
+}
@@ -3,6 +3,10 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::{
+    weighted_score, CriterionAverage, CriterionScore, RankingSort, ReviewerBias, RubricCriterion,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Rating {
     pub id: Uuid,
@@ -14,9 +18,13 @@ pub struct Rating {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Conferences with a review rubric take `scores` and derive `rating`
+/// from them; otherwise `rating` is required
 #[derive(Debug, Deserialize)]
 pub struct CreateRatingRequest {
-    pub rating: i32,
+    pub rating: Option<i32>,
+    #[serde(default)]
+    pub scores: Vec<CriterionScore>,
     pub notes: Option<String>,
 }
 
@@ -37,6 +45,11 @@ pub struct RatingResponse {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// The talk was edited after this rating was last updated
+    pub talk_changed_since_rating: bool,
+    /// Per-criterion scores, empty when the conference has no rubric
+    pub scores: Vec<CriterionScore>,
+    pub weighted_score: Option<f64>,
 }
 
 impl From<Rating> for RatingResponse {
@@ -51,6 +64,9 @@ impl From<Rating> for RatingResponse {
             notes: rating.notes,
             created_at: rating.created_at,
             updated_at: rating.updated_at,
+            talk_changed_since_rating: false, // Will be populated by handlers
+            scores: Vec::new(),               // Will be populated by handlers
+            weighted_score: None,
         }
     }
 }
@@ -61,6 +77,28 @@ impl RatingResponse {
         self.organizer_email = email;
         self
     }
+
+    pub fn with_talk_changed(mut self, changed: bool) -> Self {
+        self.talk_changed_since_rating = changed;
+        self
+    }
+
+    pub fn with_scores(
+        mut self,
+        criteria: &[RubricCriterion],
+        scores: Vec<CriterionScore>,
+    ) -> Self {
+        self.weighted_score = weighted_score(criteria, &scores);
+        self.scores = scores;
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RatingsStatisticsQuery {
+    pub conference_id: Option<Uuid>,
+    #[serde(default)]
+    pub sort: RankingSort,
 }
 
 #[derive(Debug, Serialize)]
@@ -72,6 +110,16 @@ pub struct TalkRatingStats {
     pub average_rating: Option<f64>,
     pub rating_count: i64,
     pub ratings: Vec<i32>,
+    /// Mean of the talk's per-reviewer z-scores; 0 is an average talk
+    pub normalized_score: Option<f64>,
+    /// Mean without the highest and lowest rating, from three ratings up
+    pub trimmed_average: Option<f64>,
+    /// Mean of the ratings' weighted rubric scores
+    pub weighted_average: Option<f64>,
+    pub criteria_averages: Vec<CriterionAverage>,
+    /// Elo-scale strength from head-to-head comparisons; 1500 is average
+    pub pairwise_score: Option<f64>,
+    pub comparison_count: i64,
 }
 
 #[derive(Debug, Serialize)]
@@ -81,8 +129,13 @@ pub struct RatingsStatisticsResponse {
     pub talks_with_ratings: i64,
     pub talks_without_ratings: i64,
     pub overall_average_rating: Option<f64>,
+    pub overall_weighted_average: Option<f64>,
     pub rating_distribution: RatingDistribution,
+    /// Head-to-head comparisons counted towards `pairwise_score`
+    pub total_comparisons: i64,
     pub talk_stats: Vec<TalkRatingStats>,
+    /// Reviewers from harshest to most lenient
+    pub reviewer_bias: Vec<ReviewerBias>,
 }
 
 #[derive(Debug, Serialize)]
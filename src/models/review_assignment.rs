@@ -0,0 +1,242 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::TalkState;
+
+#[derive(Debug, Deserialize)]
+pub struct AssignReviewersRequest {
+    /// How many reviewers each talk should end up with
+    pub reviews_per_talk: i32,
+    /// Organizers to draw from; defaults to every organizer
+    pub reviewer_ids: Option<Vec<Uuid>>,
+    /// Prefer reviewers whose expertise matches the talk's labels
+    #[serde(default)]
+    pub match_expertise: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssignReviewersResponse {
+    pub new_assignments: usize,
+    /// Talks that still have fewer than `reviews_per_talk` reviewers because
+    /// not enough organizers were eligible
+    pub understaffed_talk_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetExpertiseRequest {
+    pub label_ids: Vec<Uuid>,
+}
+
+/// A talk in the current user's review queue
+#[derive(Debug, Serialize, FromRow)]
+pub struct ReviewQueueItem {
+    pub talk_id: Uuid,
+    pub conference_id: Uuid,
+    pub title: String,
+    pub short_summary: String,
+    pub state: TalkState,
+    pub assigned_at: DateTime<Utc>,
+    /// The reviewer's rating, once they have rated the talk
+    pub rating: Option<i32>,
+    pub rated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ReviewerProgress {
+    pub reviewer_id: Uuid,
+    pub reviewer_name: String,
+    pub assigned: i64,
+    pub completed: i64,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct TalkReviewProgress {
+    pub talk_id: Uuid,
+    pub title: String,
+    pub assigned: i64,
+    pub completed: i64,
+    /// All ratings on the talk, including ones from unassigned organizers
+    pub total_ratings: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewCompletionReport {
+    pub conference_id: Uuid,
+    pub total_assignments: i64,
+    pub completed_assignments: i64,
+    pub reviewers: Vec<ReviewerProgress>,
+    pub talks: Vec<TalkReviewProgress>,
+}
+
+/// A talk that needs reviewers
+#[derive(Debug, Clone)]
+pub struct AssignableTalk {
+    pub talk_id: Uuid,
+    pub label_ids: Vec<Uuid>,
+    /// Reviewers already assigned to the talk
+    pub assigned: Vec<Uuid>,
+    /// Reviewers who must not review the talk, e.g. its own speakers
+    pub excluded: Vec<Uuid>,
+}
+
+/// A reviewer and the talks they already have in this conference
+#[derive(Debug, Clone)]
+pub struct ReviewerLoad {
+    pub reviewer_id: Uuid,
+    pub assigned: usize,
+    pub expertise: Vec<Uuid>,
+}
+
+/// Plan new (talk, reviewer) assignments so every talk reaches
+/// `reviews_per_talk` reviewers while keeping workloads even.
+///
+/// Talks are filled one reviewer per round, fewest reviewers first, so a
+/// shortage of reviewers is shared out. Each slot goes to the least loaded
+/// eligible reviewer; with `match_expertise`, a reviewer who knows one of
+/// the talk's labels wins over one carrying a single talk less.
+pub fn plan_review_assignments(
+    talks: &[AssignableTalk],
+    reviewers: &[ReviewerLoad],
+    reviews_per_talk: usize,
+    match_expertise: bool,
+) -> Vec<(Uuid, Uuid)> {
+    let mut load: HashMap<Uuid, usize> = reviewers
+        .iter()
+        .map(|r| (r.reviewer_id, r.assigned))
+        .collect();
+    let mut assigned: Vec<Vec<Uuid>> = talks.iter().map(|t| t.assigned.clone()).collect();
+    let mut planned = Vec::new();
+
+    for _ in 0..reviews_per_talk {
+        let mut order: Vec<usize> = (0..talks.len()).collect();
+        order.sort_by_key(|&i| (assigned[i].len(), talks[i].talk_id));
+
+        for i in order {
+            let talk = &talks[i];
+            if assigned[i].len() >= reviews_per_talk {
+                continue;
+            }
+
+            let best = reviewers
+                .iter()
+                .filter(|r| {
+                    !assigned[i].contains(&r.reviewer_id) && !talk.excluded.contains(&r.reviewer_id)
+                })
+                .min_by_key(|r| {
+                    let reviewer_load = load[&r.reviewer_id];
+                    let expert = match_expertise
+                        && r.expertise
+                            .iter()
+                            .any(|label| talk.label_ids.contains(label));
+                    (
+                        reviewer_load.saturating_sub(usize::from(expert)),
+                        !expert,
+                        reviewer_load,
+                        r.reviewer_id,
+                    )
+                });
+
+            if let Some(reviewer) = best {
+                planned.push((talk.talk_id, reviewer.reviewer_id));
+                assigned[i].push(reviewer.reviewer_id);
+                *load.entry(reviewer.reviewer_id).or_default() += 1;
+            }
+        }
+    }
+
+    planned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn talk(label_ids: Vec<Uuid>) -> AssignableTalk {
+        AssignableTalk {
+            talk_id: Uuid::new_v4(),
+            label_ids,
+            assigned: vec![],
+            excluded: vec![],
+        }
+    }
+
+    fn reviewer(assigned: usize, expertise: Vec<Uuid>) -> ReviewerLoad {
+        ReviewerLoad {
+            reviewer_id: Uuid::new_v4(),
+            assigned,
+            expertise,
+        }
+    }
+
+    fn load_of(planned: &[(Uuid, Uuid)], reviewer: &ReviewerLoad) -> usize {
+        planned
+            .iter()
+            .filter(|(_, r)| *r == reviewer.reviewer_id)
+            .count()
+    }
+
+    #[test]
+    fn spreads_reviews_evenly() {
+        let talks: Vec<_> = (0..6).map(|_| talk(vec![])).collect();
+        let reviewers = vec![
+            reviewer(0, vec![]),
+            reviewer(0, vec![]),
+            reviewer(2, vec![]),
+        ];
+
+        let planned = plan_review_assignments(&talks, &reviewers, 2, false);
+
+        assert_eq!(planned.len(), 12);
+        // Existing assignments count towards the load being evened out
+        let totals: Vec<usize> = reviewers
+            .iter()
+            .map(|r| r.assigned + load_of(&planned, r))
+            .collect();
+        assert!(totals.iter().max().unwrap() - totals.iter().min().unwrap() <= 1);
+        for t in &talks {
+            let on_talk: Vec<_> = planned.iter().filter(|(id, _)| *id == t.talk_id).collect();
+            assert_eq!(on_talk.len(), 2);
+            assert_ne!(on_talk[0].1, on_talk[1].1);
+        }
+    }
+
+    #[test]
+    fn keeps_existing_and_skips_excluded_reviewers() {
+        let speaker = reviewer(0, vec![]);
+        let other = reviewer(0, vec![]);
+        let third = reviewer(0, vec![]);
+        let mut own_talk = talk(vec![]);
+        own_talk.excluded = vec![speaker.reviewer_id];
+        own_talk.assigned = vec![third.reviewer_id];
+
+        let planned = plan_review_assignments(
+            &[own_talk.clone()],
+            &[speaker.clone(), other.clone(), third],
+            2,
+            false,
+        );
+
+        assert_eq!(planned, vec![(own_talk.talk_id, other.reviewer_id)]);
+    }
+
+    #[test]
+    fn prefers_experts_within_one_talk_of_load() {
+        let rust = Uuid::new_v4();
+        let generalist = reviewer(0, vec![]);
+        let expert = reviewer(1, vec![rust]);
+        let busy_expert = reviewer(3, vec![rust]);
+        let reviewers = vec![generalist.clone(), expert.clone(), busy_expert];
+        let rust_talk = talk(vec![rust]);
+
+        let planned =
+            plan_review_assignments(std::slice::from_ref(&rust_talk), &reviewers, 1, true);
+        assert_eq!(planned, vec![(rust_talk.talk_id, expert.reviewer_id)]);
+
+        let planned =
+            plan_review_assignments(std::slice::from_ref(&rust_talk), &reviewers, 1, false);
+        assert_eq!(planned, vec![(rust_talk.talk_id, generalist.reviewer_id)]);
+    }
+}
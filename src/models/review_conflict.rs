@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A reviewer's declared conflict with exactly one speaker, talk or email domain
+#[derive(Debug, Serialize, FromRow)]
+pub struct ReviewConflict {
+    pub id: Uuid,
+    pub reviewer_id: Uuid,
+    pub speaker_id: Option<Uuid>,
+    pub speaker_name: Option<String>,
+    pub talk_id: Option<Uuid>,
+    pub talk_title: Option<String>,
+    pub email_domain: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeclareConflictRequest {
+    pub speaker_id: Option<Uuid>,
+    pub talk_id: Option<Uuid>,
+    /// e.g. `example.com`; a leading `@` is accepted
+    pub email_domain: Option<String>,
+    pub reason: Option<String>,
+}
+
+/// Normalize an email domain for matching, or `None` if it isn't one
+pub fn normalize_email_domain(domain: &str) -> Option<String> {
+    let domain = domain.trim();
+    let domain = domain.strip_prefix('@').unwrap_or(domain).to_lowercase();
+    let valid = domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+
+    valid.then_some(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn email_domains_are_normalized() {
+        assert_eq!(
+            normalize_email_domain(" @Example.COM "),
+            Some("example.com".to_string())
+        );
+        assert_eq!(
+            normalize_email_domain("mail.my-company.org"),
+            Some("mail.my-company.org".to_string())
+        );
+        assert_eq!(normalize_email_domain("localhost"), None);
+        assert_eq!(normalize_email_domain("jane@example.com"), None);
+        assert_eq!(normalize_email_domain(".com"), None);
+        assert_eq!(normalize_email_domain(""), None);
+    }
+}
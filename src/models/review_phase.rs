@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConferenceConfig;
+
+use super::{Conference, SubmissionWindow, SubmissionWindowState};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewPhase {
+    Submission,
+    Review,
+    Decision,
+    Confirmation,
+}
+
+/// Where a conference is in its review cycle and what that allows.
+/// Ratings and decisions are only locked once the deadlines are set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewPhases {
+    pub phase: ReviewPhase,
+    pub submission_close: Option<DateTime<Utc>>,
+    pub review_deadline: Option<DateTime<Utc>>,
+    pub decision_deadline: Option<DateTime<Utc>>,
+    pub confirmation_deadline: Option<DateTime<Utc>>,
+    /// Whether ratings can still be created, changed or deleted
+    pub ratings_open: bool,
+    /// Whether organizers can accept, reject or waitlist talks
+    pub decisions_open: bool,
+    /// Human-readable status, e.g. "Review phase; reviews close at ..."
+    pub message: String,
+}
+
+impl ReviewPhases {
+    pub fn new(
+        window: &SubmissionWindow,
+        review_deadline: Option<DateTime<Utc>>,
+        decision_deadline: Option<DateTime<Utc>>,
+        confirmation_deadline: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let passed = |deadline: Option<DateTime<Utc>>| deadline.is_some_and(|d| now > d);
+
+        let phase = if passed(decision_deadline) {
+            ReviewPhase::Confirmation
+        } else if passed(review_deadline) {
+            ReviewPhase::Decision
+        } else if window.state == SubmissionWindowState::Closed {
+            ReviewPhase::Review
+        } else {
+            ReviewPhase::Submission
+        };
+
+        // Without a review deadline, reviewing and deciding run side by side
+        let decisions_open = match phase {
+            ReviewPhase::Decision => true,
+            ReviewPhase::Confirmation => false,
+            ReviewPhase::Submission | ReviewPhase::Review => review_deadline.is_none(),
+        };
+
+        let until = |label: &str, deadline: Option<DateTime<Utc>>| match deadline {
+            Some(d) => format!("; {} {}", label, d.to_rfc3339()),
+            None => String::new(),
+        };
+        let message = match phase {
+            ReviewPhase::Submission => {
+                format!(
+                    "Submission phase{}",
+                    until("CfP closes at", window.closes_at)
+                )
+            }
+            ReviewPhase::Review => {
+                format!("Review phase{}", until("reviews close at", review_deadline))
+            }
+            ReviewPhase::Decision => {
+                format!(
+                    "Decision phase{}",
+                    until("decisions due by", decision_deadline)
+                )
+            }
+            ReviewPhase::Confirmation => format!(
+                "Confirmation phase{}",
+                until("speakers confirm by", confirmation_deadline)
+            ),
+        };
+
+        Self {
+            phase,
+            submission_close: window.closes_at,
+            review_deadline,
+            decision_deadline,
+            confirmation_deadline,
+            ratings_open: !passed(review_deadline),
+            decisions_open,
+            message,
+        }
+    }
+
+    /// Resolve a conference's phase, using its effective CfP window
+    pub fn for_conference(
+        conference: &Conference,
+        config: &ConferenceConfig,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let window = SubmissionWindow::for_conference(conference, config, now);
+        Self::new(
+            &window,
+            conference.review_deadline,
+            conference.decision_deadline,
+            conference.confirmation_deadline,
+            now,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_phases_follow_deadlines() {
+        let now = Utc::now();
+        let day = Duration::days(1);
+        let closed = SubmissionWindow::new(None, Some(now - day * 3), now);
+
+        let open = SubmissionWindow::new(None, Some(now + day), now);
+        let phases = ReviewPhases::new(&open, Some(now + day * 2), None, None, now);
+        assert_eq!(phases.phase, ReviewPhase::Submission);
+
+        let phases = ReviewPhases::new(&closed, Some(now + day), Some(now + day * 2), None, now);
+        assert_eq!(phases.phase, ReviewPhase::Review);
+        assert!(phases.ratings_open);
+        assert!(!phases.decisions_open);
+
+        let phases = ReviewPhases::new(&closed, Some(now - day), Some(now + day), None, now);
+        assert_eq!(phases.phase, ReviewPhase::Decision);
+        assert!(!phases.ratings_open);
+        assert!(phases.decisions_open);
+
+        let phases = ReviewPhases::new(&closed, Some(now - day * 2), Some(now - day), None, now);
+        assert_eq!(phases.phase, ReviewPhase::Confirmation);
+        assert!(!phases.decisions_open);
+    }
+
+    #[test]
+    fn test_no_deadlines_locks_nothing() {
+        let now = Utc::now();
+        let window = SubmissionWindow::new(None, Some(now - Duration::days(1)), now);
+        let phases = ReviewPhases::new(&window, None, None, None, now);
+
+        assert_eq!(phases.phase, ReviewPhase::Review);
+        assert!(phases.ratings_open);
+        assert!(phases.decisions_open);
+        assert_eq!(phases.message, "Review phase");
+    }
+}
@@ -0,0 +1,238 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A weighted criterion on a conference's review rubric
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RubricCriterion {
+    pub id: Uuid,
+    pub conference_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub weight: f64,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRubricCriterionRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Relative weight in the overall score, defaults to 1
+    pub weight: Option<f64>,
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRubricCriterionRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub weight: Option<f64>,
+    pub position: Option<i32>,
+}
+
+/// A reviewer's 1-5 score for one criterion
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CriterionScore {
+    pub criterion_id: Uuid,
+    pub score: i32,
+}
+
+/// A stored criterion score, as read from `rating_scores`
+#[derive(Debug, Clone, FromRow)]
+pub struct RatingScore {
+    pub rating_id: Uuid,
+    pub criterion_id: Uuid,
+    pub score: i32,
+}
+
+impl From<RatingScore> for CriterionScore {
+    fn from(score: RatingScore) -> Self {
+        Self {
+            criterion_id: score.criterion_id,
+            score: score.score,
+        }
+    }
+}
+
+/// How a talk did on one criterion across all of its ratings
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CriterionAverage {
+    pub criterion_id: Uuid,
+    pub name: String,
+    pub weight: f64,
+    pub average: Option<f64>,
+    pub score_count: i64,
+}
+
+/// The weight-adjusted mean of one rating's criterion scores. Scores for
+/// criteria that are no longer on the rubric are ignored.
+pub fn weighted_score(criteria: &[RubricCriterion], scores: &[CriterionScore]) -> Option<f64> {
+    let (total, weights) = scores
+        .iter()
+        .filter_map(|s| {
+            criteria
+                .iter()
+                .find(|c| c.id == s.criterion_id)
+                .map(|c| (f64::from(s.score) * c.weight, c.weight))
+        })
+        .fold((0.0, 0.0), |(total, weights), (value, weight)| {
+            (total + value, weights + weight)
+        });
+
+    (weights > 0.0).then(|| total / weights)
+}
+
+/// Average each criterion over a talk's ratings, in rubric order
+pub fn criterion_averages(
+    criteria: &[RubricCriterion],
+    ratings: &[Vec<CriterionScore>],
+) -> Vec<CriterionAverage> {
+    criteria
+        .iter()
+        .map(|criterion| {
+            let scores: Vec<i32> = ratings
+                .iter()
+                .flatten()
+                .filter(|s| s.criterion_id == criterion.id)
+                .map(|s| s.score)
+                .collect();
+            let average = (!scores.is_empty())
+                .then(|| scores.iter().map(|&s| f64::from(s)).sum::<f64>() / scores.len() as f64);
+
+            CriterionAverage {
+                criterion_id: criterion.id,
+                name: criterion.name.clone(),
+                weight: criterion.weight,
+                average,
+                score_count: scores.len() as i64,
+            }
+        })
+        .collect()
+}
+
+/// Check that a rating scores every criterion on the rubric exactly once
+/// with a value from 1 to 5, returning the rounded weighted score
+pub fn validate_scores(
+    criteria: &[RubricCriterion],
+    scores: &[CriterionScore],
+) -> Result<i32, String> {
+    if let Some(score) = scores.iter().find(|s| !(1..=5).contains(&s.score)) {
+        let name = criteria
+            .iter()
+            .find(|c| c.id == score.criterion_id)
+            .map(|c| c.name.as_str())
+            .unwrap_or("Each criterion");
+        return Err(format!("{} must be scored between 1 and 5", name));
+    }
+
+    if let Some(unknown) = scores
+        .iter()
+        .find(|s| !criteria.iter().any(|c| c.id == s.criterion_id))
+    {
+        return Err(format!(
+            "Unknown rubric criterion: {}",
+            unknown.criterion_id
+        ));
+    }
+
+    for criterion in criteria {
+        match scores
+            .iter()
+            .filter(|s| s.criterion_id == criterion.id)
+            .count()
+        {
+            0 => return Err(format!("'{}' needs a score", criterion.name)),
+            1 => {}
+            _ => return Err(format!("'{}' was scored more than once", criterion.name)),
+        }
+    }
+
+    let overall = weighted_score(criteria, scores).unwrap_or(1.0);
+    Ok((overall.round() as i32).clamp(1, 5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn criterion(name: &str, weight: f64) -> RubricCriterion {
+        RubricCriterion {
+            id: Uuid::new_v4(),
+            conference_id: Uuid::nil(),
+            name: name.to_string(),
+            description: None,
+            weight,
+            position: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn score(criterion: &RubricCriterion, score: i32) -> CriterionScore {
+        CriterionScore {
+            criterion_id: criterion.id,
+            score,
+        }
+    }
+
+    #[test]
+    fn weighted_score_respects_weights() {
+        let relevance = criterion("Relevance", 3.0);
+        let depth = criterion("Technical depth", 1.0);
+        let criteria = vec![relevance.clone(), depth.clone()];
+
+        let overall = weighted_score(&criteria, &[score(&relevance, 5), score(&depth, 1)]).unwrap();
+        assert!((overall - 4.0).abs() < f64::EPSILON);
+        assert_eq!(weighted_score(&criteria, &[]), None);
+    }
+
+    #[test]
+    fn validate_scores_requires_every_criterion_once() {
+        let relevance = criterion("Relevance", 2.0);
+        let fit = criterion("Fit", 1.0);
+        let criteria = vec![relevance.clone(), fit.clone()];
+
+        assert_eq!(
+            validate_scores(&criteria, &[score(&relevance, 4), score(&fit, 2)]),
+            Ok(3)
+        );
+        assert!(validate_scores(&criteria, &[score(&relevance, 4)]).is_err());
+        assert!(validate_scores(&criteria, &[score(&relevance, 6), score(&fit, 2)]).is_err());
+        assert!(validate_scores(
+            &criteria,
+            &[score(&relevance, 4), score(&relevance, 5), score(&fit, 2)]
+        )
+        .is_err());
+        assert!(validate_scores(
+            &criteria,
+            &[
+                score(&relevance, 4),
+                score(&fit, 2),
+                score(&criterion("Other", 1.0), 3)
+            ]
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn criterion_averages_cover_the_whole_rubric() {
+        let relevance = criterion("Relevance", 1.0);
+        let fit = criterion("Fit", 1.0);
+        let criteria = vec![relevance.clone(), fit.clone()];
+
+        let averages = criterion_averages(
+            &criteria,
+            &[
+                vec![score(&relevance, 4), score(&fit, 2)],
+                vec![score(&relevance, 5)],
+            ],
+        );
+        assert_eq!(averages[0].average, Some(4.5));
+        assert_eq!(averages[0].score_count, 2);
+        assert_eq!(averages[1].average, Some(2.0));
+        assert_eq!(averages[1].score_count, 1);
+    }
+}
@@ -0,0 +1,535 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::Serialize;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::{
+    availability_fit, describe_windows, AvailabilityFit, AvailabilityWindow, ScheduleSlot,
+    TalkState,
+};
+
+/// A speaker switching rooms needs at least this long between talks
+pub const CHANGEOVER_MINUTES: i64 = 10;
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSeverity {
+    /// The schedule can't work as it is
+    Error,
+    /// Worth a look, but allowed
+    Warning,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    /// Two slots of the same track overlap
+    TrackOverlap,
+    /// A speaker presents in two slots at the same time
+    SpeakerDoubleBooked,
+    /// A talk fills more than one slot
+    DuplicateTalk,
+    /// A draft, rejected or withdrawn talk is on the schedule
+    TalkNotSchedulable,
+    /// A talk runs longer than its slot
+    TalkTooLong,
+    /// A speaker isn't at the conference on the slot's day
+    SpeakerUnavailable,
+    /// A submitted, waitlisted or pending talk is on the schedule
+    TalkNotConfirmed,
+    /// A speaker has less than `CHANGEOVER_MINUTES` to change rooms
+    TightChangeover,
+    /// A slot falls outside the conference dates
+    OutsideConference,
+    /// A speaker is there that day, but not for the whole slot
+    OutsideAvailability,
+}
+
+impl ConflictKind {
+    pub fn severity(self) -> ConflictSeverity {
+        match self {
+            ConflictKind::TrackOverlap
+            | ConflictKind::SpeakerDoubleBooked
+            | ConflictKind::DuplicateTalk
+            | ConflictKind::TalkNotSchedulable
+            | ConflictKind::TalkTooLong
+            | ConflictKind::SpeakerUnavailable => ConflictSeverity::Error,
+            ConflictKind::TalkNotConfirmed
+            | ConflictKind::TightChangeover
+            | ConflictKind::OutsideConference
+            | ConflictKind::OutsideAvailability => ConflictSeverity::Warning,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ScheduleConflict {
+    pub kind: ConflictKind,
+    pub severity: ConflictSeverity,
+    pub message: String,
+    /// The slots involved
+    pub slot_ids: Vec<Uuid>,
+    /// The talks involved, if any
+    pub talk_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScheduleConflictReport {
+    pub error_count: usize,
+    pub warning_count: usize,
+    /// Errors first, then warnings, each in schedule order
+    pub conflicts: Vec<ScheduleConflict>,
+}
+
+impl From<Vec<ScheduleConflict>> for ScheduleConflictReport {
+    fn from(conflicts: Vec<ScheduleConflict>) -> Self {
+        let error_count = conflicts
+            .iter()
+            .filter(|c| c.severity == ConflictSeverity::Error)
+            .count();
+        Self {
+            error_count,
+            warning_count: conflicts.len() - error_count,
+            conflicts,
+        }
+    }
+}
+
+/// A talk on the schedule, with everyone presenting it
+#[derive(Debug, Clone)]
+pub struct ScheduledTalk {
+    pub id: Uuid,
+    pub title: String,
+    pub state: TalkState,
+    pub duration_minutes: i32,
+    /// Primary speaker and accepted co-speakers, as `(user_id, name)`
+    pub speakers: Vec<(Uuid, String)>,
+    pub label_ids: Vec<Uuid>,
+    /// Windows for this talk by speaker id, for speakers who gave any
+    pub availability: HashMap<Uuid, Vec<AvailabilityWindow>>,
+}
+
+impl ScheduledTalk {
+    /// Each speaker whose availability `slot` doesn't fit, with their
+    /// windows and how the slot misses them
+    pub fn availability_misses(
+        &self,
+        slot: &ScheduleSlot,
+    ) -> Vec<(&str, &[AvailabilityWindow], AvailabilityFit)> {
+        self.speakers
+            .iter()
+            .filter_map(|(id, name)| {
+                let windows = self.availability.get(id)?;
+                match availability_fit(windows, slot.slot_date, slot.start_time, slot.end_time) {
+                    AvailabilityFit::Available => None,
+                    fit => Some((name.as_str(), windows.as_slice(), fit)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Everything needed to check a conference's schedule
+#[derive(Debug, Clone)]
+pub struct ScheduleSnapshot {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub slots: Vec<ScheduleSlot>,
+    pub talks: HashMap<Uuid, ScheduledTalk>,
+    pub track_names: HashMap<Uuid, String>,
+}
+
+pub(super) fn minutes_between(from: NaiveTime, to: NaiveTime) -> i64 {
+    (to - from).num_minutes()
+}
+
+pub(super) fn overlaps(a: &ScheduleSlot, b: &ScheduleSlot) -> bool {
+    a.slot_date == b.slot_date && a.start_time < b.end_time && b.start_time < a.end_time
+}
+
+fn state_name(state: &TalkState) -> &'static str {
+    match state {
+        TalkState::Draft => "a draft",
+        TalkState::Submitted => "still submitted",
+        TalkState::Pending => "pending confirmation",
+        TalkState::Accepted => "accepted",
+        TalkState::Rejected => "rejected",
+        TalkState::Withdrawn => "withdrawn",
+        TalkState::Waitlisted => "waitlisted",
+    }
+}
+
+impl ScheduleSnapshot {
+    fn track_name(&self, track_id: Uuid) -> &str {
+        self.track_names
+            .get(&track_id)
+            .map_or("an unknown track", String::as_str)
+    }
+
+    fn talk(&self, slot: &ScheduleSlot) -> Option<&ScheduledTalk> {
+        slot.talk_id.and_then(|id| self.talks.get(&id))
+    }
+
+    /// Put a new or changed slot in place of the stored one with its id
+    pub fn apply(&mut self, slot: ScheduleSlot) {
+        match self.slots.iter_mut().find(|s| s.id == slot.id) {
+            Some(existing) => *existing = slot,
+            None => self.slots.push(slot),
+        }
+    }
+
+    /// Every problem with the schedule, errors first
+    pub fn conflicts(&self) -> Vec<ScheduleConflict> {
+        let mut slots: Vec<&ScheduleSlot> = self.slots.iter().collect();
+        slots.sort_by_key(|s| (s.slot_date, s.start_time, self.track_name(s.track_id)));
+
+        let mut conflicts = Vec::new();
+        let mut push = |kind: ConflictKind, message: String, slot_ids, talk_ids| {
+            conflicts.push(ScheduleConflict {
+                kind,
+                severity: kind.severity(),
+                message,
+                slot_ids,
+                talk_ids,
+            });
+        };
+
+        for slot in &slots {
+            if slot.slot_date < self.start_date || slot.slot_date > self.end_date {
+                push(
+                    ConflictKind::OutsideConference,
+                    format!(
+                        "The {} slot on {} is outside the conference dates",
+                        self.track_name(slot.track_id),
+                        slot.slot_date
+                    ),
+                    vec![slot.id],
+                    slot.talk_id.into_iter().collect(),
+                );
+            }
+
+            let Some(talk) = self.talk(slot) else {
+                continue;
+            };
+            match talk.state {
+                TalkState::Accepted => {}
+                TalkState::Draft | TalkState::Rejected | TalkState::Withdrawn => push(
+                    ConflictKind::TalkNotSchedulable,
+                    format!(
+                        "\"{}\" is {} and can't be scheduled",
+                        talk.title,
+                        state_name(&talk.state)
+                    ),
+                    vec![slot.id],
+                    vec![talk.id],
+                ),
+                TalkState::Submitted | TalkState::Pending | TalkState::Waitlisted => push(
+                    ConflictKind::TalkNotConfirmed,
+                    format!(
+                        "\"{}\" is {}, not accepted",
+                        talk.title,
+                        state_name(&talk.state)
+                    ),
+                    vec![slot.id],
+                    vec![talk.id],
+                ),
+            }
+
+            for (name, windows, fit) in talk.availability_misses(slot) {
+                let (kind, message) = match fit {
+                    AvailabilityFit::AbsentThatDay => (
+                        ConflictKind::SpeakerUnavailable,
+                        format!(
+                            "{} isn't available on {} to present \"{}\"",
+                            name, slot.slot_date, talk.title
+                        ),
+                    ),
+                    _ => (
+                        ConflictKind::OutsideAvailability,
+                        format!(
+                            "{} is only available {} on {}, but \"{}\" is at {}-{}",
+                            name,
+                            describe_windows(windows, slot.slot_date),
+                            slot.slot_date,
+                            talk.title,
+                            slot.start_time.format("%H:%M"),
+                            slot.end_time.format("%H:%M")
+                        ),
+                    ),
+                };
+                push(kind, message, vec![slot.id], vec![talk.id]);
+            }
+
+            let slot_minutes = minutes_between(slot.start_time, slot.end_time);
+            if i64::from(talk.duration_minutes) > slot_minutes {
+                push(
+                    ConflictKind::TalkTooLong,
+                    format!(
+                        "\"{}\" runs {} minutes but its slot is only {} minutes long",
+                        talk.title, talk.duration_minutes, slot_minutes
+                    ),
+                    vec![slot.id],
+                    vec![talk.id],
+                );
+            }
+        }
+
+        for (i, a) in slots.iter().enumerate() {
+            for b in &slots[i + 1..] {
+                let overlap = overlaps(a, b);
+                if overlap && a.track_id == b.track_id {
+                    push(
+                        ConflictKind::TrackOverlap,
+                        format!(
+                            "Slots in {} overlap on {} ({}-{} and {}-{})",
+                            self.track_name(a.track_id),
+                            a.slot_date,
+                            a.start_time.format("%H:%M"),
+                            a.end_time.format("%H:%M"),
+                            b.start_time.format("%H:%M"),
+                            b.end_time.format("%H:%M")
+                        ),
+                        vec![a.id, b.id],
+                        a.talk_id.into_iter().chain(b.talk_id).collect(),
+                    );
+                }
+
+                let (Some(talk_a), Some(talk_b)) = (self.talk(a), self.talk(b)) else {
+                    continue;
+                };
+                if talk_a.id == talk_b.id {
+                    push(
+                        ConflictKind::DuplicateTalk,
+                        format!(
+                            "\"{}\" is scheduled both in {} on {} at {} and in {} on {} at {}",
+                            talk_a.title,
+                            self.track_name(a.track_id),
+                            a.slot_date,
+                            a.start_time.format("%H:%M"),
+                            self.track_name(b.track_id),
+                            b.slot_date,
+                            b.start_time.format("%H:%M")
+                        ),
+                        vec![a.id, b.id],
+                        vec![talk_a.id],
+                    );
+                    continue;
+                }
+
+                // Minutes from the end of the earlier slot to the start of the later one
+                let gap = if a.slot_date != b.slot_date {
+                    None
+                } else if a.end_time <= b.start_time {
+                    Some(minutes_between(a.end_time, b.start_time))
+                } else {
+                    Some(minutes_between(b.end_time, a.start_time))
+                };
+
+                for (speaker_id, name) in &talk_a.speakers {
+                    if !talk_b.speakers.iter().any(|(id, _)| id == speaker_id) {
+                        continue;
+                    }
+                    if overlap {
+                        push(
+                            ConflictKind::SpeakerDoubleBooked,
+                            format!(
+                                "{} presents \"{}\" in {} and \"{}\" in {} at the same time on {}",
+                                name,
+                                talk_a.title,
+                                self.track_name(a.track_id),
+                                talk_b.title,
+                                self.track_name(b.track_id),
+                                a.slot_date
+                            ),
+                            vec![a.id, b.id],
+                            vec![talk_a.id, talk_b.id],
+                        );
+                    } else if a.track_id != b.track_id
+                        && gap.is_some_and(|gap| gap < CHANGEOVER_MINUTES)
+                    {
+                        push(
+                            ConflictKind::TightChangeover,
+                            format!(
+                                "{} has {} minutes to get from \"{}\" in {} to \"{}\" in {}",
+                                name,
+                                gap.unwrap_or_default(),
+                                talk_a.title,
+                                self.track_name(a.track_id),
+                                talk_b.title,
+                                self.track_name(b.track_id)
+                            ),
+                            vec![a.id, b.id],
+                            vec![talk_a.id, talk_b.id],
+                        );
+                    }
+                }
+            }
+        }
+
+        // Stable, so each severity keeps schedule order
+        conflicts.sort_by_key(|c| c.severity == ConflictSeverity::Warning);
+        conflicts
+    }
+
+    /// The conflicts a slot is involved in
+    pub fn conflicts_for_slot(&self, slot_id: Uuid) -> Vec<ScheduleConflict> {
+        self.conflicts()
+            .into_iter()
+            .filter(|c| c.slot_ids.contains(&slot_id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+    }
+
+    fn slot(track_id: Uuid, start: u32, end: u32, talk_id: Option<Uuid>) -> ScheduleSlot {
+        ScheduleSlot {
+            id: Uuid::new_v4(),
+            conference_id: Uuid::nil(),
+            track_id,
+            talk_id,
+            slot_date: date(),
+            start_time: NaiveTime::from_hms_opt(start / 100, start % 100, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(end / 100, end % 100, 0).unwrap(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn talk(title: &str, state: TalkState, speakers: &[Uuid]) -> ScheduledTalk {
+        ScheduledTalk {
+            id: Uuid::new_v4(),
+            title: title.to_string(),
+            state,
+            duration_minutes: 30,
+            speakers: speakers.iter().map(|&id| (id, "Ada".to_string())).collect(),
+            label_ids: Vec::new(),
+            availability: HashMap::new(),
+        }
+    }
+
+    fn snapshot(slots: Vec<ScheduleSlot>, talks: Vec<ScheduledTalk>) -> ScheduleSnapshot {
+        ScheduleSnapshot {
+            start_date: date(),
+            end_date: date(),
+            slots,
+            talks: talks.into_iter().map(|t| (t.id, t)).collect(),
+            track_names: HashMap::new(),
+        }
+    }
+
+    fn kinds(snapshot: &ScheduleSnapshot) -> Vec<ConflictKind> {
+        snapshot.conflicts().iter().map(|c| c.kind).collect()
+    }
+
+    #[test]
+    fn overlapping_slots_in_one_track() {
+        let track = Uuid::new_v4();
+        let snapshot = snapshot(
+            vec![
+                slot(track, 900, 1000, None),
+                slot(track, 930, 1030, None),
+                slot(track, 1030, 1100, None),
+            ],
+            vec![],
+        );
+        assert_eq!(kinds(&snapshot), vec![ConflictKind::TrackOverlap]);
+    }
+
+    #[test]
+    fn speaker_double_booked_across_tracks() {
+        let ada = Uuid::new_v4();
+        let a = talk("A", TalkState::Accepted, &[ada]);
+        let b = talk("B", TalkState::Accepted, &[Uuid::new_v4(), ada]);
+        let c = talk("C", TalkState::Accepted, &[ada]);
+        let slots = vec![
+            slot(Uuid::new_v4(), 900, 1000, Some(a.id)),
+            slot(Uuid::new_v4(), 930, 1030, Some(b.id)),
+            slot(Uuid::new_v4(), 1035, 1105, Some(c.id)),
+        ];
+        let snapshot = snapshot(slots, vec![a, b, c]);
+        assert_eq!(
+            kinds(&snapshot),
+            vec![
+                ConflictKind::SpeakerDoubleBooked,
+                ConflictKind::TightChangeover
+            ]
+        );
+        assert_eq!(
+            snapshot.conflicts()[1].message,
+            "Ada has 5 minutes to get from \"B\" in an unknown track to \"C\" in an unknown track"
+        );
+    }
+
+    #[test]
+    fn talk_states_and_duplicates() {
+        let track = Uuid::new_v4();
+        let rejected = talk("Rejected", TalkState::Rejected, &[]);
+        let pending = talk("Pending", TalkState::Pending, &[]);
+        let slots = vec![
+            slot(track, 900, 930, Some(rejected.id)),
+            slot(track, 930, 1000, Some(pending.id)),
+            slot(track, 1000, 1020, Some(pending.id)),
+        ];
+        let snapshot = snapshot(slots, vec![rejected, pending]);
+        assert_eq!(
+            kinds(&snapshot),
+            vec![
+                ConflictKind::TalkNotSchedulable,
+                ConflictKind::TalkTooLong,
+                ConflictKind::DuplicateTalk,
+                ConflictKind::TalkNotConfirmed,
+                ConflictKind::TalkNotConfirmed,
+            ]
+        );
+    }
+
+    #[test]
+    fn slots_outside_speaker_availability() {
+        let ada = Uuid::new_v4();
+        let mut a = talk("A", TalkState::Accepted, &[ada]);
+        a.availability.insert(
+            ada,
+            vec![AvailabilityWindow {
+                available_date: date(),
+                start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                end_time: NaiveTime::from_hms_opt(12, 0, 0).unwrap(),
+            }],
+        );
+        let mut late = slot(Uuid::new_v4(), 1130, 1230, Some(a.id));
+        let mut snapshot = snapshot(vec![late.clone()], vec![a]);
+        assert_eq!(kinds(&snapshot), vec![ConflictKind::OutsideAvailability]);
+        assert_eq!(
+            snapshot.conflicts()[0].message,
+            "Ada is only available 09:00-12:00 on 2025-06-01, but \"A\" is at 11:30-12:30"
+        );
+
+        late.slot_date = date().succ_opt().unwrap();
+        snapshot.end_date = late.slot_date;
+        snapshot.apply(late);
+        assert_eq!(kinds(&snapshot), vec![ConflictKind::SpeakerUnavailable]);
+    }
+
+    #[test]
+    fn conflicts_for_a_changed_slot() {
+        let track = Uuid::new_v4();
+        let existing = slot(track, 900, 1000, None);
+        let mut snapshot = snapshot(vec![existing.clone()], vec![]);
+        let mut moved = slot(track, 1000, 1100, None);
+        snapshot.apply(moved.clone());
+        assert!(snapshot.conflicts_for_slot(moved.id).is_empty());
+
+        moved.start_time = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+        snapshot.apply(moved.clone());
+        assert_eq!(snapshot.slots.len(), 2);
+        assert_eq!(snapshot.conflicts_for_slot(moved.id).len(), 1);
+        assert_eq!(snapshot.conflicts_for_slot(existing.id).len(), 1);
+    }
+}
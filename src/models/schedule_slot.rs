@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use super::ScheduleConflict;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct ScheduleSlot {
     pub id: Uuid,
@@ -50,6 +52,17 @@ pub struct ScheduleSlotResponse {
     pub end_time: NaiveTime,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Soft schedule issues the slot is involved in, such as a talk that
+    /// isn't confirmed yet
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ScheduleConflict>,
+}
+
+impl ScheduleSlotResponse {
+    pub fn with_warnings(mut self, warnings: Vec<ScheduleConflict>) -> Self {
+        self.warnings = warnings;
+        self
+    }
 }
 
 impl From<ScheduleSlot> for ScheduleSlotResponse {
@@ -64,6 +77,7 @@ impl From<ScheduleSlot> for ScheduleSlotResponse {
             end_time: slot.end_time,
             created_at: slot.created_at,
             updated_at: slot.updated_at,
+            warnings: Vec::new(),
         }
     }
 }
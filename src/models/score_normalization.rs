@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::TalkRatingStats;
+
+/// One reviewer's rating of one talk
+#[derive(Debug, Clone, FromRow)]
+pub struct ReviewScore {
+    pub talk_id: Uuid,
+    pub reviewer_id: Uuid,
+    pub reviewer_name: String,
+    pub rating: i32,
+}
+
+/// How one reviewer rates compared with the rest of the committee
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReviewerBias {
+    pub reviewer_id: Uuid,
+    pub reviewer_name: String,
+    pub rating_count: i64,
+    pub mean_rating: f64,
+    pub std_dev: f64,
+    /// Mean rating minus the mean of all ratings: below zero is harsher
+    /// than average, above zero more lenient
+    pub bias: f64,
+}
+
+/// Per-talk normalized scores and per-reviewer bias for a set of ratings
+#[derive(Debug, Default)]
+pub struct ScoreNormalization {
+    /// Mean of each talk's per-reviewer z-scores
+    pub talk_scores: HashMap<Uuid, f64>,
+    /// Reviewers from harshest to most lenient
+    pub reviewers: Vec<ReviewerBias>,
+}
+
+/// How to order talks in the ratings statistics
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RankingSort {
+    /// Most rated first
+    #[default]
+    RatingCount,
+    Average,
+    Normalized,
+    Trimmed,
+    Weighted,
+    Pairwise,
+}
+
+impl RankingSort {
+    fn score(self, stats: &TalkRatingStats) -> Option<f64> {
+        match self {
+            RankingSort::RatingCount => Some(stats.rating_count as f64),
+            RankingSort::Average => stats.average_rating,
+            RankingSort::Normalized => stats.normalized_score,
+            RankingSort::Trimmed => stats.trimmed_average,
+            RankingSort::Weighted => stats.weighted_average,
+            RankingSort::Pairwise => stats.pairwise_score,
+        }
+    }
+}
+
+/// Order talks best first by the chosen score. Talks without that score go
+/// last and ties keep their existing order.
+pub fn rank_talks(talks: &mut [TalkRatingStats], sort: RankingSort) {
+    talks.sort_by(|a, b| match (sort.score(a), sort.score(b)) {
+        (Some(a), Some(b)) => b.total_cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+}
+
+fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    (mean, variance.sqrt())
+}
+
+/// Mean of the ratings after dropping the single highest and lowest, so one
+/// outlier can't swing the result. Needs at least three ratings.
+pub fn trimmed_mean(values: &[i32]) -> Option<f64> {
+    if values.len() < 3 {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let kept = &sorted[1..sorted.len() - 1];
+    Some(kept.iter().map(|&v| f64::from(v)).sum::<f64>() / kept.len() as f64)
+}
+
+/// Convert each rating to a z-score against its reviewer's own mean and
+/// spread, then average those per talk. A reviewer who gives every talk the
+/// same rating tells us nothing about relative quality, so their z-scores
+/// are zero.
+pub fn normalize_scores(scores: &[ReviewScore]) -> ScoreNormalization {
+    if scores.is_empty() {
+        return ScoreNormalization::default();
+    }
+
+    let all: Vec<f64> = scores.iter().map(|s| f64::from(s.rating)).collect();
+    let (overall_mean, _) = mean_and_std_dev(&all);
+
+    let mut by_reviewer: HashMap<Uuid, Vec<&ReviewScore>> = HashMap::new();
+    for score in scores {
+        by_reviewer
+            .entry(score.reviewer_id)
+            .or_default()
+            .push(score);
+    }
+
+    let mut z_scores: HashMap<Uuid, Vec<f64>> = HashMap::new();
+    let mut reviewers = Vec::with_capacity(by_reviewer.len());
+    for (reviewer_id, reviews) in by_reviewer {
+        let ratings: Vec<f64> = reviews.iter().map(|s| f64::from(s.rating)).collect();
+        let (mean, std_dev) = mean_and_std_dev(&ratings);
+
+        for review in &reviews {
+            let z = if std_dev > f64::EPSILON {
+                (f64::from(review.rating) - mean) / std_dev
+            } else {
+                0.0
+            };
+            z_scores.entry(review.talk_id).or_default().push(z);
+        }
+
+        reviewers.push(ReviewerBias {
+            reviewer_id,
+            reviewer_name: reviews[0].reviewer_name.clone(),
+            rating_count: reviews.len() as i64,
+            mean_rating: mean,
+            std_dev,
+            bias: mean - overall_mean,
+        });
+    }
+
+    reviewers.sort_by(|a, b| {
+        a.bias
+            .total_cmp(&b.bias)
+            .then_with(|| a.reviewer_name.cmp(&b.reviewer_name))
+    });
+
+    let talk_scores = z_scores
+        .into_iter()
+        .map(|(talk_id, zs)| (talk_id, zs.iter().sum::<f64>() / zs.len() as f64))
+        .collect();
+
+    ScoreNormalization {
+        talk_scores,
+        reviewers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(talk_id: Uuid, reviewer_id: Uuid, rating: i32) -> ReviewScore {
+        ReviewScore {
+            talk_id,
+            reviewer_id,
+            reviewer_name: reviewer_id.to_string(),
+            rating,
+        }
+    }
+
+    #[test]
+    fn trimmed_mean_drops_one_outlier_each_side() {
+        assert_eq!(trimmed_mean(&[5, 1, 4, 4]), Some(4.0));
+        assert_eq!(trimmed_mean(&[3, 3, 3]), Some(3.0));
+        assert_eq!(trimmed_mean(&[1, 5]), None);
+    }
+
+    #[test]
+    fn normalization_evens_out_harsh_and_lenient_reviewers() {
+        let (strong, weak) = (Uuid::new_v4(), Uuid::new_v4());
+        let (lenient, harsh) = (Uuid::new_v4(), Uuid::new_v4());
+        let scores = vec![
+            score(strong, lenient, 5),
+            score(weak, lenient, 4),
+            score(strong, harsh, 2),
+            score(weak, harsh, 1),
+        ];
+
+        let result = normalize_scores(&scores);
+
+        // Both reviewers agree on the order, so the talks end up at +1 and -1
+        assert!((result.talk_scores[&strong] - 1.0).abs() < 1e-9);
+        assert!((result.talk_scores[&weak] + 1.0).abs() < 1e-9);
+
+        assert_eq!(result.reviewers[0].reviewer_id, harsh);
+        assert!((result.reviewers[0].bias + 1.5).abs() < 1e-9);
+        assert_eq!(result.reviewers[1].reviewer_id, lenient);
+        assert!((result.reviewers[1].bias - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reviewers_without_spread_contribute_zero() {
+        let (talk_a, talk_b) = (Uuid::new_v4(), Uuid::new_v4());
+        let flat = Uuid::new_v4();
+        let scores = vec![score(talk_a, flat, 4), score(talk_b, flat, 4)];
+
+        let result = normalize_scores(&scores);
+
+        assert_eq!(result.talk_scores[&talk_a], 0.0);
+        assert_eq!(result.talk_scores[&talk_b], 0.0);
+        assert_eq!(result.reviewers[0].std_dev, 0.0);
+        assert!(normalize_scores(&[]).talk_scores.is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::validation::ValidationErrors;
+
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct SpeakerAvailability {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub conference_id: Uuid,
+    /// `None` for windows that apply to all of the speaker's talks
+    pub talk_id: Option<Uuid>,
+    pub available_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A time range on one conference day when a speaker can present
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub struct AvailabilityWindow {
+    pub available_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+impl From<&SpeakerAvailability> for AvailabilityWindow {
+    fn from(availability: &SpeakerAvailability) -> Self {
+        Self {
+            available_date: availability.available_date,
+            start_time: availability.start_time,
+            end_time: availability.end_time,
+        }
+    }
+}
+
+/// Replace a speaker's windows: the general ones when `talk_id` is unset,
+/// otherwise those for that talk. An empty list clears them.
+#[derive(Debug, Deserialize)]
+pub struct SetAvailabilityRequest {
+    pub talk_id: Option<Uuid>,
+    #[serde(default)]
+    pub windows: Vec<AvailabilityWindow>,
+}
+
+impl SetAvailabilityRequest {
+    /// Each window must be a non-empty range on one of the conference's days
+    pub fn validate(
+        &self,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        for (i, window) in self.windows.iter().enumerate() {
+            let field = format!("windows[{}]", i);
+            if window.available_date < start_date || window.available_date > end_date {
+                errors.add(
+                    &field,
+                    "invalid_range",
+                    format!(
+                        "{} is not a conference day ({} to {})",
+                        window.available_date, start_date, end_date
+                    ),
+                );
+            }
+            if window.start_time >= window.end_time {
+                errors.add(
+                    &field,
+                    "invalid_range",
+                    "The start time must be before the end time",
+                );
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+/// A window with who it belongs to, for organizers planning the schedule
+#[derive(Debug, Clone, FromRow, Serialize)]
+pub struct SpeakerAvailabilityResponse {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub speaker_name: String,
+    pub talk_id: Option<Uuid>,
+    pub talk_title: Option<String>,
+    pub available_date: NaiveDate,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+}
+
+/// How a time range on one day sits against a speaker's windows
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AvailabilityFit {
+    /// Inside one window, or the speaker gave none
+    Available,
+    /// The speaker is there that day, but not for the whole range
+    OutsideHours,
+    /// The speaker has no window that day
+    AbsentThatDay,
+}
+
+/// Check a range on `date` against `windows`. No windows means no
+/// constraints were given.
+pub fn availability_fit(
+    windows: &[AvailabilityWindow],
+    date: NaiveDate,
+    start_time: NaiveTime,
+    end_time: NaiveTime,
+) -> AvailabilityFit {
+    if windows.is_empty() {
+        return AvailabilityFit::Available;
+    }
+
+    let mut that_day = windows
+        .iter()
+        .filter(|w| w.available_date == date)
+        .peekable();
+    if that_day.peek().is_none() {
+        return AvailabilityFit::AbsentThatDay;
+    }
+    if that_day.any(|w| w.start_time <= start_time && end_time <= w.end_time) {
+        AvailabilityFit::Available
+    } else {
+        AvailabilityFit::OutsideHours
+    }
+}
+
+/// A speaker's windows on `date` as "09:00-12:00, 14:00-17:00"
+pub fn describe_windows(windows: &[AvailabilityWindow], date: NaiveDate) -> String {
+    let mut that_day: Vec<&AvailabilityWindow> = windows
+        .iter()
+        .filter(|w| w.available_date == date)
+        .collect();
+    that_day.sort_by_key(|w| w.start_time);
+    that_day
+        .iter()
+        .map(|w| {
+            format!(
+                "{}-{}",
+                w.start_time.format("%H:%M"),
+                w.end_time.format("%H:%M")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(n: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 6, n).unwrap()
+    }
+
+    fn time(hhmm: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hhmm / 100, hhmm % 100, 0).unwrap()
+    }
+
+    fn window(date: NaiveDate, start: u32, end: u32) -> AvailabilityWindow {
+        AvailabilityWindow {
+            available_date: date,
+            start_time: time(start),
+            end_time: time(end),
+        }
+    }
+
+    #[test]
+    fn fit_against_windows() {
+        let windows = [window(day(1), 900, 1200), window(day(1), 1400, 1700)];
+
+        assert_eq!(
+            availability_fit(&[], day(2), time(900), time(1000)),
+            AvailabilityFit::Available
+        );
+        assert_eq!(
+            availability_fit(&windows, day(1), time(1400), time(1500)),
+            AvailabilityFit::Available
+        );
+        assert_eq!(
+            availability_fit(&windows, day(1), time(1130), time(1230)),
+            AvailabilityFit::OutsideHours
+        );
+        assert_eq!(
+            availability_fit(&windows, day(2), time(900), time(1000)),
+            AvailabilityFit::AbsentThatDay
+        );
+        assert_eq!(
+            describe_windows(&windows, day(1)),
+            "09:00-12:00, 14:00-17:00"
+        );
+    }
+
+    #[test]
+    fn windows_must_be_on_conference_days() {
+        let request = SetAvailabilityRequest {
+            talk_id: None,
+            windows: vec![
+                window(day(1), 900, 1200),
+                window(day(3), 900, 1200),
+                window(day(2), 1300, 1200),
+            ],
+        };
+        let errors = request.validate(day(1), day(2)).unwrap_err();
+        let fields: Vec<&str> = errors.errors().iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["windows[1]", "windows[2]"]);
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Feedback for a talk's speakers as organizers see it, approved or not
+#[derive(Debug, Serialize, FromRow)]
+pub struct TalkFeedback {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub author_id: Uuid,
+    pub author_name: String,
+    pub body: String,
+    pub approved_by: Option<Uuid>,
+    pub approved_by_name: Option<String>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Released feedback as speakers see it. Authors stay anonymous.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SpeakerFeedback {
+    pub body: String,
+    pub approved_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedbackRequest {
+    pub body: String,
+}
+
+/// All released feedback for a talk as one block of text, for the
+/// `{{feedback}}` email variable
+pub fn feedback_text(feedback: &[SpeakerFeedback]) -> Option<String> {
+    if feedback.is_empty() {
+        return None;
+    }
+
+    Some(
+        feedback
+            .iter()
+            .map(|f| f.body.trim())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feedback_text_joins_paragraphs() {
+        let feedback = vec![
+            SpeakerFeedback {
+                body: "Great topic, but the abstract was vague.\n".to_string(),
+                approved_at: Utc::now(),
+            },
+            SpeakerFeedback {
+                body: "Please submit again next year.".to_string(),
+                approved_at: Utc::now(),
+            },
+        ];
+
+        assert_eq!(
+            feedback_text(&feedback).as_deref(),
+            Some("Great topic, but the abstract was vague.\n\nPlease submit again next year.")
+        );
+        assert_eq!(feedback_text(&[]), None);
+    }
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::config::ConferenceConfig;
+
+use super::Conference;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionWindowState {
+    NotYetOpen,
+    Open,
+    Closed,
+}
+
+/// The call-for-papers window of a conference and where we are in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmissionWindow {
+    pub opens_at: Option<DateTime<Utc>>,
+    pub closes_at: Option<DateTime<Utc>>,
+    pub state: SubmissionWindowState,
+    /// Seconds until the CfP opens, while it is not yet open
+    pub seconds_until_open: Option<i64>,
+    /// Seconds until the CfP closes, while it is open
+    pub seconds_until_close: Option<i64>,
+    /// Human-readable status, e.g. "CfP closed"
+    pub message: String,
+}
+
+impl SubmissionWindow {
+    pub fn new(
+        opens_at: Option<DateTime<Utc>>,
+        closes_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let state = match (opens_at, closes_at) {
+            (Some(open), _) if now < open => SubmissionWindowState::NotYetOpen,
+            (_, Some(close)) if now > close => SubmissionWindowState::Closed,
+            _ => SubmissionWindowState::Open,
+        };
+
+        let (seconds_until_open, seconds_until_close) = match state {
+            SubmissionWindowState::NotYetOpen => {
+                (opens_at.map(|open| (open - now).num_seconds()), None)
+            }
+            SubmissionWindowState::Open => {
+                (None, closes_at.map(|close| (close - now).num_seconds()))
+            }
+            SubmissionWindowState::Closed => (None, None),
+        };
+
+        let message = match state {
+            SubmissionWindowState::NotYetOpen => match opens_at {
+                Some(open) => format!("CfP not yet open; opens at {}", open.to_rfc3339()),
+                None => "CfP not yet open".to_string(),
+            },
+            SubmissionWindowState::Open => match closes_at {
+                Some(close) => format!("CfP open until {}", close.to_rfc3339()),
+                None => "CfP open".to_string(),
+            },
+            SubmissionWindowState::Closed => match closes_at {
+                Some(close) => format!("CfP closed at {}", close.to_rfc3339()),
+                None => "CfP closed".to_string(),
+            },
+        };
+
+        Self {
+            opens_at,
+            closes_at,
+            state,
+            seconds_until_open,
+            seconds_until_close,
+            message,
+        }
+    }
+
+    /// Resolve a conference's window, falling back to the config file for
+    /// any bound not set on the conference itself
+    pub fn for_conference(
+        conference: &Conference,
+        config: &ConferenceConfig,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let opens_at = conference
+            .submission_open
+            .or_else(|| parse_config_timestamp(&config.submission_open));
+        let closes_at = conference
+            .submission_close
+            .or_else(|| parse_config_timestamp(&config.submission_close));
+        Self::new(opens_at, closes_at, now)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state == SubmissionWindowState::Open
+    }
+}
+
+/// Config timestamps are RFC 3339 strings; blank or invalid values mean no bound
+fn parse_config_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Permission for one speaker to submit after the CfP has closed
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SubmissionExtension {
+    pub id: Uuid,
+    pub conference_id: Uuid,
+    pub user_id: Uuid,
+    pub extended_until: DateTime<Utc>,
+    pub reason: Option<String>,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GrantExtensionRequest {
+    pub user_id: Uuid,
+    pub extended_until: DateTime<Utc>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SubmissionExtensionResponse {
+    pub id: Uuid,
+    pub conference_id: Uuid,
+    pub user_id: Uuid,
+    pub speaker_name: String,
+    pub speaker_email: String,
+    pub extended_until: DateTime<Utc>,
+    pub reason: Option<String>,
+    pub granted_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_window_states() {
+        let now = Utc::now();
+        let day = Duration::days(1);
+
+        let window = SubmissionWindow::new(Some(now + day), Some(now + day * 2), now);
+        assert_eq!(window.state, SubmissionWindowState::NotYetOpen);
+        assert_eq!(window.seconds_until_open, Some(day.num_seconds()));
+
+        let window = SubmissionWindow::new(Some(now - day), Some(now + day), now);
+        assert!(window.is_open());
+        assert_eq!(window.seconds_until_close, Some(day.num_seconds()));
+
+        let window = SubmissionWindow::new(Some(now - day * 2), Some(now - day), now);
+        assert_eq!(window.state, SubmissionWindowState::Closed);
+        assert_eq!(window.seconds_until_close, None);
+    }
+
+    #[test]
+    fn test_unbounded_window_is_open() {
+        let window = SubmissionWindow::new(None, None, Utc::now());
+        assert!(window.is_open());
+        assert_eq!(window.message, "CfP open");
+    }
+
+    #[test]
+    fn test_parse_config_timestamp() {
+        assert!(parse_config_timestamp("2025-01-01T00:00:00Z").is_some());
+        assert!(parse_config_timestamp("").is_none());
+        assert!(parse_config_timestamp("not a date").is_none());
+    }
+}
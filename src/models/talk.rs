@@ -3,33 +3,73 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
-use super::LabelResponse;
+use super::{LabelResponse, SpeakerFeedback, TalkSpeakerResponse, ANONYMOUS_SPEAKER};
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
 #[sqlx(type_name = "talk_state", rename_all = "lowercase")]
 pub enum TalkState {
+    /// Saved by the speaker but not yet sent to organizers
+    Draft,
     Submitted,
     Pending,
     Accepted,
     Rejected,
+    /// Accepted, then cancelled by the speaker
+    Withdrawn,
+    /// Kept as a backup in case an accepted talk drops out
+    Waitlisted,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "talk_format", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TalkFormat {
+    Talk,
+    Workshop,
+    Lightning,
+    Panel,
+}
+
+impl TalkFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TalkFormat::Talk => "talk",
+            TalkFormat::Workshop => "workshop",
+            TalkFormat::Lightning => "lightning",
+            TalkFormat::Panel => "panel",
+        }
+    }
 }
 
 impl TalkState {
     /// Check if a state transition is valid
     pub fn can_transition_to(&self, target: &TalkState) -> bool {
         match (self, target) {
+            // From Draft (by speaker)
+            (TalkState::Draft, TalkState::Submitted) => true,
+
             // From Submitted
             (TalkState::Submitted, TalkState::Pending) => true,
             (TalkState::Submitted, TalkState::Rejected) => true,
+            (TalkState::Submitted, TalkState::Waitlisted) => true,
+
+            // From Waitlisted (promoted, or dropped)
+            (TalkState::Waitlisted, TalkState::Pending) => true,
+            (TalkState::Waitlisted, TalkState::Accepted) => true,
+            (TalkState::Waitlisted, TalkState::Rejected) => true,
 
             // From Pending (by speaker)
             (TalkState::Pending, TalkState::Accepted) => true,
             (TalkState::Pending, TalkState::Rejected) => true,
             (TalkState::Pending, TalkState::Submitted) => true, // Allow organizer to revert
 
+            // From Accepted (by speaker)
+            (TalkState::Accepted, TalkState::Withdrawn) => true,
+
             // Terminal states cannot transition
             (TalkState::Accepted, _) => false,
             (TalkState::Rejected, _) => false,
+            (TalkState::Withdrawn, _) => false,
 
             // No other transitions allowed
             _ => false,
@@ -38,13 +78,17 @@ impl TalkState {
 
     /// Check if this is a terminal state
     pub fn is_terminal(&self) -> bool {
-        matches!(self, TalkState::Accepted | TalkState::Rejected)
+        matches!(
+            self,
+            TalkState::Accepted | TalkState::Rejected | TalkState::Withdrawn
+        )
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Talk {
     pub id: Uuid,
+    pub conference_id: Uuid,
     pub speaker_id: Uuid,
     pub title: String,
     pub short_summary: String,
@@ -53,14 +97,31 @@ pub struct Talk {
     pub state: TalkState,
     pub submitted_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub duration_minutes: i32,
+    pub format: TalkFormat,
+    pub custom_answers: serde_json::Value,
+    pub withdrawal_reason: Option<String>,
+    pub withdrawn_at: Option<DateTime<Utc>>,
+    pub waitlist_rank: Option<i32>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct CreateTalkRequest {
+    /// Conference to submit to; defaults to the active conference
+    pub conference_id: Option<Uuid>,
     pub title: String,
     pub short_summary: String,
     pub long_description: Option<String>,
     pub label_ids: Option<Vec<Uuid>>,
+    /// One of `SubmissionConfig.talk_durations`; defaults to `default_duration`
+    pub duration_minutes: Option<i32>,
+    /// Defaults to a regular talk
+    pub format: Option<TalkFormat>,
+    /// Answers to the conference's custom fields, keyed by `field_key`
+    pub custom_answers: Option<serde_json::Value>,
+    /// Save without submitting; the talk stays hidden from organizers
+    #[serde(default)]
+    pub save_as_draft: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -69,6 +130,10 @@ pub struct UpdateTalkRequest {
     pub short_summary: Option<String>,
     pub long_description: Option<String>,
     pub slides_url: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub format: Option<TalkFormat>,
+    /// Replaces all custom answers when provided
+    pub custom_answers: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -83,6 +148,35 @@ pub enum TalkAction {
     Decline,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WithdrawTalkRequest {
+    pub reason: String,
+}
+
+/// Where a waitlisted talk goes when promoted
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PromoteTarget {
+    #[default]
+    Pending,
+    Accepted,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PromoteTalkRequest {
+    /// Defaults to pending so the speaker confirms first
+    #[serde(default)]
+    pub target: PromoteTarget,
+    /// Free schedule slot to put the talk into
+    pub slot_id: Option<Uuid>,
+}
+
+/// New waitlist order for a conference, first backup first
+#[derive(Debug, Deserialize)]
+pub struct ReorderWaitlistRequest {
+    pub talk_ids: Vec<Uuid>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ChangeStateRequest {
     pub new_state: TalkState,
@@ -92,6 +186,7 @@ pub struct ChangeStateRequest {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TalkResponse {
     pub id: Uuid,
+    pub conference_id: Uuid,
     pub speaker_id: Uuid,
     pub title: String,
     pub short_summary: String,
@@ -100,15 +195,30 @@ pub struct TalkResponse {
     pub state: TalkState,
     pub submitted_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub duration_minutes: i32,
+    pub format: TalkFormat,
+    pub custom_answers: serde_json::Value,
+    pub withdrawal_reason: Option<String>,
+    pub withdrawn_at: Option<DateTime<Utc>>,
+    pub waitlist_rank: Option<i32>,
     pub labels: Vec<LabelResponse>,
     pub speaker_name: String,
     pub speaker_email: String,
+    /// Primary speaker followed by co-speakers
+    pub speakers: Vec<TalkSpeakerResponse>,
+    /// Speaker identity was removed because the conference uses blind review
+    #[serde(default)]
+    pub speakers_hidden: bool,
+    /// Feedback organizers have released to the speakers
+    #[serde(default)]
+    pub feedback: Vec<SpeakerFeedback>,
 }
 
 impl From<Talk> for TalkResponse {
     fn from(talk: Talk) -> Self {
         Self {
             id: talk.id,
+            conference_id: talk.conference_id,
             speaker_id: talk.speaker_id,
             title: talk.title,
             short_summary: talk.short_summary,
@@ -117,9 +227,18 @@ impl From<Talk> for TalkResponse {
             state: talk.state,
             submitted_at: talk.submitted_at,
             updated_at: talk.updated_at,
+            duration_minutes: talk.duration_minutes,
+            format: talk.format,
+            custom_answers: talk.custom_answers,
+            withdrawal_reason: talk.withdrawal_reason,
+            withdrawn_at: talk.withdrawn_at,
+            waitlist_rank: talk.waitlist_rank,
             labels: Vec::new(), // Will be populated by handlers when needed
             speaker_name: String::new(), // Will be populated by handlers when needed
             speaker_email: String::new(), // Will be populated by handlers when needed
+            speakers: Vec::new(), // Will be populated by handlers when needed
+            speakers_hidden: false,
+            feedback: Vec::new(),
         }
     }
 }
@@ -135,9 +254,44 @@ impl TalkResponse {
         self.speaker_email = email;
         self
     }
+
+    pub fn with_speakers(mut self, speakers: Vec<TalkSpeakerResponse>) -> Self {
+        self.speakers = speakers;
+        self
+    }
+
+    pub fn with_feedback(mut self, feedback: Vec<SpeakerFeedback>) -> Self {
+        self.feedback = feedback;
+        self
+    }
+
+    /// Strip everything that identifies the speakers, for blind review
+    pub fn anonymized(mut self) -> Self {
+        self.speaker_id = Uuid::nil();
+        self.speaker_name = ANONYMOUS_SPEAKER.to_string();
+        self.speaker_email = String::new();
+        self.speakers = Vec::new();
+        self.speakers_hidden = true;
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TalksListResponse {
     pub talks: Vec<TalkResponse>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waitlisted_talks_can_be_promoted_or_dropped() {
+        assert!(TalkState::Submitted.can_transition_to(&TalkState::Waitlisted));
+        assert!(TalkState::Waitlisted.can_transition_to(&TalkState::Pending));
+        assert!(TalkState::Waitlisted.can_transition_to(&TalkState::Accepted));
+        assert!(TalkState::Waitlisted.can_transition_to(&TalkState::Rejected));
+        assert!(!TalkState::Waitlisted.can_transition_to(&TalkState::Submitted));
+        assert!(!TalkState::Accepted.can_transition_to(&TalkState::Waitlisted));
+    }
+}
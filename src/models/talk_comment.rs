@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// A comment in a talk's internal discussion. Deleted comments come back
+/// with an empty body so the thread keeps its shape.
+#[derive(Debug, Serialize, FromRow)]
+pub struct TalkComment {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub author_name: String,
+    pub body: String,
+    pub mentioned_user_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCommentRequest {
+    pub body: String,
+    /// Reply to this comment; it must be on the same talk
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCommentRequest {
+    pub body: String,
+}
+
+/// An organizer who may be @mentioned on a talk
+#[derive(Debug, Clone, FromRow)]
+pub struct MentionCandidate {
+    pub id: Uuid,
+    pub username: Option<String>,
+    pub email: String,
+    pub full_name: String,
+}
+
+fn is_handle_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '-' | '+' | '@')
+}
+
+/// Find the organizers a comment mentions as `@username` or `@email`,
+/// ignoring case. Handles that match nobody are left as plain text.
+pub fn extract_mentions(body: &str, candidates: &[MentionCandidate]) -> Vec<Uuid> {
+    let mut mentioned = Vec::new();
+    let mut previous = None;
+
+    for (index, c) in body.char_indices() {
+        let starts_mention = c == '@' && !previous.is_some_and(is_handle_char);
+        previous = Some(c);
+        if !starts_mention {
+            continue;
+        }
+
+        let rest = &body[index + 1..];
+        let end = rest.find(|c| !is_handle_char(c)).unwrap_or(rest.len());
+        // Allow sentence punctuation straight after the handle
+        let handle = rest[..end].trim_end_matches(['.', '-']);
+        if handle.is_empty() {
+            continue;
+        }
+
+        let found = candidates.iter().find(|candidate| {
+            candidate.email.eq_ignore_ascii_case(handle)
+                || candidate
+                    .username
+                    .as_deref()
+                    .is_some_and(|username| username.eq_ignore_ascii_case(handle))
+        });
+        if let Some(candidate) = found {
+            if !mentioned.contains(&candidate.id) {
+                mentioned.push(candidate.id);
+            }
+        }
+    }
+
+    mentioned
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(username: Option<&str>, email: &str) -> MentionCandidate {
+        MentionCandidate {
+            id: Uuid::new_v4(),
+            username: username.map(str::to_string),
+            email: email.to_string(),
+            full_name: email.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_usernames_and_emails() {
+        let jane = candidate(Some("jane"), "jane@example.com");
+        let bob = candidate(None, "bob@example.org");
+        let candidates = vec![jane.clone(), bob.clone()];
+
+        let mentioned = extract_mentions(
+            "@Jane what do you think? Also cc @bob@example.org. Thanks @jane",
+            &candidates,
+        );
+
+        assert_eq!(mentioned, vec![jane.id, bob.id]);
+    }
+
+    #[test]
+    fn ignores_plain_emails_and_unknown_handles() {
+        let jane = candidate(Some("jane"), "jane@example.com");
+
+        let mentioned = extract_mentions(
+            "Speaker wrote from someone@jane and @nobody, reach @ me",
+            &[jane],
+        );
+
+        assert!(mentioned.is_empty());
+    }
+}
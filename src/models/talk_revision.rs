@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use super::TalkFormat;
+
+/// Snapshot of a talk's content, taken on submission and after every edit
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TalkRevision {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub revision_number: i32,
+    pub title: String,
+    pub short_summary: String,
+    pub long_description: Option<String>,
+    pub slides_url: Option<String>,
+    pub duration_minutes: i32,
+    pub format: TalkFormat,
+    pub edited_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct TalkRevisionResponse {
+    pub revision_number: i32,
+    pub title: String,
+    pub short_summary: String,
+    pub long_description: Option<String>,
+    pub slides_url: Option<String>,
+    pub duration_minutes: i32,
+    pub format: TalkFormat,
+    pub edited_by: Option<Uuid>,
+    pub edited_by_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffQuery {
+    pub from: i32,
+    pub to: i32,
+}
+
+/// A single field that differs between two revisions
+#[derive(Debug, Serialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TalkRevisionDiff {
+    pub talk_id: Uuid,
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub changes: Vec<FieldChange>,
+}
+
+impl TalkRevision {
+    /// Field values in a comparable form, in display order
+    fn fields(&self) -> [(&'static str, Option<String>); 6] {
+        [
+            ("title", Some(self.title.clone())),
+            ("short_summary", Some(self.short_summary.clone())),
+            ("long_description", self.long_description.clone()),
+            ("slides_url", self.slides_url.clone()),
+            ("duration_minutes", Some(self.duration_minutes.to_string())),
+            ("format", Some(self.format.as_str().to_string())),
+        ]
+    }
+
+    /// List the fields that changed going from `self` to `newer`
+    pub fn diff(&self, newer: &TalkRevision) -> Vec<FieldChange> {
+        self.fields()
+            .into_iter()
+            .zip(newer.fields())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((field, old_value), (_, new_value))| FieldChange {
+                field: field.to_string(),
+                old_value,
+                new_value,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn revision(number: i32) -> TalkRevision {
+        TalkRevision {
+            id: Uuid::new_v4(),
+            talk_id: Uuid::nil(),
+            revision_number: number,
+            title: "Intro to Rust".to_string(),
+            short_summary: "Ownership and borrowing".to_string(),
+            long_description: None,
+            slides_url: None,
+            duration_minutes: 45,
+            format: TalkFormat::Talk,
+            edited_by: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn identical_revisions_have_no_changes() {
+        assert!(revision(1).diff(&revision(2)).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_changed_fields_only() {
+        let old = revision(1);
+        let mut new = revision(2);
+        new.short_summary = "Ownership, borrowing and lifetimes".to_string();
+        new.long_description = Some("Hands-on examples".to_string());
+        new.format = TalkFormat::Workshop;
+
+        let changes = old.diff(&new);
+
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: "short_summary".to_string(),
+                    old_value: Some("Ownership and borrowing".to_string()),
+                    new_value: Some("Ownership, borrowing and lifetimes".to_string()),
+                },
+                FieldChange {
+                    field: "long_description".to_string(),
+                    old_value: None,
+                    new_value: Some("Hands-on examples".to_string()),
+                },
+                FieldChange {
+                    field: "format".to_string(),
+                    old_value: Some("talk".to_string()),
+                    new_value: Some("workshop".to_string()),
+                },
+            ]
+        );
+    }
+}
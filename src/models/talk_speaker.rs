@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "talk_speaker_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TalkSpeakerStatus {
+    Invited,
+    Accepted,
+    Declined,
+}
+
+/// A co-speaker invited to present a talk alongside its primary speaker
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TalkSpeaker {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub user_id: Option<Uuid>,
+    pub email: String,
+    pub status: TalkSpeakerStatus,
+    #[serde(skip_serializing)]
+    pub invite_token: String,
+    pub invited_by: Option<Uuid>,
+    pub invited_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InviteCoSpeakerRequest {
+    pub email: String,
+}
+
+/// A speaker on a talk, either the primary speaker or a co-speaker
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TalkSpeakerResponse {
+    /// Invitation ID for co-speakers, `None` for the primary speaker
+    pub invitation_id: Option<Uuid>,
+    pub user_id: Option<Uuid>,
+    pub full_name: String,
+    pub email: String,
+    pub is_primary: bool,
+    pub status: TalkSpeakerStatus,
+}
+
+/// Invitation details shown to the invitee before they respond
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct SpeakerInvitationResponse {
+    pub id: Uuid,
+    pub talk_id: Uuid,
+    pub talk_title: String,
+    pub conference_name: String,
+    pub invited_by_name: Option<String>,
+    pub email: String,
+    pub status: TalkSpeakerStatus,
+}
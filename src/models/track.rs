@@ -50,3 +50,9 @@ impl From<Track> for TrackResponse {
         }
     }
 }
+
+/// Replace the labels a track is meant for
+#[derive(Debug, Deserialize)]
+pub struct SetTrackLabelsRequest {
+    pub label_ids: Vec<Uuid>,
+}
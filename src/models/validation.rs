@@ -0,0 +1,549 @@
+use axum::{http::StatusCode, Json};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+use super::auth::ErrorResponse;
+use crate::config::SubmissionConfig;
+
+// Column sizes for fields that have no configurable limit
+const MAX_NAME_LENGTH: usize = 255;
+const MAX_USERNAME_LENGTH: usize = 100;
+const MAX_EMAIL_LENGTH: usize = 255;
+const MAX_LABEL_NAME_LENGTH: usize = 100;
+const MAX_CRITERION_NAME_LENGTH: usize = 100;
+const MAX_LOCATION_LENGTH: usize = 500;
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// One problem with one input, e.g. `{"field": "title", "code": "too_short", ...}`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    /// Stable machine-readable reason: `required`, `too_short`, `too_long`,
+    /// `invalid_format` or `invalid_range`
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Collects every field error in a request so they can be reported together
+#[derive(Debug, Default)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, code: &'static str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            code,
+            message: message.into(),
+        });
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Check a text input's trimmed length. A blank value is reported as
+    /// `required` when `min` is non-zero.
+    pub fn length(&mut self, field: &str, label: &str, value: &str, min: usize, max: usize) {
+        let length = value.trim().chars().count();
+        if length == 0 && min > 0 {
+            self.add(field, "required", format!("{} is required", label));
+        } else if length < min {
+            self.add(
+                field,
+                "too_short",
+                format!("{} must be at least {} characters", label, min),
+            );
+        } else if length > max {
+            self.add(
+                field,
+                "too_long",
+                format!("{} must be {} characters or less", label, max),
+            );
+        }
+    }
+
+    /// Finish validation, failing if anything was collected
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl From<ValidationErrors> for (StatusCode, Json<ErrorResponse>) {
+    fn from(errors: ValidationErrors) -> Self {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::with_field_errors(errors.errors)),
+        )
+    }
+}
+
+/// Validate talk content against the configured limits. `None` means the
+/// field is not being changed. Drafts may be shorter than the minimums,
+/// which are enforced again when the talk is submitted.
+pub fn validate_talk_fields(
+    config: &SubmissionConfig,
+    title: Option<&str>,
+    short_summary: Option<&str>,
+    long_description: Option<&str>,
+    duration_minutes: Option<i32>,
+    is_draft: bool,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    let min = |configured: usize| {
+        if is_draft {
+            configured.min(1)
+        } else {
+            configured
+        }
+    };
+
+    if let Some(title) = title {
+        errors.length(
+            "title",
+            "Title",
+            title,
+            min(config.min_title_length),
+            config.max_title_length,
+        );
+    }
+    if let Some(summary) = short_summary {
+        errors.length(
+            "short_summary",
+            "Short summary",
+            summary,
+            min(config.min_summary_length),
+            config.max_summary_length,
+        );
+    }
+    if let Some(description) = long_description {
+        errors.length(
+            "long_description",
+            "Description",
+            description,
+            0,
+            config.max_description_length,
+        );
+    }
+    if let Some(minutes) = duration_minutes {
+        let allowed = &config.talk_durations;
+        if minutes <= 0
+            || (!allowed.is_empty() && !allowed.iter().any(|&d| i32::from(d) == minutes))
+        {
+            let options: Vec<String> = allowed.iter().map(|d| d.to_string()).collect();
+            errors.add(
+                "duration_minutes",
+                "invalid_range",
+                format!("Duration must be one of: {} minutes", options.join(", ")),
+            );
+        }
+    }
+
+    errors.into_result()
+}
+
+/// Validate the profile fields a speaker signs up with
+pub fn validate_profile_fields(
+    config: &SubmissionConfig,
+    email: &str,
+    username: Option<&str>,
+    password: &str,
+    full_name: &str,
+    bio: Option<&str>,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    errors.length("email", "Email", email, 1, MAX_EMAIL_LENGTH);
+    if !email.trim().is_empty() && !email.contains('@') {
+        errors.add("email", "invalid_format", "Invalid email format");
+    }
+    if password.len() < MIN_PASSWORD_LENGTH {
+        errors.add(
+            "password",
+            "too_short",
+            format!(
+                "Password must be at least {} characters",
+                MIN_PASSWORD_LENGTH
+            ),
+        );
+    }
+    if let Some(username) = username {
+        errors.length("username", "Username", username, 0, MAX_USERNAME_LENGTH);
+    }
+    errors.length("full_name", "Full name", full_name, 1, MAX_NAME_LENGTH);
+    if let Some(bio) = bio {
+        errors.length("bio", "Bio", bio, 0, config.max_description_length);
+    }
+
+    errors.into_result()
+}
+
+/// Validate a label's fields. `None` means the field is not being changed.
+pub fn validate_label_fields(
+    config: &SubmissionConfig,
+    name: Option<&str>,
+    description: Option<&str>,
+    color: Option<&str>,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if let Some(name) = name {
+        errors.length("name", "Label name", name, 1, MAX_LABEL_NAME_LENGTH);
+    }
+    if let Some(description) = description {
+        errors.length(
+            "description",
+            "Description",
+            description,
+            0,
+            config.max_description_length,
+        );
+    }
+    if let Some(color) = color {
+        let hex = color.strip_prefix('#').unwrap_or_default();
+        if !color.starts_with('#')
+            || (hex.len() != 3 && hex.len() != 6)
+            || !hex.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            errors.add(
+                "color",
+                "invalid_format",
+                "Color must be a valid hex color (e.g., #FF5733 or #F57)",
+            );
+        }
+    }
+
+    errors.into_result()
+}
+
+/// Validate a rubric criterion. `None` means the field is not being changed.
+pub fn validate_rubric_criterion_fields(
+    config: &SubmissionConfig,
+    name: Option<&str>,
+    description: Option<&str>,
+    weight: Option<f64>,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if let Some(name) = name {
+        errors.length("name", "Criterion name", name, 1, MAX_CRITERION_NAME_LENGTH);
+    }
+    if let Some(description) = description {
+        errors.length(
+            "description",
+            "Description",
+            description,
+            0,
+            config.max_description_length,
+        );
+    }
+    if let Some(weight) = weight {
+        if !weight.is_finite() || weight <= 0.0 {
+            errors.add("weight", "invalid_range", "Weight must be greater than 0");
+        }
+    }
+
+    errors.into_result()
+}
+
+/// Validate the body of a discussion comment
+pub fn validate_comment_body(
+    config: &SubmissionConfig,
+    body: &str,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+    errors.length("body", "Comment", body, 1, config.max_description_length);
+    errors.into_result()
+}
+
+/// The fields of a conference after applying an update to the stored values
+pub struct ConferenceFields<'a> {
+    /// `None` when the name is not being changed
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub location: Option<&'a str>,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub submission_open: Option<DateTime<Utc>>,
+    pub submission_close: Option<DateTime<Utc>>,
+    pub review_deadline: Option<DateTime<Utc>>,
+    pub decision_deadline: Option<DateTime<Utc>>,
+    pub confirmation_deadline: Option<DateTime<Utc>>,
+    /// `None` when the time zone is not being changed
+    pub timezone: Option<&'a str>,
+}
+
+/// Validate a conference's fields and date ranges
+pub fn validate_conference_fields(
+    config: &SubmissionConfig,
+    fields: &ConferenceFields,
+) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    if let Some(name) = fields.name {
+        errors.length("name", "Conference name", name, 1, MAX_NAME_LENGTH);
+    }
+    if let Some(description) = fields.description {
+        errors.length(
+            "description",
+            "Description",
+            description,
+            0,
+            config.max_description_length,
+        );
+    }
+    if let Some(location) = fields.location {
+        errors.length("location", "Location", location, 0, MAX_LOCATION_LENGTH);
+    }
+    if let Some(timezone) = fields.timezone {
+        if timezone.trim().parse::<chrono_tz::Tz>().is_err() {
+            errors.add(
+                "timezone",
+                "invalid_format",
+                "Unknown time zone; use an IANA name such as America/Chicago",
+            );
+        }
+    }
+    if fields.end_date < fields.start_date {
+        errors.add(
+            "end_date",
+            "invalid_range",
+            "End date must be after start date",
+        );
+    }
+    if let (Some(open), Some(close)) = (fields.submission_open, fields.submission_close) {
+        if close < open {
+            errors.add(
+                "submission_close",
+                "invalid_range",
+                "Submission close must be after submission open",
+            );
+        }
+    }
+
+    // Each phase must end after the one before it, skipping unset deadlines
+    let deadlines = [
+        (
+            "submission_close",
+            "Submission close",
+            fields.submission_close,
+        ),
+        ("review_deadline", "Review deadline", fields.review_deadline),
+        (
+            "decision_deadline",
+            "Decision deadline",
+            fields.decision_deadline,
+        ),
+        (
+            "confirmation_deadline",
+            "Confirmation deadline",
+            fields.confirmation_deadline,
+        ),
+    ];
+    let mut previous: Option<(&str, DateTime<Utc>)> = None;
+    for (field, label, deadline) in deadlines {
+        let Some(deadline) = deadline else {
+            continue;
+        };
+        if let Some((previous_label, previous_deadline)) = previous {
+            if deadline < previous_deadline {
+                errors.add(
+                    field,
+                    "invalid_range",
+                    format!("{} must be after {}", label, previous_label.to_lowercase()),
+                );
+            }
+        }
+        previous = Some((label, deadline));
+    }
+
+    errors.into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SubmissionConfig {
+        SubmissionConfig {
+            min_title_length: 5,
+            max_title_length: 20,
+            min_summary_length: 10,
+            max_summary_length: 40,
+            max_description_length: 50,
+            max_slide_size_mb: 50,
+            allowed_slide_formats: vec![],
+            talk_durations: vec![],
+            default_duration: 30,
+        }
+    }
+
+    fn codes(result: Result<(), ValidationErrors>) -> Vec<(String, &'static str)> {
+        result
+            .unwrap_err()
+            .errors()
+            .iter()
+            .map(|e| (e.field.clone(), e.code))
+            .collect()
+    }
+
+    #[test]
+    fn talk_fields_follow_configured_limits() {
+        let config = config();
+        assert!(validate_talk_fields(
+            &config,
+            Some("Intro to Rust"),
+            Some("Ownership and borrowing"),
+            Some(""),
+            Some(45),
+            false
+        )
+        .is_ok());
+
+        assert_eq!(
+            codes(validate_talk_fields(
+                &config,
+                Some("Rust"),
+                Some(" "),
+                Some(&"x".repeat(51)),
+                None,
+                false
+            )),
+            vec![
+                ("title".to_string(), "too_short"),
+                ("short_summary".to_string(), "required"),
+                ("long_description".to_string(), "too_long"),
+            ]
+        );
+
+        assert_eq!(
+            codes(validate_talk_fields(
+                &config,
+                Some(&"x".repeat(21)),
+                None,
+                None,
+                None,
+                false
+            )),
+            vec![("title".to_string(), "too_long")]
+        );
+    }
+
+    #[test]
+    fn talk_duration_is_one_of_the_configured_lengths() {
+        let config = SubmissionConfig {
+            talk_durations: vec![20, 45],
+            ..config()
+        };
+        assert!(validate_talk_fields(&config, None, None, None, Some(45), false).is_ok());
+        assert_eq!(
+            codes(validate_talk_fields(
+                &config,
+                Some("Rust"),
+                None,
+                None,
+                Some(75),
+                false
+            )),
+            vec![
+                ("title".to_string(), "too_short"),
+                ("duration_minutes".to_string(), "invalid_range"),
+            ]
+        );
+    }
+
+    #[test]
+    fn drafts_skip_minimum_lengths_but_not_maximums() {
+        let config = config();
+        assert!(validate_talk_fields(&config, Some("Rust"), Some("WIP"), None, None, true).is_ok());
+        assert_eq!(
+            codes(validate_talk_fields(
+                &config,
+                Some(""),
+                Some(&"x".repeat(41)),
+                None,
+                None,
+                true
+            )),
+            vec![
+                ("title".to_string(), "required"),
+                ("short_summary".to_string(), "too_long"),
+            ]
+        );
+    }
+
+    #[test]
+    fn label_and_conference_fields() {
+        let config = config();
+        assert_eq!(
+            codes(validate_label_fields(&config, Some(""), None, Some("#GGG"))),
+            vec![
+                ("name".to_string(), "required"),
+                ("color".to_string(), "invalid_format"),
+            ]
+        );
+
+        let fields = ConferenceFields {
+            name: Some("TXLF"),
+            description: None,
+            location: None,
+            start_date: NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+            submission_open: None,
+            submission_close: None,
+            review_deadline: None,
+            decision_deadline: None,
+            confirmation_deadline: None,
+            timezone: Some("Texas/Austin"),
+        };
+        assert_eq!(
+            codes(validate_conference_fields(&config, &fields)),
+            vec![
+                ("timezone".to_string(), "invalid_format"),
+                ("end_date".to_string(), "invalid_range"),
+            ]
+        );
+    }
+
+    #[test]
+    fn phase_deadlines_must_follow_each_other() {
+        let config = config();
+        let at = |day| {
+            NaiveDate::from_ymd_opt(2025, 3, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+        let fields = ConferenceFields {
+            name: None,
+            description: None,
+            location: None,
+            start_date: NaiveDate::from_ymd_opt(2025, 6, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 6, 2).unwrap(),
+            submission_open: None,
+            submission_close: Some(at(10)),
+            review_deadline: None,
+            decision_deadline: Some(at(5)),
+            confirmation_deadline: Some(at(20)),
+            timezone: None,
+        };
+        assert_eq!(
+            codes(validate_conference_fields(&config, &fields)),
+            vec![("decision_deadline".to_string(), "invalid_range")]
+        );
+    }
+}
@@ -13,7 +13,8 @@ use crate::config::Config;
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct EmailTemplate {
     pub id: Uuid,
-    pub conference_id: Uuid,
+    /// None for the defaults every conference falls back to
+    pub conference_id: Option<Uuid>,
     pub template_type: String,
     pub subject: String,
     pub body: String,
@@ -27,6 +28,8 @@ pub struct EmailVariables {
     pub talk_title: String,
     pub talk_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub conference_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schedule_date: Option<String>,
@@ -34,6 +37,11 @@ pub struct EmailVariables {
     pub schedule_time: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub track_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitation_url: Option<String>,
+    /// Feedback organizers have released to the speaker
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feedback: Option<String>,
 }
 
 #[derive(Clone)]
@@ -70,8 +78,10 @@ impl EmailService {
         // Try to get conference-specific template first
         if let Ok(template) = sqlx::query_as::<_, EmailTemplate>(
             r#"
-            SELECT * FROM email_templates
-            WHERE conference_id = $1 AND template_type = $2
+            SELECT id, conference_id, template_type::text AS template_type,
+                   subject, body, is_default
+            FROM email_templates
+            WHERE conference_id = $1 AND template_type = $2::email_template_type
             ORDER BY is_default DESC, created_at DESC
             LIMIT 1
             "#,
@@ -87,8 +97,10 @@ impl EmailService {
         // Fall back to default template
         sqlx::query_as::<_, EmailTemplate>(
             r#"
-            SELECT * FROM email_templates
-            WHERE is_default = true AND template_type = $1
+            SELECT id, conference_id, template_type::text AS template_type,
+                   subject, body, is_default
+            FROM email_templates
+            WHERE is_default = true AND template_type = $1::email_template_type
             LIMIT 1
             "#,
         )
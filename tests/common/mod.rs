@@ -44,6 +44,8 @@ impl TestContext {
 
         // Clean up any leftover data from previous failed tests
         let tables = vec![
+            "submission_extensions",
+            "talk_speakers",
             "talk_labels",
             "ratings",
             "schedule_slots",
@@ -126,6 +128,26 @@ impl TestContext {
         (status, json)
     }
 
+    /// Make an HTTP request to the app and read the body as text
+    pub async fn request_text(&self, req: Request<Body>) -> (StatusCode, String) {
+        let response = self
+            .app
+            .clone()
+            .oneshot(req)
+            .await
+            .expect("Failed to send request");
+
+        let status = response.status();
+        let bytes = response
+            .into_body()
+            .collect()
+            .await
+            .expect("Failed to read body")
+            .to_bytes();
+
+        (status, String::from_utf8_lossy(&bytes).into_owned())
+    }
+
     /// Make a typed HTTP request
     pub async fn request_typed<T: DeserializeOwned>(
         &self,
@@ -262,8 +284,10 @@ pub async fn create_test_label(db: &PgPool, name: &str, color: &str) -> uuid::Uu
 pub async fn create_test_conference(db: &PgPool, name: &str) -> uuid::Uuid {
     sqlx::query_scalar::<_, uuid::Uuid>(
         r#"
-        INSERT INTO conferences (name, description, start_date, end_date, location, is_active)
-        VALUES ($1, 'Test Conference', '2025-04-18', '2025-04-20', 'Test City', true)
+        INSERT INTO conferences (name, description, start_date, end_date, location, is_active,
+                                 submission_open, submission_close)
+        VALUES ($1, 'Test Conference', '2025-04-18', '2025-04-20', 'Test City', true,
+                NOW() - INTERVAL '1 day', NOW() + INTERVAL '30 days')
         RETURNING id
         "#,
     )
@@ -290,21 +314,56 @@ pub async fn create_test_track(db: &PgPool, conference_id: uuid::Uuid, name: &st
     .expect("Failed to create test track")
 }
 
-/// Create a test talk
+/// Get the active test conference, creating one if none exists
+#[allow(dead_code)]
+pub async fn ensure_test_conference(db: &PgPool) -> uuid::Uuid {
+    let existing = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        SELECT id FROM conferences
+        WHERE is_active = true
+        ORDER BY created_at ASC
+        LIMIT 1
+        "#,
+    )
+    .fetch_optional(db)
+    .await
+    .expect("Failed to look up test conference");
+
+    match existing {
+        Some(id) => id,
+        None => create_test_conference(db, "Test Conference").await,
+    }
+}
+
+/// Create a test talk in the active test conference
 #[allow(dead_code)]
 pub async fn create_test_talk(
     db: &PgPool,
     speaker_id: uuid::Uuid,
     title: &str,
     summary: &str,
+) -> uuid::Uuid {
+    let conference_id = ensure_test_conference(db).await;
+    create_test_talk_in_conference(db, conference_id, speaker_id, title, summary).await
+}
+
+/// Create a test talk in a specific conference
+#[allow(dead_code)]
+pub async fn create_test_talk_in_conference(
+    db: &PgPool,
+    conference_id: uuid::Uuid,
+    speaker_id: uuid::Uuid,
+    title: &str,
+    summary: &str,
 ) -> uuid::Uuid {
     sqlx::query_scalar::<_, uuid::Uuid>(
         r#"
-        INSERT INTO talks (speaker_id, title, short_summary, state)
-        VALUES ($1, $2, $3, 'submitted')
+        INSERT INTO talks (conference_id, speaker_id, title, short_summary, state)
+        VALUES ($1, $2, $3, $4, 'submitted')
         RETURNING id
         "#,
     )
+    .bind(conference_id)
     .bind(speaker_id)
     .bind(title)
     .bind(summary)
@@ -252,6 +252,32 @@ pub async fn cleanup_database() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Create an active conference whose CfP is open, so talks submitted
+/// through the UI have a conference to go to
+pub async fn seed_open_conference() -> Result<uuid::Uuid, Box<dyn std::error::Error>> {
+    use sqlx::postgres::PgPoolOptions;
+
+    let db_url = get_test_database_url();
+    let pool = PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&db_url)
+        .await?;
+
+    let conference_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        INSERT INTO conferences (name, start_date, end_date, is_active,
+                                 submission_open, submission_close)
+        VALUES ('E2E Conference', CURRENT_DATE + 60, CURRENT_DATE + 62, true,
+                NOW() - INTERVAL '1 day', NOW() + INTERVAL '30 days')
+        RETURNING id
+        "#,
+    )
+    .fetch_one(&pool)
+    .await?;
+
+    Ok(conference_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
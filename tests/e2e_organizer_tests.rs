@@ -1,6 +1,6 @@
 mod e2e;
 
-use e2e::{cleanup_database, setup_database, E2eContext};
+use e2e::{cleanup_database, seed_open_conference, setup_database, E2eContext};
 use sqlx::postgres::PgPoolOptions;
 
 /// Helper to create an organizer user directly in database
@@ -92,6 +92,9 @@ async fn test_organizer_view_all_talks() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     // Create organizer
     create_organizer_user(
@@ -127,9 +130,12 @@ async fn test_organizer_view_all_talks() {
     ctx.fill_input("#title", "Test Talk for Review")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "A talk to be reviewed by organizer")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "A talk submitted so that an organizer can review it later",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
@@ -179,6 +185,9 @@ async fn test_organizer_rate_talk() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     // Create organizer
     create_organizer_user(
@@ -214,9 +223,12 @@ async fn test_organizer_rate_talk() {
     ctx.fill_input("#title", "Talk to Rate")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "Summary of talk to rate")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "A talk submitted so that an organizer can give it a rating",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
@@ -287,6 +299,9 @@ async fn test_organizer_add_label_to_talk() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     // Create organizer
     create_organizer_user(
@@ -322,9 +337,12 @@ async fn test_organizer_add_label_to_talk() {
     ctx.fill_input("#title", "Rust Programming Talk")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "About Rust")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "About Rust, and why its ownership model prevents data races",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
@@ -409,6 +427,9 @@ async fn test_organizer_change_talk_state() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     // Create organizer
     create_organizer_user(
@@ -444,9 +465,12 @@ async fn test_organizer_change_talk_state() {
     ctx.fill_input("#title", "Talk to Accept")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "This talk will be accepted")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "This talk will be accepted by an organizer once it is submitted",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
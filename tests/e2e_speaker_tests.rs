@@ -1,6 +1,6 @@
 mod e2e;
 
-use e2e::{cleanup_database, setup_database, E2eContext};
+use e2e::{cleanup_database, seed_open_conference, setup_database, E2eContext};
 
 #[tokio::test]
 #[ignore] // Requires WebDriver and running application
@@ -53,6 +53,9 @@ async fn test_speaker_submit_talk() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     let ctx = E2eContext::new()
         .await
@@ -85,7 +88,7 @@ async fn test_speaker_submit_talk() {
 
     ctx.fill_input(
         "#short_summary",
-        "Learn the basics of Rust programming language",
+        "Learn the basics of the Rust programming language, from cargo to crates",
     )
     .await
     .expect("Failed to fill summary");
@@ -141,6 +144,9 @@ async fn test_speaker_edit_talk() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     let ctx = E2eContext::new()
         .await
@@ -167,9 +173,12 @@ async fn test_speaker_edit_talk() {
     ctx.fill_input("#title", "Original Title")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "Original summary")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "The original summary, written before the talk was edited",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
@@ -235,6 +244,9 @@ async fn test_speaker_delete_talk() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     let ctx = E2eContext::new()
         .await
@@ -261,9 +273,12 @@ async fn test_speaker_delete_talk() {
     ctx.fill_input("#title", "Talk to Delete")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "This talk will be deleted")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "This talk will be deleted again right after it is submitted",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
@@ -324,6 +339,9 @@ async fn test_speaker_view_talk_status() {
     cleanup_database()
         .await
         .expect("Failed to cleanup database");
+    seed_open_conference()
+        .await
+        .expect("Failed to seed conference");
 
     let ctx = E2eContext::new()
         .await
@@ -350,9 +368,12 @@ async fn test_speaker_view_talk_status() {
     ctx.fill_input("#title", "Test Talk Status")
         .await
         .expect("Failed to fill title");
-    ctx.fill_input("#short_summary", "Testing status display")
-        .await
-        .expect("Failed to fill summary");
+    ctx.fill_input(
+        "#short_summary",
+        "A talk submitted to check how its status is displayed",
+    )
+    .await
+    .expect("Failed to fill summary");
     ctx.click("button[type='submit']")
         .await
         .expect("Failed to submit talk");
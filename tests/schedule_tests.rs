@@ -216,6 +216,73 @@ async fn test_update_conference() {
     ctx.cleanup().await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_update_conference_null_clears_submission_window() {
+    let ctx = TestContext::new().await;
+
+    let conference_id = create_test_conference(&ctx.db, "Windowed Conference").await;
+
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+
+    let token = generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    // Leaving the fields out keeps them
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/conferences/{}", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "location": "Austin" }).to_string()))
+        .unwrap();
+
+    let (status, response) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(!response["submission_open"].is_null());
+    assert!(!response["submission_close"].is_null());
+
+    // An explicit null clears them
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/conferences/{}", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "submission_open": null, "submission_close": null }).to_string(),
+        ))
+        .unwrap();
+
+    let (status, response) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(response["submission_open"].is_null());
+    assert!(response["submission_close"].is_null());
+
+    let stored = sqlx::query_as::<
+        _,
+        (
+            Option<chrono::DateTime<chrono::Utc>>,
+            Option<chrono::DateTime<chrono::Utc>>,
+        ),
+    >("SELECT submission_open, submission_close FROM conferences WHERE id = $1")
+    .bind(conference_id)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(stored, (None, None));
+
+    ctx.cleanup().await;
+}
+
 // ============================================================================
 // Track Tests
 // ============================================================================
@@ -743,3 +810,533 @@ async fn test_delete_schedule_slot() {
 
     ctx.cleanup().await;
 }
+
+// ============================================================================
+// Speaker Availability Tests
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_set_my_availability() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let other_id = create_test_user(
+        &ctx.db,
+        "other@example.com",
+        "other",
+        "password",
+        "Other Speaker",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "Availability Conference").await;
+    let other_talk =
+        create_test_talk_in_conference(&ctx.db, conference_id, other_id, "Not Mine", "Summary")
+            .await;
+    let token = generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+
+    let set_availability = |body: serde_json::Value| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!(
+                "/api/conferences/{}/availability/mine",
+                conference_id
+            ))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    // Windows must fall on conference days
+    let (status, _) = ctx
+        .request(set_availability(json!({
+            "windows": [
+                { "available_date": "2025-04-21", "start_time": "09:00:00", "end_time": "12:00:00" }
+            ]
+        })))
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Only for talks the speaker presents
+    let (status, _) = ctx
+        .request(set_availability(json!({
+            "talk_id": other_talk,
+            "windows": []
+        })))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, response) = ctx
+        .request(set_availability(json!({
+            "windows": [
+                { "available_date": "2025-04-18", "start_time": "09:00", "end_time": "12:00" }
+            ]
+        })))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let windows = response.as_array().unwrap();
+    assert_eq!(windows.len(), 1);
+    assert_eq!(windows[0]["speaker_name"], "Speaker User");
+    assert_eq!(windows[0]["available_date"], "2025-04-18");
+    assert_eq!(windows[0]["start_time"], "09:00:00");
+    assert!(windows[0]["talk_id"].is_null());
+
+    // Setting them again replaces them
+    let (status, response) = ctx
+        .request(set_availability(json!({ "windows": [] })))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response.as_array().unwrap().len(), 0);
+
+    ctx.cleanup().await;
+}
+
+// ============================================================================
+// Auto-Schedule Tests
+// ============================================================================
+
+/// A conference with three one-hour slots in one track, the first holding a
+/// pinned talk, and two accepted talks still to place
+async fn auto_schedule_fixture(
+    ctx: &TestContext,
+) -> (uuid::Uuid, [uuid::Uuid; 3], uuid::Uuid, String) {
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let track_id = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    let mut talk_ids = Vec::new();
+    for title in ["Pinned Keynote", "Packaging for Distros", "Kernel Tracing"] {
+        let talk_id = create_test_talk_in_conference(
+            &ctx.db,
+            conference_id,
+            speaker_id,
+            title,
+            "An accepted talk waiting for its place in the schedule",
+        )
+        .await;
+        sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+            .bind(talk_id)
+            .execute(&ctx.db)
+            .await
+            .unwrap();
+        talk_ids.push(talk_id);
+    }
+
+    let mut slot_ids = [uuid::Uuid::nil(); 3];
+    for (i, slot_id) in slot_ids.iter_mut().enumerate() {
+        *slot_id = sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+            VALUES ($1, $2, $3, '2025-04-18', $4::time, $5::time)
+            RETURNING id
+            "#,
+        )
+        .bind(conference_id)
+        .bind(track_id)
+        .bind(if i == 0 { Some(talk_ids[0]) } else { None })
+        .bind(format!("{:02}:00", 9 + i))
+        .bind(format!("{:02}:00", 10 + i))
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    }
+
+    (conference_id, slot_ids, talk_ids[0], token)
+}
+
+async fn propose_schedule(
+    ctx: &TestContext,
+    conference_id: uuid::Uuid,
+    token: &str,
+) -> serde_json::Value {
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/api/conferences/{}/schedule/proposal",
+            conference_id
+        ))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({}).to_string()))
+        .unwrap();
+    let (status, proposal) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    proposal
+}
+
+fn commit_schedule_request(
+    conference_id: uuid::Uuid,
+    token: &str,
+    proposal: &serde_json::Value,
+) -> Request<Body> {
+    let assignments: Vec<serde_json::Value> = proposal["assignments"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| json!({ "slot_id": a["slot_id"], "talk_id": a["talk_id"] }))
+        .collect();
+    Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/api/conferences/{}/schedule/commit",
+            conference_id
+        ))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "assignments": assignments }).to_string(),
+        ))
+        .unwrap()
+}
+
+async fn slot_talks(ctx: &TestContext, slot_ids: &[uuid::Uuid]) -> Vec<Option<uuid::Uuid>> {
+    let mut talks = Vec::with_capacity(slot_ids.len());
+    for slot_id in slot_ids {
+        talks.push(
+            sqlx::query_scalar::<_, Option<uuid::Uuid>>(
+                "SELECT talk_id FROM schedule_slots WHERE id = $1",
+            )
+            .bind(slot_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap(),
+        );
+    }
+    talks
+}
+
+#[tokio::test]
+#[serial]
+async fn test_auto_schedule_leaves_pinned_slots_alone() {
+    let ctx = TestContext::new().await;
+    let (conference_id, slots, pinned_talk, token) = auto_schedule_fixture(&ctx).await;
+
+    let proposal = propose_schedule(&ctx, conference_id, &token).await;
+    assert_eq!(proposal["pinned_count"], 1);
+    let assignments = proposal["assignments"].as_array().unwrap();
+    assert_eq!(assignments.len(), 2);
+    assert!(assignments
+        .iter()
+        .all(|a| a["slot_id"] != slots[0].to_string() && a["talk_id"] != pinned_talk.to_string()));
+
+    // Proposing saves nothing
+    assert_eq!(
+        slot_talks(&ctx, &slots).await,
+        vec![Some(pinned_talk), None, None]
+    );
+
+    let (status, response) = ctx
+        .request(commit_schedule_request(conference_id, &token, &proposal))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response.as_array().unwrap().len(), 2);
+
+    let filled = slot_talks(&ctx, &slots).await;
+    assert_eq!(filled[0], Some(pinned_talk));
+    assert!(filled[1].is_some() && filled[2].is_some());
+    assert_ne!(filled[1], filled[2]);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_auto_schedule_commit_refuses_slot_filled_meanwhile() {
+    let ctx = TestContext::new().await;
+    let (conference_id, slots, pinned_talk, token) = auto_schedule_fixture(&ctx).await;
+
+    let proposal = propose_schedule(&ctx, conference_id, &token).await;
+    assert_eq!(proposal["assignments"].as_array().unwrap().len(), 2);
+
+    // An organizer fills one of the proposed slots by hand
+    let speaker_id =
+        sqlx::query_scalar::<_, uuid::Uuid>("SELECT speaker_id FROM talks WHERE id = $1")
+            .bind(pinned_talk)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+    let manual_talk = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Lightning Talks",
+        "A session of short talks put into the schedule by hand",
+    )
+    .await;
+    sqlx::query("UPDATE schedule_slots SET talk_id = $1 WHERE id = $2")
+        .bind(manual_talk)
+        .bind(slots[1])
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+
+    let (status, _) = ctx
+        .request(commit_schedule_request(conference_id, &token, &proposal))
+        .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Nothing from the batch was saved
+    assert_eq!(
+        slot_talks(&ctx, &slots).await,
+        vec![Some(pinned_talk), Some(manual_talk), None]
+    );
+
+    ctx.cleanup().await;
+}
+
+// ============================================================================
+// Calendar Feed Tests
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_calendar_feeds() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "Calendar Conference").await;
+    let main_hall = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let workshop = create_test_track(&ctx.db, conference_id, "Workshop Room").await;
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Kernels, Containers",
+        "Summary",
+    )
+    .await;
+    // Still waiting for its speaker to confirm, so not published
+    let pending_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Unconfirmed Talk",
+        "Summary",
+    )
+    .await;
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE talks SET state = 'pending' WHERE id = $1")
+        .bind(pending_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+        VALUES ($1, $2, $3, '2025-04-18', '09:00', '10:00'),
+               ($1, $4, NULL, '2025-04-18', '09:00', '10:00'),
+               ($1, $4, $5, '2025-04-18', '10:00', '11:00')
+        "#,
+    )
+    .bind(conference_id)
+    .bind(main_hall)
+    .bind(talk_id)
+    .bind(workshop)
+    .bind(pending_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let get = |uri: String| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+    let (status, calendar) = ctx
+        .request_text(get(format!(
+            "/api/schedule.ics?conference_id={}",
+            conference_id
+        )))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 1);
+    assert!(calendar.contains(&format!("UID:talk-{}@", talk_id)));
+    assert!(calendar.contains("SUMMARY:Kernels\\, Containers\r\n"));
+    // 09:00 in Austin during daylight saving time
+    assert!(calendar.contains("DTSTART:20250418T140000Z\r\n"));
+    assert!(calendar.contains("LOCATION:Main Hall\r\n"));
+    assert!(!calendar.contains("Unconfirmed Talk"));
+
+    let (status, calendar) = ctx
+        .request_text(get(format!("/api/tracks/{}/schedule.ics", workshop)))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 0);
+
+    let (status, calendar) = ctx
+        .request_text(get(format!(
+            "/api/speakers/{}/schedule.ics?conference_id={}",
+            speaker_id, conference_id
+        )))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(calendar.contains("X-WR-CALNAME:Calendar Conference - Speaker User\r\n"));
+    assert_eq!(calendar.matches("BEGIN:VEVENT").count(), 1);
+
+    let (status, _) = ctx
+        .request_text(get(format!(
+            "/api/speakers/{}/schedule.ics",
+            uuid::Uuid::new_v4()
+        )))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Any existing user who has nothing on the schedule is not a speaker
+    let attendee_id = create_test_user(
+        &ctx.db,
+        "attendee@example.com",
+        "attendee",
+        "password",
+        "Attendee User",
+        false,
+    )
+    .await;
+    let (status, calendar) = ctx
+        .request_text(get(format!(
+            "/api/speakers/{}/schedule.ics?conference_id={}",
+            attendee_id, conference_id
+        )))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(!calendar.contains("Attendee User"));
+
+    ctx.cleanup().await;
+}
+
+// ============================================================================
+// Frab Export Tests
+// ============================================================================
+
+#[tokio::test]
+#[serial]
+async fn test_frab_schedule_export() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "Frab Conference").await;
+    let main_hall = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let talk_id =
+        create_test_talk_in_conference(&ctx.db, conference_id, speaker_id, "Rust & You", "Summary")
+            .await;
+    // Still waiting for its speaker to confirm, so not published
+    let pending_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Unconfirmed Talk",
+        "Summary",
+    )
+    .await;
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE talks SET state = 'pending' WHERE id = $1")
+        .bind(pending_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+        VALUES ($1, $2, $3, '2025-04-19', '10:30', '11:15'),
+               ($1, $2, $4, '2025-04-18', '09:00', '10:00')
+        "#,
+    )
+    .bind(conference_id)
+    .bind(main_hall)
+    .bind(talk_id)
+    .bind(pending_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let (status, response) = ctx
+        .request(
+            Request::builder()
+                .uri(format!(
+                    "/api/schedule.json?conference_id={}",
+                    conference_id
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let conference = &response["schedule"]["conference"];
+    assert_eq!(conference["title"], "Frab Conference");
+    assert_eq!(conference["daysCount"], 3);
+    assert_eq!(conference["rooms"][0]["name"], "Main Hall");
+    assert!(conference["days"][0]["rooms"]["Main Hall"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    let event = &conference["days"][1]["rooms"]["Main Hall"][0];
+    assert_eq!(event["guid"], talk_id.to_string());
+    assert_eq!(event["date"], "2025-04-19T10:30:00-05:00");
+    assert_eq!(event["duration"], "00:45");
+    assert_eq!(event["abstract"], "Summary");
+    assert_eq!(event["persons"][0]["public_name"], "Speaker User");
+
+    let (status, xml) = ctx
+        .request_text(
+            Request::builder()
+                .uri(format!("/api/schedule.xml?conference_id={}", conference_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(xml.contains("<title>Rust &amp; You</title>"));
+    assert!(xml.contains("<day index=\"2\" date=\"2025-04-19\""));
+    assert!(!xml.contains("Unconfirmed Talk"));
+
+    ctx.cleanup().await;
+}
@@ -22,6 +22,7 @@ async fn test_create_talk_success() {
     )
     .await;
 
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
     let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
 
     let req = Request::builder()
@@ -32,7 +33,7 @@ async fn test_create_talk_success() {
         .body(Body::from(
             json!({
                 "title": "Introduction to Rust",
-                "short_summary": "Learn the basics of Rust programming",
+                "short_summary": "Learn the basics of Rust programming, from ownership to cargo",
                 "long_description": "This talk covers Rust fundamentals including ownership, borrowing, and lifetimes.",
                 "label_ids": []
             })
@@ -47,9 +48,10 @@ async fn test_create_talk_success() {
     assert_eq!(response["title"], "Introduction to Rust");
     assert_eq!(
         response["short_summary"],
-        "Learn the basics of Rust programming"
+        "Learn the basics of Rust programming, from ownership to cargo"
     );
     assert_eq!(response["speaker_id"], user_id.to_string());
+    assert_eq!(response["conference_id"], conference_id.to_string());
     assert_eq!(response["state"], "submitted");
 
     ctx.cleanup().await;
@@ -67,7 +69,7 @@ async fn test_create_talk_without_auth() {
         .body(Body::from(
             json!({
                 "title": "Introduction to Rust",
-                "short_summary": "Learn the basics of Rust programming"
+                "short_summary": "Learn the basics of Rust programming, from ownership to cargo"
             })
             .to_string(),
         ))
@@ -157,6 +159,47 @@ async fn test_list_my_talks() {
     ctx.cleanup().await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_list_my_talks_filtered_by_conference() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+
+    let conf_2024 = create_test_conference(&ctx.db, "TXLF 2024").await;
+    let conf_2025 = create_test_conference(&ctx.db, "TXLF 2025").await;
+
+    create_test_talk_in_conference(&ctx.db, conf_2024, user_id, "Old Talk", "Summary").await;
+    create_test_talk_in_conference(&ctx.db, conf_2025, user_id, "New Talk", "Summary").await;
+
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/talks/mine?conference_id={}", conf_2025))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+
+    let (status, response) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let talks = response["talks"].as_array().unwrap();
+    assert_eq!(talks.len(), 1);
+    assert_eq!(talks[0]["title"], "New Talk");
+    assert_eq!(talks[0]["conference_id"], conf_2025.to_string());
+
+    ctx.cleanup().await;
+}
+
 #[tokio::test]
 #[serial]
 async fn test_get_talk_by_id() {
@@ -193,6 +236,156 @@ async fn test_get_talk_by_id() {
     ctx.cleanup().await;
 }
 
+#[tokio::test]
+#[serial]
+async fn test_get_talk_hides_speakers_under_blind_review() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+
+    let conference_id = create_test_conference(&ctx.db, "Blind Conf").await;
+    sqlx::query("UPDATE conferences SET blind_review = true WHERE id = $1")
+        .bind(conference_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Blind Talk",
+        "A summary",
+    )
+    .await;
+
+    let organizer_token =
+        generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+    let speaker_token =
+        generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let get_talk = |token: &str| {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Reviewers don't see who submitted the talk
+    let (status, response) = ctx.request(get_talk(&organizer_token)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["speakers_hidden"], true);
+    assert_eq!(response["speaker_name"], "Anonymous speaker");
+    assert_eq!(response["speaker_id"], uuid::Uuid::nil().to_string());
+    assert_eq!(response["speakers"].as_array().unwrap().len(), 0);
+
+    // The speaker still sees their own talk in full
+    let (status, response) = ctx.request(get_talk(&speaker_token)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["speakers_hidden"], false);
+    assert_eq!(response["speakers"][0]["full_name"], "Speaker User");
+
+    // Once revealed, the organizer sees the speakers too
+    sqlx::query("INSERT INTO speaker_identity_reveals (talk_id, user_id) VALUES ($1, $2)")
+        .bind(talk_id)
+        .bind(organizer_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let (status, response) = ctx.request(get_talk(&organizer_token)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["speakers_hidden"], false);
+    assert_eq!(response["speaker_id"], speaker_id.to_string());
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_get_talk_shows_only_released_feedback() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let talk_id = create_test_talk(&ctx.db, speaker_id, "Feedback Talk", "A summary").await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO speaker_feedback (talk_id, author_id, body, approved_by, approved_at)
+        VALUES ($1, $2, 'Approved feedback', $2, NOW()), ($1, $2, 'Draft feedback', NULL, NULL)
+        "#,
+    )
+    .bind(talk_id)
+    .bind(organizer_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let token = generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let get_talk = || {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    // Nothing is released before the talk is decided
+    let (status, response) = ctx.request(get_talk()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["feedback"].as_array().unwrap().len(), 0);
+
+    sqlx::query("UPDATE talks SET state = 'rejected' WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+
+    // Only approved feedback reaches the speaker, without its author
+    let (status, response) = ctx.request(get_talk()).await;
+    assert_eq!(status, StatusCode::OK);
+    let feedback = response["feedback"].as_array().unwrap();
+    assert_eq!(feedback.len(), 1);
+    assert_eq!(feedback[0]["body"], "Approved feedback");
+    assert!(feedback[0].get("author_id").is_none());
+
+    ctx.cleanup().await;
+}
+
 #[tokio::test]
 #[serial]
 async fn test_update_talk() {
@@ -220,7 +413,7 @@ async fn test_update_talk() {
         .body(Body::from(
             json!({
                 "title": "Updated Title",
-                "short_summary": "Updated Summary",
+                "short_summary": "An updated summary that is long enough for the submission form",
                 "long_description": "Updated description"
             })
             .to_string(),
@@ -231,7 +424,10 @@ async fn test_update_talk() {
 
     assert_eq!(status, StatusCode::OK);
     assert_eq!(response["title"], "Updated Title");
-    assert_eq!(response["short_summary"], "Updated Summary");
+    assert_eq!(
+        response["short_summary"],
+        "An updated summary that is long enough for the submission form"
+    );
     assert_eq!(response["long_description"], "Updated description");
 
     ctx.cleanup().await;
@@ -656,3 +852,2052 @@ async fn test_respond_to_talk_wrong_state() {
 
     ctx.cleanup().await;
 }
+
+#[tokio::test]
+#[serial]
+async fn test_co_speaker_invitation_grants_edit_rights() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let co_speaker_id = create_test_user(
+        &ctx.db,
+        "cospeaker@example.com",
+        "cospeaker",
+        "password",
+        "Co Speaker",
+        false,
+    )
+    .await;
+
+    let talk_id = create_test_talk(&ctx.db, speaker_id, "Pairing on Rust", "Two voices").await;
+    let speaker_token =
+        generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let co_speaker_token =
+        generate_test_token(&ctx.db, co_speaker_id, "cospeaker@example.com", false).await;
+
+    // Invite the co-speaker
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/talks/{}/speakers", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", speaker_token))
+        .body(Body::from(
+            json!({ "email": "cospeaker@example.com" }).to_string(),
+        ))
+        .unwrap();
+    let (status, response) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(response["status"], "invited");
+
+    // Before accepting, the invitee cannot edit the talk
+    let update_req = |token: &str| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({ "title": "Pairing on Rust, revised" }).to_string(),
+            ))
+            .unwrap()
+    };
+    let (status, _) = ctx.request(update_req(&co_speaker_token)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let invite_token: String =
+        sqlx::query_scalar("SELECT invite_token FROM talk_speakers WHERE talk_id = $1")
+            .bind(talk_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/speaker-invitations/{}/accept", invite_token))
+        .header("authorization", format!("Bearer {}", co_speaker_token))
+        .body(Body::empty())
+        .unwrap();
+    let (status, response) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["status"], "accepted");
+
+    // Now the co-speaker shares edit rights and sees both speakers
+    let (status, response) = ctx.request(update_req(&co_speaker_token)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["title"], "Pairing on Rust, revised");
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/talks/{}", talk_id))
+        .header("authorization", format!("Bearer {}", co_speaker_token))
+        .body(Body::empty())
+        .unwrap();
+    let (status, response) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    let speakers = response["speakers"].as_array().unwrap();
+    assert_eq!(speakers.len(), 2);
+    assert_eq!(speakers[0]["is_primary"], true);
+    assert_eq!(speakers[1]["full_name"], "Co Speaker");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_reinviting_accepted_co_speaker_is_refused() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let co_speaker_id = create_test_user(
+        &ctx.db,
+        "cospeaker@example.com",
+        "cospeaker",
+        "password",
+        "Co Speaker",
+        false,
+    )
+    .await;
+
+    let talk_id = create_test_talk(&ctx.db, speaker_id, "Pairing on Rust", "Two voices").await;
+    let speaker_token =
+        generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let co_speaker_token =
+        generate_test_token(&ctx.db, co_speaker_id, "cospeaker@example.com", false).await;
+
+    // Invited at an address that isn't their account email
+    let invite = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/speakers", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", speaker_token))
+            .body(Body::from(
+                json!({ "email": "alias@example.com" }).to_string(),
+            ))
+            .unwrap()
+    };
+    let (status, _) = ctx.request(invite()).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let invite_token: String =
+        sqlx::query_scalar("SELECT invite_token FROM talk_speakers WHERE talk_id = $1")
+            .bind(talk_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+    let accept = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/speaker-invitations/{}/accept", invite_token))
+            .header("authorization", format!("Bearer {}", co_speaker_token))
+            .body(Body::empty())
+            .unwrap()
+    };
+    let (status, _) = ctx.request(accept()).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = ctx.request(accept()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Inviting the same address again must not demote them
+    let (status, _) = ctx.request(invite()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let status: String =
+        sqlx::query_scalar("SELECT status::text FROM talk_speakers WHERE talk_id = $1")
+            .bind(talk_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+    assert_eq!(status, "accepted");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_talk_outside_cfp_window_requires_extension() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "late@example.com",
+        "late",
+        "password",
+        "Late Speaker",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "Closed Conf").await;
+    sqlx::query(
+        "UPDATE conferences SET submission_close = NOW() - INTERVAL '1 hour' WHERE id = $1",
+    )
+    .bind(conference_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+    let token = generate_test_token(&ctx.db, user_id, "late@example.com", false).await;
+
+    let submit = || {
+        Request::builder()
+            .method("POST")
+            .uri("/api/talks")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "conference_id": conference_id,
+                    "title": "Late Talk",
+                    "short_summary": "Submitted after the deadline, so it needs an extension first"
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+
+    let (status, response) = ctx.request(submit()).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(response["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("CfP closed"));
+
+    sqlx::query(
+        r#"
+        INSERT INTO submission_extensions (conference_id, user_id, extended_until)
+        VALUES ($1, $2, NOW() + INTERVAL '1 day')
+        "#,
+    )
+    .bind(conference_id)
+    .bind(user_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let (status, _) = ctx.request(submit()).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_talk_validates_duration_and_format() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    create_test_conference(&ctx.db, "TXLF 2025").await;
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    let submit = |duration: i32| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/talks")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "title": "Hands-on eBPF",
+                    "short_summary": "Write your first eBPF program and load it into a running kernel",
+                    "duration_minutes": duration,
+                    "format": "workshop"
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+
+    // 75 minutes is not one of the configured talk durations
+    let (status, _) = ctx.request(submit(75)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, response) = ctx.request(submit(90)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(response["duration_minutes"], 90);
+    assert_eq!(response["format"], "workshop");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_scheduled_talk_cannot_outgrow_its_slot() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let track_id = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        user_id,
+        "Hands-on eBPF",
+        "Write your first eBPF program and load it into a running kernel",
+    )
+    .await;
+    sqlx::query("UPDATE talks SET state = 'accepted', duration_minutes = 45 WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    sqlx::query(
+        r#"
+        INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+        VALUES ($1, $2, $3, '2025-04-18', '09:00', '10:00')
+        "#,
+    )
+    .bind(conference_id)
+    .bind(track_id)
+    .bind(talk_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    let update = |duration: i32| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({ "duration_minutes": duration }).to_string(),
+            ))
+            .unwrap()
+    };
+
+    // The hour-long slot can't hold 90 minutes
+    let (status, response) = ctx.request(update(90)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response["error"],
+        "Talk runs 90 minutes but the slot is only 60 minutes long"
+    );
+
+    let (status, response) = ctx.request(update(60)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["duration_minutes"], 60);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_talk_reports_field_errors() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    create_test_conference(&ctx.db, "TXLF 2025").await;
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    // Every field breaks the configured limits, so all are reported
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/talks")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "title": "Rust",
+                "short_summary": "Too short",
+                "duration_minutes": 75
+            })
+            .to_string(),
+        ))
+        .unwrap();
+    let (status, response) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let errors = response["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors[0]["field"], "title");
+    assert_eq!(errors[0]["code"], "too_short");
+    assert_eq!(errors[1]["field"], "short_summary");
+    assert_eq!(errors[1]["code"], "too_short");
+    assert_eq!(errors[2]["field"], "duration_minutes");
+    assert_eq!(errors[2]["code"], "invalid_range");
+    assert!(response["error"].as_str().unwrap().contains("Title"));
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_talk_edits_are_stored_as_revisions() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    create_test_conference(&ctx.db, "TXLF 2025").await;
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/talks")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "title": "Intro to Rust",
+                "short_summary": "Ownership and borrowing, explained with small runnable examples"
+            })
+            .to_string(),
+        ))
+        .unwrap();
+    let (status, talk) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let talk_id = talk["id"].as_str().unwrap().to_string();
+
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/talks/{}", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "short_summary": "Ownership, borrowing and lifetimes, explained with runnable examples" }).to_string(),
+        ))
+        .unwrap();
+    let (status, _) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/talks/{}/revisions", talk_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let (status, revisions) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    let revisions = revisions.as_array().unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(
+        revisions[0]["short_summary"],
+        "Ownership and borrowing, explained with small runnable examples"
+    );
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/talks/{}/revisions/diff?from=1&to=2", talk_id))
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let (status, diff) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    let changes = diff["changes"].as_array().unwrap();
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0]["field"], "short_summary");
+    assert_eq!(
+        changes[0]["new_value"],
+        "Ownership, borrowing and lifetimes, explained with runnable examples"
+    );
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_concurrent_edits_get_consecutive_revisions() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        user_id,
+        "Intro to Rust",
+        "Ownership and borrowing, explained with small runnable examples",
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    let edit = |n: usize| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({ "title": format!("Intro to Rust, take {}", n) }).to_string(),
+            ))
+            .unwrap()
+    };
+    let (first, second, third, fourth) = tokio::join!(
+        ctx.request(edit(1)),
+        ctx.request(edit(2)),
+        ctx.request(edit(3)),
+        ctx.request(edit(4)),
+    );
+    for (status, _) in [first, second, third, fourth] {
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let numbers = sqlx::query_scalar::<_, i32>(
+        "SELECT revision_number FROM talk_revisions WHERE talk_id = $1 ORDER BY revision_number",
+    )
+    .bind(talk_id)
+    .fetch_all(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(numbers, vec![1, 2, 3, 4]);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_talk_validates_custom_answers() {
+    let ctx = TestContext::new().await;
+
+    let user_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let token = generate_test_token(&ctx.db, user_id, "speaker@example.com", false).await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO custom_fields (conference_id, field_key, label, field_type, options, is_required)
+        VALUES ($1, 'target_audience', 'Target audience', 'select', ARRAY['Beginner', 'Advanced'], TRUE)
+        "#,
+    )
+    .bind(conference_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/api/conferences/{}/custom-fields", conference_id))
+        .body(Body::empty())
+        .unwrap();
+    let (status, fields) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(fields[0]["field_key"], "target_audience");
+
+    let submit = |answers: serde_json::Value| {
+        Request::builder()
+            .method("POST")
+            .uri("/api/talks")
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "title": "Intro to Rust",
+                    "short_summary": "Ownership and borrowing, explained with small runnable examples",
+                    "custom_answers": answers
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+
+    let (status, response) = ctx.request(submit(json!({}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response["errors"][0]["field"],
+        "custom_answers.target_audience"
+    );
+    assert_eq!(response["errors"][0]["code"], "required");
+
+    let (status, response) = ctx
+        .request(submit(json!({ "target_audience": "Everyone" })))
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response["errors"][0]["field"],
+        "custom_answers.target_audience"
+    );
+    assert_eq!(response["errors"][0]["code"], "invalid_format");
+
+    let (status, response) = ctx
+        .request(submit(json!({ "target_audience": "Beginner" })))
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(response["custom_answers"]["target_audience"], "Beginner");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_create_custom_field_checks_key() {
+    let ctx = TestContext::new().await;
+
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let token = generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    let create = |field_key: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/conferences/{}/custom-fields", conference_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({
+                    "field_key": field_key,
+                    "label": "Equipment",
+                    "field_type": "text"
+                })
+                .to_string(),
+            ))
+            .unwrap()
+    };
+
+    let (status, _) = ctx.request(create("Needs Projector")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // The key has to fit its column
+    let (status, _) = ctx.request(create(&"k".repeat(101))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, response) = ctx.request(create(&"k".repeat(100))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(response["field_key"], "k".repeat(100));
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_draft_talks_are_hidden_until_submitted() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let speaker_token =
+        generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let organizer_token =
+        generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO custom_fields (conference_id, field_key, label, field_type, is_required)
+        VALUES ($1, 'coc', 'I agree to the code of conduct', 'checkbox', TRUE)
+        "#,
+    )
+    .bind(conference_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    // Drafts may leave required questions unanswered
+    let req = Request::builder()
+        .method("POST")
+        .uri("/api/talks")
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", speaker_token))
+        .body(Body::from(
+            json!({
+                "title": "Intro to Rust",
+                "short_summary": "Ownership and borrowing, explained with small runnable examples",
+                "save_as_draft": true
+            })
+            .to_string(),
+        ))
+        .unwrap();
+    let (status, draft) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let talk_id = draft["id"].as_str().unwrap().to_string();
+
+    let talk_state = || async {
+        sqlx::query_scalar::<_, String>("SELECT state::text FROM talks WHERE id = $1::uuid")
+            .bind(&talk_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap()
+    };
+    assert_eq!(talk_state().await, "draft");
+
+    let get_talk = |token: &str| {
+        Request::builder()
+            .method("GET")
+            .uri(format!("/api/talks/{}", talk_id))
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+    let submit = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/submit", talk_id))
+            .header("authorization", format!("Bearer {}", speaker_token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let (status, _) = ctx.request(get_talk(&organizer_token)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Submitting enforces the required question
+    let (status, _) = ctx.request(submit()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/talks/{}", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", speaker_token))
+        .body(Body::from(
+            json!({ "custom_answers": { "coc": true } }).to_string(),
+        ))
+        .unwrap();
+    let (status, _) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = ctx.request(submit()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(talk_state().await, "submitted");
+
+    let (status, _) = ctx.request(submit()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = ctx.request(get_talk(&organizer_token)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_withdraw_accepted_talk_frees_schedule_slot() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let track_id = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Test Talk",
+        "Test Summary",
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let slot_id = sqlx::query_scalar::<_, uuid::Uuid>(
+        r#"
+        INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+        VALUES ($1, $2, $3, '2025-04-18', '09:00', '10:00')
+        RETURNING id
+        "#,
+    )
+    .bind(conference_id)
+    .bind(track_id)
+    .bind(talk_id)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+
+    let withdraw = |reason: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/withdraw", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "reason": reason }).to_string()))
+            .unwrap()
+    };
+
+    // A reason is required
+    let (status, _) = ctx.request(withdraw("  ")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, response) = ctx.request(withdraw("Can't travel that week")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["withdrawal_reason"], "Can't travel that week");
+    assert!(response["withdrawn_at"].is_string());
+
+    let (state, reason) = sqlx::query_as::<_, (String, Option<String>)>(
+        "SELECT state::text, withdrawal_reason FROM talks WHERE id = $1",
+    )
+    .bind(talk_id)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(state, "withdrawn");
+    assert_eq!(reason.as_deref(), Some("Can't travel that week"));
+
+    let slot_talk = sqlx::query_scalar::<_, Option<uuid::Uuid>>(
+        "SELECT talk_id FROM schedule_slots WHERE id = $1",
+    )
+    .bind(slot_id)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert!(slot_talk.is_none());
+
+    // Withdrawn is terminal
+    let (status, _) = ctx.request(withdraw("Again")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_concurrent_withdrawals_withdraw_once() {
+    let ctx = TestContext::new().await;
+
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let talk_id = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Test Talk",
+        "Test Summary",
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(talk_id)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+
+    let withdraw = |reason: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/withdraw", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "reason": reason }).to_string()))
+            .unwrap()
+    };
+    let ((first, _), (second, _)) = tokio::join!(
+        ctx.request(withdraw("Can't travel that week")),
+        ctx.request(withdraw("Double-booked")),
+    );
+
+    // The loser sees either the stale state (409) or the new one (400)
+    let statuses = [first, second];
+    assert_eq!(statuses.iter().filter(|s| **s == StatusCode::OK).count(), 1);
+    assert!(statuses.iter().all(|s| [
+        StatusCode::OK,
+        StatusCode::CONFLICT,
+        StatusCode::BAD_REQUEST
+    ]
+    .contains(s)));
+
+    ctx.cleanup().await;
+}
+
+/// Waitlist `count` talks of a fresh conference, ranked in creation order,
+/// and return the conference, the talks and an organizer token
+async fn waitlisted_talks(
+    ctx: &TestContext,
+    count: usize,
+) -> (uuid::Uuid, Vec<uuid::Uuid>, String) {
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let mut talk_ids = Vec::with_capacity(count);
+    for rank in 1..=count {
+        let talk_id = create_test_talk_in_conference(
+            &ctx.db,
+            conference_id,
+            speaker_id,
+            &format!("Backup Talk {}", rank),
+            "A talk kept in reserve in case an accepted one drops out",
+        )
+        .await;
+        sqlx::query("UPDATE talks SET state = 'waitlisted', waitlist_rank = $1 WHERE id = $2")
+            .bind(rank as i32)
+            .bind(talk_id)
+            .execute(&ctx.db)
+            .await
+            .unwrap();
+        talk_ids.push(talk_id);
+    }
+
+    (conference_id, talk_ids, token)
+}
+
+async fn waitlist_ranks(ctx: &TestContext, talk_ids: &[uuid::Uuid]) -> Vec<Option<i32>> {
+    let mut ranks = Vec::with_capacity(talk_ids.len());
+    for talk_id in talk_ids {
+        let rank =
+            sqlx::query_scalar::<_, Option<i32>>("SELECT waitlist_rank FROM talks WHERE id = $1")
+                .bind(talk_id)
+                .fetch_one(&ctx.db)
+                .await
+                .unwrap();
+        ranks.push(rank);
+    }
+    ranks
+}
+
+#[tokio::test]
+#[serial]
+async fn test_promote_waitlisted_talk() {
+    let ctx = TestContext::new().await;
+    let (conference_id, talks, token) = waitlisted_talks(&ctx, 3).await;
+
+    let track_id = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let other_speaker = create_test_user(
+        &ctx.db,
+        "other@example.com",
+        "other",
+        "password",
+        "Other Speaker",
+        false,
+    )
+    .await;
+    let scheduled = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        other_speaker,
+        "Keynote",
+        "The opening keynote of the conference, already on the schedule",
+    )
+    .await;
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(scheduled)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let slot = |talk_id: Option<uuid::Uuid>, start: &'static str, end: &'static str| {
+        sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+            VALUES ($1, $2, $3, '2025-04-18', $4::time, $5::time)
+            RETURNING id
+            "#,
+        )
+        .bind(conference_id)
+        .bind(track_id)
+        .bind(talk_id)
+        .bind(start)
+        .bind(end)
+        .fetch_one(&ctx.db)
+    };
+    let taken_slot = slot(Some(scheduled), "09:00", "10:00").await.unwrap();
+    let free_slot = slot(None, "10:00", "11:00").await.unwrap();
+
+    let promote = |talk_id: uuid::Uuid, slot_id: uuid::Uuid| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/promote", talk_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({ "target": "accepted", "slot_id": slot_id }).to_string(),
+            ))
+            .unwrap()
+    };
+
+    // A taken slot refuses the talk and nothing changes
+    let (status, _) = ctx.request(promote(talks[0], taken_slot)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(
+        waitlist_ranks(&ctx, &talks).await,
+        vec![Some(1), Some(2), Some(3)]
+    );
+    let holder = sqlx::query_scalar::<_, Option<uuid::Uuid>>(
+        "SELECT talk_id FROM schedule_slots WHERE id = $1",
+    )
+    .bind(taken_slot)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(holder, Some(scheduled));
+
+    // A free slot takes it; the talks below move up
+    let (status, response) = ctx.request(promote(talks[0], free_slot)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["state"], "Accepted");
+    assert!(response["waitlist_rank"].is_null());
+    assert_eq!(
+        waitlist_ranks(&ctx, &talks).await,
+        vec![None, Some(1), Some(2)]
+    );
+    let holder = sqlx::query_scalar::<_, Option<uuid::Uuid>>(
+        "SELECT talk_id FROM schedule_slots WHERE id = $1",
+    )
+    .bind(free_slot)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(holder, Some(talks[0]));
+
+    // Only waitlisted talks can be promoted
+    let (status, _) = ctx.request(promote(talks[0], free_slot)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_promote_into_conflicting_slot_is_refused() {
+    let ctx = TestContext::new().await;
+    let (conference_id, talks, token) = waitlisted_talks(&ctx, 1).await;
+    let speaker_id =
+        sqlx::query_scalar::<_, uuid::Uuid>("SELECT speaker_id FROM talks WHERE id = $1")
+            .bind(talks[0])
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+
+    // The same speaker already gives a talk in another track at that time
+    let main_hall = create_test_track(&ctx.db, conference_id, "Main Hall").await;
+    let side_room = create_test_track(&ctx.db, conference_id, "Side Room").await;
+    let scheduled = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Already Scheduled",
+        "A talk by the same speaker that is already on the schedule",
+    )
+    .await;
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(scheduled)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let slot = |track_id: uuid::Uuid, talk_id: Option<uuid::Uuid>| {
+        sqlx::query_scalar::<_, uuid::Uuid>(
+            r#"
+            INSERT INTO schedule_slots (conference_id, track_id, talk_id, slot_date, start_time, end_time)
+            VALUES ($1, $2, $3, '2025-04-18', '10:00', '11:00')
+            RETURNING id
+            "#,
+        )
+        .bind(conference_id)
+        .bind(track_id)
+        .bind(talk_id)
+        .fetch_one(&ctx.db)
+    };
+    slot(side_room, Some(scheduled)).await.unwrap();
+    let free_slot = slot(main_hall, None).await.unwrap();
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/talks/{}/promote", talks[0]))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "target": "accepted", "slot_id": free_slot }).to_string(),
+        ))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(talk_state(&ctx, talks[0]).await, "waitlisted");
+    let holder = sqlx::query_scalar::<_, Option<uuid::Uuid>>(
+        "SELECT talk_id FROM schedule_slots WHERE id = $1",
+    )
+    .bind(free_slot)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert!(holder.is_none());
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_reorder_waitlist() {
+    let ctx = TestContext::new().await;
+    let (conference_id, talks, token) = waitlisted_talks(&ctx, 3).await;
+
+    let reorder = |talk_ids: Vec<uuid::Uuid>| {
+        Request::builder()
+            .method("PUT")
+            .uri(format!("/api/conferences/{}/waitlist", conference_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "talk_ids": talk_ids }).to_string()))
+            .unwrap()
+    };
+
+    // Every waitlisted talk must be listed exactly once
+    let (status, _) = ctx.request(reorder(vec![talks[2], talks[0]])).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = ctx
+        .request(reorder(vec![talks[2], talks[0], talks[0]]))
+        .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, response) = ctx
+        .request(reorder(vec![talks[2], talks[0], talks[1]]))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let order: Vec<&str> = response["talks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            talks[2].to_string(),
+            talks[0].to_string(),
+            talks[1].to_string()
+        ]
+    );
+    assert_eq!(
+        waitlist_ranks(&ctx, &talks).await,
+        vec![Some(2), Some(3), Some(1)]
+    );
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_concurrent_promotions_promote_once() {
+    let ctx = TestContext::new().await;
+    let (_, talks, token) = waitlisted_talks(&ctx, 3).await;
+
+    let promote = |target: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/talks/{}/promote", talks[0]))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "target": target }).to_string()))
+            .unwrap()
+    };
+    let ((first, _), (second, _)) = tokio::join!(
+        ctx.request(promote("accepted")),
+        ctx.request(promote("pending")),
+    );
+
+    // The loser sees either the stale state (409) or the new one (400)
+    let statuses = [first, second];
+    assert_eq!(statuses.iter().filter(|s| **s == StatusCode::OK).count(), 1);
+    assert!(statuses.iter().all(|s| [
+        StatusCode::OK,
+        StatusCode::CONFLICT,
+        StatusCode::BAD_REQUEST
+    ]
+    .contains(s)));
+
+    // The gap was closed once
+    assert_eq!(
+        waitlist_ranks(&ctx, &talks).await,
+        vec![None, Some(1), Some(2)]
+    );
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_dropping_waitlisted_talk_closes_gap() {
+    let ctx = TestContext::new().await;
+    let (_, talks, token) = waitlisted_talks(&ctx, 3).await;
+
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/talks/{}/state", talks[1]))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "new_state": "Rejected" }).to_string()))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        waitlist_ranks(&ctx, &talks).await,
+        vec![Some(1), None, Some(2)]
+    );
+
+    ctx.cleanup().await;
+}
+
+/// Two submitted talks in a conference still under review, plus an
+/// organizer token
+async fn open_review_cycle(ctx: &TestContext) -> (uuid::Uuid, uuid::Uuid, uuid::Uuid, String) {
+    let speaker_id = create_test_user(
+        &ctx.db,
+        "speaker@example.com",
+        "speaker",
+        "password",
+        "Speaker User",
+        false,
+    )
+    .await;
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let token = generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true).await;
+
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+    let first = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Kernel Tracing",
+        "A tour of tracing the Linux kernel with eBPF and friends",
+    )
+    .await;
+    let second = create_test_talk_in_conference(
+        &ctx.db,
+        conference_id,
+        speaker_id,
+        "Packaging for Distros",
+        "How to get your project packaged by the major distributions",
+    )
+    .await;
+
+    (conference_id, first, second, token)
+}
+
+/// As [`open_review_cycle`], but the review and decision deadlines have both
+/// passed
+async fn closed_review_cycle(ctx: &TestContext) -> (uuid::Uuid, uuid::Uuid, uuid::Uuid, String) {
+    let (conference_id, first, second, token) = open_review_cycle(ctx).await;
+
+    sqlx::query(
+        r#"
+        UPDATE conferences
+        SET submission_open = NOW() - INTERVAL '30 days',
+            submission_close = NOW() - INTERVAL '20 days',
+            review_deadline = NOW() - INTERVAL '10 days',
+            decision_deadline = NOW() - INTERVAL '5 days'
+        WHERE id = $1
+        "#,
+    )
+    .bind(conference_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    (conference_id, first, second, token)
+}
+
+#[tokio::test]
+#[serial]
+async fn test_ratings_locked_after_review_deadline() {
+    let ctx = TestContext::new().await;
+    let (conference_id, talk_id, _, token) = closed_review_cycle(&ctx).await;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/talks/{}/rate", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "rating": 4 }).to_string()))
+        .unwrap();
+
+    let (status, response) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(response["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("Reviews closed at"));
+
+    // Clearing the deadlines reopens reviewing
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/conferences/{}", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "review_deadline": null, "decision_deadline": null }).to_string(),
+        ))
+        .unwrap();
+
+    let (status, response) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert!(response["review_deadline"].is_null());
+    assert!(response["decision_deadline"].is_null());
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/talks/{}/rate", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "rating": 4 }).to_string()))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_change_talk_state_locked_after_decision_deadline() {
+    let ctx = TestContext::new().await;
+    let (_, talk_id, _, token) = closed_review_cycle(&ctx).await;
+
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/talks/{}/state", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "new_state": "Accepted" }).to_string()))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let state = sqlx::query_scalar::<_, String>("SELECT state::text FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(state, "submitted");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_bulk_decision_locked_after_decision_deadline() {
+    let ctx = TestContext::new().await;
+    let (conference_id, first, second, token) = closed_review_cycle(&ctx).await;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/conferences/{}/decisions", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({
+                "decisions": [
+                    { "talk_id": first, "from_state": "Submitted", "to_state": "Accepted" },
+                    { "talk_id": second, "from_state": "Submitted", "to_state": "Rejected" }
+                ]
+            })
+            .to_string(),
+        ))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let changed = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM talks WHERE conference_id = $1 AND state <> 'submitted'",
+    )
+    .bind(conference_id)
+    .fetch_one(&ctx.db)
+    .await
+    .unwrap();
+    assert_eq!(changed, 0);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_comparisons_locked_after_review_deadline() {
+    let ctx = TestContext::new().await;
+    let (conference_id, first, second, token) = closed_review_cycle(&ctx).await;
+
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/api/conferences/{}/comparisons", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "winner_id": first, "loser_id": second }).to_string(),
+        ))
+        .unwrap();
+
+    let (status, _) = ctx.request(req).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let recorded = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM pairwise_comparisons")
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(recorded, 0);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_assign_reviewers_twice_adds_nothing_new() {
+    let ctx = TestContext::new().await;
+    let (conference_id, _, _, token) = open_review_cycle(&ctx).await;
+    create_test_user(
+        &ctx.db,
+        "reviewer@example.com",
+        "reviewer",
+        "password",
+        "Second Reviewer",
+        true,
+    )
+    .await;
+
+    let assign = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!(
+                "/api/conferences/{}/review-assignments",
+                conference_id
+            ))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(json!({ "reviews_per_talk": 2 }).to_string()))
+            .unwrap()
+    };
+
+    let (status, response) = ctx.request(assign()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["new_assignments"], 4);
+    assert_eq!(response["understaffed_talk_ids"], json!([]));
+
+    let (status, response) = ctx.request(assign()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["new_assignments"], 0);
+    assert_eq!(response["understaffed_talk_ids"], json!([]));
+
+    let assignments = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM review_assignments")
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(assignments, 4);
+
+    ctx.cleanup().await;
+}
+
+/// Three submitted talks whose speakers have different email domains, and
+/// two organizers who can review them
+struct ConflictFixture {
+    conference_id: uuid::Uuid,
+    /// By ada@acme.org, bob@other.org and cy@partner.com
+    talks: [uuid::Uuid; 3],
+    ada_id: uuid::Uuid,
+    organizer_token: String,
+    reviewer_id: uuid::Uuid,
+    reviewer_token: String,
+}
+
+async fn conflict_fixture(ctx: &TestContext) -> ConflictFixture {
+    let conference_id = create_test_conference(&ctx.db, "TXLF 2025").await;
+
+    let mut speakers = Vec::new();
+    for (email, username, name) in [
+        ("ada@acme.org", "ada", "Ada Speaker"),
+        ("bob@other.org", "bob", "Bob Speaker"),
+        ("cy@partner.com", "cy", "Cy Speaker"),
+    ] {
+        speakers.push(create_test_user(&ctx.db, email, username, "password", name, false).await);
+    }
+    let mut talks = [uuid::Uuid::nil(); 3];
+    for (talk, speaker_id) in talks.iter_mut().zip(&speakers) {
+        *talk = create_test_talk_in_conference(
+            &ctx.db,
+            conference_id,
+            *speaker_id,
+            "Conflicted Talk",
+            "A talk that one of the reviewers may have a conflict of interest with",
+        )
+        .await;
+    }
+
+    let organizer_id = create_test_user(
+        &ctx.db,
+        "organizer@example.com",
+        "organizer",
+        "password",
+        "Organizer User",
+        true,
+    )
+    .await;
+    let reviewer_id = create_test_user(
+        &ctx.db,
+        "reviewer@example.com",
+        "reviewer",
+        "password",
+        "Conflicted Reviewer",
+        true,
+    )
+    .await;
+
+    ConflictFixture {
+        conference_id,
+        talks,
+        ada_id: speakers[0],
+        organizer_token: generate_test_token(&ctx.db, organizer_id, "organizer@example.com", true)
+            .await,
+        reviewer_id,
+        reviewer_token: generate_test_token(&ctx.db, reviewer_id, "reviewer@example.com", true)
+            .await,
+    }
+}
+
+fn rate_request(talk_id: uuid::Uuid, token: &str, rating: i32) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!("/api/talks/{}/rate", talk_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "rating": rating, "notes": format!("Worth a {}", rating) }).to_string(),
+        ))
+        .unwrap()
+}
+
+/// Declare one conflict per kind for the fixture's reviewer: with Ada, with
+/// Bob's talk and with Cy's email domain
+async fn declare_conflicts(ctx: &TestContext, fixture: &ConflictFixture) {
+    for body in [
+        json!({ "speaker_id": fixture.ada_id }),
+        json!({ "talk_id": fixture.talks[1] }),
+        json!({ "email_domain": "@Partner.com" }),
+    ] {
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/reviews/conflicts")
+            .header("content-type", "application/json")
+            .header(
+                "authorization",
+                format!("Bearer {}", fixture.reviewer_token),
+            )
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let (status, _) = ctx.request(req).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conflicted_reviewer_cannot_rate_and_is_left_out() {
+    let ctx = TestContext::new().await;
+    let fixture = conflict_fixture(&ctx).await;
+
+    // Both organizers rate the first two talks before any conflict exists
+    for talk_id in &fixture.talks[..2] {
+        let (status, _) = ctx
+            .request(rate_request(*talk_id, &fixture.organizer_token, 3))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = ctx
+            .request(rate_request(*talk_id, &fixture.reviewer_token, 5))
+            .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    declare_conflicts(&ctx, &fixture).await;
+
+    // Updating the existing ratings and creating a new one are refused for
+    // conflicts by speaker, talk and email domain alike
+    for talk_id in fixture.talks {
+        let (status, _) = ctx
+            .request(rate_request(talk_id, &fixture.reviewer_token, 1))
+            .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    // The earlier ratings and their notes no longer show up
+    for talk_id in &fixture.talks[..2] {
+        let req = Request::builder()
+            .method("GET")
+            .uri(format!("/api/talks/{}/ratings", talk_id))
+            .header(
+                "authorization",
+                format!("Bearer {}", fixture.organizer_token),
+            )
+            .body(Body::empty())
+            .unwrap();
+        let (status, response) = ctx.request(req).await;
+        assert_eq!(status, StatusCode::OK);
+        let ratings = response.as_array().unwrap();
+        assert_eq!(ratings.len(), 1);
+        assert_eq!(ratings[0]["rating"], 3);
+        assert_eq!(ratings[0]["notes"], "Worth a 3");
+        assert!(!response.to_string().contains("Worth a 5"));
+    }
+
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/ratings/statistics?conference_id={}",
+            fixture.conference_id
+        ))
+        .header(
+            "authorization",
+            format!("Bearer {}", fixture.organizer_token),
+        )
+        .body(Body::empty())
+        .unwrap();
+    let (status, response) = ctx.request(req).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["total_ratings"], 2);
+    for talk_id in &fixture.talks[..2] {
+        let stats = response["talk_stats"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["talk_id"] == talk_id.to_string())
+            .unwrap();
+        assert_eq!(stats["rating_count"], 1);
+        assert_eq!(stats["ratings"], json!([3]));
+    }
+
+    // The ratings themselves are kept
+    let stored =
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM ratings WHERE organizer_id = $1")
+            .bind(fixture.reviewer_id)
+            .fetch_one(&ctx.db)
+            .await
+            .unwrap();
+    assert_eq!(stored, 2);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_conflicted_reviewer_is_never_assigned() {
+    let ctx = TestContext::new().await;
+    let fixture = conflict_fixture(&ctx).await;
+
+    let assign = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!(
+                "/api/conferences/{}/review-assignments",
+                fixture.conference_id
+            ))
+            .header("content-type", "application/json")
+            .header(
+                "authorization",
+                format!("Bearer {}", fixture.organizer_token),
+            )
+            .body(Body::from(json!({ "reviews_per_talk": 2 }).to_string()))
+            .unwrap()
+    };
+    let reviewer_assignments = || {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM review_assignments WHERE reviewer_id = $1",
+        )
+        .bind(fixture.reviewer_id)
+        .fetch_one(&ctx.db)
+    };
+
+    let (status, response) = ctx.request(assign()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["new_assignments"], 6);
+    assert_eq!(reviewer_assignments().await.unwrap(), 3);
+
+    // Each declaration takes the reviewer off the talk it covers
+    declare_conflicts(&ctx, &fixture).await;
+    assert_eq!(reviewer_assignments().await.unwrap(), 0);
+
+    // Assigning again can't give the talks their second reviewer
+    let (status, response) = ctx.request(assign()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["new_assignments"], 0);
+    assert_eq!(reviewer_assignments().await.unwrap(), 0);
+    let mut understaffed: Vec<String> = response["understaffed_talk_ids"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_str().unwrap().to_string())
+        .collect();
+    understaffed.sort();
+    let mut expected: Vec<String> = fixture.talks.iter().map(|id| id.to_string()).collect();
+    expected.sort();
+    assert_eq!(understaffed, expected);
+
+    ctx.cleanup().await;
+}
+
+fn preview_decision_request(
+    conference_id: uuid::Uuid,
+    token: &str,
+    selected: uuid::Uuid,
+) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri(format!(
+            "/api/conferences/{}/decisions/preview",
+            conference_id
+        ))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(
+            json!({ "talk_ids": [selected], "others": "Rejected" }).to_string(),
+        ))
+        .unwrap()
+}
+
+/// Commit the changes a preview planned, as the bulk decision page does
+fn commit_decision_request(
+    conference_id: uuid::Uuid,
+    token: &str,
+    preview: &serde_json::Value,
+) -> Request<Body> {
+    let decisions: Vec<serde_json::Value> = preview["decisions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| !d["to_state"].is_null())
+        .map(|d| {
+            json!({
+                "talk_id": d["talk_id"],
+                "from_state": d["from_state"],
+                "to_state": d["to_state"],
+            })
+        })
+        .collect();
+    Request::builder()
+        .method("POST")
+        .uri(format!("/api/conferences/{}/decisions", conference_id))
+        .header("content-type", "application/json")
+        .header("authorization", format!("Bearer {}", token))
+        .body(Body::from(json!({ "decisions": decisions }).to_string()))
+        .unwrap()
+}
+
+async fn talk_state(ctx: &TestContext, talk_id: uuid::Uuid) -> String {
+    sqlx::query_scalar::<_, String>("SELECT state::text FROM talks WHERE id = $1")
+        .bind(talk_id)
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_bulk_decision_commits_previewed_changes() {
+    let ctx = TestContext::new().await;
+    let (conference_id, selected, other, token) = open_review_cycle(&ctx).await;
+
+    let (status, preview) = ctx
+        .request(preview_decision_request(conference_id, &token, selected))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // One email per changed talk, addressed to its speaker
+    let mut emails: Vec<(String, String, String)> = preview["emails"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["talk_id"].as_str().unwrap().to_string(),
+                e["template_type"].as_str().unwrap().to_string(),
+                e["to"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    emails.sort();
+    let mut expected = vec![
+        (
+            selected.to_string(),
+            "talk_pending".to_string(),
+            "speaker@example.com".to_string(),
+        ),
+        (
+            other.to_string(),
+            "talk_rejected".to_string(),
+            "speaker@example.com".to_string(),
+        ),
+    ];
+    expected.sort();
+    assert_eq!(emails, expected);
+    assert_eq!(preview["emails_enabled"], false);
+
+    // Previewing changes nothing
+    assert_eq!(talk_state(&ctx, selected).await, "submitted");
+    assert_eq!(talk_state(&ctx, other).await, "submitted");
+
+    let (status, response) = ctx
+        .request(commit_decision_request(conference_id, &token, &preview))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response["talks"].as_array().unwrap().len(), 2);
+    assert_eq!(talk_state(&ctx, selected).await, "pending");
+    assert_eq!(talk_state(&ctx, other).await, "rejected");
+
+    // Email isn't configured in tests, so nothing went out
+    let sent = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM email_logs")
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(sent, 0);
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_bulk_decision_with_stale_state_commits_nothing() {
+    let ctx = TestContext::new().await;
+    let (conference_id, selected, other, token) = open_review_cycle(&ctx).await;
+
+    let (status, preview) = ctx
+        .request(preview_decision_request(conference_id, &token, selected))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    // The selected talk is applied first, so the conflict has to undo it
+    assert_eq!(preview["decisions"][0]["talk_id"], selected.to_string());
+
+    // The second talk moves on after the preview
+    sqlx::query("UPDATE talks SET state = 'waitlisted', waitlist_rank = 1 WHERE id = $1")
+        .bind(other)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+
+    let (status, _) = ctx
+        .request(commit_decision_request(conference_id, &token, &preview))
+        .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // The first talk's change was rolled back with the rest
+    assert_eq!(talk_state(&ctx, selected).await, "submitted");
+    assert_eq!(talk_state(&ctx, other).await, "waitlisted");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_bulk_decision_emails_carry_approved_feedback() {
+    let ctx = TestContext::new().await;
+    let (conference_id, selected, other, token) = open_review_cycle(&ctx).await;
+
+    sqlx::query(
+        r#"
+        INSERT INTO email_templates (conference_id, template_type, name, subject, body)
+        VALUES ($1, 'talk_rejected', 'Rejected', 'About {{talk_title}}', 'Feedback: {{feedback}}')
+        "#,
+    )
+    .bind(conference_id)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+    // Approved while the talk is still submitted, so not released yet
+    sqlx::query(
+        r#"
+        INSERT INTO speaker_feedback (talk_id, author_id, body, approved_by, approved_at)
+        SELECT $1, id, 'Tighten the abstract', id, NOW()
+        FROM users WHERE email = 'organizer@example.com'
+        "#,
+    )
+    .bind(other)
+    .execute(&ctx.db)
+    .await
+    .unwrap();
+
+    let (status, preview) = ctx
+        .request(preview_decision_request(conference_id, &token, selected))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let email = preview["emails"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["talk_id"] == other.to_string())
+        .unwrap();
+    assert_eq!(email["subject"], "About Packaging for Distros");
+    assert_eq!(email["body"], "Feedback: Tighten the abstract");
+
+    let (status, _) = ctx
+        .request(commit_decision_request(conference_id, &token, &preview))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The sent email is rendered from the committed talk, which now releases
+    // the same feedback to its speaker
+    let speaker_id = sqlx::query_scalar::<_, uuid::Uuid>("SELECT id FROM users WHERE email = $1")
+        .bind("speaker@example.com")
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    let speaker_token =
+        generate_test_token(&ctx.db, speaker_id, "speaker@example.com", false).await;
+    let (status, talk) = ctx
+        .request(
+            Request::builder()
+                .uri(format!("/api/talks/{}", other))
+                .header("authorization", format!("Bearer {}", speaker_token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(talk["feedback"][0]["body"], "Tighten the abstract");
+
+    ctx.cleanup().await;
+}
+
+#[tokio::test]
+#[serial]
+async fn test_comparisons_only_between_submitted_talks() {
+    let ctx = TestContext::new().await;
+    let (conference_id, first, second, token) = open_review_cycle(&ctx).await;
+
+    let compare = || {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/api/conferences/{}/comparisons", conference_id))
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(
+                json!({ "winner_id": first, "loser_id": second }).to_string(),
+            ))
+            .unwrap()
+    };
+
+    let (status, _) = ctx.request(compare()).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // A decided talk is no longer offered, so it can't be compared either
+    sqlx::query("UPDATE talks SET state = 'accepted' WHERE id = $1")
+        .bind(second)
+        .execute(&ctx.db)
+        .await
+        .unwrap();
+    let (status, _) = ctx.request(compare()).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let recorded = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM pairwise_comparisons")
+        .fetch_one(&ctx.db)
+        .await
+        .unwrap();
+    assert_eq!(recorded, 1);
+
+    ctx.cleanup().await;
+}